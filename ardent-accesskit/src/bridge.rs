@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use accesskit::{Tree, TreeUpdate};
+use ardent_core::node::NodeId;
+use ardent_core::scene::{ChangeObserverId, Scene, SceneChange};
+
+use crate::convert;
+
+/// Mirrors a scene as an AccessKit tree and produces incremental updates.
+///
+/// Every scene node becomes an AccessKit node with the same ID. The bridge
+/// registers a change observer on the scene, so updates are only produced
+/// after [`Scene::notify_observers`] ran, and only for nodes whose
+/// accessible properties, children, transform, or bounds changed.
+pub struct AccessibilityBridge {
+    state: Arc<Mutex<BridgeState>>,
+    observer: ChangeObserverId,
+}
+
+/// The tree as last sent to the adapter, plus the nodes changed since.
+struct BridgeState {
+    root: NodeId,
+    nodes: HashMap<NodeId, accesskit::Node>,
+    pending: HashMap<NodeId, accesskit::Node>,
+    focus: NodeId,
+    focus_changed: bool,
+}
+
+impl AccessibilityBridge {
+    /// Builds the accessibility tree of `scene` and starts observing it.
+    pub fn new(scene: &mut Scene) -> Self {
+        let root = scene.root();
+        let mut nodes = HashMap::new();
+        scene.traverse(|node| {
+            nodes.insert(node.id(), convert::node(node));
+        });
+
+        let state = Arc::new(Mutex::new(BridgeState {
+            root,
+            nodes,
+            pending: HashMap::new(),
+            focus: root,
+            focus_changed: false,
+        }));
+        let observer = {
+            let state = state.clone();
+            scene.observe(move |scene, changes| lock(&state).apply(scene, changes))
+        };
+
+        Self { state, observer }
+    }
+
+    /// Returns the complete tree, e.g. when the platform adapter is
+    /// activated by an assistive technology.
+    ///
+    /// Pending changes are included, so they are not sent again.
+    pub fn initial_tree(&self) -> TreeUpdate {
+        let mut state = lock(&self.state);
+        let pending = std::mem::take(&mut state.pending);
+        state.nodes.extend(pending);
+        state.focus_changed = false;
+
+        TreeUpdate {
+            nodes: state
+                .nodes
+                .iter()
+                .map(|(&id, node)| (convert::node_id(id), node.clone()))
+                .collect(),
+            tree: Some(Tree::new(convert::node_id(state.root))),
+            focus: convert::node_id(state.focus),
+        }
+    }
+
+    /// Returns the nodes changed since the last update, or `None` if
+    /// nothing changed.
+    pub fn take_update(&self) -> Option<TreeUpdate> {
+        let mut state = lock(&self.state);
+        if state.pending.is_empty() && !state.focus_changed {
+            return None;
+        }
+        state.focus_changed = false;
+
+        let pending = std::mem::take(&mut state.pending);
+        let nodes = pending
+            .iter()
+            .map(|(&id, node)| (convert::node_id(id), node.clone()))
+            .collect();
+        state.nodes.extend(pending);
+
+        Some(TreeUpdate {
+            nodes,
+            tree: None,
+            focus: convert::node_id(state.focus),
+        })
+    }
+
+    /// Moves the accessibility focus to the given node, e.g. when a text
+    /// field gains keyboard focus.
+    pub fn set_focus(&self, node: NodeId) {
+        let mut state = lock(&self.state);
+        if state.focus != node {
+            state.focus = node;
+            state.focus_changed = true;
+        }
+    }
+
+    /// Stops observing the scene.
+    pub fn detach(self, scene: &mut Scene) {
+        scene.remove_observer(self.observer);
+    }
+}
+
+impl BridgeState {
+    /// Queues updated nodes for the changes reported by the scene.
+    fn apply(&mut self, scene: &Scene, changes: &[SceneChange]) {
+        for change in changes {
+            match *change {
+                SceneChange::Added(id) | SceneChange::Modified(id) => {
+                    let Some(node) = scene.get_node(id) else {
+                        continue;
+                    };
+                    let node = convert::node(node);
+                    if self.nodes.get(&id) != Some(&node) {
+                        self.pending.insert(id, node);
+                    } else {
+                        self.pending.remove(&id);
+                    }
+                }
+                SceneChange::Removed(id) => {
+                    // Removed nodes disappear with their parent's children.
+                    self.nodes.remove(&id);
+                    self.pending.remove(&id);
+                    if self.focus == id {
+                        self.focus = self.root;
+                        self.focus_changed = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn lock(state: &Mutex<BridgeState>) -> MutexGuard<'_, BridgeState> {
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,88 @@
+use accesskit::{Action, Affine, Rect};
+use ardent_core::node::{Node, NodeId, Role};
+use ardent_core::transform::Transform;
+
+/// Converts a scene node ID into an AccessKit node ID.
+pub(crate) fn node_id(id: NodeId) -> accesskit::NodeId {
+    accesskit::NodeId(id.0)
+}
+
+/// Builds the AccessKit node describing `node`.
+///
+/// Bounds are given in the node's local coordinates, with its transform
+/// relative to the parent, so moving a node does not touch its
+/// descendants.
+pub(crate) fn node(node: &Node) -> accesskit::Node {
+    let mut out = accesskit::Node::new(role(node.role()));
+    out.set_children(
+        node.children()
+            .iter()
+            .map(|&child| node_id(child))
+            .collect::<Vec<_>>(),
+    );
+
+    if let Some(label) = node.label() {
+        out.set_label(label);
+    }
+    if let Some(value) = node.value() {
+        out.set_value(value);
+    }
+    if let Some(tooltip) = node.tooltip() {
+        out.set_description(tooltip);
+    }
+
+    let transform = node.transform();
+    if *transform != Transform::default() {
+        out.set_transform(affine(transform));
+    }
+    if let Some(bounds) = bounds(node) {
+        out.set_bounds(bounds);
+    }
+
+    match node.role() {
+        Role::Button | Role::CheckBox | Role::Link => out.add_action(Action::Click),
+        Role::TextInput | Role::Slider => out.add_action(Action::Focus),
+        _ => {}
+    }
+    out
+}
+
+fn role(role: Role) -> accesskit::Role {
+    match role {
+        Role::Generic => accesskit::Role::GenericContainer,
+        Role::Group => accesskit::Role::Group,
+        Role::Button => accesskit::Role::Button,
+        Role::CheckBox => accesskit::Role::CheckBox,
+        Role::Slider => accesskit::Role::Slider,
+        Role::TextInput => accesskit::Role::TextInput,
+        Role::Label => accesskit::Role::Label,
+        Role::Image => accesskit::Role::Image,
+        Role::Link => accesskit::Role::Link,
+        Role::List => accesskit::Role::List,
+        Role::ListItem => accesskit::Role::ListItem,
+    }
+}
+
+/// Returns the affine matrix of a transform: scale, then rotate clockwise,
+/// then translate.
+fn affine(transform: &Transform) -> Affine {
+    let (sx, sy) = (transform.scale.0 as f64, transform.scale.1 as f64);
+    let (sin, cos) = (transform.rotate as f64).sin_cos();
+    let (tx, ty) = (transform.translate.0 as f64, transform.translate.1 as f64);
+    Affine::new([sx * cos, sx * sin, -sy * sin, sy * cos, tx, ty])
+}
+
+/// Returns the local bounding box of the node's shape, falling back to its
+/// layout box for nodes without a shape.
+fn bounds(node: &Node) -> Option<Rect> {
+    match node.shape() {
+        Some(shape) => shape
+            .bounds()
+            .map(|((x0, y0), (x1, y1))| Rect::new(x0 as f64, y0 as f64, x1 as f64, y1 as f64)),
+        None => {
+            let layout = node.computed_layout();
+            (layout.width > 0.0 && layout.height > 0.0)
+                .then(|| Rect::new(0.0, 0.0, layout.width as f64, layout.height as f64))
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! The `ardent_accesskit` crate exposes ardent scenes to screen readers and
+//! other assistive technologies through [AccessKit](accesskit).
+//!
+//! An [`AccessibilityBridge`] mirrors the scene graph as an AccessKit tree,
+//! using each node's role, label, and value. It observes the scene, so after
+//! the initial tree only the nodes that actually changed are sent to the
+//! platform adapter.
+//!
+//! ```rust,ignore
+//! let bridge = AccessibilityBridge::new(&mut scene);
+//!
+//! // In the platform adapter's activation handler:
+//! Some(bridge.initial_tree())
+//!
+//! // Each frame, after updating the scene:
+//! scene.notify_observers();
+//! if let Some(update) = bridge.take_update() {
+//!     adapter.update_if_active(|| update);
+//! }
+//! ```
+
+mod bridge;
+mod convert;
+
+pub use bridge::AccessibilityBridge;
@@ -0,0 +1,243 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use ardent_core::animation::Animator;
+use ardent_core::scene::Scene;
+use ardent_core::style::Color;
+use ardent_render::gpu::{Backends, HdrOptions};
+use winit::event_loop::{ControlFlow, EventLoop};
+
+use crate::context::AppContext;
+use crate::error::AppError;
+use crate::record::{Recorder, Recording};
+use crate::redraw::RedrawPolicy;
+use crate::runner::Runner;
+use crate::window::AppWindow;
+
+/// Entry point for running a scene in a window.
+///
+/// The scene is built once by a setup closure before the window opens and
+/// is then owned by the app until the window is closed. Use
+/// [`App::builder`] to configure the window and renderer.
+pub struct App;
+
+impl App {
+    /// Builds a scene with `setup` and runs it in a window with default
+    /// settings until the window is closed.
+    ///
+    /// Event handlers registered on nodes receive pointer input, and frame
+    /// callbacks registered with [`Scene::on_frame`] run before every frame.
+    pub fn run<F>(setup: F) -> Result<(), AppError>
+    where
+        F: FnOnce(&mut Scene),
+    {
+        Self::builder().run(setup)
+    }
+
+    /// Like [`run`](App::run), but also hands `setup` the app's services:
+    /// the animator ticked every frame and a handle to the window.
+    pub fn run_with<F>(setup: F) -> Result<(), AppError>
+    where
+        F: FnOnce(&mut Scene, &mut AppContext),
+    {
+        Self::builder().run_with(setup)
+    }
+
+    /// Returns a builder for configuring the app before running it.
+    pub fn builder() -> AppBuilder {
+        AppBuilder::default()
+    }
+}
+
+/// Startup options of an app.
+#[derive(Debug, Clone)]
+pub(crate) struct AppConfig {
+    pub(crate) title: String,
+    pub(crate) size: (f32, f32),
+    pub(crate) resizable: bool,
+    pub(crate) decorations: bool,
+    pub(crate) vsync: bool,
+    pub(crate) background: Color,
+    pub(crate) sample_count: u32,
+    pub(crate) backends: Backends,
+    pub(crate) hdr: Option<HdrOptions>,
+    pub(crate) redraw: RedrawPolicy,
+    pub(crate) record: Option<PathBuf>,
+    pub(crate) replay: Option<PathBuf>,
+}
+
+/// Configures the window and renderer of an [`App`].
+///
+/// ```rust,ignore
+/// App::builder()
+///     .title("Editor")
+///     .size(1280.0, 800.0)
+///     .background(Color::rgb(0.1, 0.1, 0.12))
+///     .msaa_samples(4)
+///     .run(|scene| build_ui(scene))?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct AppBuilder {
+    config: AppConfig,
+}
+
+impl Default for AppBuilder {
+    fn default() -> Self {
+        Self {
+            config: AppConfig {
+                title: "Ardent".to_string(),
+                size: (800.0, 600.0),
+                resizable: true,
+                decorations: true,
+                vsync: true,
+                background: Color::white(),
+                sample_count: 1,
+                backends: Backends::all(),
+                hdr: None,
+                redraw: RedrawPolicy::Wait,
+                record: None,
+                replay: None,
+            },
+        }
+    }
+}
+
+impl AppBuilder {
+    /// Sets the window title. Defaults to "Ardent".
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.config.title = title.into();
+        self
+    }
+
+    /// Sets the initial size of the window's content area in logical
+    /// pixels. Defaults to 800 × 600.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.config.size = (width, height);
+        self
+    }
+
+    /// Sets whether the user can resize the window. Defaults to `true`.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.config.resizable = resizable;
+        self
+    }
+
+    /// Sets whether the window has the platform's title bar and borders.
+    /// Defaults to `true`. See [`AppWindow::set_region`](crate::AppWindow::set_region) for drawing a
+    /// title bar in the scene instead.
+    pub fn decorations(mut self, decorations: bool) -> Self {
+        self.config.decorations = decorations;
+        self
+    }
+
+    /// Sets whether frames are synchronized with the display's refresh,
+    /// avoiding tearing. Defaults to `true`.
+    pub fn vsync(mut self, vsync: bool) -> Self {
+        self.config.vsync = vsync;
+        self
+    }
+
+    /// Sets the color behind the scene. Defaults to white.
+    pub fn background(mut self, color: Color) -> Self {
+        self.config.background = color;
+        self
+    }
+
+    /// Sets the number of samples per pixel for multisample anti-aliasing:
+    /// 1 (off, the default) or 4. Other counts make the app fail to start.
+    pub fn msaa_samples(mut self, samples: u32) -> Self {
+        self.config.sample_count = samples;
+        self
+    }
+
+    /// Restricts the graphics APIs the renderer may use, e.g.
+    /// `Backends::VULKAN | Backends::METAL`. Defaults to all of them.
+    pub fn backends(mut self, backends: Backends) -> Self {
+        self.config.backends = backends;
+        self
+    }
+
+    /// Draws into an extended-range surface on HDR and wide-gamut displays,
+    /// mapping colors to the display with `options`, e.g. to raise the
+    /// white level of UI shown over HDR video. Falls back to ordinary
+    /// output where the platform offers no such surface. Off by default.
+    pub fn hdr(mut self, options: HdrOptions) -> Self {
+        self.config.hdr = Some(options);
+        self
+    }
+
+    /// Sets when frames are drawn. Defaults to [`RedrawPolicy::Wait`].
+    pub fn redraw_policy(mut self, policy: RedrawPolicy) -> Self {
+        self.config.redraw = policy;
+        self
+    }
+
+    /// Records the run to a [`Recording`] file at `path`, replacing an
+    /// existing file.
+    ///
+    /// Each frame's time and inputs are written as it is drawn, together
+    /// with the changes made to the scene, so the file stays usable if the
+    /// app crashes.
+    pub fn record(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.record = Some(path.into());
+        self
+    }
+
+    /// Plays back the [`Recording`] at `path` before handing control to the
+    /// user.
+    ///
+    /// The recorded frames are drawn one after another as fast as the
+    /// redraw policy allows, each advancing the scene by its recorded
+    /// duration after applying its recorded inputs, while live pointer
+    /// input is ignored. Timers see the recorded time. Work done on other
+    /// threads, e.g. signals set by background tasks, is not recorded, so
+    /// apps relying on it may diverge from the recording.
+    pub fn replay(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.replay = Some(path.into());
+        self
+    }
+
+    /// Builds a scene with `setup` and runs it until the window is closed.
+    pub fn run<F>(self, setup: F) -> Result<(), AppError>
+    where
+        F: FnOnce(&mut Scene),
+    {
+        self.run_with(|scene, _| setup(scene))
+    }
+
+    /// Like [`run`](Self::run), but also hands `setup` the app's services.
+    pub fn run_with<F>(self, setup: F) -> Result<(), AppError>
+    where
+        F: FnOnce(&mut Scene, &mut AppContext),
+    {
+        let event_loop = EventLoop::new()?;
+        event_loop.set_control_flow(ControlFlow::Wait);
+
+        let mut scene = Scene::new();
+        // Wakes the event loop when signals change, possibly on another
+        // thread, so bound nodes are redrawn.
+        let proxy = Mutex::new(event_loop.create_proxy());
+        scene.set_waker(move || {
+            if let Ok(proxy) = proxy.lock() {
+                let _ = proxy.send_event(());
+            }
+        });
+        let mut context = AppContext {
+            animator: Animator::new(),
+            window: AppWindow::new(event_loop.create_proxy()),
+        };
+        setup(&mut scene, &mut context);
+
+        let replay = match &self.config.replay {
+            Some(path) => Some(Recording::load(path)?.frames),
+            None => None,
+        };
+        let recorder = match &self.config.record {
+            Some(path) => Some(Recorder::create(path, &mut scene)?),
+            None => None,
+        };
+        let mut runner = Runner::new(self.config, scene, context, recorder, replay);
+        event_loop.run_app(&mut runner)?;
+        runner.finish()
+    }
+}
@@ -0,0 +1,21 @@
+use ardent_core::animation::Animator;
+
+use crate::window::AppWindow;
+
+/// App services available while setting up the scene.
+pub struct AppContext {
+    pub(crate) animator: Animator,
+    pub(crate) window: AppWindow,
+}
+
+impl AppContext {
+    /// Returns the app's animator, which is ticked every frame.
+    pub fn animator(&mut self) -> &mut Animator {
+        &mut self.animator
+    }
+
+    /// Returns a handle for changing the window, e.g. from event handlers.
+    pub fn window(&self) -> &AppWindow {
+        &self.window
+    }
+}
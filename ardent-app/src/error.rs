@@ -0,0 +1,80 @@
+use std::fmt;
+
+use ardent_render::{GpuInitError, RenderError};
+use winit::error::{EventLoopError, OsError};
+
+use crate::record::RecordingError;
+
+/// An error that stopped an [`App`](crate::App) from running.
+#[derive(Debug)]
+pub enum AppError {
+    /// The event loop could not be created or failed while running.
+    EventLoop(EventLoopError),
+
+    /// The window could not be created.
+    Window(OsError),
+
+    /// No GPU could draw into the window.
+    Gpu(GpuInitError),
+
+    /// The renderer could not be set up or stopped drawing frames.
+    Render(RenderError),
+
+    /// The recording to replay could not be read, or the recording to make
+    /// could not be created.
+    Recording(RecordingError),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::EventLoop(err) => write!(f, "event loop failed: {err}"),
+            AppError::Window(err) => write!(f, "failed to create window: {err}"),
+            AppError::Gpu(err) => write!(f, "failed to set up GPU: {err}"),
+            AppError::Render(err) => write!(f, "rendering failed: {err}"),
+            AppError::Recording(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AppError::EventLoop(err) => Some(err),
+            AppError::Window(err) => Some(err),
+            AppError::Gpu(err) => Some(err),
+            AppError::Render(err) => Some(err),
+            AppError::Recording(err) => Some(err),
+        }
+    }
+}
+
+impl From<EventLoopError> for AppError {
+    fn from(err: EventLoopError) -> Self {
+        AppError::EventLoop(err)
+    }
+}
+
+impl From<OsError> for AppError {
+    fn from(err: OsError) -> Self {
+        AppError::Window(err)
+    }
+}
+
+impl From<GpuInitError> for AppError {
+    fn from(err: GpuInitError) -> Self {
+        AppError::Gpu(err)
+    }
+}
+
+impl From<RenderError> for AppError {
+    fn from(err: RenderError) -> Self {
+        AppError::Render(err)
+    }
+}
+
+impl From<RecordingError> for AppError {
+    fn from(err: RecordingError) -> Self {
+        AppError::Recording(err)
+    }
+}
@@ -0,0 +1,63 @@
+//! The `ardent_app` crate runs a scene in a window.
+//!
+//! It owns everything between the operating system and the scene graph:
+//! the `winit` event loop and window, the GPU context and renderer, pointer
+//! input dispatch, and redraw scheduling. Each frame runs due timers and
+//! frame callbacks, updates nodes bound to changed signals, advances
+//! animations and inertial scrolling, lays the scene out for the window
+//! size, and renders it. When nothing is moving the event loop sleeps until
+//! the next input or timer; [`AppBuilder::redraw_policy`] caps the frame
+//! rate of moving scenes or makes the app redraw continuously instead.
+//!
+//! ```rust,ignore
+//! use ardent_app::App;
+//! use ardent_core::prelude::*;
+//!
+//! fn main() -> Result<(), ardent_app::AppError> {
+//!     App::run(|scene| {
+//!         let root = scene.root();
+//!         NodeBuilder::rect()
+//!             .size(200.0, 100.0)
+//!             .fill("#3380cc")
+//!             .on_click(|| println!("clicked"))
+//!             .build(scene, root)
+//!             .expect("the root node is always in the scene");
+//!     })
+//! }
+//! ```
+//!
+//! [`App::run_with`] also hands the setup closure an [`AppContext`] with the
+//! app's animator and an [`AppWindow`] handle, through which event handlers
+//! change the cursor, title, icon, size, and fullscreen state without
+//! touching `winit`. Undecorated windows mark nodes of an app-drawn title
+//! bar as drag regions and window controls with [`AppWindow::set_region`].
+//!
+//! Nodes given a tooltip, e.g. with `NodeBuilder::tooltip`, show it once
+//! the pointer has rested on them for half a second.
+//!
+//! [`AppBuilder::record`] writes the inputs, frame times, and scene changes
+//! of a run to a file, and [`AppBuilder::replay`] feeds a recording back
+//! into the app frame by frame, e.g. to reproduce a rendering bug reported
+//! by a user or to profile a fixed workload. Recording while replaying and
+//! comparing the files shows whether the app behaves deterministically.
+//!
+//! Press F12 in a running app to toggle the layout debug overlay, F11 to
+//! toggle the hit-test overlay showing the node under the pointer, and F10
+//! to toggle the performance HUD.
+
+mod app;
+mod context;
+mod error;
+mod record;
+mod redraw;
+mod runner;
+mod window;
+
+pub use app::{App, AppBuilder};
+pub use ardent_render::gpu::{Backends, HdrOptions, ToneMapping};
+pub use context::AppContext;
+pub use error::AppError;
+pub use record::{RecordedFrame, RecordedInput, Recording, RecordingError};
+pub use redraw::RedrawPolicy;
+pub use window::{AppWindow, WindowMode, WindowRegion};
+pub use winit::window::{CursorIcon, Icon};
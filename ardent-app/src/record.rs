@@ -0,0 +1,407 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use ardent_core::node::NodeId;
+use ardent_core::scene::{Scene, SceneChange};
+
+/// First line of every recording, naming the format and its version.
+const HEADER: &str = "ardent-recording 1";
+
+/// An input applied to the scene by the runner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordedInput {
+    /// The pointer moved to the given position in logical pixels.
+    PointerMoved(f32, f32),
+
+    /// The pointer left the window.
+    PointerLeft,
+
+    /// The primary button was pressed.
+    PointerPressed,
+
+    /// The primary button was released.
+    PointerReleased,
+
+    /// The wheel or touchpad scrolled by the given distance in logical
+    /// pixels, in the direction the view moves.
+    Scroll(f32, f32),
+
+    /// The window's content area was resized to the given physical size.
+    Resized(u32, u32),
+}
+
+/// One frame of a [`Recording`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecordedFrame {
+    /// Seconds since the recording started when the frame was drawn.
+    pub time: f32,
+
+    /// Seconds the scene was advanced by in this frame.
+    pub dt: f32,
+
+    /// Inputs received since the previous frame, with the seconds since
+    /// the recording started at which they arrived.
+    pub inputs: Vec<(f32, RecordedInput)>,
+
+    /// Changes made to the scene while the frame was prepared, as reported
+    /// to change observers.
+    pub changes: Vec<SceneChange>,
+}
+
+/// Inputs, frame times, and scene changes of a run of an app, e.g. to
+/// reproduce a rendering bug reported by a user or to profile a fixed
+/// workload.
+///
+/// Apps write recordings with [`AppBuilder::record`](crate::AppBuilder::record)
+/// and play them back with [`AppBuilder::replay`](crate::AppBuilder::replay).
+/// Recordings are plain text with one input, frame, or change per line, so
+/// they can be attached to bug reports, read, and compared with `diff`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recording {
+    /// The recorded frames in order.
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl Recording {
+    /// Reads a recording from a file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RecordingError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parses a recording from its text form.
+    ///
+    /// Inputs recorded after the last frame are dropped, since no frame
+    /// applied them. Times and durations must be non-negative.
+    pub fn parse(text: &str) -> Result<Self, RecordingError> {
+        let mut lines = text.lines().enumerate();
+        match lines.next() {
+            Some((_, line)) if line.trim() == HEADER => {}
+            _ => return Err(RecordingError::parse(1, "missing recording header")),
+        }
+        let mut frames: Vec<RecordedFrame> = Vec::new();
+        let mut inputs = Vec::new();
+        for (index, line) in lines {
+            let error = |message: &str| RecordingError::parse(index + 1, message);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                [] => {}
+                ["frame", time, dt] => frames.push(RecordedFrame {
+                    time: seconds(time).ok_or_else(|| error("invalid frame time"))?,
+                    dt: seconds(dt).ok_or_else(|| error("invalid frame duration"))?,
+                    inputs: std::mem::take(&mut inputs),
+                    changes: Vec::new(),
+                }),
+                ["input", time, input @ ..] => {
+                    let time = seconds(time).ok_or_else(|| error("invalid input time"))?;
+                    let input = parse_input(input).ok_or_else(|| error("invalid input"))?;
+                    inputs.push((time, input));
+                }
+                ["change", change @ ..] => {
+                    let change = parse_change(change).ok_or_else(|| error("invalid change"))?;
+                    frames
+                        .last_mut()
+                        .ok_or_else(|| error("change before the first frame"))?
+                        .changes
+                        .push(change);
+                }
+                _ => return Err(error("unknown line")),
+            }
+        }
+        Ok(Self { frames })
+    }
+
+    /// Writes the recording to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for frame in &self.frames {
+            for &(time, input) in &frame.inputs {
+                writeln!(f, "{}", InputLine(time, input))?;
+            }
+            writeln!(f, "frame {} {}", frame.time, frame.dt)?;
+            for &change in &frame.changes {
+                writeln!(f, "{}", ChangeLine(change))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An error reading a [`Recording`].
+#[derive(Debug)]
+pub enum RecordingError {
+    /// The file could not be read or written.
+    Io(io::Error),
+
+    /// A line of the recording is malformed.
+    Parse {
+        /// The number of the line, starting at 1.
+        line: usize,
+
+        /// What is wrong with it.
+        message: String,
+    },
+}
+
+impl RecordingError {
+    fn parse(line: usize, message: &str) -> Self {
+        RecordingError::Parse {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordingError::Io(err) => write!(f, "failed to access recording: {err}"),
+            RecordingError::Parse { line, message } => {
+                write!(f, "invalid recording at line {line}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecordingError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RecordingError::Io(err) => Some(err),
+            RecordingError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for RecordingError {
+    fn from(err: io::Error) -> Self {
+        RecordingError::Io(err)
+    }
+}
+
+/// Writes a recording to a file while the app runs.
+///
+/// Lines are flushed after every frame, so the recording survives a crash
+/// up to the frame before it.
+pub(crate) struct Recorder {
+    out: BufWriter<File>,
+
+    /// Changes reported by the scene observer since the last frame.
+    changes: Arc<Mutex<Vec<SceneChange>>>,
+}
+
+impl Recorder {
+    /// Creates the recording file, replacing an existing one, and starts
+    /// observing changes to `scene`.
+    pub(crate) fn create(path: &Path, scene: &mut Scene) -> Result<Self, RecordingError> {
+        let mut out = BufWriter::new(File::create(path)?);
+        writeln!(out, "{HEADER}")?;
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let observed = changes.clone();
+        scene.observe(move |_, changes| {
+            if let Ok(mut observed) = observed.lock() {
+                observed.extend_from_slice(changes);
+            }
+        });
+        Ok(Self { out, changes })
+    }
+
+    /// Records an input applied `time` seconds after the start.
+    pub(crate) fn input(&mut self, time: f32, input: RecordedInput) {
+        let _ = writeln!(self.out, "{}", InputLine(time, input));
+    }
+
+    /// Records a frame drawn `time` seconds after the start, advancing the
+    /// scene by `dt` seconds, with the changes observers were notified of
+    /// while preparing it.
+    ///
+    /// Write errors are ignored, so a full disk does not stop the app.
+    pub(crate) fn frame(&mut self, time: f32, dt: f32) {
+        let _ = writeln!(self.out, "frame {time} {dt}");
+        if let Ok(mut changes) = self.changes.lock() {
+            for change in changes.drain(..) {
+                let _ = writeln!(self.out, "{}", ChangeLine(change));
+            }
+        }
+        let _ = self.out.flush();
+    }
+}
+
+/// Formats an input as a line of a recording.
+struct InputLine(f32, RecordedInput);
+
+impl fmt::Display for InputLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "input {} ", self.0)?;
+        match self.1 {
+            RecordedInput::PointerMoved(x, y) => write!(f, "move {x} {y}"),
+            RecordedInput::PointerLeft => write!(f, "leave"),
+            RecordedInput::PointerPressed => write!(f, "press"),
+            RecordedInput::PointerReleased => write!(f, "release"),
+            RecordedInput::Scroll(dx, dy) => write!(f, "scroll {dx} {dy}"),
+            RecordedInput::Resized(width, height) => write!(f, "resize {width} {height}"),
+        }
+    }
+}
+
+/// Formats a scene change as a line of a recording.
+struct ChangeLine(SceneChange);
+
+impl fmt::Display for ChangeLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            SceneChange::Added(NodeId(id)) => write!(f, "change added {id}"),
+            SceneChange::Removed(NodeId(id)) => write!(f, "change removed {id}"),
+            SceneChange::Modified(NodeId(id)) => write!(f, "change modified {id}"),
+        }
+    }
+}
+
+/// Parses the words of an input line after its time.
+fn parse_input(words: &[&str]) -> Option<RecordedInput> {
+    Some(match *words {
+        ["move", x, y] => RecordedInput::PointerMoved(number(x)?, number(y)?),
+        ["leave"] => RecordedInput::PointerLeft,
+        ["press"] => RecordedInput::PointerPressed,
+        ["release"] => RecordedInput::PointerReleased,
+        ["scroll", dx, dy] => RecordedInput::Scroll(number(dx)?, number(dy)?),
+        ["resize", width, height] => {
+            RecordedInput::Resized(width.parse().ok()?, height.parse().ok()?)
+        }
+        _ => return None,
+    })
+}
+
+/// Parses the words of a change line.
+fn parse_change(words: &[&str]) -> Option<SceneChange> {
+    let [kind, id] = *words else {
+        return None;
+    };
+    let id = NodeId(id.parse().ok()?);
+    Some(match kind {
+        "added" => SceneChange::Added(id),
+        "removed" => SceneChange::Removed(id),
+        "modified" => SceneChange::Modified(id),
+        _ => return None,
+    })
+}
+
+/// Parses a finite number.
+fn number(word: &str) -> Option<f32> {
+    word.parse().ok().filter(|n: &f32| n.is_finite())
+}
+
+/// Parses a time or duration in seconds, which must be non-negative and
+/// fit in a [`Duration`].
+fn seconds(word: &str) -> Option<f32> {
+    number(word).filter(|&n| Duration::try_from_secs_f32(n).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the line and message of the error parsing `text`.
+    fn parse_error(text: &str) -> (usize, String) {
+        match Recording::parse(text) {
+            Err(RecordingError::Parse { line, message }) => (line, message),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        let recording = Recording {
+            frames: vec![
+                RecordedFrame {
+                    time: 0.0,
+                    dt: 0.0,
+                    inputs: vec![(0.0, RecordedInput::Resized(800, 600))],
+                    changes: vec![SceneChange::Added(NodeId(3))],
+                },
+                RecordedFrame {
+                    time: 0.016,
+                    dt: 0.016,
+                    inputs: vec![
+                        (0.004, RecordedInput::PointerMoved(12.5, -3.25)),
+                        (0.005, RecordedInput::PointerPressed),
+                        (0.01, RecordedInput::PointerReleased),
+                        (0.012, RecordedInput::Scroll(0.0, 40.0)),
+                        (0.015, RecordedInput::PointerLeft),
+                    ],
+                    changes: vec![
+                        SceneChange::Modified(NodeId(3)),
+                        SceneChange::Removed(NodeId(3)),
+                    ],
+                },
+                RecordedFrame {
+                    time: 1.5,
+                    dt: 0.25,
+                    ..RecordedFrame::default()
+                },
+            ],
+        };
+        let text = recording.to_string();
+        assert!(text.starts_with(HEADER));
+        assert_eq!(Recording::parse(&text).unwrap(), recording);
+    }
+
+    #[test]
+    fn inputs_after_the_last_frame_are_dropped() {
+        let text = format!("{HEADER}\nframe 0 0\n\ninput 0.5 press\n");
+        let recording = Recording::parse(&text).unwrap();
+        assert_eq!(recording.frames.len(), 1);
+        assert!(recording.frames[0].inputs.is_empty());
+    }
+
+    #[test]
+    fn malformed_lines_are_reported_with_their_number() {
+        assert_eq!(parse_error(""), (1, "missing recording header".into()));
+        assert_eq!(
+            parse_error("ardent-recording 2\nframe 0 0"),
+            (1, "missing recording header".into())
+        );
+        for (line, message) in [
+            ("bogus", "unknown line"),
+            ("frame 0", "unknown line"),
+            ("frame zero 0", "invalid frame time"),
+            ("frame 0 NaN", "invalid frame duration"),
+            ("input 0 jump", "invalid input"),
+            ("input 0 move 1", "invalid input"),
+            ("input 0 resize -1 5", "invalid input"),
+            ("change renamed 1", "invalid change"),
+            ("change added x", "invalid change"),
+        ] {
+            let text = format!("{HEADER}\nframe 0 0\n{line}");
+            assert_eq!(parse_error(&text), (3, message.into()), "{line}");
+        }
+        assert_eq!(
+            parse_error(&format!("{HEADER}\nchange added 1")),
+            (2, "change before the first frame".into())
+        );
+    }
+
+    #[test]
+    fn negative_and_huge_times_are_rejected() {
+        for (line, message) in [
+            ("frame -1 0", "invalid frame time"),
+            ("frame 1e30 0", "invalid frame time"),
+            ("frame inf 0", "invalid frame time"),
+            ("frame 0 -0.016", "invalid frame duration"),
+            ("frame 0 1e30", "invalid frame duration"),
+            ("input -0.5 press", "invalid input time"),
+            ("input 1e30 press", "invalid input time"),
+        ] {
+            let text = format!("{HEADER}\n{line}");
+            assert_eq!(parse_error(&text), (2, message.into()), "{line}");
+        }
+    }
+}
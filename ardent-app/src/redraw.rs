@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+/// Lowest frame rate of [`RedrawPolicy::FixedRate`], one frame per hour.
+/// Lower rates are raised to it, so the time between frames stays
+/// representable.
+const MIN_FIXED_RATE: f32 = 1.0 / 3600.0;
+
+/// When the app draws frames.
+///
+/// With [`Wait`](Self::Wait) and [`FixedRate`](Self::FixedRate) the runner
+/// switches between idling and drawing on its own: it draws continuously
+/// while animations, inertial scrolling, frame callbacks, or background
+/// tessellation are in progress, and sleeps until the next input or timer
+/// once they stop.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum RedrawPolicy {
+    /// Draw only when something changed, and at the display's refresh
+    /// rate while anything moves.
+    #[default]
+    Wait,
+
+    /// Draw continuously, even when nothing changed, e.g. for games or
+    /// content updated outside the scene.
+    Poll,
+
+    /// Like [`Wait`](Self::Wait), but draw moving scenes at most the given
+    /// number of frames per second, e.g. to save power on battery.
+    ///
+    /// Rates that are not positive draw as fast as [`Wait`](Self::Wait), and
+    /// rates below one frame per hour are raised to it.
+    FixedRate(f32),
+}
+
+impl RedrawPolicy {
+    /// Returns the minimum time between frames while the scene moves.
+    pub(crate) fn frame_interval(&self) -> Option<Duration> {
+        match *self {
+            RedrawPolicy::FixedRate(hz) if hz > 0.0 => {
+                Some(Duration::from_secs_f32(1.0 / hz.max(MIN_FIXED_RATE)))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_rates_give_the_time_between_frames() {
+        let interval = |hz| RedrawPolicy::FixedRate(hz).frame_interval();
+        assert_eq!(interval(50.0), Some(Duration::from_millis(20)));
+        assert_eq!(interval(f32::INFINITY), Some(Duration::ZERO));
+        for hz in [0.0, -30.0, f32::NAN] {
+            assert_eq!(interval(hz), None, "{hz}");
+        }
+        assert_eq!(RedrawPolicy::Wait.frame_interval(), None);
+        assert_eq!(RedrawPolicy::Poll.frame_interval(), None);
+    }
+
+    #[test]
+    fn tiny_rates_are_raised_to_one_frame_per_hour() {
+        let hour = Duration::from_secs(3600);
+        for hz in [1e-40, f32::MIN_POSITIVE, 1e-20, 1.0 / 7200.0] {
+            let interval = RedrawPolicy::FixedRate(hz).frame_interval().unwrap();
+            assert!(interval.abs_diff(hour) < Duration::from_millis(1), "{hz}");
+        }
+    }
+}
@@ -0,0 +1,541 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ardent_core::animation::{Animator, FrameClock};
+use ardent_core::event::Event;
+use ardent_core::layout::Display;
+use ardent_core::node::NodeId;
+use ardent_core::scene::Scene;
+use ardent_input::{Dispatcher, HitOverlay, Tooltips};
+use ardent_layout::{LayoutExt, LayoutOverlay, Scroller};
+use ardent_render::gpu::SurfaceError;
+use ardent_render::{GpuContext, GpuOptions, PerfHud, RenderError, Renderer};
+use pollster::FutureExt;
+use winit::application::ApplicationHandler;
+use winit::dpi::{LogicalSize, PhysicalSize};
+use winit::event::{ElementState, MouseButton, MouseScrollDelta, StartCause, WindowEvent};
+use winit::event_loop::{ActiveEventLoop, ControlFlow};
+use winit::keyboard::{Key, NamedKey};
+use winit::window::{Window, WindowId};
+
+use crate::app::AppConfig;
+use crate::context::AppContext;
+use crate::error::AppError;
+use crate::record::{RecordedFrame, RecordedInput, Recorder};
+use crate::redraw::RedrawPolicy;
+use crate::window::{self, AppWindow, WindowRegion};
+
+/// Distance in logical pixels scrolled per wheel notch.
+const LINE_HEIGHT: f32 = 40.0;
+
+/// The window and everything drawing into it.
+struct Surface {
+    window: Arc<Window>,
+    context: GpuContext<'static>,
+    renderer: Renderer,
+    clock: FrameClock,
+    layout_overlay: Option<LayoutOverlay>,
+    hit_overlay: Option<HitOverlay>,
+    perf_hud: Option<PerfHud>,
+
+    /// When the last frame was drawn, for pacing fixed-rate redraws.
+    last_frame: Option<Instant>,
+}
+
+/// Drives an app's scene from `winit` events.
+pub(crate) struct Runner {
+    config: AppConfig,
+    scene: Scene,
+    animator: Animator,
+    scroller: Scroller,
+    dispatcher: Dispatcher,
+    tooltips: Tooltips,
+    window: AppWindow,
+    surface: Option<Surface>,
+    error: Option<AppError>,
+
+    /// The title bar control the pointer was pressed on, which acts when
+    /// the pointer is released over it.
+    pressed_control: Option<NodeId>,
+
+    /// Writes inputs, frames, and scene changes to a file while recording.
+    recorder: Option<Recorder>,
+
+    /// Recorded frames still to be played back while replaying. Live
+    /// pointer input is ignored until they run out.
+    replay: Option<std::vec::IntoIter<RecordedFrame>>,
+
+    /// When the runner was created; recorded times count from here.
+    started: Instant,
+
+    /// Recorded time of the frame being played back.
+    replay_time: f32,
+}
+
+impl Runner {
+    pub(crate) fn new(
+        config: AppConfig,
+        scene: Scene,
+        context: AppContext,
+        recorder: Option<Recorder>,
+        replay: Option<Vec<RecordedFrame>>,
+    ) -> Self {
+        let AppContext { animator, window } = context;
+        Self {
+            config,
+            scene,
+            animator,
+            scroller: Scroller::new(),
+            dispatcher: Dispatcher::new(),
+            tooltips: Tooltips::new(),
+            window,
+            surface: None,
+            error: None,
+            pressed_control: None,
+            recorder,
+            replay: replay.map(Vec::into_iter),
+            started: Instant::now(),
+            replay_time: 0.0,
+        }
+    }
+
+    /// Returns the error that stopped the event loop, if any.
+    pub(crate) fn finish(self) -> Result<(), AppError> {
+        self.error.map_or(Ok(()), Err)
+    }
+
+    fn create_surface(&mut self, event_loop: &ActiveEventLoop) -> Result<Surface, AppError> {
+        let config = &self.config;
+        let attributes = Window::default_attributes()
+            .with_title(config.title.as_str())
+            .with_inner_size(LogicalSize::new(config.size.0, config.size.1))
+            .with_resizable(config.resizable)
+            .with_decorations(config.decorations);
+        let window = Arc::new(event_loop.create_window(attributes)?);
+        self.window.apply(&window);
+
+        let size = window.inner_size();
+        let options = GpuOptions {
+            backends: config.backends,
+            vsync: config.vsync,
+            hdr: config.hdr.is_some(),
+            ..Default::default()
+        };
+        let mut context =
+            GpuContext::with_options(window.clone(), size.width, size.height, options)
+                .block_on()?;
+        let scale_factor = window.scale_factor() as f32;
+        context.set_scale_factor(scale_factor);
+        self.scene.set_scale_factor(scale_factor);
+
+        let mut renderer = Renderer::new(&context);
+        renderer.set_clear_color(config.background);
+        renderer.set_sample_count(config.sample_count)?;
+        if let Some(hdr) = config.hdr {
+            renderer.set_hdr_options(hdr);
+        }
+        self.scene.compute_layout(context.logical_size());
+        Ok(Surface {
+            window,
+            context,
+            renderer,
+            clock: FrameClock::new(),
+            layout_overlay: None,
+            hit_overlay: None,
+            perf_hud: None,
+            last_frame: None,
+        })
+    }
+
+    /// Advances the scene by one frame and draws it.
+    ///
+    /// Returns an error only if the window can no longer be drawn to; lost
+    /// or outdated surfaces are reconfigured and the frame is retried.
+    fn redraw(&mut self) -> Result<(), RenderError> {
+        if self.surface.is_none() {
+            return Ok(());
+        }
+        // Replayed frames apply their inputs and take their recorded time.
+        let replayed = self.replay.as_mut().and_then(Iterator::next);
+        if replayed.is_none() {
+            self.replay = None;
+        }
+        if let Some(frame) = &replayed {
+            self.replay_time = frame.time;
+            for &(_, input) in &frame.inputs {
+                match (input, &self.surface) {
+                    // The window is asked for the recorded size; the
+                    // platform reports the size it gets as a live resize.
+                    (RecordedInput::Resized(width, height), Some(surface)) => {
+                        let _ = surface
+                            .window
+                            .request_inner_size(PhysicalSize::new(width, height));
+                    }
+                    _ => self.apply_input(input),
+                }
+            }
+        }
+        let Some(surface) = &mut self.surface else {
+            return Ok(());
+        };
+        let dt = surface.clock.tick();
+        let now = Instant::now();
+        surface.last_frame = Some(now);
+        let (time, dt, now) = match &replayed {
+            // Parsed recordings only hold times that fit in a `Duration`,
+            // but frames edited in code may not.
+            Some(frame) => (
+                frame.time,
+                frame.dt,
+                Duration::try_from_secs_f32(frame.time)
+                    .ok()
+                    .and_then(|time| self.started.checked_add(time))
+                    .unwrap_or(now),
+            ),
+            None => ((now - self.started).as_secs_f32(), dt, now),
+        };
+        self.scene.run_timers(now);
+        self.scene.run_frame_callbacks(dt);
+        self.scene.update_bindings();
+        self.animator.tick(&mut self.scene, dt);
+        self.scroller.tick(&mut self.scene, dt);
+        self.scene.compute_layout(surface.context.logical_size());
+        self.scene.notify_observers();
+        if let Some(recorder) = &mut self.recorder {
+            recorder.frame(time, dt);
+        }
+        self.dispatcher.refresh(&self.scene);
+
+        let mut layers = vec![&self.scene];
+        if let Some(overlay) = &mut surface.layout_overlay {
+            overlay.update(&self.scene);
+            layers.push(overlay.scene());
+        }
+        if let Some(overlay) = &mut surface.hit_overlay {
+            overlay.update(&self.scene, self.dispatcher.position());
+            layers.push(overlay.scene());
+        }
+        if let Some(hud) = &mut surface.perf_hud {
+            // The renderer's statistics describe the previous frame.
+            hud.update(surface.renderer.stats(), dt);
+            layers.push(hud.scene());
+        }
+        self.tooltips.update(
+            &self.scene,
+            self.dispatcher.position(),
+            surface.context.logical_size(),
+            now,
+        );
+        layers.push(self.tooltips.scene());
+        let result = surface.renderer.render_layers(&layers, &surface.context);
+        match result {
+            Ok(()) => {
+                // The same scenes in the same order as the layers above.
+                let mut scenes = vec![&mut self.scene];
+                if let Some(overlay) = &mut surface.layout_overlay {
+                    scenes.push(overlay.scene_mut());
+                }
+                if let Some(overlay) = &mut surface.hit_overlay {
+                    scenes.push(overlay.scene_mut());
+                }
+                if let Some(hud) = &mut surface.perf_hud {
+                    scenes.push(hud.scene_mut());
+                }
+                scenes.push(self.tooltips.scene_mut());
+                for (layer, scene) in scenes.into_iter().enumerate() {
+                    scene.clear_dirty(surface.renderer.rendered_nodes(layer));
+                }
+            }
+            Err(RenderError::Surface(SurfaceError::Lost | SurfaceError::Outdated)) => {
+                let (width, height) = surface.context.size;
+                surface.context.resize(width, height);
+                surface.window.request_redraw();
+            }
+            // The frame is skipped; the next one will try again.
+            Err(RenderError::Surface(SurfaceError::Timeout)) => {}
+            Err(err) => return Err(err),
+        }
+        if !self.needs_redraw()
+            && let Some(surface) = &mut self.surface
+        {
+            surface.clock.reset();
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if another frame should be drawn right away, either
+    /// because something is moving or meshes are still being prepared.
+    fn needs_redraw(&self) -> bool {
+        self.animator.is_active()
+            || self.replay.as_ref().is_some_and(|frames| frames.len() > 0)
+            || self.scroller.is_active()
+            || self.scene.has_frame_callbacks()
+            || self.scene.has_pending_bindings()
+            || self
+                .surface
+                .as_ref()
+                .is_some_and(|surface| surface.renderer.has_pending_tessellation())
+    }
+
+    /// Returns the seconds since the runner started, or the recorded time
+    /// of the current frame while replaying.
+    fn clock(&self) -> f32 {
+        match self.replay {
+            Some(_) => self.replay_time,
+            None => self.started.elapsed().as_secs_f32(),
+        }
+    }
+
+    /// Applies a live or replayed input to the scene, recording it if a
+    /// recording is being made.
+    fn apply_input(&mut self, input: RecordedInput) {
+        let time = self.clock();
+        if let Some(recorder) = &mut self.recorder {
+            recorder.input(time, input);
+        }
+        match input {
+            RecordedInput::PointerMoved(x, y) => self.dispatcher.pointer_moved(&self.scene, (x, y)),
+            RecordedInput::PointerLeft => self.dispatcher.pointer_left(&self.scene),
+            RecordedInput::PointerPressed => {
+                self.tooltips.dismiss();
+                self.dispatcher.pointer_pressed(&self.scene);
+            }
+            RecordedInput::PointerReleased => self.dispatcher.pointer_released(&self.scene),
+            RecordedInput::Scroll(dx, dy) => self.scroll((dx, dy)),
+            RecordedInput::Resized(width, height) => {
+                let Some(surface) = &mut self.surface else {
+                    return;
+                };
+                surface.context.resize(width, height);
+                self.scene.compute_layout(surface.context.logical_size());
+                self.window.sync(&surface.window);
+            }
+        }
+    }
+
+    /// Scrolls the node under the pointer and the nearest scroll container
+    /// around it.
+    fn scroll(&mut self, delta: (f32, f32)) {
+        let Some(hit) = self.dispatcher.scroll(&self.scene, delta) else {
+            return;
+        };
+        if let Some(container) = scroll_container(&self.scene, hit) {
+            self.scroller
+                .handle_event(&mut self.scene, container, Event::Scroll(delta.0, delta.1));
+        }
+    }
+}
+
+impl ApplicationHandler for Runner {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.surface.is_some() {
+            return;
+        }
+        match self.create_surface(event_loop) {
+            Ok(surface) => self.surface = Some(surface),
+            Err(err) => {
+                self.error = Some(err);
+                event_loop.exit();
+            }
+        }
+    }
+
+    /// Applies window changes requested through [`AppWindow`] and redraws
+    /// nodes whose bound signals changed.
+    fn user_event(&mut self, _: &ActiveEventLoop, _: ()) {
+        if let Some(surface) = &self.surface {
+            self.window.apply(&surface.window);
+            if self.scene.has_pending_bindings() {
+                surface.window.request_redraw();
+            }
+        }
+    }
+
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, id: WindowId, event: WindowEvent) {
+        let Some(surface) = &mut self.surface else {
+            return;
+        };
+        if surface.window.id() != id {
+            return;
+        }
+        match event {
+            WindowEvent::CloseRequested => {
+                event_loop.exit();
+                return;
+            }
+            WindowEvent::Resized(size) => {
+                self.apply_input(RecordedInput::Resized(size.width, size.height));
+            }
+            WindowEvent::Moved(_) => {
+                self.window.sync(&surface.window);
+                return;
+            }
+            // A `Resized` event with the new physical size follows.
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                surface.context.set_scale_factor(scale_factor as f32);
+                self.scene.set_scale_factor(scale_factor as f32);
+            }
+            WindowEvent::RedrawRequested => {
+                if let Err(err) = self.redraw() {
+                    self.error = Some(err.into());
+                    event_loop.exit();
+                }
+                return;
+            }
+            WindowEvent::KeyboardInput { event, .. }
+                if event.state == ElementState::Pressed
+                    && event.logical_key == Key::Named(NamedKey::F12) =>
+            {
+                surface.layout_overlay = match surface.layout_overlay {
+                    Some(_) => None,
+                    None => Some(LayoutOverlay::new()),
+                };
+            }
+            WindowEvent::KeyboardInput { event, .. }
+                if event.state == ElementState::Pressed
+                    && event.logical_key == Key::Named(NamedKey::F11) =>
+            {
+                surface.hit_overlay = match surface.hit_overlay {
+                    Some(_) => None,
+                    None => Some(HitOverlay::new()),
+                };
+            }
+            WindowEvent::KeyboardInput { event, .. }
+                if event.state == ElementState::Pressed
+                    && event.logical_key == Key::Named(NamedKey::F10) =>
+            {
+                surface.perf_hud = match surface.perf_hud {
+                    Some(_) => None,
+                    None => Some(PerfHud::new()),
+                };
+            }
+            // Live pointer input is ignored while a recording plays back.
+            WindowEvent::CursorMoved { .. }
+            | WindowEvent::CursorLeft { .. }
+            | WindowEvent::MouseInput { .. }
+            | WindowEvent::MouseWheel { .. }
+                if self.replay.is_some() =>
+            {
+                return;
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                let position = position.to_logical::<f32>(surface.window.scale_factor());
+                self.apply_input(RecordedInput::PointerMoved(position.x, position.y));
+            }
+            WindowEvent::CursorLeft { .. } => self.apply_input(RecordedInput::PointerLeft),
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Left,
+                ..
+            } => {
+                let region = self
+                    .dispatcher
+                    .position()
+                    .and_then(|position| self.window.region_at(&self.scene, position));
+                match state {
+                    ElementState::Pressed => {
+                        if let Some((_, WindowRegion::Drag)) = region {
+                            // The platform moves the window until the
+                            // button is released.
+                            let _ = surface.window.drag_window();
+                            return;
+                        }
+                        self.pressed_control = region.map(|(node, _)| node);
+                        self.apply_input(RecordedInput::PointerPressed);
+                    }
+                    ElementState::Released => {
+                        self.apply_input(RecordedInput::PointerReleased);
+                        let pressed = self.pressed_control.take();
+                        if let Some((node, region)) = region
+                            && pressed == Some(node)
+                            && let Some(surface) = &self.surface
+                            && window::apply_control(&surface.window, region)
+                        {
+                            event_loop.exit();
+                            return;
+                        }
+                    }
+                }
+            }
+            WindowEvent::MouseWheel { delta, .. } => {
+                // Wheel deltas point in the direction the content moves,
+                // scroll offsets in the direction the view moves.
+                let (dx, dy) = match delta {
+                    MouseScrollDelta::LineDelta(x, y) => (x * LINE_HEIGHT, y * LINE_HEIGHT),
+                    MouseScrollDelta::PixelDelta(position) => {
+                        let position = position.to_logical::<f32>(surface.window.scale_factor());
+                        (position.x, position.y)
+                    }
+                };
+                self.apply_input(RecordedInput::Scroll(-dx, -dy));
+            }
+            _ => return,
+        }
+        if let Some(surface) = &self.surface {
+            surface.window.request_redraw();
+        }
+    }
+
+    /// Draws a frame when the next scheduled timer, tooltip, or fixed-rate
+    /// frame is due.
+    fn new_events(&mut self, _: &ActiveEventLoop, cause: StartCause) {
+        if let StartCause::ResumeTimeReached { .. } = cause
+            && let Some(surface) = &self.surface
+        {
+            surface.window.request_redraw();
+        }
+    }
+
+    /// Schedules the next frame according to the redraw policy.
+    ///
+    /// Unless polling, frames keep coming while anything moves and the app
+    /// goes back to sleep once the scene is static, so an idle app never
+    /// busy-loops. While timers are scheduled or a tooltip is about to show,
+    /// the event loop wakes up for the next one.
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        let Some(surface) = &self.surface else {
+            return;
+        };
+        let mut wake = match (self.scene.next_timer(), self.tooltips.next_change()) {
+            (Some(timer), Some(tooltip)) => Some(timer.min(tooltip)),
+            (timer, tooltip) => timer.or(tooltip),
+        };
+        let policy = self.config.redraw;
+        if policy == RedrawPolicy::Poll {
+            event_loop.set_control_flow(ControlFlow::Poll);
+            surface.window.request_redraw();
+            return;
+        }
+
+        if self.needs_redraw() {
+            // Frames at a fixed rate wait for their slot; others are drawn
+            // as fast as the display allows.
+            let next_frame = policy
+                .frame_interval()
+                .zip(surface.last_frame)
+                .and_then(|(interval, last)| last.checked_add(interval))
+                .filter(|&next| next > Instant::now());
+            match next_frame {
+                Some(next) => wake = Some(wake.map_or(next, |timer| timer.min(next))),
+                None => surface.window.request_redraw(),
+            }
+        }
+        event_loop.set_control_flow(match wake {
+            Some(deadline) => ControlFlow::WaitUntil(deadline),
+            None => ControlFlow::Wait,
+        });
+    }
+}
+
+/// Returns the nearest scroll container enclosing the node `id`.
+fn scroll_container(scene: &Scene, id: NodeId) -> Option<NodeId> {
+    let mut current = Some(id);
+    while let Some(id) = current {
+        let node = scene.get_node(id)?;
+        if matches!(node.layout().display, Display::Scroll(_)) {
+            return Some(id);
+        }
+        current = node.parent();
+    }
+    None
+}
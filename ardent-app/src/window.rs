@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use ardent_core::node::NodeId;
+use ardent_core::scene::Scene;
+use ardent_input::hit_test;
+use winit::dpi::{LogicalPosition, LogicalSize};
+use winit::event_loop::EventLoopProxy;
+use winit::window::{CursorIcon, Fullscreen, Icon, Window};
+
+/// How the window occupies the screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WindowMode {
+    /// A regular window with its own size and position.
+    #[default]
+    Windowed,
+
+    /// The window fills the screen's work area, keeping its decorations.
+    Maximized,
+
+    /// The window is hidden in the taskbar or dock.
+    Minimized,
+
+    /// The window covers the whole screen it is on, without decorations.
+    /// The display's video mode is left unchanged.
+    Fullscreen,
+}
+
+/// What a node of an app-drawn title bar does to the window.
+///
+/// Regions apply to the node's whole subtree, so a title bar's label and
+/// icon drag the window along with the bar itself. The innermost region
+/// wins, letting control buttons sit on a draggable bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowRegion {
+    /// Pressing the node starts moving the window.
+    Drag,
+
+    /// Clicking the node minimizes the window.
+    Minimize,
+
+    /// Clicking the node maximizes the window, or restores it if it is
+    /// already maximized.
+    Maximize,
+
+    /// Clicking the node closes the window and exits the app.
+    Close,
+}
+
+/// Window changes requested since they were last applied.
+#[derive(Default)]
+struct Requests {
+    title: Option<String>,
+    cursor: Option<CursorIcon>,
+    icon: Option<Option<Icon>>,
+    mode: Option<WindowMode>,
+    size: Option<(f32, f32)>,
+    position: Option<(f32, f32)>,
+    decorations: Option<bool>,
+}
+
+/// The window's last known state, in logical pixels.
+#[derive(Default, Clone, Copy)]
+struct Snapshot {
+    mode: WindowMode,
+    size: (f32, f32),
+    position: Option<(f32, f32)>,
+}
+
+struct Shared {
+    requests: Requests,
+    snapshot: Snapshot,
+    regions: HashMap<NodeId, WindowRegion>,
+    proxy: EventLoopProxy<()>,
+}
+
+/// A handle for changing the app's window from application code.
+///
+/// The handle is cheap to clone and can be moved into event handlers and
+/// frame callbacks, e.g. to show a hand cursor while a button is hovered,
+/// to put the open document's name in the title, or to toggle fullscreen. Changes are applied by the
+/// event loop right after the current event; requests made before the
+/// window opens are applied once it does.
+///
+/// ```rust,ignore
+/// let window = app.window().clone();
+/// node.set_event_handler(Box::new(move |event| match event {
+///     Event::PointerEnter => window.set_cursor(CursorIcon::Pointer),
+///     Event::PointerLeave => window.set_cursor(CursorIcon::Default),
+///     _ => {}
+/// }));
+/// ```
+#[derive(Clone)]
+pub struct AppWindow {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl AppWindow {
+    pub(crate) fn new(proxy: EventLoopProxy<()>) -> Self {
+        Self {
+            shared: Arc::new(Mutex::new(Shared {
+                requests: Requests::default(),
+                snapshot: Snapshot::default(),
+                regions: HashMap::new(),
+                proxy,
+            })),
+        }
+    }
+
+    /// Sets the window title.
+    pub fn set_title(&self, title: impl Into<String>) {
+        let title = title.into();
+        self.request(|requests| requests.title = Some(title));
+    }
+
+    /// Sets the cursor shown while the pointer is over the window.
+    pub fn set_cursor(&self, cursor: CursorIcon) {
+        self.request(|requests| requests.cursor = Some(cursor));
+    }
+
+    /// Sets the window icon, or restores the platform default for `None`.
+    ///
+    /// Icons are created from RGBA pixels with [`Icon::from_rgba`]. Not all
+    /// platforms show window icons.
+    pub fn set_icon(&self, icon: Option<Icon>) {
+        self.request(|requests| requests.icon = Some(icon));
+    }
+
+    /// Switches the window between windowed, maximized, minimized, and
+    /// fullscreen.
+    pub fn set_mode(&self, mode: WindowMode) {
+        self.request(|requests| requests.mode = Some(mode));
+    }
+
+    /// Resizes the window's content area to the given logical size. The
+    /// platform may adjust or ignore the request, e.g. while maximized.
+    pub fn set_size(&self, width: f32, height: f32) {
+        self.request(|requests| requests.size = Some((width, height)));
+    }
+
+    /// Moves the window's top-left corner to the given logical position on
+    /// the desktop. Not supported on all platforms, e.g. Wayland.
+    pub fn set_position(&self, x: f32, y: f32) {
+        self.request(|requests| requests.position = Some((x, y)));
+    }
+
+    /// Shows or hides the platform's title bar and borders. Apps hiding
+    /// them draw their own title bar and mark its parts with
+    /// [`set_region`](Self::set_region).
+    pub fn set_decorations(&self, decorations: bool) {
+        self.request(|requests| requests.decorations = Some(decorations));
+    }
+
+    /// Makes `node` act as part of the window's title bar.
+    ///
+    /// Presses on drag regions are not dispatched to the scene; control
+    /// regions still receive their events, so buttons can show hover and
+    /// pressed states.
+    ///
+    /// ```rust,ignore
+    /// let window = app.window();
+    /// window.set_decorations(false);
+    /// window.set_region(title_bar, WindowRegion::Drag);
+    /// window.set_region(close_button, WindowRegion::Close);
+    /// ```
+    pub fn set_region(&self, node: NodeId, region: WindowRegion) {
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.regions.insert(node, region);
+        }
+    }
+
+    /// Makes `node` an ordinary node again.
+    pub fn clear_region(&self, node: NodeId) {
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.regions.remove(&node);
+        }
+    }
+
+    /// Returns the region under `point`, in the root's coordinate space,
+    /// with the node it was set on.
+    pub(crate) fn region_at(
+        &self,
+        scene: &Scene,
+        point: (f32, f32),
+    ) -> Option<(NodeId, WindowRegion)> {
+        let node = hit_test(scene, point)?;
+        let mut shared = self.shared.lock().ok()?;
+        shared.regions.retain(|&id, _| scene.get_node(id).is_some());
+        let mut current = Some(node);
+        while let Some(id) = current {
+            if let Some(&region) = shared.regions.get(&id) {
+                return Some((id, region));
+            }
+            current = scene.get_node(id)?.parent();
+        }
+        None
+    }
+
+    /// Returns the window's current mode.
+    pub fn mode(&self) -> WindowMode {
+        self.snapshot().mode
+    }
+
+    /// Returns the logical size of the window's content area, or zero
+    /// before the window opens.
+    pub fn size(&self) -> (f32, f32) {
+        self.snapshot().size
+    }
+
+    /// Returns the logical position of the window's top-left corner, if the
+    /// platform reports it.
+    pub fn position(&self) -> Option<(f32, f32)> {
+        self.snapshot().position
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        self.shared
+            .lock()
+            .map(|shared| shared.snapshot)
+            .unwrap_or_default()
+    }
+
+    /// Records the window's current state for the getters, after it was
+    /// resized, moved, or changed mode.
+    pub(crate) fn sync(&self, window: &Window) {
+        let scale_factor = window.scale_factor();
+        let size = window.inner_size().to_logical::<f32>(scale_factor);
+        let position = window
+            .outer_position()
+            .ok()
+            .map(|position| position.to_logical::<f32>(scale_factor));
+        let mode = if window.fullscreen().is_some() {
+            WindowMode::Fullscreen
+        } else if window.is_minimized() == Some(true) {
+            WindowMode::Minimized
+        } else if window.is_maximized() {
+            WindowMode::Maximized
+        } else {
+            WindowMode::Windowed
+        };
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.snapshot = Snapshot {
+                mode,
+                size: (size.width, size.height),
+                position: position.map(|position| (position.x, position.y)),
+            };
+        }
+    }
+
+    /// Records a change and wakes the event loop to apply it.
+    fn request(&self, change: impl FnOnce(&mut Requests)) {
+        let Ok(mut shared) = self.shared.lock() else {
+            return;
+        };
+        change(&mut shared.requests);
+        // Fails only once the event loop has exited.
+        let _ = shared.proxy.send_event(());
+    }
+
+    /// Applies all pending changes to the window.
+    pub(crate) fn apply(&self, window: &Window) {
+        let Ok(mut shared) = self.shared.lock() else {
+            return;
+        };
+        let requests = std::mem::take(&mut shared.requests);
+        drop(shared);
+
+        if let Some(title) = requests.title {
+            window.set_title(&title);
+        }
+        if let Some(cursor) = requests.cursor {
+            window.set_cursor(cursor);
+        }
+        if let Some(icon) = requests.icon {
+            window.set_window_icon(icon);
+        }
+        if let Some(mode) = requests.mode {
+            apply_mode(window, mode);
+        }
+        if let Some((width, height)) = requests.size {
+            let _ = window.request_inner_size(LogicalSize::new(width, height));
+        }
+        if let Some((x, y)) = requests.position {
+            window.set_outer_position(LogicalPosition::new(x, y));
+        }
+        if let Some(decorations) = requests.decorations {
+            window.set_decorations(decorations);
+        }
+        self.sync(window);
+    }
+}
+
+/// Performs the action of a title bar control. Returns `true` if the app
+/// should exit.
+pub(crate) fn apply_control(window: &Window, region: WindowRegion) -> bool {
+    match region {
+        WindowRegion::Drag => {}
+        WindowRegion::Minimize => window.set_minimized(true),
+        WindowRegion::Maximize => window.set_maximized(!window.is_maximized()),
+        WindowRegion::Close => return true,
+    }
+    false
+}
+
+fn apply_mode(window: &Window, mode: WindowMode) {
+    match mode {
+        WindowMode::Windowed => {
+            window.set_fullscreen(None);
+            window.set_minimized(false);
+            window.set_maximized(false);
+        }
+        WindowMode::Maximized => {
+            window.set_fullscreen(None);
+            window.set_minimized(false);
+            window.set_maximized(true);
+        }
+        WindowMode::Minimized => window.set_minimized(true),
+        WindowMode::Fullscreen => {
+            window.set_minimized(false);
+            window.set_fullscreen(Some(Fullscreen::Borderless(None)));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Scene traversal over synthetic scenes of increasing size and depth.
+//!
+//! Run with `cargo bench -p ardent-core --features synthetic`.
+
+use ardent_core::scene::SyntheticScene;
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+const DEPTHS: [usize; 2] = [4, 16];
+
+fn traverse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("traverse");
+    for nodes in SIZES {
+        group.throughput(Throughput::Elements(nodes as u64));
+        for depth in DEPTHS {
+            let scene = SyntheticScene::new(nodes).depth(depth).build();
+            group.bench_with_input(
+                BenchmarkId::new(format!("depth {depth}"), nodes),
+                &scene,
+                |b, scene| {
+                    b.iter(|| {
+                        let mut shapes = 0;
+                        scene.traverse(|node| shapes += node.shape().is_some() as usize);
+                        black_box(shapes)
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+fn traverse_mut(c: &mut Criterion) {
+    let mut group = c.benchmark_group("traverse_mut");
+    for nodes in SIZES {
+        group.throughput(Throughput::Elements(nodes as u64));
+        let mut scene = SyntheticScene::new(nodes).build();
+        group.bench_function(BenchmarkId::from_parameter(nodes), |b| {
+            b.iter(|| scene.traverse_mut(|node| node.clear_dirty()))
+        });
+    }
+    group.finish();
+}
+
+fn touch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("touch");
+    for ratio in [0.01, 0.1, 1.0] {
+        let generator = SyntheticScene::new(10_000).dirty_ratio(ratio);
+        let mut scene = generator.build();
+        group.bench_function(BenchmarkId::from_parameter(ratio), |b| {
+            b.iter(|| generator.touch(&mut scene))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, traverse, traverse_mut, touch);
+criterion_main!(benches);
@@ -0,0 +1,186 @@
+//! Time-based animation of node properties.
+//!
+//! An [`Animator`] owns all running animations and advances them once per
+//! frame via [`Animator::tick`]. Each animation writes its current value into
+//! the targeted node, which marks the node dirty through the usual accessors
+//! so the renderer picks up the change.
+//!
+//! Animations are described by the [`Animation`] trait. The built-in
+//! [`Tween`] interpolates a single [`Property`] between two values over a
+//! fixed duration with an [`Easing`] curve, while a [`Spring`] follows a
+//! (possibly moving) target with damped spring physics. A [`Timeline`]
+//! combines keyframed tracks for several properties and can loop.
+//!
+//! Nodes can also declare a [`Transition`] per property; values written
+//! through [`Animator::set`] then animate implicitly.
+//!
+//! To avoid busy-looping, applications should only request continuous
+//! redraws while [`Animator::is_active`] returns `true`, using a
+//! [`FrameClock`] to measure the time between frames.
+
+mod clock;
+mod easing;
+mod property;
+mod spring;
+mod timeline;
+mod transition;
+mod tween;
+
+pub use clock::FrameClock;
+pub use easing::Easing;
+pub use property::{Property, Value};
+pub use spring::Spring;
+pub use timeline::{Keyframe, Repeat, Timeline, TimelineAnimation, Track};
+pub use transition::Transition;
+pub use tween::Tween;
+
+use std::collections::HashMap;
+
+use crate::node::{Node, NodeId};
+use crate::scene::Scene;
+
+/// A unique identifier for an animation registered with an [`Animator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnimationId(u64);
+
+/// Something that changes a node's properties over time.
+///
+/// Implementors are advanced by the [`Animator`] once per frame. They are
+/// required to be `Send` and `Sync` so that animators can live on any
+/// thread and be shared like scenes.
+pub trait Animation: Send + Sync {
+    /// Returns the node this animation drives.
+    fn target(&self) -> NodeId;
+
+    /// Advances the animation by `dt` seconds and writes the new value(s)
+    /// into `node`.
+    ///
+    /// Returns `true` once the animation has finished. A finished animation
+    /// must already have written its final value.
+    fn advance(&mut self, dt: f32, node: &mut Node) -> bool;
+
+    /// Changes the value the animation is heading toward.
+    ///
+    /// Animations that cannot change course ignore this.
+    fn retarget(&mut self, _target: Value) {}
+}
+
+/// Owns and advances the active animations of a scene.
+///
+/// Call [`tick`](Animator::tick) once per frame with the elapsed time. The
+/// return value tells the caller whether anything changed and a redraw is
+/// needed.
+#[derive(Default)]
+pub struct Animator {
+    /// Active animations in insertion order.
+    animations: Vec<(AnimationId, Box<dyn Animation>)>,
+
+    /// Running implicit transitions, so a new value restarts the transition
+    /// of the same property instead of fighting it.
+    transitions: HashMap<(NodeId, Property), AnimationId>,
+
+    /// Counter used to generate animation IDs.
+    next_id: u64,
+}
+
+impl Animator {
+    /// Creates an animator with no active animations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts an animation and returns an ID that can be used to cancel it.
+    ///
+    /// Animations added later take precedence when several animations
+    /// write the same property of the same node.
+    pub fn add(&mut self, animation: impl Animation + 'static) -> AnimationId {
+        let id = AnimationId(self.next_id);
+        self.next_id += 1;
+        self.animations.push((id, Box::new(animation)));
+        id
+    }
+
+    /// Sets a node property, animating it if the node declares a transition.
+    ///
+    /// Without a transition for `property`, the value is applied immediately.
+    /// Otherwise a tween from the current value is started, replacing any
+    /// transition already running on the same property. Returns `false` if
+    /// the node does not exist.
+    pub fn set(
+        &mut self,
+        scene: &mut Scene,
+        node: NodeId,
+        property: Property,
+        value: Value,
+    ) -> bool {
+        let Some(target) = scene.get_node_mut(node) else {
+            return false;
+        };
+
+        if let Some(previous) = self.transitions.remove(&(node, property)) {
+            self.cancel(previous);
+        }
+
+        match target.transition(property) {
+            Some(transition) => {
+                let tween = Tween::to(target, property, value, transition.duration)
+                    .with_easing(transition.easing);
+                let id = self.add(tween);
+                self.transitions.insert((node, property), id);
+            }
+            None => property.set(target, value),
+        }
+        true
+    }
+
+    /// Stops an animation, leaving the node property at its current value.
+    pub fn cancel(&mut self, id: AnimationId) {
+        self.animations
+            .retain(|(animation_id, _)| *animation_id != id);
+    }
+
+    /// Changes the target value of a running animation (e.g., a spring
+    /// following the pointer during a drag).
+    pub fn retarget(&mut self, id: AnimationId, target: Value) {
+        if let Some((_, animation)) = self
+            .animations
+            .iter_mut()
+            .find(|(animation_id, _)| *animation_id == id)
+        {
+            animation.retarget(target);
+        }
+    }
+
+    /// Stops all animations targeting the given node.
+    pub fn cancel_node(&mut self, node: NodeId) {
+        self.animations
+            .retain(|(_, animation)| animation.target() != node);
+        self.transitions.retain(|(target, _), _| *target != node);
+    }
+
+    /// Returns `true` if any animation is still running.
+    pub fn is_active(&self) -> bool {
+        !self.animations.is_empty()
+    }
+
+    /// Advances all animations by `dt` seconds.
+    ///
+    /// Finished animations and animations whose node no longer exists are
+    /// removed. Returns `true` if any node was updated, meaning the scene
+    /// needs to be redrawn.
+    pub fn tick(&mut self, scene: &mut Scene, dt: f32) -> bool {
+        let mut changed = false;
+        self.animations.retain_mut(|(_, animation)| {
+            let Some(node) = scene.get_node_mut(animation.target()) else {
+                return false;
+            };
+            changed = true;
+            !animation.advance(dt, node)
+        });
+
+        let animations = &self.animations;
+        self.transitions
+            .retain(|_, id| animations.iter().any(|(running, _)| running == id));
+        changed
+    }
+}
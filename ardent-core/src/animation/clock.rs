@@ -0,0 +1,40 @@
+use std::time::{Duration, Instant};
+
+/// Longest frame delta reported by a [`FrameClock`].
+///
+/// After the application was idle (or the process was suspended), the real
+/// elapsed time can be seconds long. Capping it keeps animations from
+/// jumping straight to their end.
+const MAX_FRAME_DELTA: Duration = Duration::from_millis(100);
+
+/// Measures the time between rendered frames for driving an [`Animator`](super::Animator).
+///
+/// Call [`tick`](FrameClock::tick) once per frame. When the application goes
+/// idle (no active animations), call [`reset`](FrameClock::reset) so the
+/// first frame of the next animation starts with a zero delta.
+#[derive(Debug, Default)]
+pub struct FrameClock {
+    last: Option<Instant>,
+}
+
+impl FrameClock {
+    /// Creates a clock that reports a zero delta on its first tick.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the seconds elapsed since the previous tick.
+    pub fn tick(&mut self) -> f32 {
+        let now = Instant::now();
+        let dt = self
+            .last
+            .map_or(Duration::ZERO, |last| (now - last).min(MAX_FRAME_DELTA));
+        self.last = Some(now);
+        dt.as_secs_f32()
+    }
+
+    /// Forgets the previous tick.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
@@ -0,0 +1,88 @@
+/// Maps linear animation progress to eased progress.
+///
+/// Easing curves shape how a value moves between its start and end,
+/// e.g. starting slowly and speeding up. All curves map 0.0 to 0.0 and
+/// 1.0 to 1.0.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+
+    /// Starts slowly and accelerates (quadratic).
+    EaseIn,
+
+    /// Starts quickly and decelerates (quadratic).
+    EaseOut,
+
+    /// Accelerates, then decelerates (cubic).
+    EaseInOut,
+
+    /// A CSS-style cubic Bézier curve through (0, 0), (x1, y1), (x2, y2), (1, 1).
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Returns the eased progress for linear progress `t` in [0.0, 1.0].
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let u = -2.0 * t + 2.0;
+                    1.0 - u * u * u / 2.0
+                }
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
+
+/// Evaluates a unit cubic Bézier easing curve at `x`.
+///
+/// Solves for the curve parameter with a few Newton iterations, falling back
+/// to bisection when the slope is too flat.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    let sample = |a: f32, b: f32, s: f32| {
+        let u = 1.0 - s;
+        3.0 * u * u * s * a + 3.0 * u * s * s * b + s * s * s
+    };
+    let slope = |a: f32, b: f32, s: f32| {
+        let u = 1.0 - s;
+        3.0 * u * u * a + 6.0 * u * s * (b - a) + 3.0 * s * s * (1.0 - b)
+    };
+
+    let mut s = x;
+    for _ in 0..8 {
+        let error = sample(x1, x2, s) - x;
+        if error.abs() < 1e-5 {
+            return sample(y1, y2, s);
+        }
+        let d = slope(x1, x2, s);
+        if d.abs() < 1e-6 {
+            break;
+        }
+        s -= error / d;
+    }
+
+    let (mut lo, mut hi) = (0.0, 1.0);
+    s = x;
+    for _ in 0..32 {
+        let value = sample(x1, x2, s);
+        if (value - x).abs() < 1e-5 {
+            break;
+        }
+        if value < x {
+            lo = s;
+        } else {
+            hi = s;
+        }
+        s = (lo + hi) / 2.0;
+    }
+    sample(y1, y2, s)
+}
@@ -0,0 +1,180 @@
+use crate::node::Node;
+use crate::style::{Color, Fill, GradientStop, Stroke};
+
+/// A node property that can be animated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Property {
+    /// The transform's translation, as [`Value::Vec2`].
+    Translate,
+
+    /// The transform's scale, as [`Value::Vec2`].
+    Scale,
+
+    /// The transform's rotation in radians, as [`Value::Float`].
+    Rotate,
+
+    /// The fill color, as [`Value::Color`].
+    FillColor,
+
+    /// The style opacity, as [`Value::Float`].
+    Opacity,
+
+    /// The color of the n-th stop of the fill gradient, as [`Value::Color`].
+    GradientStopColor(usize),
+
+    /// The offset of the n-th stop of the fill gradient, as [`Value::Float`].
+    ///
+    /// Animating offsets moves bands of color across a shape, e.g. for
+    /// skeleton-loading shimmer effects.
+    GradientStopOffset(usize),
+
+    /// The start of the stroke trim range, as [`Value::Float`].
+    TrimStart,
+
+    /// The end of the stroke trim range, as [`Value::Float`].
+    ///
+    /// Animating this from 0.0 to 1.0 draws the outline progressively.
+    TrimEnd,
+}
+
+/// The value of an animatable property.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    Float(f32),
+    Vec2(f32, f32),
+    Color(Color),
+}
+
+impl Property {
+    /// Reads the current value of this property from a node.
+    ///
+    /// A node without a fill reports a transparent fill color.
+    pub fn get(self, node: &Node) -> Value {
+        let transform = node.transform();
+        let style = node.style();
+        match self {
+            Property::Translate => Value::Vec2(transform.translate.0, transform.translate.1),
+            Property::Scale => Value::Vec2(transform.scale.0, transform.scale.1),
+            Property::Rotate => Value::Float(transform.rotate),
+            Property::FillColor => Value::Color(
+                style
+                    .fill
+                    .as_ref()
+                    .map(|fill| fill.color)
+                    .unwrap_or_else(Color::transparent),
+            ),
+            Property::Opacity => Value::Float(style.opacity),
+            Property::GradientStopColor(i) => {
+                Value::Color(gradient_stop(node, i).map_or_else(Color::transparent, |s| s.color))
+            }
+            Property::GradientStopOffset(i) => {
+                Value::Float(gradient_stop(node, i).map_or(0.0, |s| s.offset))
+            }
+            Property::TrimStart => {
+                Value::Float(style.stroke.as_ref().map_or(0.0, |s| s.trim_start))
+            }
+            Property::TrimEnd => Value::Float(style.stroke.as_ref().map_or(1.0, |s| s.trim_end)),
+        }
+    }
+
+    /// Writes a value into this property of a node, marking it dirty.
+    ///
+    /// Values of the wrong kind for the property are ignored. Setting the
+    /// fill color of a node without a fill creates a solid fill; setting a
+    /// gradient stop that does not exist, or trimming a node without a
+    /// stroke, has no effect.
+    pub fn set(self, node: &mut Node, value: Value) {
+        match (self, value) {
+            (Property::Translate, Value::Vec2(x, y)) => node.transform_mut().translate = (x, y),
+            (Property::Scale, Value::Vec2(x, y)) => node.transform_mut().scale = (x, y),
+            (Property::Rotate, Value::Float(angle)) => node.transform_mut().rotate = angle,
+            (Property::FillColor, Value::Color(color)) => match &mut node.style_mut().fill {
+                Some(fill) => fill.color = color,
+                fill @ None => *fill = Some(Fill::solid(color)),
+            },
+            (Property::Opacity, Value::Float(opacity)) => node.style_mut().opacity = opacity,
+            (Property::GradientStopColor(i), Value::Color(color)) => {
+                if let Some(stop) = gradient_stop_mut(node, i) {
+                    stop.color = color;
+                }
+            }
+            (Property::GradientStopOffset(i), Value::Float(offset)) => {
+                if let Some(stop) = gradient_stop_mut(node, i) {
+                    stop.offset = offset;
+                }
+            }
+            (Property::TrimStart, Value::Float(start)) => {
+                if let Some(stroke) = stroke_mut(node) {
+                    stroke.trim_start = start;
+                }
+            }
+            (Property::TrimEnd, Value::Float(end)) => {
+                if let Some(stroke) = stroke_mut(node) {
+                    stroke.trim_end = end;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Value {
+    /// Linearly interpolates between `self` (at `t = 0`) and `other` (at `t = 1`).
+    ///
+    /// `t` may lie outside [0.0, 1.0] for overshooting easing curves. Colors
+    /// are blended perceptually (see [`Color::lerp_perceptual`]). Values of
+    /// different kinds cannot be blended and snap at the midpoint.
+    pub fn lerp(self, other: Value, t: f32) -> Value {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        match (self, other) {
+            (Value::Float(a), Value::Float(b)) => Value::Float(mix(a, b)),
+            (Value::Vec2(ax, ay), Value::Vec2(bx, by)) => Value::Vec2(mix(ax, bx), mix(ay, by)),
+            (Value::Color(a), Value::Color(b)) => Value::Color(a.lerp_perceptual(b, t)),
+            _ if t < 0.5 => self,
+            _ => other,
+        }
+    }
+
+    /// Returns the components of this value and how many are in use.
+    ///
+    /// Used by physics-based animations that integrate each component
+    /// independently.
+    pub(crate) fn components(self) -> ([f32; 4], usize) {
+        match self {
+            Value::Float(v) => ([v, 0.0, 0.0, 0.0], 1),
+            Value::Vec2(x, y) => ([x, y, 0.0, 0.0], 2),
+            Value::Color(Color(r, g, b, a)) => ([r, g, b, a], 4),
+        }
+    }
+
+    /// Builds a value of the same kind as `self` from raw components.
+    pub(crate) fn with_components(self, c: [f32; 4]) -> Value {
+        match self {
+            Value::Float(_) => Value::Float(c[0]),
+            Value::Vec2(..) => Value::Vec2(c[0], c[1]),
+            Value::Color(_) => Value::Color(Color(c[0], c[1], c[2], c[3])),
+        }
+    }
+}
+
+/// Returns the n-th stop of a node's fill gradient.
+fn gradient_stop(node: &Node, index: usize) -> Option<&GradientStop> {
+    let gradient = node.style().fill.as_ref()?.gradient.as_ref()?;
+    gradient.stops().get(index)
+}
+
+/// Returns the n-th stop of a node's fill gradient for modification.
+///
+/// The node is only marked style-dirty if the stop exists.
+fn gradient_stop_mut(node: &mut Node, index: usize) -> Option<&mut GradientStop> {
+    gradient_stop(node, index)?;
+    let gradient = node.style_mut().fill.as_mut()?.gradient.as_mut()?;
+    gradient.stops_mut().get_mut(index)
+}
+
+/// Returns a node's stroke for modification, marking it style-dirty only if
+/// the node has a stroke.
+fn stroke_mut(node: &mut Node) -> Option<&mut Stroke> {
+    node.style().stroke.as_ref()?;
+    node.style_mut().stroke.as_mut()
+}
@@ -0,0 +1,144 @@
+use super::{Animation, Property, Value};
+use crate::node::{Node, NodeId};
+
+/// Largest integration step in seconds. Longer frames are subdivided so the
+/// simulation stays stable with stiff springs or frame hitches.
+const MAX_STEP: f32 = 1.0 / 240.0;
+
+/// Displacement and velocity below which the spring is considered at rest.
+const REST_EPSILON: f32 = 1e-3;
+
+/// Drives a property toward a target value with a damped spring.
+///
+/// Unlike a [`Tween`](super::Tween), a spring has no fixed duration: it
+/// keeps its velocity when the target changes, so values follow interactive
+/// input (e.g., a drag) and settle naturally. By default the spring is
+/// critically damped, reaching the target as fast as possible without
+/// overshooting.
+///
+/// # Example
+/// ```rust,ignore
+/// let id = animator.add(Spring::new(node_id, Property::Translate, Value::Vec2(100.0, 0.0)));
+/// // Later, while dragging:
+/// animator.retarget(id, Value::Vec2(pointer_x, pointer_y));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Spring {
+    node: NodeId,
+    property: Property,
+    target: Value,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    /// Current value, read from the node on the first frame.
+    current: Option<[f32; 4]>,
+    velocity: [f32; 4],
+}
+
+impl Spring {
+    /// Creates a critically damped spring with a moderate stiffness.
+    pub fn new(node: NodeId, property: Property, target: Value) -> Self {
+        Self {
+            node,
+            property,
+            target,
+            stiffness: 170.0,
+            damping: 2.0 * 170.0_f32.sqrt(),
+            mass: 1.0,
+            current: None,
+            velocity: [0.0; 4],
+        }
+    }
+
+    /// Sets the spring stiffness (force per unit of displacement).
+    ///
+    /// The damping ratio is preserved.
+    pub fn with_stiffness(mut self, stiffness: f32) -> Self {
+        let ratio = self.damping_ratio();
+        self.stiffness = stiffness;
+        self.with_damping_ratio(ratio)
+    }
+
+    /// Sets the mass attached to the spring.
+    ///
+    /// The damping ratio is preserved.
+    pub fn with_mass(mut self, mass: f32) -> Self {
+        let ratio = self.damping_ratio();
+        self.mass = mass;
+        self.with_damping_ratio(ratio)
+    }
+
+    /// Sets the damping coefficient directly.
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Sets the damping relative to critical damping.
+    ///
+    /// `1.0` is critically damped, values below `1.0` overshoot and
+    /// oscillate, and values above `1.0` approach the target more slowly.
+    pub fn with_damping_ratio(mut self, ratio: f32) -> Self {
+        self.damping = ratio * 2.0 * (self.stiffness * self.mass).sqrt();
+        self
+    }
+
+    /// Sets the initial velocity, in units per second, for each component.
+    pub fn with_velocity(mut self, velocity: Value) -> Self {
+        self.velocity = velocity.components().0;
+        self
+    }
+
+    /// Returns the ratio of the damping to critical damping.
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Returns the animated property.
+    pub fn property(&self) -> Property {
+        self.property
+    }
+}
+
+impl Animation for Spring {
+    fn target(&self) -> NodeId {
+        self.node
+    }
+
+    fn advance(&mut self, dt: f32, node: &mut Node) -> bool {
+        let (target, count) = self.target.components();
+        let mut current = match self.current {
+            Some(current) => current,
+            None => self.property.get(node).components().0,
+        };
+
+        let steps = (dt / MAX_STEP).ceil().max(1.0);
+        let step = dt / steps;
+        for _ in 0..steps as u32 {
+            for i in 0..count {
+                let force =
+                    -self.stiffness * (current[i] - target[i]) - self.damping * self.velocity[i];
+                self.velocity[i] += force / self.mass * step;
+                current[i] += self.velocity[i] * step;
+            }
+        }
+
+        let at_rest = (0..count).all(|i| {
+            (current[i] - target[i]).abs() < REST_EPSILON && self.velocity[i].abs() < REST_EPSILON
+        });
+        if at_rest {
+            self.property.set(node, self.target);
+            self.current = Some(target);
+            self.velocity = [0.0; 4];
+        } else {
+            self.property
+                .set(node, self.target.with_components(current));
+            self.current = Some(current);
+        }
+        at_rest
+    }
+
+    fn retarget(&mut self, target: Value) {
+        self.target = target;
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Animation, Easing, Property, Value};
+use crate::node::{Node, NodeId};
+
+/// A value a track passes through at a given time.
+#[derive(Clone, Debug)]
+pub struct Keyframe {
+    /// Time of the keyframe in seconds from the start of the timeline.
+    pub time: f32,
+
+    /// Value of the property at `time`.
+    pub value: Value,
+
+    /// Easing applied to the segment leading into this keyframe.
+    pub easing: Easing,
+}
+
+/// The keyframes of a single property.
+#[derive(Clone, Debug)]
+pub struct Track {
+    property: Property,
+    keyframes: Vec<Keyframe>,
+}
+
+impl Track {
+    /// Creates an empty track for the given property.
+    pub fn new(property: Property) -> Self {
+        Self {
+            property,
+            keyframes: Vec::new(),
+        }
+    }
+
+    /// Adds a keyframe reached with linear interpolation.
+    pub fn key(self, time: f32, value: Value) -> Self {
+        self.key_eased(time, value, Easing::Linear)
+    }
+
+    /// Adds a keyframe reached with the given easing curve.
+    ///
+    /// Keyframes may be added in any order; they are kept sorted by time.
+    pub fn key_eased(mut self, time: f32, value: Value, easing: Easing) -> Self {
+        let index = self.keyframes.partition_point(|k| k.time <= time);
+        self.keyframes.insert(
+            index,
+            Keyframe {
+                time,
+                value,
+                easing,
+            },
+        );
+        self
+    }
+
+    /// Returns the animated property.
+    pub fn property(&self) -> Property {
+        self.property
+    }
+
+    /// Returns the time of the last keyframe.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |k| k.time)
+    }
+
+    /// Returns the interpolated value at time `t`, if the track has keyframes.
+    ///
+    /// Before the first keyframe the first value is held; after the last
+    /// keyframe the last value is held.
+    pub fn sample(&self, t: f32) -> Option<Value> {
+        let next = self.keyframes.partition_point(|k| k.time <= t);
+        match (next.checked_sub(1), self.keyframes.get(next)) {
+            (Some(prev), Some(next)) => {
+                let prev = &self.keyframes[prev];
+                let span = next.time - prev.time;
+                let progress = if span > 0.0 {
+                    (t - prev.time) / span
+                } else {
+                    1.0
+                };
+                Some(prev.value.lerp(next.value, next.easing.apply(progress)))
+            }
+            (Some(prev), None) => Some(self.keyframes[prev].value),
+            (None, next) => next.map(|k| k.value),
+        }
+    }
+}
+
+/// How many times a timeline plays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Repeat {
+    /// Play once and stop.
+    #[default]
+    Once,
+
+    /// Play the given number of times.
+    Times(u32),
+
+    /// Loop until cancelled.
+    Forever,
+}
+
+/// A reusable set of keyframed tracks for several properties.
+///
+/// A timeline only describes the motion; call [`play`](Timeline::play) to
+/// obtain an [`Animation`] bound to a specific node. The same timeline can
+/// be played on any number of nodes.
+///
+/// # Example
+/// ```rust,ignore
+/// let pulse = Timeline::new()
+///     .track(Track::new(Property::Scale)
+///         .key(0.0, Value::Vec2(1.0, 1.0))
+///         .key_eased(0.5, Value::Vec2(1.2, 1.2), Easing::EaseOut))
+///     .repeat(Repeat::Forever)
+///     .ping_pong(true);
+/// animator.add(pulse.play(node_id));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Timeline {
+    tracks: Vec<Track>,
+    repeat: Repeat,
+    ping_pong: bool,
+}
+
+impl Timeline {
+    /// Creates an empty timeline that plays once.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a track.
+    pub fn track(mut self, track: Track) -> Self {
+        self.tracks.push(track);
+        self
+    }
+
+    /// Sets how many times the timeline plays.
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// When enabled, every other iteration plays backwards.
+    pub fn ping_pong(mut self, ping_pong: bool) -> Self {
+        self.ping_pong = ping_pong;
+        self
+    }
+
+    /// Returns the length of one iteration in seconds.
+    pub fn duration(&self) -> f32 {
+        self.tracks.iter().map(Track::duration).fold(0.0, f32::max)
+    }
+
+    /// Returns the local time within an iteration for the given elapsed time,
+    /// and whether playback has finished.
+    fn local_time(&self, elapsed: f32) -> (f32, bool) {
+        let duration = self.duration();
+        if duration <= 0.0 {
+            return (0.0, true);
+        }
+
+        let iterations = match self.repeat {
+            Repeat::Once => Some(1),
+            Repeat::Times(n) => Some(n.max(1)),
+            Repeat::Forever => None,
+        };
+        let reversed = |iteration: u32| self.ping_pong && iteration % 2 == 1;
+
+        if let Some(n) = iterations
+            && elapsed >= duration * n as f32
+        {
+            let end = if reversed(n - 1) { 0.0 } else { duration };
+            return (end, true);
+        }
+
+        let iteration = (elapsed / duration).floor() as u32;
+        let local = elapsed - iteration as f32 * duration;
+        let local = if reversed(iteration) {
+            duration - local
+        } else {
+            local
+        };
+        (local, false)
+    }
+
+    /// Binds the timeline to a node, producing a playable animation.
+    pub fn play(&self, node: NodeId) -> TimelineAnimation {
+        TimelineAnimation {
+            node,
+            timeline: self.clone(),
+            elapsed: 0.0,
+        }
+    }
+}
+
+/// A [`Timeline`] playing on a specific node.
+#[derive(Clone, Debug)]
+pub struct TimelineAnimation {
+    node: NodeId,
+    timeline: Timeline,
+    elapsed: f32,
+}
+
+impl Animation for TimelineAnimation {
+    fn target(&self) -> NodeId {
+        self.node
+    }
+
+    fn advance(&mut self, dt: f32, node: &mut Node) -> bool {
+        self.elapsed += dt;
+        let (t, finished) = self.timeline.local_time(self.elapsed);
+        for track in &self.timeline.tracks {
+            if let Some(value) = track.sample(t) {
+                track.property.set(node, value);
+            }
+        }
+        finished
+    }
+}
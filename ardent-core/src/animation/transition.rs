@@ -0,0 +1,32 @@
+use super::Easing;
+
+/// Describes how changes to a node property are animated implicitly.
+///
+/// Attach a transition to a node with
+/// [`Node::set_transition`](crate::node::Node::set_transition). Values set
+/// through [`Animator::set`](super::Animator::set) then animate from the
+/// current value instead of snapping, much like CSS transitions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    /// Duration of the transition in seconds.
+    pub duration: f32,
+
+    /// Easing curve applied to the transition.
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// Creates a transition with the given duration and an ease-in-out curve.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            easing: Easing::EaseInOut,
+        }
+    }
+
+    /// Sets the easing curve.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
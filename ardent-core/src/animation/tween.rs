@@ -0,0 +1,70 @@
+use super::{Animation, Easing, Property, Value};
+use crate::node::{Node, NodeId};
+
+/// Interpolates one property of a node between two values over a fixed duration.
+///
+/// # Example
+/// ```rust,ignore
+/// let tween = Tween::new(node_id, Property::Opacity, Value::Float(0.0), Value::Float(1.0), 0.3)
+///     .with_easing(Easing::EaseOut);
+/// animator.add(tween);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tween {
+    node: NodeId,
+    property: Property,
+    from: Value,
+    to: Value,
+    duration: f32,
+    elapsed: f32,
+    easing: Easing,
+}
+
+impl Tween {
+    /// Creates a linear tween lasting `duration` seconds.
+    pub fn new(node: NodeId, property: Property, from: Value, to: Value, duration: f32) -> Self {
+        Self {
+            node,
+            property,
+            from,
+            to,
+            duration,
+            elapsed: 0.0,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Creates a tween from the node's current property value to `to`.
+    pub fn to(node: &Node, property: Property, to: Value, duration: f32) -> Self {
+        Self::new(node.id(), property, property.get(node), to, duration)
+    }
+
+    /// Sets the easing curve.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the animated property.
+    pub fn property(&self) -> Property {
+        self.property
+    }
+}
+
+impl Animation for Tween {
+    fn target(&self) -> NodeId {
+        self.node
+    }
+
+    fn advance(&mut self, dt: f32, node: &mut Node) -> bool {
+        self.elapsed += dt;
+        let progress = if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        };
+        let value = self.from.lerp(self.to, self.easing.apply(progress));
+        self.property.set(node, value);
+        progress >= 1.0
+    }
+}
@@ -0,0 +1,136 @@
+//! Copy and paste support.
+//!
+//! The clipboard itself is provided by the host application, since access
+//! to the system clipboard depends on the windowing stack. Ardent only
+//! defines the [`ClipboardProvider`] interface and routes copy, cut, and
+//! paste requests to nodes as [`Event`]s, so editable nodes such as text
+//! inputs decide what is copied and how pasted text is inserted.
+//!
+//! # Example
+//! ```rust,ignore
+//! struct SystemClipboard(arboard::Clipboard);
+//!
+//! impl ClipboardProvider for SystemClipboard {
+//!     fn text(&mut self) -> Option<String> {
+//!         self.0.get_text().ok()
+//!     }
+//!
+//!     fn set_text(&mut self, text: &str) {
+//!         let _ = self.0.set_text(text);
+//!     }
+//! }
+//!
+//! let clipboard = Clipboard::new(SystemClipboard(arboard::Clipboard::new()?));
+//!
+//! // On Ctrl+V:
+//! clipboard.paste(&scene, focused);
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::event::Event;
+use crate::node::NodeId;
+use crate::scene::Scene;
+
+/// Reads and writes text on a clipboard, e.g. the system clipboard.
+pub trait ClipboardProvider: Send {
+    /// Returns the text currently on the clipboard, if any.
+    fn text(&mut self) -> Option<String>;
+
+    /// Replaces the clipboard contents with `text`.
+    fn set_text(&mut self, text: &str);
+}
+
+/// A clipboard private to the application, used when no system clipboard
+/// is available (e.g. in tests or headless tools).
+#[derive(Debug, Default)]
+pub struct MemoryClipboard {
+    text: Option<String>,
+}
+
+impl ClipboardProvider for MemoryClipboard {
+    fn text(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+    }
+}
+
+/// A shared handle to a clipboard.
+///
+/// Handles are cheap to clone. Copy and cut events carry one, so event
+/// handlers can write their selection without capturing the clipboard.
+#[derive(Clone)]
+pub struct Clipboard(Arc<Mutex<dyn ClipboardProvider>>);
+
+impl Clipboard {
+    /// Wraps a clipboard provider.
+    pub fn new(provider: impl ClipboardProvider + 'static) -> Self {
+        Self(Arc::new(Mutex::new(provider)))
+    }
+
+    /// Returns the text currently on the clipboard, if any.
+    pub fn text(&self) -> Option<String> {
+        self.lock().text()
+    }
+
+    /// Replaces the clipboard contents with `text`.
+    pub fn set_text(&self, text: &str) {
+        self.lock().set_text(text);
+    }
+
+    /// Asks the node `target` to copy its selection to this clipboard.
+    ///
+    /// Returns `true` if the node has an event handler.
+    pub fn copy(&self, scene: &Scene, target: NodeId) -> bool {
+        dispatch(scene, target, Event::Copy(self.clone()))
+    }
+
+    /// Asks the node `target` to move its selection to this clipboard.
+    ///
+    /// Returns `true` if the node has an event handler.
+    pub fn cut(&self, scene: &Scene, target: NodeId) -> bool {
+        dispatch(scene, target, Event::Cut(self.clone()))
+    }
+
+    /// Sends the clipboard text to the node `target`.
+    ///
+    /// Returns `true` if the clipboard holds text and the node has an event
+    /// handler.
+    pub fn paste(&self, scene: &Scene, target: NodeId) -> bool {
+        match self.text() {
+            Some(text) => dispatch(scene, target, Event::Paste(text)),
+            None => false,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, dyn ClipboardProvider + 'static> {
+        // Providers hold no invariants across calls, so a poisoned lock is
+        // still usable.
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for Clipboard {
+    /// Creates a handle to a new [`MemoryClipboard`].
+    fn default() -> Self {
+        Self::new(MemoryClipboard::default())
+    }
+}
+
+impl fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clipboard").finish_non_exhaustive()
+    }
+}
+
+fn dispatch(scene: &Scene, target: NodeId, event: Event) -> bool {
+    scene
+        .get_node(target)
+        .is_some_and(|node| node.handle_event(event))
+}
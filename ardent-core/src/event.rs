@@ -1,3 +1,5 @@
+use crate::clipboard::Clipboard;
+
 /// Represents a basic user interaction or input event.
 ///
 /// Events describe interactions such as mouse clicks or hover changes.
@@ -5,7 +7,7 @@
 ///
 /// Events are designed to be high-level and shape-aware — they are routed
 /// to specific nodes based on hit-testing results, not as global signals.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum Event {
     /// The user clicked on the node (usually via left mouse button).
     Click,
@@ -15,9 +17,32 @@ pub enum Event {
 
     /// The pointer exited the node’s area (hover end).
     PointerLeave,
+
+    /// The user turned the mouse wheel or swiped a touchpad over the node,
+    /// by the given horizontal and vertical distance in logical pixels.
+    Scroll(f32, f32),
+
+    /// The user pressed the pointer on the node and started moving it.
+    DragStart,
+
+    /// The pointer moved by the given distance while dragging.
+    DragUpdate(f32, f32),
+
+    /// The user released the pointer after dragging.
+    DragEnd,
+
+    /// The user asked to copy the node's selection, e.g. with Ctrl+C. The
+    /// handler writes the selection to the given clipboard.
+    Copy(Clipboard),
+
+    /// The user asked to cut the node's selection, e.g. with Ctrl+X. The
+    /// handler writes the selection to the given clipboard and removes it.
+    Cut(Clipboard),
+
+    /// The user pasted the given text into the node, e.g. with Ctrl+V.
+    Paste(String),
     // TODO:
-    // DragStart, DragUpdate, DragEnd
-    // KeyPress(char), Scroll(f32), etc.
+    // KeyPress(char), etc.
 }
 
 /// A boxed callback that responds to an input `Event`.
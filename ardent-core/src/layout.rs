@@ -0,0 +1,137 @@
+mod dimension;
+mod edges;
+mod grid;
+mod measure;
+mod scroll;
+mod stack;
+
+pub use dimension::Dimension;
+pub use edges::Edges;
+pub use grid::{GridLayout, GridPlacement, TrackSize};
+pub use measure::{AvailableSpace, MeasureFn};
+pub use scroll::{Axis, Overflow, ScrollLayout};
+pub use stack::StackLayout;
+
+/// Describes how a node takes part in layout.
+///
+/// The layout style is pure data; the actual layout pass lives in the
+/// `ardent_layout` crate, which reads these properties and writes the
+/// resulting position into each node's transform and the resulting box into
+/// its [`ComputedLayout`].
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LayoutStyle {
+    /// How this node arranges its children.
+    pub display: Display,
+
+    /// Preferred width of the box, including padding.
+    pub width: Dimension,
+
+    /// Preferred height of the box, including padding.
+    pub height: Dimension,
+
+    /// Smallest allowed width. Takes precedence over `max_width`.
+    pub min_width: Dimension,
+
+    /// Smallest allowed height. Takes precedence over `max_height`.
+    pub min_height: Dimension,
+
+    /// Largest allowed width, or unconstrained when auto.
+    pub max_width: Dimension,
+
+    /// Largest allowed height, or unconstrained when auto.
+    pub max_height: Dimension,
+
+    /// Preferred width divided by height. When only one of the sizes is
+    /// known, the other is derived from it.
+    pub aspect_ratio: Option<f32>,
+
+    /// Space between the box's edges and its children.
+    pub padding: Edges,
+
+    /// Space kept free around the box within the area assigned by the
+    /// parent. Manually positioned nodes only use it to size auto-sized
+    /// parents.
+    pub margin: Edges,
+
+    /// Columns occupied by this node when its parent is a grid.
+    pub grid_column: GridPlacement,
+
+    /// Rows occupied by this node when its parent is a grid.
+    pub grid_row: GridPlacement,
+
+    /// Whether content outside the box is clipped. Scroll containers always
+    /// clip.
+    pub overflow: Overflow,
+
+    /// Makes this node a scrollbar thumb along the given axis when its
+    /// parent is a scroll container.
+    pub scrollbar: Option<Axis>,
+
+    /// Horizontal alignment within the area assigned by the parent,
+    /// overriding the parent's default item alignment.
+    pub justify_self: Option<Align>,
+
+    /// Vertical alignment within the area assigned by the parent,
+    /// overriding the parent's default item alignment.
+    pub align_self: Option<Align>,
+}
+
+/// How a node arranges its children.
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Display {
+    /// Children are positioned manually through their transforms.
+    ///
+    /// Their own subtrees are still laid out.
+    #[default]
+    Manual,
+
+    /// Children are placed into the cells of a grid.
+    Grid(GridLayout),
+
+    /// Children are stacked on top of each other.
+    Stack(StackLayout),
+
+    /// Children are scrolled within the node's box.
+    Scroll(ScrollLayout),
+}
+
+/// Alignment of an item within the area assigned to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Align {
+    /// Align to the start (left or top) edge.
+    Start,
+
+    /// Center within the area.
+    Center,
+
+    /// Align to the end (right or bottom) edge.
+    End,
+
+    /// Fill the whole area, unless the item has an explicit size.
+    ///
+    /// Margins and min/max constraints still apply.
+    #[default]
+    Stretch,
+}
+
+/// The box assigned to a node by the most recent layout pass.
+///
+/// Coordinates are relative to the parent node's origin.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ComputedLayout {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl LayoutStyle {
+    /// Returns `true` if content outside the node's box is clipped.
+    pub fn clips(&self) -> bool {
+        self.overflow == Overflow::Clip || matches!(self.display, Display::Scroll(_))
+    }
+}
@@ -0,0 +1,49 @@
+/// A length along one axis of a node's box.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dimension {
+    /// Sized from content, or unconstrained when used as a limit.
+    #[default]
+    Auto,
+
+    /// A fixed length in logical pixels.
+    Px(f32),
+
+    /// A percentage of the parent's content box, where `100.0` is the full
+    /// size.
+    Percent(f32),
+
+    /// A percentage of the viewport width, like CSS `vw`.
+    Vw(f32),
+
+    /// A percentage of the viewport height, like CSS `vh`.
+    Vh(f32),
+}
+
+impl Dimension {
+    /// Resolves the dimension against the parent's content size and the
+    /// viewport size.
+    ///
+    /// Returns `None` for [`Dimension::Auto`], and for percentages when the
+    /// parent size is not known yet.
+    pub fn resolve(self, parent: Option<f32>, viewport: (f32, f32)) -> Option<f32> {
+        match self {
+            Dimension::Auto => None,
+            Dimension::Px(px) => Some(px),
+            Dimension::Percent(percent) => parent.map(|size| size * percent / 100.0),
+            Dimension::Vw(percent) => Some(viewport.0 * percent / 100.0),
+            Dimension::Vh(percent) => Some(viewport.1 * percent / 100.0),
+        }
+    }
+
+    /// Returns `true` if the dimension is [`Dimension::Auto`].
+    pub fn is_auto(self) -> bool {
+        self == Dimension::Auto
+    }
+}
+
+impl From<f32> for Dimension {
+    fn from(px: f32) -> Self {
+        Dimension::Px(px)
+    }
+}
@@ -0,0 +1,48 @@
+/// Spacing on the four sides of a box, in logical pixels.
+///
+/// Used for [`padding`](super::LayoutStyle::padding) and
+/// [`margin`](super::LayoutStyle::margin).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Edges {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Edges {
+    /// No spacing on any side.
+    pub const ZERO: Self = Self::all(0.0);
+
+    /// The same spacing on every side.
+    pub const fn all(value: f32) -> Self {
+        Self {
+            top: value,
+            right: value,
+            bottom: value,
+            left: value,
+        }
+    }
+
+    /// Separate spacing for the top/bottom and left/right sides.
+    pub const fn symmetric(vertical: f32, horizontal: f32) -> Self {
+        Self {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+
+    /// Total spacing along the horizontal axis.
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    /// Total spacing along the vertical axis.
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
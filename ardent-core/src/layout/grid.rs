@@ -0,0 +1,138 @@
+use super::Align;
+
+/// A grid container, similar to CSS grid.
+///
+/// Columns and rows are described by track sizes. Children are placed by
+/// their [`GridPlacement`]s, or automatically into the next free cell in
+/// row-major order. Rows are added implicitly when items do not fit.
+///
+/// # Example
+/// ```rust,ignore
+/// node.layout_mut().display = Display::Grid(
+///     GridLayout::new()
+///         .columns([TrackSize::Px(200.0), TrackSize::Fr(1.0)])
+///         .rows([TrackSize::Px(48.0), TrackSize::Fr(1.0)])
+///         .gap(8.0),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct GridLayout {
+    /// Column track sizes, left to right.
+    pub columns: Vec<TrackSize>,
+
+    /// Row track sizes, top to bottom.
+    pub rows: Vec<TrackSize>,
+
+    /// Size of rows created implicitly for items beyond the explicit rows.
+    pub auto_rows: TrackSize,
+
+    /// Horizontal space between columns.
+    pub column_gap: f32,
+
+    /// Vertical space between rows.
+    pub row_gap: f32,
+
+    /// Default horizontal alignment of items within their cells.
+    pub justify_items: Align,
+
+    /// Default vertical alignment of items within their cells.
+    pub align_items: Align,
+}
+
+impl GridLayout {
+    /// Creates an empty grid with a single implicit column.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the column tracks.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = TrackSize>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    /// Sets the row tracks.
+    pub fn rows(mut self, rows: impl IntoIterator<Item = TrackSize>) -> Self {
+        self.rows = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets both the row and column gap.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.row_gap = gap;
+        self.column_gap = gap;
+        self
+    }
+
+    /// Sets the default alignment of items in both directions.
+    pub fn align(mut self, align: Align) -> Self {
+        self.justify_items = align;
+        self.align_items = align;
+        self
+    }
+}
+
+/// The size of a grid row or column.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrackSize {
+    /// A fixed size in logical pixels.
+    Px(f32),
+
+    /// A share of the space left after fixed and auto tracks.
+    Fr(f32),
+
+    /// As large as the largest item spanning only this track.
+    #[default]
+    Auto,
+
+    /// As small as possible without overflowing the items spanning only
+    /// this track, e.g. wrapping text at every opportunity.
+    MinContent,
+
+    /// As large as the largest item spanning only this track, measured
+    /// without any wrapping.
+    MaxContent,
+}
+
+/// The cells a grid item occupies along one axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct GridPlacement {
+    /// Zero-based index of the first track, or `None` for auto-placement.
+    pub start: Option<usize>,
+
+    /// Number of tracks spanned (at least 1).
+    pub span: usize,
+}
+
+impl GridPlacement {
+    /// Places the item automatically, spanning one track.
+    pub const AUTO: Self = Self {
+        start: None,
+        span: 1,
+    };
+
+    /// Places the item at the given track, spanning one track.
+    pub fn at(start: usize) -> Self {
+        Self {
+            start: Some(start),
+            span: 1,
+        }
+    }
+
+    /// Sets the number of tracks spanned.
+    pub fn span(mut self, span: usize) -> Self {
+        self.span = span.max(1);
+        self
+    }
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self::AUTO
+    }
+}
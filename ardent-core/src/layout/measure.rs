@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+/// The space available to a node's content along the horizontal axis while
+/// it is being measured.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AvailableSpace {
+    /// The content must fit the given width, e.g. text wraps at it.
+    Definite(f32),
+
+    /// The smallest width the content can take without overflowing, e.g. the
+    /// widest word of a text.
+    MinContent,
+
+    /// The width the content takes without any wrapping.
+    MaxContent,
+}
+
+/// Computes the intrinsic size of a node's content for the given space.
+///
+/// Set through [`Node::set_measure`](crate::node::Node::set_measure) for
+/// content whose size the layout pass cannot know by itself, like text.
+pub type MeasureFn = Arc<dyn Fn(AvailableSpace) -> (f32, f32) + Send + Sync>;
@@ -0,0 +1,86 @@
+/// A container whose children may be larger than the container itself and
+/// are scrolled into view.
+///
+/// Children are stacked at the top-left corner of the content box and
+/// shifted by the scroll offset. Along scrolling axes they keep their natural
+/// size; along the other axes they are fitted to the container. Content
+/// outside the container is clipped.
+///
+/// Children with [`LayoutStyle::scrollbar`](super::LayoutStyle::scrollbar)
+/// set are not scrolled but act as scrollbar thumbs, sized and positioned
+/// along the container's edge to reflect the visible part of the content.
+///
+/// # Example
+/// ```rust,ignore
+/// node.layout_mut().display = Display::Scroll(ScrollLayout::vertical());
+/// node.layout_mut().height = Dimension::Px(300.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ScrollLayout {
+    /// Whether content scrolls horizontally.
+    pub horizontal: bool,
+
+    /// Whether content scrolls vertically.
+    pub vertical: bool,
+
+    /// How far the content is scrolled, in logical pixels. The layout pass
+    /// clamps it to the scrollable range.
+    pub offset: (f32, f32),
+}
+
+impl ScrollLayout {
+    /// Creates a container scrolling vertically.
+    pub fn vertical() -> Self {
+        Self {
+            horizontal: false,
+            vertical: true,
+            offset: (0.0, 0.0),
+        }
+    }
+
+    /// Creates a container scrolling horizontally.
+    pub fn horizontal() -> Self {
+        Self {
+            horizontal: true,
+            vertical: false,
+            offset: (0.0, 0.0),
+        }
+    }
+
+    /// Creates a container scrolling in both directions.
+    pub fn both() -> Self {
+        Self {
+            horizontal: true,
+            vertical: true,
+            offset: (0.0, 0.0),
+        }
+    }
+}
+
+impl Default for ScrollLayout {
+    fn default() -> Self {
+        Self::vertical()
+    }
+}
+
+/// A horizontal or vertical direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+/// Whether a node's content is clipped to its box.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Overflow {
+    /// Content outside the box is drawn.
+    #[default]
+    Visible,
+
+    /// Content outside the box is clipped away.
+    Clip,
+}
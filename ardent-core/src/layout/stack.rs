@@ -0,0 +1,40 @@
+use super::Align;
+
+/// A container that stacks its children on top of each other.
+///
+/// The stack sizes itself to its largest child, and every child is aligned
+/// within the stack's content box. Later children are drawn on top. This is
+/// the building block for overlays such as badges, spinners, and gradients
+/// over content.
+///
+/// # Example
+/// ```rust,ignore
+/// // Pin a badge to the top-right corner of an image.
+/// node.layout_mut().display = Display::Stack(StackLayout::new().align(Align::Center));
+/// badge.layout_mut().justify_self = Some(Align::End);
+/// badge.layout_mut().align_self = Some(Align::Start);
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StackLayout {
+    /// Default horizontal alignment of children.
+    pub justify_items: Align,
+
+    /// Default vertical alignment of children.
+    pub align_items: Align,
+}
+
+impl StackLayout {
+    /// Creates a stack that stretches its children.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the default alignment of children in both directions.
+    pub fn align(mut self, align: Align) -> Self {
+        self.justify_items = align;
+        self.align_items = align;
+        self
+    }
+}
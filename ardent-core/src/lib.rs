@@ -1,15 +1,32 @@
+pub mod animation;
+pub mod clipboard;
 pub mod event;
+pub mod layout;
 pub mod node;
+pub mod reactive;
 pub mod scene;
 pub mod shape;
 pub mod style;
 pub mod transform;
+pub mod view;
 
 pub mod prelude {
+    pub use crate::animation::{
+        Animation, Animator, Easing, FrameClock, Property, Repeat, Spring, Timeline, Track,
+        Transition, Tween, Value,
+    };
+    pub use crate::clipboard::{Clipboard, ClipboardProvider, MemoryClipboard};
     pub use crate::event::*;
-    pub use crate::node::Node;
-    pub use crate::scene::Scene;
+    pub use crate::layout::*;
+    pub use crate::node::{DirtyFlags, Node, Role};
+    pub use crate::reactive::{Computed, Signal};
+    pub use crate::scene;
+    pub use crate::scene::{
+        BindingId, ChangeObserverId, FrameCallbackId, NodeBuilder, Scene, SceneChange, SceneError,
+        TimerId,
+    };
     pub use crate::shape::*;
     pub use crate::style::*;
     pub use crate::transform::Transform;
+    pub use crate::view::{View, ViewRoot};
 }
@@ -1,22 +1,37 @@
-use std::sync::atomic::{AtomicU64, Ordering};
+mod dirty;
+mod lod;
+mod role;
 
-use crate::event::EventHandler;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub use dirty::DirtyFlags;
+pub use lod::{Lod, LodLevel};
+pub use role::Role;
+
+use crate::animation::{Property, Transition};
+use crate::event::{Event, EventHandler};
+use crate::layout::{AvailableSpace, ComputedLayout, LayoutStyle, MeasureFn};
 use crate::shape::Shape;
 use crate::style::Style;
 use crate::transform::Transform;
 
 /// A unique identifier for a node within the scene graph.
 ///
-/// Every node has a stable `NodeId` that distinguishes it from other nodes.
-/// These are used for parenting, traversal, event routing, and lookup.
+/// Every node in a scene has a stable `NodeId` that distinguishes it from
+/// other nodes. These are used for parenting, traversal, event routing, and
+/// lookup.
+///
+/// IDs are assigned by the scene when a node is inserted, counting up from
+/// the root's ID of 1, and are never reused within a scene. Building the
+/// same scene twice therefore yields the same IDs, independent of other
+/// scenes in the process.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NodeId(pub u64);
 
-/// Generates a new globally unique `NodeId`.
-fn generate_id() -> NodeId {
-    static COUNTER: AtomicU64 = AtomicU64::new(1);
-    let id = COUNTER.fetch_add(1, Ordering::Relaxed);
-    NodeId(id)
+impl NodeId {
+    /// The ID of a node that has not been inserted into a scene yet.
+    pub const DETACHED: NodeId = NodeId(0);
 }
 
 /// A node in the scene graph representing a visual or interactive element.
@@ -52,39 +67,99 @@ pub struct Node {
     /// Optional event handler function.
     on_event: Option<EventHandler>,
 
-    /// Dirty flag for re-rendering.
-    dirty: bool,
+    /// How this node is sized and how it arranges its children.
+    layout: LayoutStyle,
+
+    /// The box assigned by the most recent layout pass.
+    computed_layout: ComputedLayout,
+
+    /// Optional measure function for content sized outside the layout pass.
+    measure: Option<MeasureFn>,
+
+    /// Implicit transitions applied when properties are set via the animator.
+    transitions: HashMap<Property, Transition>,
+
+    /// Semantic role exposed to assistive technologies.
+    role: Role,
+
+    /// Accessible name, e.g. the text of a button.
+    label: Option<String>,
+
+    /// Accessible value, e.g. the contents of a text field.
+    value: Option<String>,
+
+    /// Text shown in a tooltip while the pointer rests on the node.
+    tooltip: Option<String>,
+
+    /// Zoom range the subtree is drawn at, and what replaces it outside.
+    lod: Option<Lod>,
+
+    /// Tessellation tolerance overriding the renderer's, in local units.
+    tolerance: Option<f32>,
+
+    /// Which aspects of the node changed since it was last rendered.
+    dirty: DirtyFlags,
 }
 
 impl Node {
-    /// Creates a new scene graph node with a unique ID and default properties.
+    /// Smallest tessellation tolerance, in local units. Finer tolerances
+    /// are raised to it, as lyon cannot flatten curves more finely.
+    pub const MIN_TOLERANCE: f32 = 1e-4;
+
+    /// Creates a new detached scene graph node with default properties.
+    ///
+    /// The node gets its ID when it is inserted into a scene.
     ///
     /// By default, the node has no shape or parent, no styling, and no
     /// event handler. It is initialized with an identity transform and an
-    /// empty list of children. The node is marked as dirty initially.
+    /// empty list of children. The node is marked as fully dirty initially.
     ///
     /// This method is useful when building a new scene from scratch.
     pub fn new() -> Self {
         Self {
-            id: generate_id(),
+            id: NodeId::DETACHED,
             parent: None,
             children: Vec::new(),
             transform: Transform::default(),
             shape: None,
             style: Style::default(),
             on_event: None,
-            dirty: true,
+            layout: LayoutStyle::default(),
+            computed_layout: ComputedLayout::default(),
+            measure: None,
+            transitions: HashMap::new(),
+            role: Role::Generic,
+            label: None,
+            value: None,
+            tooltip: None,
+            lod: None,
+            tolerance: None,
+            dirty: DirtyFlags::ALL,
         }
     }
 
-    /// Returns the globally unique identifier of this node.
+    /// Returns the identifier of this node within its scene.
     ///
-    /// `NodeId` values are assigned automatically when a node is created,
+    /// `NodeId` values are assigned by the scene when the node is inserted,
     /// and they remain stable and unique for the lifetime of the node.
+    /// Detached nodes return [`NodeId::DETACHED`].
     pub fn id(&self) -> NodeId {
         self.id
     }
 
+    /// Gives the node its ID in a scene.
+    pub(crate) fn set_id(&mut self, id: NodeId) {
+        self.id = id;
+    }
+
+    /// Replaces every child ID with `map(id)`, e.g. when the node moves to
+    /// another scene.
+    pub(crate) fn remap_children(&mut self, map: impl Fn(NodeId) -> NodeId) {
+        for child in &mut self.children {
+            *child = map(*child);
+        }
+    }
+
     /// Returns the ID of the parent node, if this node has a parent.
     ///
     /// Parent-child relationships define the scene graph structure and
@@ -115,6 +190,12 @@ impl Node {
         self.children.push(child);
     }
 
+    /// Inserts a child node ID at `index`, or at the end if `index` is past
+    /// the end of the child list.
+    pub(crate) fn insert_child(&mut self, index: usize, child: NodeId) {
+        self.children.insert(index.min(self.children.len()), child);
+    }
+
     /// Removes a child node ID if present.
     ///
     /// This does not delete the actual node from the scene graph — it only
@@ -134,8 +215,9 @@ impl Node {
     /// Returns a mutable reference to the node's transform.
     ///
     /// Use this to modify the position, scale, or rotation of the node
-    /// in its parent's coordinate space.
+    /// in its parent's coordinate space. The node is marked transform-dirty.
     pub fn transform_mut(&mut self) -> &mut Transform {
+        self.dirty.insert(DirtyFlags::TRANSFORM);
         &mut self.transform
     }
 
@@ -150,7 +232,9 @@ impl Node {
     /// Sets the shape to be rendered for this node.
     ///
     /// The shape is drawn using the styling information provided by `style()`.
+    /// The node is marked geometry- and layout-dirty.
     pub fn set_shape(&mut self, shape: Shape) {
+        self.dirty.insert(DirtyFlags::GEOMETRY | DirtyFlags::LAYOUT);
         self.shape = Some(shape);
     }
 
@@ -158,6 +242,7 @@ impl Node {
     ///
     /// After calling this, the node will no longer be visually rendered.
     pub fn clear_shape(&mut self) {
+        self.dirty.insert(DirtyFlags::GEOMETRY | DirtyFlags::LAYOUT);
         self.shape = None;
     }
 
@@ -171,10 +256,63 @@ impl Node {
     /// Returns a mutable reference to this node's style.
     ///
     /// Use this to update fill color, stroke color, or other styling parameters.
+    /// The node is marked style-dirty.
     pub fn style_mut(&mut self) -> &mut Style {
+        self.dirty.insert(DirtyFlags::STYLE);
         &mut self.style
     }
 
+    /// Returns the layout properties of this node.
+    ///
+    /// These are read by the layout pass in `ardent_layout` to size and
+    /// position this node and its children.
+    pub fn layout(&self) -> &LayoutStyle {
+        &self.layout
+    }
+
+    /// Returns a mutable reference to this node's layout properties.
+    ///
+    /// The node is marked layout-dirty.
+    pub fn layout_mut(&mut self) -> &mut LayoutStyle {
+        self.dirty.insert(DirtyFlags::LAYOUT);
+        &mut self.layout
+    }
+
+    /// Returns the box assigned to this node by the most recent layout pass.
+    pub fn computed_layout(&self) -> &ComputedLayout {
+        &self.computed_layout
+    }
+
+    /// Stores the result of a layout pass.
+    ///
+    /// Normally called by the layout pass only.
+    pub fn set_computed_layout(&mut self, layout: ComputedLayout) {
+        self.computed_layout = layout;
+    }
+
+    /// Returns the function measuring this node's content, if one is set.
+    pub fn measure(&self) -> Option<&MeasureFn> {
+        self.measure.as_ref()
+    }
+
+    /// Sets a function measuring this node's content.
+    ///
+    /// Auto-sized nodes use it instead of their shape's size, e.g. to size
+    /// around text. The node is marked layout-dirty.
+    pub fn set_measure<F>(&mut self, measure: F)
+    where
+        F: Fn(AvailableSpace) -> (f32, f32) + Send + Sync + 'static,
+    {
+        self.dirty.insert(DirtyFlags::LAYOUT);
+        self.measure = Some(Arc::new(measure));
+    }
+
+    /// Removes the measure function. The node is marked layout-dirty.
+    pub fn clear_measure(&mut self) {
+        self.dirty.insert(DirtyFlags::LAYOUT);
+        self.measure = None;
+    }
+
     /// Assigns an event handler to this node.
     ///
     /// This allows the node to respond to user interaction like mouse clicks
@@ -188,23 +326,187 @@ impl Node {
         self.on_event = None;
     }
 
-    /// Returns `true` if the node is marked as dirty.
+    /// Returns `true` if the node has an event handler.
+    pub fn has_event_handler(&self) -> bool {
+        self.on_event.is_some()
+    }
+
+    /// Invokes the node's event handler with `event`.
+    ///
+    /// Returns `true` if the node has an event handler.
+    pub fn handle_event(&self, event: Event) -> bool {
+        match &self.on_event {
+            Some(handler) => {
+                handler(event);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Declares that changes to `property` should animate with `transition`.
+    ///
+    /// Only values set through `Animator::set` are animated; direct writes
+    /// via `transform_mut` or `style_mut` still apply immediately.
+    pub fn set_transition(&mut self, property: Property, transition: Transition) {
+        self.transitions.insert(property, transition);
+    }
+
+    /// Removes the transition for `property`, so changes apply immediately.
+    pub fn clear_transition(&mut self, property: Property) {
+        self.transitions.remove(&property);
+    }
+
+    /// Returns the transition declared for `property`, if any.
+    pub fn transition(&self, property: Property) -> Option<Transition> {
+        self.transitions.get(&property).copied()
+    }
+
+    /// Returns the semantic role of this node.
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    /// Sets the semantic role exposed to assistive technologies.
+    pub fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
+    /// Returns the accessible name of this node, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Sets the accessible name read by screen readers, e.g. the text of a
+    /// button or a description of an image.
+    pub fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
+
+    /// Removes the accessible name.
+    pub fn clear_label(&mut self) {
+        self.label = None;
+    }
+
+    /// Returns the accessible value of this node, if any.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Sets the accessible value, e.g. the contents of a text field or the
+    /// position of a slider.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = Some(value.into());
+    }
+
+    /// Removes the accessible value.
+    pub fn clear_value(&mut self) {
+        self.value = None;
+    }
+
+    /// Returns the tooltip text of this node, if any.
+    pub fn tooltip(&self) -> Option<&str> {
+        self.tooltip.as_deref()
+    }
+
+    /// Sets the text shown in a tooltip while the pointer rests on this
+    /// node or on a descendant without a tooltip of its own.
+    ///
+    /// Tooltips are shown by `Tooltips` in `ardent_input` and exposed to
+    /// assistive technologies as a description.
+    pub fn set_tooltip(&mut self, tooltip: impl Into<String>) {
+        self.tooltip = Some(tooltip.into());
+    }
+
+    /// Removes the tooltip.
+    pub fn clear_tooltip(&mut self) {
+        self.tooltip = None;
+    }
+
+    /// Returns the level-of-detail settings of this node's subtree, if any.
+    pub fn lod(&self) -> Option<&Lod> {
+        self.lod.as_ref()
+    }
+
+    /// Sets the zoom range this node and its descendants are drawn at, and
+    /// the shape drawn instead when zoomed out.
+    ///
+    /// The node is marked geometry-dirty.
+    pub fn set_lod(&mut self, lod: Lod) {
+        self.dirty.insert(DirtyFlags::GEOMETRY);
+        self.lod = Some(lod);
+    }
+
+    /// Removes the level-of-detail settings, drawing the subtree at every
+    /// zoom.
+    pub fn clear_lod(&mut self) {
+        self.dirty.insert(DirtyFlags::GEOMETRY);
+        self.lod = None;
+    }
+
+    /// Returns the tessellation tolerance of this node's shape, if it
+    /// overrides the renderer's.
+    pub fn tolerance(&self) -> Option<f32> {
+        self.tolerance
+    }
+
+    /// Sets the largest distance, in local units, between the node's curves
+    /// and the straight segments they are drawn with, in place of the
+    /// renderer's tolerance.
+    ///
+    /// Lower it for shapes drawn greatly magnified, so curves stay smooth,
+    /// or raise it for many small shapes, so they take fewer triangles.
+    /// Non-finite and non-positive values are ignored, and values below
+    /// [`Node::MIN_TOLERANCE`] are raised to it. The node is marked
+    /// geometry-dirty.
+    pub fn set_tolerance(&mut self, tolerance: f32) {
+        if tolerance.is_finite() && tolerance > 0.0 {
+            self.dirty.insert(DirtyFlags::GEOMETRY);
+            self.tolerance = Some(tolerance.max(Self::MIN_TOLERANCE));
+        }
+    }
+
+    /// Removes the node's tolerance, tessellating it with the renderer's.
+    pub fn clear_tolerance(&mut self) {
+        self.dirty.insert(DirtyFlags::GEOMETRY);
+        self.tolerance = None;
+    }
+
+    /// Returns `true` if any aspect of the node is marked as dirty.
     ///
     /// Dirty nodes are those that have changed and need to be redrawn.
     pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Returns the set of aspects that changed since the node was last rendered.
+    ///
+    /// The renderer uses this to decide whether a node needs re-tessellation
+    /// or merely an updated transform or color.
+    pub fn dirty_flags(&self) -> DirtyFlags {
         self.dirty
     }
 
-    /// Marks this node as dirty, indicating it needs to be redrawn.
+    /// Marks every aspect of this node as dirty, forcing a full redraw.
     ///
     /// Typically triggered when a property like shape, style, or transform changes.
     pub fn mark_dirty(&mut self) {
-        self.dirty = true;
+        self.dirty = DirtyFlags::ALL;
+    }
+
+    /// Marks only the given aspects of this node as dirty.
+    pub fn mark_dirty_flags(&mut self, flags: DirtyFlags) {
+        self.dirty.insert(flags);
     }
 
-    /// Clears the dirty flag, indicating the node has been rendered.
+    /// Clears the dirty flags, indicating the node has been rendered.
     pub fn clear_dirty(&mut self) {
-        self.dirty = false;
+        self.dirty = DirtyFlags::NONE;
+    }
+
+    /// Clears only the given aspects, e.g. after a layout pass.
+    pub fn clear_dirty_flags(&mut self, flags: DirtyFlags) {
+        self.dirty.remove(flags);
     }
 }
 
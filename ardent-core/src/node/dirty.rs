@@ -0,0 +1,71 @@
+/// A set of flags describing which aspects of a node changed since it was
+/// last rendered or laid out.
+///
+/// Splitting the dirty state lets the renderer do the minimum amount of work:
+/// a node whose transform or style changed can reuse its tessellated mesh and
+/// only refresh its per-node uniforms, while a geometry change requires the
+/// shape to be tessellated again. Likewise, the layout pass only re-solves
+/// the parts of the tree containing layout-dirty nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DirtyFlags(u8);
+
+impl DirtyFlags {
+    /// Nothing changed.
+    pub const NONE: Self = Self(0);
+
+    /// The node's shape changed and must be re-tessellated.
+    pub const GEOMETRY: Self = Self(1 << 0);
+
+    /// The node's transform changed (affects this node and its descendants).
+    pub const TRANSFORM: Self = Self(1 << 1);
+
+    /// The node's style (e.g., fill color) changed.
+    pub const STYLE: Self = Self(1 << 2);
+
+    /// The node's layout inputs (layout style, children, or content size)
+    /// changed and its part of the tree must be laid out again.
+    pub const LAYOUT: Self = Self(1 << 3);
+
+    /// Every aspect of the node changed.
+    pub const ALL: Self =
+        Self(Self::GEOMETRY.0 | Self::TRANSFORM.0 | Self::STYLE.0 | Self::LAYOUT.0);
+
+    /// Returns `true` if no flags are set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all flags in `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any flag in `other` is set.
+    pub fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Sets all flags in `other`.
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Clears all flags in `other`.
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+impl std::ops::BitOr for DirtyFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for DirtyFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs);
+    }
+}
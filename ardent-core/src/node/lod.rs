@@ -0,0 +1,89 @@
+use crate::shape::Shape;
+
+/// Level-of-detail settings of a subtree, e.g. a region of a map or a
+/// cluster of nodes on a canvas.
+///
+/// The zoom of a node is the scale at which it is drawn: the combined
+/// scale of its own and its ancestors' transforms, so zooming a canvas by
+/// scaling its root node zooms every group inside it. Between
+/// [`min_zoom`](Self::min_zoom) and [`max_zoom`](Self::max_zoom) the
+/// subtree is drawn as usual. Zoomed out further, it is replaced by the
+/// [`fallback`](Self::fallback) shape drawn with the node's style, or
+/// skipped entirely without one; zoomed in further, it is skipped. Skipped
+/// subtrees cost nothing to draw, so frame times stay flat however much
+/// detail is zoomed out of view.
+///
+/// Only drawing is affected; hidden nodes still take part in layout and
+/// hit testing.
+///
+/// ```rust,ignore
+/// let district = NodeBuilder::new()
+///     .lod(Lod::new(0.5, f32::INFINITY).fallback(Shape::Rect(Rect::new(400.0, 300.0))))
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lod {
+    /// Smallest zoom at which the subtree is drawn.
+    pub min_zoom: f32,
+
+    /// Largest zoom at which the subtree is drawn.
+    pub max_zoom: f32,
+
+    /// Simplified shape drawn in place of the subtree below `min_zoom`.
+    pub fallback: Option<Shape>,
+}
+
+/// How a subtree with [`Lod`] settings is drawn at a given zoom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LodLevel<'a> {
+    /// The node and its descendants are drawn as usual.
+    Detail,
+
+    /// Only the fallback shape is drawn, in place of the node's shape.
+    Fallback(&'a Shape),
+
+    /// Nothing in the subtree is drawn.
+    Hidden,
+}
+
+impl Lod {
+    /// Creates settings drawing the subtree between `min_zoom` and
+    /// `max_zoom`, without a fallback.
+    pub fn new(min_zoom: f32, max_zoom: f32) -> Self {
+        Self {
+            min_zoom,
+            max_zoom,
+            fallback: None,
+        }
+    }
+
+    /// Returns the settings with `shape` drawn in place of the subtree
+    /// below the minimum zoom.
+    pub fn fallback(self, shape: Shape) -> Self {
+        Self {
+            fallback: Some(shape),
+            ..self
+        }
+    }
+
+    /// Returns how the subtree is drawn at `zoom`.
+    pub fn level(&self, zoom: f32) -> LodLevel<'_> {
+        if zoom < self.min_zoom {
+            self.fallback
+                .as_ref()
+                .map_or(LodLevel::Hidden, LodLevel::Fallback)
+        } else if zoom > self.max_zoom {
+            LodLevel::Hidden
+        } else {
+            LodLevel::Detail
+        }
+    }
+}
+
+impl Default for Lod {
+    /// Draws the subtree at every zoom.
+    fn default() -> Self {
+        Self::new(0.0, f32::INFINITY)
+    }
+}
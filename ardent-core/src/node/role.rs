@@ -0,0 +1,43 @@
+/// The semantic role of a node, exposed to assistive technologies such as
+/// screen readers.
+///
+/// Roles describe what a node is to the user rather than how it looks. Most
+/// nodes are purely visual and keep the default [`Role::Generic`], which
+/// assistive technologies skip over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    /// A node without semantic meaning, e.g. decoration or a layout box.
+    #[default]
+    Generic,
+
+    /// A set of related nodes, e.g. a toolbar or a form section.
+    Group,
+
+    /// A clickable control performing an action.
+    Button,
+
+    /// A two-state control that can be checked or unchecked.
+    CheckBox,
+
+    /// A control selecting a value from a range.
+    Slider,
+
+    /// An editable text field.
+    TextInput,
+
+    /// Static text, e.g. a caption.
+    Label,
+
+    /// A picture or icon. Should be given a label describing it.
+    Image,
+
+    /// A link navigating elsewhere.
+    Link,
+
+    /// A list of items.
+    List,
+
+    /// An item within a [`Role::List`].
+    ListItem,
+}
@@ -0,0 +1,30 @@
+//! Reactive state that keeps the scene in sync with application data.
+//!
+//! A [`Signal`] holds a value and remembers who read it. A [`Computed`]
+//! derives a value from signals and recomputes it lazily when they change.
+//! Scene bindings, registered with [`Scene::bind`](crate::scene::Scene::bind),
+//! update a node from signals and run again whenever a signal they read
+//! changes, so the node is marked dirty and redrawn without any manual
+//! bookkeeping.
+//!
+//! Dependencies are tracked automatically: whatever a computation or binding
+//! reads while it runs becomes a dependency.
+//!
+//! ```rust,ignore
+//! let count = Signal::new(0);
+//! let label = Computed::new({
+//!     let count = count.clone();
+//!     move || format!("Clicked {} times", count.get())
+//! });
+//!
+//! scene.bind(button, move |node| node.set_label(label.get()))?;
+//! node.set_event_handler(Box::new(move |_| count.update(|n| *n += 1)));
+//! ```
+
+mod computed;
+mod signal;
+mod tracking;
+
+pub use computed::Computed;
+pub use signal::Signal;
+pub(crate) use tracking::{Subscriber, with_observer};
@@ -0,0 +1,84 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::tracking::{Subscriber, Subscribers, with_observer};
+
+type Compute<T> = Box<dyn Fn() -> T + Send + Sync>;
+
+struct Inner<T> {
+    compute: Compute<T>,
+
+    /// The cached value, `None` until computed or after a dependency
+    /// changed.
+    value: Mutex<Option<T>>,
+    subscribers: Subscribers,
+}
+
+impl<T: Send + Sync> Subscriber for Inner<T> {
+    fn notify(&self) {
+        if let Ok(mut value) = self.value.lock() {
+            *value = None;
+        }
+        self.subscribers.notify();
+    }
+}
+
+/// A value derived from signals and other computed values.
+///
+/// The value is computed on first read and cached until one of the values
+/// read while computing it changes. Readers of a `Computed` are notified
+/// when its dependencies change, so bindings can depend on it like on a
+/// [`Signal`](super::Signal).
+///
+/// Clones share the same cache.
+pub struct Computed<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> Computed<T> {
+    /// Creates a value computed by `compute`.
+    pub fn new<F>(compute: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self {
+            inner: Arc::new(Inner {
+                compute: Box::new(compute),
+                value: Mutex::new(None),
+                subscribers: Subscribers::default(),
+            }),
+        }
+    }
+
+    /// Returns the value, computing it if a dependency changed, and
+    /// subscribes the running computation or binding to changes.
+    pub fn get(&self) -> T {
+        self.inner.subscribers.track();
+        if let Ok(value) = self.inner.value.lock()
+            && let Some(value) = &*value
+        {
+            return value.clone();
+        }
+
+        let observer: Arc<dyn Subscriber> = self.inner.clone();
+        let value = with_observer(observer, || (self.inner.compute)());
+        if let Ok(mut cached) = self.inner.value.lock() {
+            *cached = Some(value.clone());
+        }
+        value
+    }
+}
+
+impl<T> Clone for Computed<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Computed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Computed").finish_non_exhaustive()
+    }
+}
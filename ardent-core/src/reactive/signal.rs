@@ -0,0 +1,94 @@
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use super::tracking::Subscribers;
+
+struct Inner<T> {
+    value: RwLock<T>,
+    subscribers: Subscribers,
+}
+
+/// A value that notifies its readers when it changes.
+///
+/// Signals are cheap to clone; clones share the same value. They can be
+/// read and written from any thread, e.g. from event handlers or
+/// background tasks.
+pub struct Signal<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T: Send + Sync + 'static> Signal<T> {
+    /// Creates a signal holding `value`.
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                value: RwLock::new(value),
+                subscribers: Subscribers::default(),
+            }),
+        }
+    }
+
+    /// Returns a copy of the current value and subscribes the running
+    /// computation or binding to changes.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.with(T::clone)
+    }
+
+    /// Calls `f` with the current value and subscribes the running
+    /// computation or binding to changes.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.inner.subscribers.track();
+        self.with_untracked(f)
+    }
+
+    /// Calls `f` with the current value without subscribing to changes.
+    pub fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let value = self
+            .inner
+            .value
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+        f(&value)
+    }
+
+    /// Replaces the value and notifies subscribers.
+    pub fn set(&self, value: T) {
+        self.update(|current| *current = value);
+    }
+
+    /// Modifies the value in place and notifies subscribers.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        {
+            let mut value = self
+                .inner
+                .value
+                .write()
+                .unwrap_or_else(|err| err.into_inner());
+            f(&mut value);
+        }
+        self.inner.subscribers.notify();
+    }
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> Default for Signal<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug + Send + Sync + 'static> fmt::Debug for Signal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with_untracked(|value| f.debug_tuple("Signal").field(value).finish())
+    }
+}
@@ -0,0 +1,103 @@
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, Weak};
+
+/// Something that depends on reactive values and is told when they change.
+pub(crate) trait Subscriber: Send + Sync {
+    /// Called after a value the subscriber read has changed, on the thread
+    /// that changed it.
+    fn notify(&self);
+}
+
+thread_local! {
+    /// The computation currently running on this thread, which becomes a
+    /// subscriber of every value it reads.
+    static OBSERVER: RefCell<Option<Arc<dyn Subscriber>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `observer` recording the values it reads.
+///
+/// The previous observer is restored even if `f` panics, so a caught panic
+/// does not leave later reads subscribing the failed computation.
+pub(crate) fn with_observer<R>(observer: Arc<dyn Subscriber>, f: impl FnOnce() -> R) -> R {
+    /// Puts back the observer that was running when dropped.
+    struct Restore(Option<Arc<dyn Subscriber>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            OBSERVER.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let _restore = Restore(OBSERVER.with(|current| current.replace(Some(observer))));
+    f()
+}
+
+/// The subscribers of a reactive value.
+///
+/// Subscribers are held weakly, so dropping a computation or binding ends
+/// its subscriptions.
+#[derive(Default)]
+pub(crate) struct Subscribers(Mutex<Vec<Weak<dyn Subscriber>>>);
+
+impl Subscribers {
+    /// Subscribes the running computation, if any, to the value.
+    pub(crate) fn track(&self) {
+        let Some(observer) = OBSERVER.with(|current| current.borrow().clone()) else {
+            return;
+        };
+        let Ok(mut subscribers) = self.0.lock() else {
+            return;
+        };
+        let observer = Arc::downgrade(&observer);
+        if !subscribers.iter().any(|known| known.ptr_eq(&observer)) {
+            subscribers.push(observer);
+        }
+    }
+
+    /// Notifies all live subscribers that the value changed.
+    pub(crate) fn notify(&self) {
+        // Subscribers may read the value again, so the lock is released
+        // before they run.
+        let live: Vec<_> = match self.0.lock() {
+            Ok(mut subscribers) => {
+                subscribers.retain(|subscriber| subscriber.strong_count() > 0);
+                subscribers.iter().filter_map(Weak::upgrade).collect()
+            }
+            Err(_) => return,
+        };
+        for subscriber in live {
+            subscriber.notify();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::panic::{self, AssertUnwindSafe};
+
+    struct Ignore;
+
+    impl Subscriber for Ignore {
+        fn notify(&self) {}
+    }
+
+    fn current() -> Option<Arc<dyn Subscriber>> {
+        OBSERVER.with(|current| current.borrow().clone())
+    }
+
+    #[test]
+    fn panics_restore_the_previous_observer() {
+        let outer: Arc<dyn Subscriber> = Arc::new(Ignore);
+        with_observer(outer.clone(), || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                with_observer(Arc::new(Ignore), || panic!("computation failed"))
+            }));
+            assert!(result.is_err());
+            assert!(current().is_some_and(|observer| Arc::ptr_eq(&observer, &outer)));
+        });
+        assert!(current().is_none());
+    }
+}
@@ -1,6 +1,40 @@
+mod bind;
+mod builder;
+mod error;
+mod observe;
+mod svg;
+#[cfg(feature = "synthetic")]
+mod synthetic;
+mod timer;
+mod validate;
+
+use std::any::Any;
 use std::collections::HashMap;
 
-use crate::node::{Node, NodeId};
+use crate::node::{DirtyFlags, Node, NodeId};
+
+use bind::Bindings;
+pub use bind::{Binding, BindingId};
+pub use builder::{IntoFill, NodeBuilder};
+pub use error::SceneError;
+use observe::ChangeLog;
+pub use observe::{ChangeObserver, ChangeObserverId, SceneChange};
+#[cfg(feature = "synthetic")]
+pub use synthetic::SyntheticScene;
+use timer::Timers;
+pub use timer::{TimerCallback, TimerId};
+pub use validate::IntegrityError;
+
+/// A callback invoked once per rendered frame with the scene and the time
+/// elapsed since the previous frame, in seconds.
+///
+/// Callbacks are required to be thread-safe, like event handlers, so the
+/// scene can be shared with rendering threads.
+pub type FrameCallback = Box<dyn FnMut(&mut Scene, f32) + Send + Sync>;
+
+/// Identifies a registered frame callback so it can be removed later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameCallbackId(u64);
 
 /// A scene graph managing a tree of UI nodes.
 ///
@@ -11,12 +45,48 @@ use crate::node::{Node, NodeId};
 ///
 /// Unlike HTML or SVG, the `Scene` stores its nodes in a flat `HashMap` keyed
 /// by `NodeId`, with explicit parent/child references to form a tree.
+///
+/// Scenes are `Send` and `Sync`: every callback they store must be
+/// thread-safe, so a scene can be built on one thread, rendered on another,
+/// and read by several threads at once.
+///
+/// With the `debug-validate` feature, the scene checks that parent and
+/// child links are consistent after every structural change and panics
+/// otherwise, which catches misuse of [`Node::add_child`] and
+/// [`Node::set_parent`] early.
 pub struct Scene {
     /// All nodes in the scene, indexed by their unique IDs.
     nodes: HashMap<NodeId, Node>,
 
     /// The root node of the scene.
     root: NodeId,
+
+    /// Callbacks run once per frame, in registration order.
+    frame_callbacks: Vec<(FrameCallbackId, FrameCallback)>,
+
+    /// Callbacks removed while callbacks were running.
+    removed_callbacks: Vec<FrameCallbackId>,
+
+    /// Counter used to generate frame callback IDs.
+    next_callback_id: u64,
+
+    /// Change observers and the changes pending delivery to them.
+    change_log: ChangeLog,
+
+    /// Physical pixels per logical pixel of the display showing the scene.
+    scale_factor: f32,
+
+    /// Nodes bound to reactive values.
+    bindings: Bindings,
+
+    /// Callbacks scheduled to run after a delay.
+    timers: Timers,
+
+    /// The ID given to the next inserted node.
+    next_node_id: u64,
+
+    /// State a layout solver keeps between passes.
+    layout_state: Option<Box<dyn Any + Send + Sync>>,
 }
 
 impl Scene {
@@ -24,10 +94,11 @@ impl Scene {
     ///
     /// The root node is always created automatically and serves as the top-most
     /// container in the hierarchy. You can attach other nodes to it as children
-    /// using `add_node`.
+    /// using `add_node`, `add_to_root`, or `spawn`.
     pub fn new() -> Self {
-        let root = Node::new();
-        let root_id = root.id();
+        let root_id = NodeId(1);
+        let mut root = Node::new();
+        root.set_id(root_id);
 
         let mut nodes = HashMap::new();
         nodes.insert(root_id, root);
@@ -35,6 +106,15 @@ impl Scene {
         Self {
             nodes,
             root: root_id,
+            frame_callbacks: Vec::new(),
+            removed_callbacks: Vec::new(),
+            next_callback_id: 0,
+            change_log: ChangeLog::default(),
+            scale_factor: 1.0,
+            bindings: Bindings::default(),
+            timers: Timers::default(),
+            next_node_id: root_id.0 + 1,
+            layout_state: None,
         }
     }
 
@@ -45,37 +125,250 @@ impl Scene {
         self.root
     }
 
+    /// Returns the number of physical pixels per logical pixel of the
+    /// display showing the scene, e.g. 2.0 on retina displays.
+    ///
+    /// Layout snaps boxes to physical pixels with it, and content generated
+    /// at a resolution, such as text outlines, can use it to stay crisp.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Takes the state a layout solver left with the scene after its last
+    /// pass, such as a cached copy of the tree.
+    pub fn take_layout_state(&mut self) -> Option<Box<dyn Any + Send + Sync>> {
+        self.layout_state.take()
+    }
+
+    /// Keeps state for the layout solver until its next pass.
+    pub fn set_layout_state(&mut self, state: Box<dyn Any + Send + Sync>) {
+        self.layout_state = Some(state);
+    }
+
+    /// Changes the display scale factor and marks every node layout-dirty,
+    /// so the next layout pass snaps boxes to the new pixel grid.
+    ///
+    /// Non-positive and non-finite factors are ignored.
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        if scale_factor.is_finite() && scale_factor > 0.0 && scale_factor != self.scale_factor {
+            self.scale_factor = scale_factor;
+            for node in self.nodes.values_mut() {
+                node.mark_dirty_flags(DirtyFlags::LAYOUT);
+            }
+        }
+    }
+
     /// Inserts a new node into the scene graph and attaches it to a parent.
     ///
     /// The child node must be constructed by the caller. This method sets the
     /// child's parent field, updates the parent’s children list, and stores the
-    /// node in the internal registry.
+    /// node in the internal registry. The parent is marked layout-dirty.
     ///
-    /// # Panics
-    /// Panics if the `parent` node does not exist in the scene.
-    pub fn add_node(&mut self, parent: NodeId, mut node: Node) {
-        node.set_parent(parent);
-        self.nodes
+    /// Returns the ID the scene assigned to the node, or an error if the
+    /// `parent` node does not exist. The scene is left unchanged on error.
+    pub fn add_node(&mut self, parent: NodeId, mut node: Node) -> Result<NodeId, SceneError> {
+        let parent_node = self
+            .nodes
             .get_mut(&parent)
-            .map(|parent_node| parent_node.add_child(node.id()))
-            .unwrap_or_else(|| panic!("Parent node with ID {:?} not found", parent));
-        self.nodes.insert(node.id(), node);
+            .ok_or(SceneError::NodeNotFound(parent))?;
+        let id = NodeId(self.next_node_id);
+        self.next_node_id += 1;
+        parent_node.add_child(id);
+        parent_node.mark_dirty_flags(DirtyFlags::LAYOUT);
+        node.set_id(id);
+        node.set_parent(parent);
+        self.change_log.record(SceneChange::Added(id));
+        self.change_log.record(SceneChange::Modified(parent));
+        self.nodes.insert(id, node);
+        #[cfg(feature = "debug-validate")]
+        self.validate("add_node");
+        Ok(id)
+    }
+
+    /// Adds `node` as a child of the root node and returns its ID.
+    pub fn add_to_root(&mut self, node: Node) -> Result<NodeId, SceneError> {
+        self.add_node(self.root, node)
+    }
+
+    /// Inserts an empty node under `parent` and returns it for setting up.
+    ///
+    /// This saves constructing a detached node and remembering its ID
+    /// before inserting it; the ID is available from [`Node::id`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let node = scene.spawn(root)?;
+    /// node.set_shape(Shape::Rect(Rect::new(200.0, 100.0)));
+    /// node.style_mut().fill = Some(Fill::solid(Color::white()));
+    /// let id = node.id();
+    /// ```
+    pub fn spawn(&mut self, parent: NodeId) -> Result<&mut Node, SceneError> {
+        let id = self.add_node(parent, Node::new())?;
+        self.nodes.get_mut(&id).ok_or(SceneError::NodeNotFound(id))
+    }
+
+    /// Moves all nodes of `source` into this scene, with its root becoming a
+    /// child of `parent`.
+    ///
+    /// The imported nodes get new IDs in this scene, in depth-first order,
+    /// so importing is deterministic. Returns the mapping from their IDs in
+    /// `source` to their new IDs, or an error if `parent` does not exist.
+    /// Frame callbacks, bindings, timers, and observers of `source` are
+    /// dropped, since they refer to the old IDs.
+    pub fn import(
+        &mut self,
+        parent: NodeId,
+        mut source: Scene,
+    ) -> Result<HashMap<NodeId, NodeId>, SceneError> {
+        if !self.nodes.contains_key(&parent) {
+            return Err(SceneError::NodeNotFound(parent));
+        }
+
+        let mut order = Vec::new();
+        source.traverse(|node| order.push(node.id()));
+        let ids: HashMap<NodeId, NodeId> = order
+            .iter()
+            .zip(self.next_node_id..)
+            .map(|(&old, new)| (old, NodeId(new)))
+            .collect();
+        self.next_node_id += order.len() as u64;
+
+        for old in order {
+            let Some(mut node) = source.nodes.remove(&old) else {
+                continue;
+            };
+            let id = ids[&old];
+            node.set_id(id);
+            node.set_parent(node.parent().map_or(parent, |parent| ids[&parent]));
+            node.remap_children(|child| ids[&child]);
+            node.mark_dirty();
+            self.change_log.record(SceneChange::Added(id));
+            self.nodes.insert(id, node);
+        }
+
+        let root = ids[&source.root];
+        if let Some(parent_node) = self.nodes.get_mut(&parent) {
+            parent_node.add_child(root);
+            parent_node.mark_dirty_flags(DirtyFlags::LAYOUT);
+        }
+        self.change_log.record(SceneChange::Modified(parent));
+        #[cfg(feature = "debug-validate")]
+        self.validate("import");
+        Ok(ids)
     }
 
     /// Removes a node and its entire subtree from the scene graph.
     ///
     /// This will recursively delete the node and all of its children,
     /// removing them from the internal registry and detaching them from
-    /// their parent.
-    pub fn remove_node(&mut self, node_id: NodeId) {
+    /// their parent. The parent is marked layout-dirty.
+    ///
+    /// Returns an error if the node does not exist or is the root node.
+    pub fn remove_node(&mut self, node_id: NodeId) -> Result<(), SceneError> {
+        if node_id == self.root {
+            return Err(SceneError::RootNode);
+        }
+        if !self.nodes.contains_key(&node_id) {
+            return Err(SceneError::NodeNotFound(node_id));
+        }
+        self.remove_subtree(node_id);
+        #[cfg(feature = "debug-validate")]
+        self.validate("remove_node");
+        Ok(())
+    }
+
+    /// Moves a node and its subtree to the end of `parent`'s children.
+    ///
+    /// The old and new parents are marked layout-dirty, and the node
+    /// transform-dirty since its position in the world changes.
+    ///
+    /// Returns an error if either node does not exist, if `node_id` is the
+    /// root, or if `parent` is the node itself or one of its descendants.
+    /// The scene is left unchanged on error.
+    pub fn reparent(&mut self, node_id: NodeId, parent: NodeId) -> Result<(), SceneError> {
+        if node_id == self.root {
+            return Err(SceneError::RootNode);
+        }
+        let old_parent = self
+            .nodes
+            .get(&node_id)
+            .ok_or(SceneError::NodeNotFound(node_id))?
+            .parent();
+        if !self.nodes.contains_key(&parent) {
+            return Err(SceneError::NodeNotFound(parent));
+        }
+        let mut ancestor = Some(parent);
+        while let Some(id) = ancestor {
+            if id == node_id {
+                return Err(SceneError::Cycle {
+                    node: node_id,
+                    parent,
+                });
+            }
+            ancestor = self.nodes.get(&id).and_then(Node::parent);
+        }
+
+        if let Some(old_parent) = old_parent
+            && let Some(old) = self.nodes.get_mut(&old_parent)
+        {
+            old.remove_child(node_id);
+            old.mark_dirty_flags(DirtyFlags::LAYOUT);
+            self.change_log.record(SceneChange::Modified(old_parent));
+        }
+        if let Some(new) = self.nodes.get_mut(&parent) {
+            new.add_child(node_id);
+            new.mark_dirty_flags(DirtyFlags::LAYOUT);
+            self.change_log.record(SceneChange::Modified(parent));
+        }
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            node.set_parent(parent);
+            node.mark_dirty_flags(DirtyFlags::TRANSFORM);
+            self.change_log.record(SceneChange::Modified(node_id));
+        }
+        #[cfg(feature = "debug-validate")]
+        self.validate("reparent");
+        Ok(())
+    }
+
+    /// Moves a node to position `index` among its siblings, which changes
+    /// the order it is laid out and drawn in. Indices past the end move it
+    /// to the end.
+    ///
+    /// Returns an error if the node does not exist or is the root node.
+    pub fn reorder(&mut self, node_id: NodeId, index: usize) -> Result<(), SceneError> {
+        if node_id == self.root {
+            return Err(SceneError::RootNode);
+        }
+        let parent_id = self
+            .nodes
+            .get(&node_id)
+            .and_then(Node::parent)
+            .ok_or(SceneError::NodeNotFound(node_id))?;
+        let parent = self
+            .nodes
+            .get_mut(&parent_id)
+            .ok_or(SceneError::NodeNotFound(parent_id))?;
+        parent.remove_child(node_id);
+        parent.insert_child(index, node_id);
+        parent.mark_dirty_flags(DirtyFlags::LAYOUT);
+        self.change_log.record(SceneChange::Modified(parent_id));
+        #[cfg(feature = "debug-validate")]
+        self.validate("reorder");
+        Ok(())
+    }
+
+    fn remove_subtree(&mut self, node_id: NodeId) {
         if let Some(node) = self.nodes.remove(&node_id) {
-            if let Some(parent_id) = node.parent() {
-                if let Some(parent) = self.nodes.get_mut(&parent_id) {
-                    parent.remove_child(node_id);
-                }
+            self.change_log.record(SceneChange::Removed(node_id));
+            if let Some(parent_id) = node.parent()
+                && let Some(parent) = self.nodes.get_mut(&parent_id)
+            {
+                parent.remove_child(node_id);
+                parent.mark_dirty_flags(DirtyFlags::LAYOUT);
+                self.change_log.record(SceneChange::Modified(parent_id));
             }
             for child_id in node.children() {
-                self.remove_node(*child_id);
+                self.remove_subtree(*child_id);
             }
         }
     }
@@ -89,9 +382,99 @@ impl Scene {
 
     /// Returns a mutable reference to the node with the given ID, if it exists.
     ///
-    /// Use this to update properties like transform, shape, or style.
+    /// Use this to update properties like transform, shape, or style. The
+    /// node is reported to change observers as modified.
     pub fn get_node_mut(&mut self, node_id: NodeId) -> Option<&mut Node> {
-        self.nodes.get_mut(&node_id)
+        let node = self.nodes.get_mut(&node_id)?;
+        self.change_log.record(SceneChange::Modified(node_id));
+        Some(node)
+    }
+
+    /// Clears the geometry, transform, and style flags of the given nodes
+    /// once a frame showing them has been rendered.
+    ///
+    /// Renderers only read the scene, so they cannot reset these flags
+    /// themselves; without this every node stays dirty and is prepared again
+    /// each frame. Layout flags are left for the layout pass, and IDs of
+    /// removed nodes are ignored.
+    pub fn clear_dirty(&mut self, ids: impl IntoIterator<Item = NodeId>) {
+        let rendered = DirtyFlags::GEOMETRY | DirtyFlags::TRANSFORM | DirtyFlags::STYLE;
+        for id in ids {
+            if let Some(node) = self.nodes.get_mut(&id) {
+                node.clear_dirty_flags(rendered);
+            }
+        }
+    }
+
+    /// Clears the layout flag of the given nodes once a layout pass has
+    /// placed them.
+    ///
+    /// Like [`clear_dirty`](Self::clear_dirty), this is bookkeeping: the
+    /// nodes are not reported to change observers as modified. IDs of
+    /// removed nodes are ignored.
+    pub fn clear_layout_dirty(&mut self, ids: impl IntoIterator<Item = NodeId>) {
+        for id in ids {
+            if let Some(node) = self.nodes.get_mut(&id) {
+                node.clear_dirty_flags(DirtyFlags::LAYOUT);
+            }
+        }
+    }
+
+    /// Registers a callback to run once per rendered frame.
+    ///
+    /// The callback receives the scene and the frame delta time in seconds,
+    /// which makes it suitable for game-loop-style logic and custom
+    /// animations. While any callback is registered, the application should
+    /// keep rendering continuously.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// scene.on_frame(move |scene, dt| {
+    ///     if let Some(node) = scene.get_node_mut(spinner) {
+    ///         node.transform_mut().rotate += dt * std::f32::consts::PI;
+    ///     }
+    /// });
+    /// ```
+    pub fn on_frame<F>(&mut self, callback: F) -> FrameCallbackId
+    where
+        F: FnMut(&mut Scene, f32) + Send + Sync + 'static,
+    {
+        let id = FrameCallbackId(self.next_callback_id);
+        self.next_callback_id += 1;
+        self.frame_callbacks.push((id, Box::new(callback)));
+        id
+    }
+
+    /// Unregisters a frame callback.
+    pub fn remove_frame_callback(&mut self, id: FrameCallbackId) {
+        self.frame_callbacks
+            .retain(|(callback_id, _)| *callback_id != id);
+        self.removed_callbacks.push(id);
+    }
+
+    /// Returns `true` if any frame callback is registered.
+    pub fn has_frame_callbacks(&self) -> bool {
+        !self.frame_callbacks.is_empty()
+    }
+
+    /// Invokes all frame callbacks with the elapsed time `dt` in seconds.
+    ///
+    /// Call this once per frame before rendering. Callbacks may register or
+    /// remove other callbacks; newly registered ones first run next frame.
+    pub fn run_frame_callbacks(&mut self, dt: f32) {
+        self.removed_callbacks.clear();
+        let mut callbacks = std::mem::take(&mut self.frame_callbacks);
+        for (_, callback) in &mut callbacks {
+            callback(self, dt);
+        }
+
+        // Keep callbacks added during this frame, and drop those removed.
+        let removed = std::mem::take(&mut self.removed_callbacks);
+        callbacks.retain(|(id, _)| !removed.contains(id));
+        let added = std::mem::replace(&mut self.frame_callbacks, callbacks);
+        self.frame_callbacks.extend(added);
+        #[cfg(feature = "debug-validate")]
+        self.validate("run_frame_callbacks");
     }
 
     /// Traverses all nodes in the scene graph in depth-first order.
@@ -100,11 +483,12 @@ impl Scene {
     /// The traversal starts at the root node and visits children recursively.
     ///
     /// # Example
-    /// ```rust
+    /// ```rust,ignore
     /// scene.traverse(|node| {
     ///     println!("Node {:?}", node.id());
     /// });
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn traverse<F: FnMut(&Node)>(&self, mut callback: F) {
         fn recurse<F: FnMut(&Node)>(scene: &Scene, node_id: NodeId, callback: &mut F) {
             if let Some(node) = scene.get_node(node_id) {
@@ -117,9 +501,42 @@ impl Scene {
         recurse(self, self.root, &mut callback);
     }
 
+    /// Visits all nodes in the scene graph in parallel, in no particular
+    /// order.
+    ///
+    /// Use this for read-only passes over large scenes where each node is
+    /// handled on its own, such as computing bounds or preparing shapes for
+    /// tessellation. The callback runs on rayon's thread pool. Requires the
+    /// `parallel` feature.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let shapes = AtomicUsize::new(0);
+    /// scene.par_traverse(|node| {
+    ///     if node.shape().is_some() {
+    ///         shapes.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// });
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn par_traverse<F>(&self, callback: F)
+    where
+        F: Fn(&Node) + Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        // Every stored node is reachable from the root, since nodes can only
+        // be added under an existing parent and are removed with their
+        // subtree.
+        self.nodes.par_iter().for_each(|(_, node)| callback(node));
+    }
+
     /// Traverses all nodes in the scene graph mutably in depth-first order.
     ///
-    /// This is useful when modifying each node (e.g., during layout or style updates).
+    /// This is useful when modifying each node (e.g., during style updates).
+    /// Every visited node is reported to change observers as modified.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn traverse_mut<F: FnMut(&mut Node)>(&mut self, mut callback: F) {
         fn recurse<F: FnMut(&mut Node)>(scene: &mut Scene, node_id: NodeId, callback: &mut F) {
             if let Some(node) = scene.get_node_mut(node_id) {
@@ -132,6 +549,8 @@ impl Scene {
         }
         let root = self.root;
         recurse(self, root, &mut callback);
+        #[cfg(feature = "debug-validate")]
+        self.validate("traverse_mut");
     }
 }
 
@@ -140,3 +559,10 @@ impl Default for Scene {
         Self::new()
     }
 }
+
+// Scenes are shared with rendering and layout threads.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Scene>();
+    assert_send_sync::<Node>();
+};
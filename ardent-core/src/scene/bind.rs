@@ -0,0 +1,155 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use super::{Scene, SceneChange, SceneError};
+use crate::node::{Node, NodeId};
+use crate::reactive::{Subscriber, with_observer};
+
+/// A callback updating a node from reactive values, run again whenever a
+/// value it read changes.
+pub type Binding = Box<dyn FnMut(&mut Node) + Send + Sync>;
+
+/// Identifies a binding so it can be removed later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BindingId(u64);
+
+/// A callback run when a binding needs to run again, e.g. to schedule a
+/// frame.
+type Waker = Arc<dyn Fn() + Send + Sync>;
+
+/// Marks a binding for another run when one of its dependencies changes.
+struct Trigger {
+    dirty: AtomicBool,
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl Subscriber for Trigger {
+    fn notify(&self) {
+        if self.dirty.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let waker = self.waker.lock().ok().and_then(|waker| waker.clone());
+        if let Some(waker) = waker {
+            waker();
+        }
+    }
+}
+
+struct Entry {
+    id: BindingId,
+    node: NodeId,
+    update: Binding,
+    trigger: Arc<Trigger>,
+}
+
+impl Entry {
+    /// Runs the binding on its node, tracking the values it reads.
+    fn run(&mut self, node: &mut Node) {
+        let observer: Arc<dyn Subscriber> = self.trigger.clone();
+        with_observer(observer, || (self.update)(node));
+    }
+}
+
+/// Registered bindings and the waker shared with their triggers.
+#[derive(Default)]
+pub(super) struct Bindings {
+    entries: Vec<Entry>,
+    waker: Arc<Mutex<Option<Waker>>>,
+    next_id: u64,
+}
+
+impl Scene {
+    /// Binds a node to reactive values.
+    ///
+    /// `update` runs right away and again before the next frame whenever a
+    /// [`Signal`](crate::reactive::Signal) or
+    /// [`Computed`](crate::reactive::Computed) it read has changed. Node
+    /// setters mark the node dirty as usual, so the change is laid out and
+    /// redrawn. Bindings of removed nodes are dropped.
+    ///
+    /// Returns an error if the node does not exist.
+    ///
+    /// ```rust,ignore
+    /// let opacity = Signal::new(1.0);
+    /// scene.bind(id, {
+    ///     let opacity = opacity.clone();
+    ///     move |node| node.style_mut().opacity = opacity.get()
+    /// })?;
+    /// opacity.set(0.5); // applied by the next `update_bindings`
+    /// ```
+    pub fn bind<F>(&mut self, node: NodeId, update: F) -> Result<BindingId, SceneError>
+    where
+        F: FnMut(&mut Node) + Send + Sync + 'static,
+    {
+        let target = self
+            .nodes
+            .get_mut(&node)
+            .ok_or(SceneError::NodeNotFound(node))?;
+        let bindings = &mut self.bindings;
+        let id = BindingId(bindings.next_id);
+        bindings.next_id += 1;
+        let mut entry = Entry {
+            id,
+            node,
+            update: Box::new(update),
+            trigger: Arc::new(Trigger {
+                dirty: AtomicBool::new(false),
+                waker: bindings.waker.clone(),
+            }),
+        };
+        entry.run(target);
+        self.change_log.record(SceneChange::Modified(node));
+        bindings.entries.push(entry);
+        Ok(id)
+    }
+
+    /// Removes a binding.
+    pub fn unbind(&mut self, id: BindingId) {
+        self.bindings.entries.retain(|entry| entry.id != id);
+    }
+
+    /// Runs the bindings whose dependencies changed since their last run.
+    ///
+    /// Call once per frame before layout. Returns `true` if any binding
+    /// ran.
+    pub fn update_bindings(&mut self) -> bool {
+        let mut ran = false;
+        let nodes = &mut self.nodes;
+        let change_log = &mut self.change_log;
+        self.bindings.entries.retain_mut(|entry| {
+            let Some(node) = nodes.get_mut(&entry.node) else {
+                return false;
+            };
+            if entry.trigger.dirty.swap(false, Ordering::AcqRel) {
+                entry.run(node);
+                change_log.record(SceneChange::Modified(entry.node));
+                ran = true;
+            }
+            true
+        });
+        ran
+    }
+
+    /// Returns `true` if a binding waits to run because a value it depends
+    /// on changed.
+    pub fn has_pending_bindings(&self) -> bool {
+        self.bindings
+            .entries
+            .iter()
+            .any(|entry| entry.trigger.dirty.load(Ordering::Acquire))
+    }
+
+    /// Sets a callback run when a binding's dependencies change, on the
+    /// thread that changed them.
+    ///
+    /// Windowing integrations use it to schedule a frame, so changes made
+    /// from background threads show up without polling.
+    pub fn set_waker<F>(&mut self, waker: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        if let Ok(mut current) = self.bindings.waker.lock() {
+            *current = Some(Arc::new(waker));
+        }
+    }
+}
@@ -0,0 +1,424 @@
+use super::{Scene, SceneError};
+use crate::event::Event;
+use crate::layout::{Dimension, Display, LayoutStyle};
+use crate::node::{Lod, Node, NodeId, Role};
+use crate::shape::{Ellipse, Line, Polygon, Polyline, Rect, Ring, Shape};
+use crate::style::{
+    Color, Fill, ImageHandle, MeshGradient, NineSlice, Noise, PathEffect, Pattern, Stroke,
+};
+
+/// Builds a node together with its subtree.
+///
+/// Builders describe a hierarchy declaratively, so constructing a UI does
+/// not require calling `add_node` for every node and threading parent IDs
+/// by hand. The [`scene!`](crate::scene!) macro offers the same in a more
+/// compact syntax.
+///
+/// # Example
+/// ```rust,ignore
+/// let card = NodeBuilder::rect()
+///     .size(200.0, 100.0)
+///     .fill("#3380cc")
+///     .on_click(|| println!("clicked"))
+///     .child(NodeBuilder::rect().size(20.0, 20.0).fill(Color::white()));
+/// let card_id = card.build(&mut scene, root)?;
+/// ```
+pub struct NodeBuilder {
+    node: Node,
+    children: Vec<NodeBuilder>,
+
+    /// The first error from setting a property, reported by `build`.
+    error: Option<SceneError>,
+}
+
+impl NodeBuilder {
+    /// Starts building a node without a shape, e.g. a layout container.
+    pub fn new() -> Self {
+        Self {
+            node: Node::new(),
+            children: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Starts building a node without a shape. Same as [`new`](Self::new).
+    pub fn group() -> Self {
+        Self::new()
+    }
+
+    /// Starts building a rectangle, empty until given a [`size`](Self::size).
+    pub fn rect() -> Self {
+        Self::new().shape(Shape::Rect(Rect::new(0.0, 0.0)))
+    }
+
+    /// Starts building an ellipse with the given radii, filling the box from the
+    /// origin to twice the radii.
+    pub fn ellipse(rx: f32, ry: f32) -> Self {
+        Self::new().shape(Shape::Ellipse(Ellipse::new(rx, ry)))
+    }
+
+    /// Starts building a ring between circles of the given radii.
+    pub fn ring(outer_radius: f32, inner_radius: f32) -> Self {
+        Self::new().shape(Shape::Ring(Ring::new(outer_radius, inner_radius)))
+    }
+
+    /// Starts building a line from `start` to `end`, drawn with the
+    /// [`stroke`](Self::stroke) only.
+    pub fn line(start: (f32, f32), end: (f32, f32)) -> Self {
+        Self::new().shape(Shape::Line(Line::new(start, end)))
+    }
+
+    /// Starts building a chain of straight segments through `points`, drawn
+    /// with the [`stroke`](Self::stroke) only.
+    pub fn polyline(points: Vec<(f32, f32)>) -> Self {
+        Self::new().shape(Shape::Polyline(Polyline::new(points)))
+    }
+
+    /// Starts building a closed polygon through `points`.
+    pub fn polygon(points: Vec<(f32, f32)>) -> Self {
+        Self::new().shape(Shape::Polygon(Polygon::new(points)))
+    }
+
+    /// Sets the node's shape.
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.node.set_shape(shape);
+        self
+    }
+
+    /// Makes the node's shape a rectangle of the given size.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        self.shape(Shape::Rect(Rect::new(width, height)))
+    }
+
+    /// Sets the fill, from a [`Fill`], a [`Color`], or a hex color string
+    /// such as `"#3380cc"`.
+    ///
+    /// A string that is not a valid hex color leaves the fill unchanged and
+    /// makes [`build`](Self::build) return [`SceneError::InvalidColor`].
+    pub fn fill(mut self, fill: impl IntoFill) -> Self {
+        match fill.into_fill() {
+            Ok(fill) => self.node.style_mut().fill = Some(fill),
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+        self
+    }
+
+    /// Fills the node with `image` scaled in nine slices, so its corners
+    /// keep their size, e.g. for textured panels. A fill color set before
+    /// is shown while the image loads.
+    pub fn nine_slice(mut self, image: ImageHandle, slice: NineSlice) -> Self {
+        let fill = self
+            .node
+            .style_mut()
+            .fill
+            .get_or_insert_with(|| Fill::solid(Color::transparent()));
+        fill.image = Some(image);
+        fill.slice = Some(slice);
+        self
+    }
+
+    /// Fills the patches of `mesh` with its blended colors instead of the
+    /// node's shape.
+    pub fn mesh_gradient(mut self, mesh: MeshGradient) -> Self {
+        self.node
+            .style_mut()
+            .fill
+            .get_or_insert_with(|| Fill::solid(Color::white()))
+            .mesh = Some(mesh);
+        self
+    }
+
+    /// Fills the node with procedural `noise` instead of the fill color,
+    /// e.g. for a textured background.
+    pub fn noise(mut self, noise: Noise) -> Self {
+        self.node
+            .style_mut()
+            .fill
+            .get_or_insert_with(|| Fill::solid(Color::transparent()))
+            .noise = Some(noise);
+        self
+    }
+
+    /// Draws `pattern` over the fill, e.g. hatching for a chart bar. A fill
+    /// color set before shows between the marks.
+    pub fn pattern(mut self, pattern: Pattern) -> Self {
+        self.node
+            .style_mut()
+            .fill
+            .get_or_insert_with(|| Fill::solid(Color::transparent()))
+            .pattern = Some(pattern);
+        self
+    }
+
+    /// Sets the stroke.
+    pub fn stroke(mut self, stroke: Stroke) -> Self {
+        self.node.style_mut().stroke = Some(stroke);
+        self
+    }
+
+    /// Appends `effect` to the changes applied to the outline before it is
+    /// filled.
+    pub fn effect(mut self, effect: PathEffect) -> Self {
+        self.node.style_mut().effects.push(effect);
+        self
+    }
+
+    /// Sets the opacity in the range [0.0, 1.0].
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.node.style_mut().opacity = opacity;
+        self
+    }
+
+    /// Sets the offset from the parent's origin.
+    pub fn translate(mut self, x: f32, y: f32) -> Self {
+        self.node.transform_mut().translate = (x, y);
+        self
+    }
+
+    /// Sets the scale factors.
+    pub fn scale(mut self, x: f32, y: f32) -> Self {
+        self.node.transform_mut().scale = (x, y);
+        self
+    }
+
+    /// Sets the rotation in radians, clockwise.
+    pub fn rotate(mut self, radians: f32) -> Self {
+        self.node.transform_mut().rotate = radians;
+        self
+    }
+
+    /// Replaces the node's layout properties.
+    pub fn layout(mut self, layout: LayoutStyle) -> Self {
+        *self.node.layout_mut() = layout;
+        self
+    }
+
+    /// Sets how the node arranges its children.
+    pub fn display(mut self, display: Display) -> Self {
+        self.node.layout_mut().display = display;
+        self
+    }
+
+    /// Sets the preferred width used by the layout pass.
+    pub fn width(mut self, width: Dimension) -> Self {
+        self.node.layout_mut().width = width;
+        self
+    }
+
+    /// Sets the preferred height used by the layout pass.
+    pub fn height(mut self, height: Dimension) -> Self {
+        self.node.layout_mut().height = height;
+        self
+    }
+
+    /// Sets the minimum width used by the layout pass.
+    pub fn min_width(mut self, width: Dimension) -> Self {
+        self.node.layout_mut().min_width = width;
+        self
+    }
+
+    /// Sets the minimum height used by the layout pass.
+    pub fn min_height(mut self, height: Dimension) -> Self {
+        self.node.layout_mut().min_height = height;
+        self
+    }
+
+    /// Sets the semantic role exposed to assistive technologies.
+    pub fn role(mut self, role: Role) -> Self {
+        self.node.set_role(role);
+        self
+    }
+
+    /// Sets the accessible name.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.node.set_label(label);
+        self
+    }
+
+    /// Sets the text shown in a tooltip while the pointer rests on the
+    /// node.
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.node.set_tooltip(tooltip);
+        self
+    }
+
+    /// Sets the zoom range the node's subtree is drawn at, and the shape
+    /// drawn instead when zoomed out.
+    pub fn lod(mut self, lod: Lod) -> Self {
+        self.node.set_lod(lod);
+        self
+    }
+
+    /// Sets the tessellation tolerance of the node's shape, in place of the
+    /// renderer's. Invalid values are ignored as by [`Node::set_tolerance`].
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.node.set_tolerance(tolerance);
+        self
+    }
+
+    /// Sets the event handler.
+    pub fn on_event<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(Event) + Send + Sync + 'static,
+    {
+        self.node.set_event_handler(Box::new(handler));
+        self
+    }
+
+    /// Sets an event handler reacting to clicks only.
+    pub fn on_click<F>(self, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_event(move |event| {
+            if let Event::Click = event {
+                handler();
+            }
+        })
+    }
+
+    /// Applies arbitrary changes to the node, for properties without a
+    /// dedicated builder method.
+    pub fn with(mut self, f: impl FnOnce(&mut Node)) -> Self {
+        f(&mut self.node);
+        self
+    }
+
+    /// Appends a child.
+    pub fn child(mut self, child: NodeBuilder) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Appends several children, in order.
+    pub fn children(mut self, children: impl IntoIterator<Item = NodeBuilder>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Adds the node and its subtree to the scene under `parent`, and
+    /// returns the node's ID.
+    ///
+    /// Returns an error if the `parent` node does not exist in the scene or
+    /// a property of the subtree was invalid, such as a malformed color, in
+    /// which case nothing is added.
+    pub fn build(self, scene: &mut Scene, parent: NodeId) -> Result<NodeId, SceneError> {
+        if let Some(err) = self.first_error() {
+            return Err(err);
+        }
+        self.add(scene, parent)
+    }
+
+    /// Returns the first error from setting a property in the subtree.
+    fn first_error(&self) -> Option<SceneError> {
+        self.error
+            .or_else(|| self.children.iter().find_map(Self::first_error))
+    }
+
+    /// Adds the node and its subtree to the scene under `parent`.
+    fn add(self, scene: &mut Scene, parent: NodeId) -> Result<NodeId, SceneError> {
+        let id = scene.add_node(parent, self.node)?;
+        for child in self.children {
+            child.add(scene, id)?;
+        }
+        Ok(id)
+    }
+}
+
+impl Default for NodeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Values accepted by [`NodeBuilder::fill`].
+pub trait IntoFill {
+    /// Converts the value into a fill, or returns an error if the value
+    /// does not describe one.
+    fn into_fill(self) -> Result<Fill, SceneError>;
+}
+
+impl IntoFill for Fill {
+    fn into_fill(self) -> Result<Fill, SceneError> {
+        Ok(self)
+    }
+}
+
+impl IntoFill for Color {
+    fn into_fill(self) -> Result<Fill, SceneError> {
+        Ok(Fill::solid(self))
+    }
+}
+
+impl IntoFill for &str {
+    fn into_fill(self) -> Result<Fill, SceneError> {
+        Color::from_hex(self)
+            .map(Fill::solid)
+            .ok_or(SceneError::InvalidColor)
+    }
+}
+
+/// Builds a node hierarchy declaratively, returning a [`NodeBuilder`].
+///
+/// Each node is written as a kind (`rect` or `group`) followed by its
+/// properties in braces. Every property calls the [`NodeBuilder`] method of
+/// the same name; pairs such as `size: (200, 100)` pass both values as
+/// `f32`. Nested nodes go into `children: [...]`. A `fill` given as a
+/// string literal is checked as the macro compiles, so a malformed color
+/// is a compile error rather than an error from `build`.
+///
+/// # Example
+/// ```rust,ignore
+/// let root = scene.root();
+/// scene! {
+///     group {
+///         display: Display::Stack(StackLayout::new().align(Align::Center)),
+///         children: [
+///             rect { size: (200, 100), fill: "#3380cc", on_click: || println!("clicked") },
+///             rect { size: (100, 100), fill: Color::black(), rotate: 0.3 },
+///         ],
+///     }
+/// }
+/// .build(&mut scene, root)?;
+/// ```
+#[macro_export]
+macro_rules! scene {
+    ($kind:ident { $($body:tt)* }) => {
+        $crate::__scene_properties!($crate::scene::NodeBuilder::$kind(); $($body)*)
+    };
+}
+
+/// Applies the properties of a [`scene!`] node to its builder, one at a
+/// time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __scene_properties {
+    ($builder:expr;) => {
+        $builder
+    };
+    ($builder:expr; children: [ $($kind:ident { $($body:tt)* }),* $(,)? ] $(, $($rest:tt)*)?) => {
+        $crate::__scene_properties!(
+            $builder $(.child($crate::scene!($kind { $($body)* })))*;
+            $($($rest)*)?
+        )
+    };
+    ($builder:expr; $key:ident : ($x:expr, $y:expr) $(, $($rest:tt)*)?) => {
+        $crate::__scene_properties!($builder.$key($x as f32, $y as f32); $($($rest)*)?)
+    };
+    ($builder:expr; fill : $value:literal $(, $($rest:tt)*)?) => {
+        $crate::__scene_properties!(
+            $builder.fill(const {
+                match $crate::style::Color::from_hex($value) {
+                    ::core::option::Option::Some(color) => color,
+                    ::core::option::Option::None => ::core::panic!("invalid hex color in scene!"),
+                }
+            });
+            $($($rest)*)?
+        )
+    };
+    ($builder:expr; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__scene_properties!($builder.$key($value); $($($rest)*)?)
+    };
+}
@@ -0,0 +1,41 @@
+use std::fmt;
+
+use crate::node::NodeId;
+
+/// An error from changing the structure of a [`Scene`](super::Scene).
+///
+/// Editors and other apps holding on to node IDs can match on it to handle
+/// IDs that went stale when their node was removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SceneError {
+    /// No node with the ID is in the scene.
+    NodeNotFound(NodeId),
+
+    /// The operation is not allowed on the root node, e.g. removing it.
+    RootNode,
+
+    /// Moving `node` under `parent` would make it its own ancestor, because
+    /// `parent` is `node` itself or one of its descendants.
+    Cycle { node: NodeId, parent: NodeId },
+
+    /// A fill was given as a string that is not a valid hex color.
+    InvalidColor,
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneError::NodeNotFound(id) => write!(f, "node {id:?} not found"),
+            SceneError::RootNode => write!(f, "operation not allowed on the root node"),
+            SceneError::Cycle { node, parent } => {
+                write!(
+                    f,
+                    "cannot move node {node:?} into its own subtree at {parent:?}"
+                )
+            }
+            SceneError::InvalidColor => write!(f, "invalid hex color"),
+        }
+    }
+}
+
+impl std::error::Error for SceneError {}
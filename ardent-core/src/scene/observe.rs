@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use super::Scene;
+use crate::node::NodeId;
+
+/// A change to the scene graph, as reported to change observers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SceneChange {
+    /// The node was inserted into the scene.
+    Added(NodeId),
+
+    /// The node was removed from the scene. Reported for every node of a
+    /// removed subtree.
+    Removed(NodeId),
+
+    /// The node may have changed: it was borrowed mutably, or a child was
+    /// added to or removed from it.
+    Modified(NodeId),
+}
+
+/// A callback receiving the scene and the changes made since the previous
+/// notification, in the order they happened.
+///
+/// Observers are required to be thread-safe, like frame callbacks.
+pub type ChangeObserver = Box<dyn FnMut(&Scene, &[SceneChange]) + Send + Sync>;
+
+/// Identifies a registered change observer so it can be removed later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChangeObserverId(u64);
+
+/// Registered observers and the changes they have not been notified of.
+///
+/// Changes are only recorded while at least one observer is registered.
+#[derive(Default)]
+pub(super) struct ChangeLog {
+    observers: Vec<(ChangeObserverId, ChangeObserver)>,
+    changes: Vec<SceneChange>,
+    modified: HashSet<NodeId>,
+    next_id: u64,
+}
+
+impl ChangeLog {
+    /// Records a change, collapsing repeated modifications of a node.
+    pub(super) fn record(&mut self, change: SceneChange) {
+        if self.observers.is_empty() {
+            return;
+        }
+        if let SceneChange::Modified(id) = change
+            && !self.modified.insert(id)
+        {
+            return;
+        }
+        self.changes.push(change);
+    }
+}
+
+impl Scene {
+    /// Registers an observer notified of structural and node changes.
+    ///
+    /// Changes are batched and delivered by
+    /// [`notify_observers`](Self::notify_observers), so observers can keep
+    /// derived state (e.g., an accessibility tree) up to date incrementally
+    /// instead of walking the whole scene.
+    ///
+    /// Node changes are detected conservatively: any node borrowed through
+    /// [`get_node_mut`](Self::get_node_mut) is reported as modified. Layout
+    /// passes only borrow nodes whose box changed, and clearing dirty flags
+    /// is not reported.
+    pub fn observe<F>(&mut self, observer: F) -> ChangeObserverId
+    where
+        F: FnMut(&Scene, &[SceneChange]) + Send + Sync + 'static,
+    {
+        let log = &mut self.change_log;
+        let id = ChangeObserverId(log.next_id);
+        log.next_id += 1;
+        log.observers.push((id, Box::new(observer)));
+        id
+    }
+
+    /// Unregisters a change observer.
+    pub fn remove_observer(&mut self, id: ChangeObserverId) {
+        let log = &mut self.change_log;
+        log.observers.retain(|(observer_id, _)| *observer_id != id);
+        if log.observers.is_empty() {
+            log.changes.clear();
+            log.modified.clear();
+        }
+    }
+
+    /// Delivers the changes recorded since the last call to all observers.
+    ///
+    /// Call this once per frame after the scene was updated, e.g. after
+    /// running frame callbacks and handling input.
+    pub fn notify_observers(&mut self) {
+        let log = &mut self.change_log;
+        if log.changes.is_empty() {
+            return;
+        }
+        let changes = std::mem::take(&mut log.changes);
+        let mut observers = std::mem::take(&mut log.observers);
+        log.modified.clear();
+
+        for (_, observer) in &mut observers {
+            observer(self, &changes);
+        }
+        self.change_log.observers = observers;
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt::Write;
+
+use super::Scene;
+use crate::node::{Node, NodeId};
+use crate::shape::{PathCommand, Shape};
+use crate::style::{Color, FillRule, Gradient, Stroke, StrokeCap, StrokeJoin};
+use crate::transform::Transform;
+
+impl Scene {
+    /// Serializes the scene into a standalone SVG document.
+    ///
+    /// Every node becomes a `<g>` element carrying its transform, with the
+    /// node's shape as its first child followed by the groups of its
+    /// children, so the SVG tree mirrors the scene graph. The document size
+    /// is taken from the root's computed layout when one is available.
+    ///
+    /// SVG strokes are always centered on the outline, so inside and outside
+    /// stroke alignment is exported as centered. Trimmed strokes are
+    /// expressed with a dash pattern, which replaces their own dashes.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// std::fs::write("scene.svg", scene.to_svg())?;
+    /// ```
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::from(r#"<svg xmlns="http://www.w3.org/2000/svg""#);
+        if let Some(root) = self.get_node(self.root) {
+            let layout = root.computed_layout();
+            if layout.width > 0.0 && layout.height > 0.0 {
+                let _ = write!(
+                    svg,
+                    r#" width="{w}" height="{h}" viewBox="0 0 {w} {h}""#,
+                    w = layout.width,
+                    h = layout.height,
+                );
+            }
+        }
+        svg.push_str(">\n");
+
+        let mut writer = SvgWriter { svg, gradients: 0 };
+        writer.node(self, self.root, 1);
+        writer.svg.push_str("</svg>\n");
+        writer.svg
+    }
+}
+
+/// Accumulates the document and numbers gradient definitions.
+struct SvgWriter {
+    svg: String,
+    gradients: usize,
+}
+
+impl SvgWriter {
+    fn node(&mut self, scene: &Scene, id: NodeId, depth: usize) {
+        let Some(node) = scene.get_node(id) else {
+            return;
+        };
+
+        self.indent(depth);
+        self.svg.push_str("<g");
+        self.transform(node.transform());
+        self.svg.push_str(">\n");
+
+        if let Some(shape) = node.shape() {
+            self.shape(node, shape, depth + 1);
+        }
+        for &child in node.children() {
+            self.node(scene, child, depth + 1);
+        }
+
+        self.indent(depth);
+        self.svg.push_str("</g>\n");
+    }
+
+    /// Writes the `transform` attribute, which SVG applies right to left:
+    /// scale, then rotation, then translation.
+    fn transform(&mut self, transform: &Transform) {
+        if *transform == Transform::default() {
+            return;
+        }
+        let (tx, ty) = transform.translate;
+        let (sx, sy) = transform.scale;
+        let _ = write!(
+            self.svg,
+            r#" transform="translate({tx} {ty}) rotate({}) scale({sx} {sy})""#,
+            transform.rotate.to_degrees(),
+        );
+    }
+
+    fn shape(&mut self, node: &Node, shape: &Shape, depth: usize) {
+        let style = node.style();
+
+        // Gradients are defined in the node's local space, so they are
+        // written next to the shape that uses them.
+        let gradient = style
+            .fill
+            .as_ref()
+            .filter(|_| !shape.is_open())
+            .and_then(|fill| fill.gradient.as_ref())
+            .map(|gradient| self.gradient(gradient, depth));
+
+        self.indent(depth);
+        match shape {
+            Shape::Rect(rect) => {
+                let _ = write!(
+                    self.svg,
+                    r#"<rect width="{}" height="{}""#,
+                    rect.width, rect.height
+                );
+            }
+            Shape::Ellipse(ellipse) => {
+                let _ = write!(
+                    self.svg,
+                    r#"<ellipse cx="{rx}" cy="{ry}" rx="{rx}" ry="{ry}""#,
+                    rx = ellipse.rx,
+                    ry = ellipse.ry
+                );
+            }
+            Shape::Line(line) => {
+                let ((x1, y1), (x2, y2)) = (line.start, line.end);
+                let _ = write!(self.svg, r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}""#);
+            }
+            Shape::Polyline(polyline) => {
+                self.svg.push_str(r#"<polyline points=""#);
+                for (i, (x, y)) in polyline.points.iter().enumerate() {
+                    let separator = if i == 0 { "" } else { " " };
+                    let _ = write!(self.svg, "{separator}{x},{y}");
+                }
+                self.svg.push('"');
+            }
+            Shape::Path(path) => {
+                self.svg.push_str(r#"<path d=""#);
+                path_data(&mut self.svg, path.commands());
+                self.svg.push('"');
+            }
+            Shape::Polygon(polygon) => {
+                self.svg.push_str(r#"<path d=""#);
+                path_data(&mut self.svg, polygon.to_path().commands());
+                self.svg.push('"');
+            }
+            Shape::Ring(ring) => {
+                self.svg.push_str(r#"<path d=""#);
+                path_data(&mut self.svg, ring.to_path().commands());
+                self.svg.push('"');
+            }
+        }
+
+        // Open shapes are drawn with their stroke only.
+        match (&style.fill, gradient) {
+            _ if shape.is_open() => self.svg.push_str(r#" fill="none""#),
+            (Some(_), Some(id)) => {
+                let _ = write!(self.svg, r##" fill="url(#{id})""##);
+            }
+            (Some(fill), None) => paint(&mut self.svg, "fill", fill.color),
+            (None, _) => self.svg.push_str(r#" fill="none""#),
+        }
+        if let Some(fill) = &style.fill
+            && fill.rule == FillRule::EvenOdd
+            && !shape.is_open()
+        {
+            self.svg.push_str(r#" fill-rule="evenodd""#);
+        }
+        if let Some(stroke) = &style.stroke {
+            self.stroke(stroke);
+        }
+        if style.opacity < 1.0 {
+            let _ = write!(self.svg, r#" opacity="{}""#, style.opacity);
+        }
+        self.svg.push_str("/>\n");
+    }
+
+    fn stroke(&mut self, stroke: &Stroke) {
+        paint(&mut self.svg, "stroke", stroke.color);
+        let _ = write!(self.svg, r#" stroke-width="{}""#, stroke.width);
+        match stroke.cap {
+            StrokeCap::Butt => {}
+            StrokeCap::Round => self.svg.push_str(r#" stroke-linecap="round""#),
+            StrokeCap::Square => self.svg.push_str(r#" stroke-linecap="square""#),
+        }
+        match stroke.join {
+            StrokeJoin::Miter => {}
+            StrokeJoin::Round => self.svg.push_str(r#" stroke-linejoin="round""#),
+            StrokeJoin::Bevel => self.svg.push_str(r#" stroke-linejoin="bevel""#),
+        }
+        if stroke.join == StrokeJoin::Miter && stroke.miter_limit != Stroke::DEFAULT_MITER_LIMIT {
+            let _ = write!(self.svg, r#" stroke-miterlimit="{}""#, stroke.miter_limit);
+        }
+        if stroke.is_trimmed() {
+            // With a path length of 1, the dash pattern is expressed in
+            // fractions of the outline, just like the trim range.
+            let _ = write!(
+                self.svg,
+                r#" pathLength="1" stroke-dasharray="{} 1" stroke-dashoffset="{}""#,
+                (stroke.trim_end - stroke.trim_start).max(0.0),
+                -stroke.trim_start,
+            );
+        } else if stroke.is_dashed() {
+            let dashes: Vec<String> = stroke.dashes.iter().map(f32::to_string).collect();
+            let _ = write!(self.svg, r#" stroke-dasharray="{}""#, dashes.join(" "));
+            if stroke.dash_offset != 0.0 {
+                let _ = write!(self.svg, r#" stroke-dashoffset="{}""#, stroke.dash_offset);
+            }
+        }
+    }
+
+    /// Writes a gradient definition and returns its element ID.
+    fn gradient(&mut self, gradient: &Gradient, depth: usize) -> String {
+        let id = format!("gradient{}", self.gradients);
+        self.gradients += 1;
+
+        self.indent(depth);
+        self.svg.push_str("<defs>\n");
+        self.indent(depth + 1);
+        let element = match gradient {
+            Gradient::Linear { start, end, .. } => {
+                let _ = write!(
+                    self.svg,
+                    r#"<linearGradient id="{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">"#,
+                    start.0, start.1, end.0, end.1,
+                );
+                "linearGradient"
+            }
+            Gradient::Radial { center, radius, .. } => {
+                let _ = write!(
+                    self.svg,
+                    r#"<radialGradient id="{id}" gradientUnits="userSpaceOnUse" cx="{}" cy="{}" r="{radius}">"#,
+                    center.0, center.1,
+                );
+                "radialGradient"
+            }
+        };
+        self.svg.push('\n');
+        for stop in gradient.stops() {
+            self.indent(depth + 2);
+            let _ = write!(self.svg, r#"<stop offset="{}""#, stop.offset);
+            paint(&mut self.svg, "stop-color", stop.color);
+            self.svg.push_str("/>\n");
+        }
+        self.indent(depth + 1);
+        let _ = writeln!(self.svg, "</{element}>");
+        self.indent(depth);
+        self.svg.push_str("</defs>\n");
+        id
+    }
+
+    fn indent(&mut self, depth: usize) {
+        for _ in 0..depth {
+            self.svg.push_str("  ");
+        }
+    }
+}
+
+/// Writes a color attribute, with its alpha as the matching opacity
+/// attribute (`fill-opacity`, `stroke-opacity`, or `stop-opacity`).
+fn paint(svg: &mut String, attribute: &str, color: Color) {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let _ = write!(
+        svg,
+        r##" {attribute}="#{:02x}{:02x}{:02x}""##,
+        channel(color.0),
+        channel(color.1),
+        channel(color.2),
+    );
+    if color.3 < 1.0 {
+        let prefix = attribute.strip_suffix("-color").unwrap_or(attribute);
+        let _ = write!(svg, r#" {prefix}-opacity="{}""#, color.3.max(0.0));
+    }
+}
+
+/// Writes the commands of a path in SVG path data syntax.
+fn path_data(svg: &mut String, commands: &[PathCommand]) {
+    for (i, command) in commands.iter().enumerate() {
+        if i > 0 {
+            svg.push(' ');
+        }
+        let _ = match *command {
+            PathCommand::MoveTo(x, y) => write!(svg, "M{x} {y}"),
+            PathCommand::LineTo(x, y) => write!(svg, "L{x} {y}"),
+            PathCommand::QuadTo { ctrl, to } => {
+                write!(svg, "Q{} {} {} {}", ctrl.0, ctrl.1, to.0, to.1)
+            }
+            PathCommand::CubicTo { ctrl1, ctrl2, to } => write!(
+                svg,
+                "C{} {} {} {} {} {}",
+                ctrl1.0, ctrl1.1, ctrl2.0, ctrl2.1, to.0, to.1
+            ),
+            PathCommand::Close => write!(svg, "Z"),
+        };
+    }
+}
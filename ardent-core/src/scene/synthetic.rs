@@ -0,0 +1,168 @@
+use crate::node::{Node, NodeId};
+use crate::shape::{Path, PathCommand, Rect, Shape};
+use crate::style::{Color, Fill};
+
+use super::Scene;
+
+/// Generates scenes of a given size and shape, e.g. for benchmarks.
+///
+/// Nodes are spread evenly over `depth` levels below the root, each
+/// attached to a random node of the level above, and get a random position,
+/// color, and either a rectangle or a star-shaped path. Generation is
+/// deterministic for a given seed, so runs can be compared. Requires the
+/// `synthetic` feature.
+///
+/// # Example
+/// ```rust,ignore
+/// let generator = SyntheticScene::new(10_000).depth(8).dirty_ratio(0.05);
+/// let mut scene = generator.build();
+///
+/// // Before each frame: change 5% of the nodes.
+/// generator.touch(&mut scene);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SyntheticScene {
+    nodes: usize,
+    depth: usize,
+    dirty_ratio: f32,
+    seed: u64,
+}
+
+impl SyntheticScene {
+    /// Creates a generator for scenes with `nodes` nodes besides the root,
+    /// four levels deep, of which a tenth change per [`touch`](Self::touch).
+    pub fn new(nodes: usize) -> Self {
+        Self {
+            nodes,
+            depth: 4,
+            dirty_ratio: 0.1,
+            seed: 1,
+        }
+    }
+
+    /// Sets the number of levels below the root. Values below one are
+    /// treated as one.
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth.max(1);
+        self
+    }
+
+    /// Sets the fraction of nodes changed by [`touch`](Self::touch), in
+    /// `[0.0, 1.0]`.
+    pub fn dirty_ratio(mut self, ratio: f32) -> Self {
+        self.dirty_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the seed of the random generator.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Builds a new scene.
+    pub fn build(&self) -> Scene {
+        let mut scene = Scene::new();
+        let mut rng = SplitMix64(self.seed);
+        let mut above = vec![scene.root()];
+        let mut level = Vec::new();
+        let per_level = self.nodes.div_ceil(self.depth).max(1);
+
+        for i in 0..self.nodes {
+            if i > 0 && i % per_level == 0 {
+                above = std::mem::take(&mut level);
+            }
+            let parent = above[rng.below(above.len())];
+
+            let mut node = Node::new();
+            node.transform_mut().translate = (rng.unit() * 1024.0, rng.unit() * 768.0);
+            node.set_shape(shape(&mut rng));
+            node.style_mut().fill = Some(Fill::solid(color(&mut rng)));
+
+            if let Ok(id) = scene.add_node(parent, node) {
+                level.push(id);
+            }
+        }
+        scene
+    }
+
+    /// Changes the configured fraction of the scene's nodes, spread evenly
+    /// over moving them, recoloring them, and reshaping them.
+    ///
+    /// The same nodes are picked on every call, with the same changes.
+    pub fn touch(&self, scene: &mut Scene) {
+        let mut ids = Vec::new();
+        scene.traverse(|node| ids.push(node.id()));
+
+        let mut rng = SplitMix64(self.seed ^ 0x5eed);
+        let root = scene.root();
+        let changed: Vec<NodeId> = ids
+            .into_iter()
+            .filter(|&id| id != root && rng.unit() < self.dirty_ratio)
+            .collect();
+
+        for (i, id) in changed.into_iter().enumerate() {
+            let Some(node) = scene.get_node_mut(id) else {
+                continue;
+            };
+            match i % 3 {
+                0 => node.transform_mut().translate.0 += 1.0,
+                1 => node.style_mut().opacity = rng.unit(),
+                _ => node.set_shape(shape(&mut rng)),
+            }
+        }
+    }
+}
+
+/// Returns a random rectangle or star.
+fn shape(rng: &mut SplitMix64) -> Shape {
+    let size = 8.0 + rng.unit() * 56.0;
+    if rng.unit() < 0.5 {
+        return Shape::Rect(Rect::new(size, size * (0.5 + rng.unit())));
+    }
+
+    let points = 5 + rng.below(4);
+    let radius = size / 2.0;
+    let mut path = Path::new();
+    for i in 0..points * 2 {
+        let angle = i as f32 * std::f32::consts::PI / points as f32;
+        let r = if i % 2 == 0 { radius } else { radius / 2.0 };
+        let point = (radius + r * angle.cos(), radius + r * angle.sin());
+        path.push(if i == 0 {
+            PathCommand::MoveTo(point.0, point.1)
+        } else {
+            PathCommand::LineTo(point.0, point.1)
+        });
+    }
+    path.push(PathCommand::Close);
+    Shape::Path(path)
+}
+
+/// Returns a random opaque color.
+fn color(rng: &mut SplitMix64) -> Color {
+    Color::rgb(rng.unit(), rng.unit(), rng.unit())
+}
+
+/// A small, fast pseudo-random generator, so generating scenes needs no
+/// dependencies.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `[0.0, 1.0)`.
+    fn unit(&mut self) -> f32 {
+        (self.next() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Returns an index in `0..n`.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
@@ -0,0 +1,189 @@
+use std::time::{Duration, Instant};
+
+use super::Scene;
+
+/// Shortest interval of a repeating timer. Shorter intervals are raised to
+/// it, so a timer cannot keep the application busy running it.
+const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A callback run by a timer with the scene.
+///
+/// Timer callbacks are required to be thread-safe, like frame callbacks.
+pub type TimerCallback = Box<dyn FnMut(&mut Scene) + Send + Sync>;
+
+/// Identifies a scheduled timer so it can be cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerId(u64);
+
+struct Timer {
+    id: TimerId,
+    deadline: Instant,
+
+    /// The period of a repeating timer, `None` for one-shot timers.
+    interval: Option<Duration>,
+    callback: TimerCallback,
+}
+
+/// Scheduled timers, in no particular order.
+#[derive(Default)]
+pub(super) struct Timers {
+    timers: Vec<Timer>,
+
+    /// Timers cancelled while timers were running.
+    cancelled: Vec<TimerId>,
+    next_id: u64,
+}
+
+impl Scene {
+    /// Schedules `callback` to run once after `delay`, e.g. to dismiss a
+    /// notification.
+    ///
+    /// Timers run when the application calls
+    /// [`run_timers`](Self::run_timers), so their precision depends on how
+    /// promptly it wakes up for [`next_timer`](Self::next_timer). A delay too
+    /// long to represent, such as [`Duration::MAX`], never elapses.
+    pub fn after<F>(&mut self, delay: Duration, callback: F) -> TimerId
+    where
+        F: FnOnce(&mut Scene) + Send + Sync + 'static,
+    {
+        let mut callback = Some(callback);
+        self.schedule(delay, None, move |scene| {
+            if let Some(callback) = callback.take() {
+                callback(scene);
+            }
+        })
+    }
+
+    /// Schedules `callback` to run every `interval`, e.g. to blink a caret
+    /// or poll for updates, until the timer is cancelled.
+    ///
+    /// Missed ticks are not made up for: after a stall the timer runs once
+    /// and continues a full interval later. Intervals shorter than a
+    /// millisecond are raised to one.
+    pub fn every<F>(&mut self, interval: Duration, callback: F) -> TimerId
+    where
+        F: FnMut(&mut Scene) + Send + Sync + 'static,
+    {
+        let interval = interval.max(MIN_INTERVAL);
+        self.schedule(interval, Some(interval), callback)
+    }
+
+    /// Cancels a timer. Cancelling a finished timer has no effect.
+    pub fn cancel_timer(&mut self, id: TimerId) {
+        self.timers.timers.retain(|timer| timer.id != id);
+        self.timers.cancelled.push(id);
+    }
+
+    /// Returns when the next timer is due, if any are scheduled.
+    pub fn next_timer(&self) -> Option<Instant> {
+        self.timers.timers.iter().map(|timer| timer.deadline).min()
+    }
+
+    /// Runs all timers due at `now`, in deadline order.
+    ///
+    /// Callbacks may schedule or cancel timers; newly scheduled ones run at
+    /// the earliest on the next call. Returns `true` if any timer ran.
+    pub fn run_timers(&mut self, now: Instant) -> bool {
+        self.timers.cancelled.clear();
+        let (mut due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.timers.timers)
+            .into_iter()
+            .partition(|timer| timer.deadline <= now);
+        self.timers.timers = pending;
+        if due.is_empty() {
+            return false;
+        }
+
+        due.sort_by_key(|timer| timer.deadline);
+        for timer in &mut due {
+            if !self.timers.cancelled.contains(&timer.id) {
+                (timer.callback)(self);
+            }
+        }
+
+        // Reschedule repeating timers unless they were cancelled.
+        let cancelled = std::mem::take(&mut self.timers.cancelled);
+        for mut timer in due {
+            let Some(interval) = timer.interval else {
+                continue;
+            };
+            if cancelled.contains(&timer.id) {
+                continue;
+            }
+            // Timers whose next tick cannot be represented never run again.
+            let next = timer
+                .deadline
+                .checked_add(interval)
+                .filter(|&next| next > now)
+                .or_else(|| now.checked_add(interval));
+            if let Some(next) = next {
+                timer.deadline = next;
+                self.timers.timers.push(timer);
+            }
+        }
+        true
+    }
+
+    fn schedule<F>(&mut self, delay: Duration, interval: Option<Duration>, callback: F) -> TimerId
+    where
+        F: FnMut(&mut Scene) + Send + Sync + 'static,
+    {
+        let timers = &mut self.timers;
+        let id = TimerId(timers.next_id);
+        timers.next_id += 1;
+        // A deadline too far away to represent is never reached, so the
+        // timer is not kept.
+        if let Some(deadline) = Instant::now().checked_add(delay) {
+            timers.timers.push(Timer {
+                id,
+                deadline,
+                interval,
+                callback: Box::new(callback),
+            });
+        }
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn unrepresentable_delays_never_elapse() {
+        let mut scene = Scene::new();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        scene.after(Duration::MAX, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        let counter = runs.clone();
+        let id = scene.every(Duration::MAX, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        assert_eq!(scene.next_timer(), None);
+        assert!(!scene.run_timers(Instant::now() + Duration::from_secs(3600)));
+        scene.cancel_timer(id);
+        assert_eq!(runs.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn zero_intervals_are_raised_to_the_minimum() {
+        let mut scene = Scene::new();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        scene.every(Duration::ZERO, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        let now = scene.next_timer().unwrap();
+        assert!(scene.run_timers(now));
+        assert_eq!(runs.load(Ordering::Relaxed), 1);
+
+        // The timer is not due again at the same instant.
+        assert!(scene.next_timer().unwrap() >= now + MIN_INTERVAL);
+        assert!(!scene.run_timers(now));
+        assert_eq!(runs.load(Ordering::Relaxed), 1);
+    }
+}
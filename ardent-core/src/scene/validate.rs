@@ -0,0 +1,112 @@
+//! Scene graph integrity checks.
+//!
+//! Nodes expose `add_child`, `remove_child`, and `set_parent`, so the links
+//! between nodes can be edited without going through the scene. With the
+//! `debug-validate` feature, these checks run after operations that
+//! restructure the graph or hand out nodes to user code, and panic on the
+//! first broken link instead of letting traversal, layout, or rendering
+//! silently skip or revisit nodes.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use super::Scene;
+
+/// A broken link in the scene graph found by [`Scene::check_integrity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityError(String);
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for IntegrityError {}
+
+impl Scene {
+    /// Checks that child lists match parent pointers, that no node refers
+    /// to a missing node, and that every node is reachable from the root
+    /// exactly once, which rules out cycles.
+    ///
+    /// The scene's own operations always keep these invariants; a failure
+    /// means links were edited directly on nodes.
+    pub fn check_integrity(&self) -> Result<(), IntegrityError> {
+        self.find_broken_link().map_err(IntegrityError)
+    }
+
+    /// Verifies the scene invariants, panicking with a diagnostic that names
+    /// `operation` if any is violated.
+    #[cfg(feature = "debug-validate")]
+    pub(super) fn validate(&self, operation: &str) {
+        if let Err(problem) = self.check_integrity() {
+            panic!("scene integrity check failed after `{operation}`: {problem}");
+        }
+    }
+
+    fn find_broken_link(&self) -> Result<(), String> {
+        let root = self
+            .nodes
+            .get(&self.root)
+            .ok_or_else(|| format!("root node {:?} is missing", self.root))?;
+        if let Some(parent) = root.parent() {
+            return Err(format!("root node {:?} has parent {parent:?}", self.root));
+        }
+
+        for (&id, node) in &self.nodes {
+            if node.id() != id {
+                return Err(format!("node stored as {id:?} has ID {:?}", node.id()));
+            }
+
+            if id != self.root {
+                let parent = node
+                    .parent()
+                    .ok_or_else(|| format!("node {id:?} has no parent"))?;
+                let parent_node = self
+                    .nodes
+                    .get(&parent)
+                    .ok_or_else(|| format!("node {id:?} has missing parent {parent:?}"))?;
+                if !parent_node.children().contains(&id) {
+                    return Err(format!(
+                        "node {id:?} has parent {parent:?}, which does not list it as a child"
+                    ));
+                }
+            }
+
+            let mut seen = HashSet::new();
+            for &child in node.children() {
+                if !seen.insert(child) {
+                    return Err(format!("node {id:?} lists child {child:?} more than once"));
+                }
+                let child_node = self
+                    .nodes
+                    .get(&child)
+                    .ok_or_else(|| format!("node {id:?} lists missing child {child:?}"))?;
+                if child_node.parent() != Some(id) {
+                    return Err(format!(
+                        "node {id:?} lists child {child:?}, whose parent is {:?}",
+                        child_node.parent()
+                    ));
+                }
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![self.root];
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) {
+                return Err(format!("node {id:?} is reachable more than once"));
+            }
+            if let Some(node) = self.nodes.get(&id) {
+                stack.extend(node.children());
+            }
+        }
+        if let Some(id) = self.nodes.keys().find(|id| !visited.contains(id)) {
+            return Err(format!(
+                "node {id:?} is not reachable from the root; its ancestors form a cycle"
+            ));
+        }
+
+        Ok(())
+    }
+}
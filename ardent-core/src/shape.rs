@@ -1,6 +1,23 @@
+mod contains;
+mod ellipse;
+mod line;
+mod measure;
+mod morph;
+mod path;
+mod path_data;
+mod polygon;
 mod rect;
+mod ring;
+mod visit;
 
+pub use ellipse::Ellipse;
+pub use line::{Line, Polyline};
+pub use path::{Path, PathCommand};
+pub use path_data::PathDataError;
+pub use polygon::Polygon;
 pub use rect::Rect;
+pub use ring::Ring;
+pub use visit::ShapeVisitor;
 
 /// Represents a geometric shape that can be rendered on screen.
 ///
@@ -8,11 +25,137 @@ pub use rect::Rect;
 /// Unlike raster images, these shapes are resolution-independent and
 /// scalable without loss of quality. Shapes are eventually tessellated
 /// into GPU-friendly triangles for rendering.
-#[derive(Clone, Debug)]
+///
+/// Shapes hash by the exact bit patterns of their parameters, so two shapes
+/// with identical definitions produce the same hash and can share geometry.
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Shape {
     /// A simple rectangle defined by its width and height.
     ///
     /// Rectangles are axis-aligned by default. Transformations such as
     /// rotation or scaling can be applied separately via the node's `Transform`.
     Rect(rect::Rect),
+
+    /// An ellipse defined by its radii, e.g. for avatars and status dots.
+    Ellipse(ellipse::Ellipse),
+
+    /// The band between two concentric circles.
+    Ring(ring::Ring),
+
+    /// A straight line between two points, drawn with the stroke only.
+    Line(line::Line),
+
+    /// A chain of straight segments, drawn with the stroke only, e.g. for
+    /// charts and connectors.
+    Polyline(line::Polyline),
+
+    /// A closed outline of straight edges, which may be concave and have
+    /// holes.
+    Polygon(polygon::Polygon),
+
+    /// An arbitrary outline of lines and curves, e.g. imported from SVG.
+    Path(path::Path),
+}
+
+impl Shape {
+    /// Returns a regular polygon with `sides` corners on a circle of
+    /// `radius`, e.g. a hexagonal badge. See [`Polygon::regular`].
+    pub fn regular_polygon(sides: usize, radius: f32) -> Self {
+        Shape::Polygon(Polygon::regular(sides, radius))
+    }
+
+    /// Returns a star with `points` tips between the given radii, e.g. a
+    /// rating icon. See [`Polygon::star`].
+    pub fn star(points: usize, inner_radius: f32, outer_radius: f32) -> Self {
+        Shape::Polygon(Polygon::star(points, inner_radius, outer_radius))
+    }
+
+    /// Returns the width and height of the shape's bounding box.
+    pub fn size(&self) -> (f32, f32) {
+        match self {
+            Shape::Rect(rect) => (rect.width, rect.height),
+            Shape::Ellipse(ellipse) => (2.0 * ellipse.rx, 2.0 * ellipse.ry),
+            Shape::Ring(ring) => (2.0 * ring.outer_radius, 2.0 * ring.outer_radius),
+            shape => extent(shape.bounds()),
+        }
+    }
+
+    /// Returns the minimum and maximum corner of the tightest local box
+    /// enclosing the shape, or `None` for an empty path.
+    ///
+    /// Unlike [`Path::bounds`], curves are measured by the points they pass
+    /// through rather than their control points, so the box fits the
+    /// outline exactly. The stroke is not included.
+    pub fn bounds(&self) -> Option<((f32, f32), (f32, f32))> {
+        match self {
+            Shape::Rect(rect) => Some(((0.0, 0.0), (rect.width, rect.height))),
+            Shape::Ellipse(ellipse) => Some(((0.0, 0.0), (2.0 * ellipse.rx, 2.0 * ellipse.ry))),
+            Shape::Ring(ring) => {
+                let diameter = 2.0 * ring.outer_radius;
+                Some(((0.0, 0.0), (diameter, diameter)))
+            }
+            Shape::Line(line) => line.to_path().bounds(),
+            Shape::Polyline(polyline) => polyline.to_path().bounds(),
+            Shape::Polygon(polygon) => polygon.to_path().bounds(),
+            Shape::Path(path) => path.tight_bounds(),
+        }
+    }
+
+    /// Returns `true` if the shape has no interior, so it is drawn with the
+    /// node's stroke only and its fill is ignored.
+    pub fn is_open(&self) -> bool {
+        matches!(self, Shape::Line(_) | Shape::Polyline(_))
+    }
+
+    /// Returns the outline of the shape as a path.
+    ///
+    /// Every shape can be described as a path, so code that does not handle
+    /// a shape specially can fall back to its outline.
+    pub fn to_path(&self) -> Path {
+        match self {
+            Shape::Rect(rect) => rect.to_path(),
+            Shape::Ellipse(ellipse) => ellipse.to_path(),
+            Shape::Ring(ring) => ring.to_path(),
+            Shape::Line(line) => line.to_path(),
+            Shape::Polyline(polyline) => polyline.to_path(),
+            Shape::Polygon(polygon) => polygon.to_path(),
+            Shape::Path(path) => path.clone(),
+        }
+    }
+
+    /// Calls `f` with each outline the shape is made of.
+    ///
+    /// Unlike [`to_path`](Self::to_path), paths stored in the shape are
+    /// passed by reference instead of being copied.
+    pub fn for_each_path(&self, mut f: impl FnMut(&Path)) {
+        match self {
+            Shape::Rect(rect) => f(&rect.to_path()),
+            Shape::Ellipse(ellipse) => f(&ellipse.to_path()),
+            Shape::Ring(ring) => f(&ring.to_path()),
+            Shape::Line(line) => f(&line.to_path()),
+            Shape::Polyline(polyline) => f(&polyline.to_path()),
+            Shape::Polygon(polygon) => f(&polygon.to_path()),
+            Shape::Path(path) => f(path),
+        }
+    }
+
+    /// Calls the method of `visitor` matching the kind of shape.
+    pub fn accept<V: ShapeVisitor + ?Sized>(&self, visitor: &mut V) -> V::Output {
+        match self {
+            Shape::Rect(rect) => visitor.visit_rect(rect),
+            Shape::Ellipse(ellipse) => visitor.visit_ellipse(ellipse),
+            Shape::Ring(ring) => visitor.visit_ring(ring),
+            Shape::Line(line) => visitor.visit_line(line),
+            Shape::Polyline(polyline) => visitor.visit_polyline(polyline),
+            Shape::Polygon(polygon) => visitor.visit_polygon(polygon),
+            Shape::Path(path) => visitor.visit_path(path),
+        }
+    }
+}
+
+/// Returns the width and height of a bounding box, or zero for no box.
+fn extent(bounds: Option<((f32, f32), (f32, f32))>) -> (f32, f32) {
+    bounds.map_or((0.0, 0.0), |(min, max)| (max.0 - min.0, max.1 - min.1))
 }
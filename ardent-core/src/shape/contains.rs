@@ -0,0 +1,74 @@
+//! Point-in-shape tests.
+
+use super::Shape;
+use super::measure::{self, Contour};
+use crate::style::FillRule;
+
+impl Shape {
+    /// Returns `true` if the local point lies inside the shape when filled
+    /// with `rule`, e.g. to route a click to the node under the pointer.
+    ///
+    /// Points on the outline count as inside. Rectangles, ellipses, and
+    /// rings are tested exactly, other shapes against their outline
+    /// flattened within a hundredth of a unit. Open shapes have no
+    /// interior, so they contain no points.
+    pub fn contains(&self, (x, y): (f32, f32), rule: FillRule) -> bool {
+        match self {
+            Shape::Rect(rect) => {
+                (0.0..=rect.width).contains(&x) && (0.0..=rect.height).contains(&y)
+            }
+            Shape::Ellipse(ellipse) => {
+                let (dx, dy) = ((x - ellipse.rx) / ellipse.rx, (y - ellipse.ry) / ellipse.ry);
+                dx * dx + dy * dy <= 1.0
+            }
+            Shape::Ring(ring) => {
+                let c = ring.outer_radius;
+                let distance = (x - c).hypot(y - c);
+                distance <= ring.outer_radius && distance >= ring.inner_radius.min(c)
+            }
+            Shape::Line(_) | Shape::Polyline(_) => false,
+            shape => {
+                let mut winding = Some(0);
+                shape.for_each_path(|path| {
+                    for contour in measure::flatten(path, measure::TOLERANCE) {
+                        winding = winding
+                            .zip(winding_number(&contour, (x, y)))
+                            .map(|(a, b)| a + b);
+                    }
+                });
+                match (winding, rule) {
+                    (None, _) => true,
+                    (Some(winding), FillRule::NonZero) => winding != 0,
+                    (Some(winding), FillRule::EvenOdd) => winding % 2 != 0,
+                }
+            }
+        }
+    }
+}
+
+/// Returns how many times the contour, closed implicitly as it is when
+/// filled, winds around `point`, or `None` if the point is on it.
+fn winding_number(contour: &Contour, (x, y): (f32, f32)) -> Option<i32> {
+    let points = &contour.points;
+    let Some(&last) = points.last() else {
+        return Some(0);
+    };
+    let mut winding = 0;
+    let mut from = last;
+    for &to in points {
+        // Which side of the edge the point is on.
+        let side = (to.0 - from.0) * (y - from.1) - (x - from.0) * (to.1 - from.1);
+        if from.1 <= y && to.1 > y && side > 0.0 {
+            winding += 1;
+        } else if from.1 > y && to.1 <= y && side < 0.0 {
+            winding -= 1;
+        } else if side == 0.0
+            && (from.0.min(to.0)..=from.0.max(to.0)).contains(&x)
+            && (from.1.min(to.1)..=from.1.max(to.1)).contains(&y)
+        {
+            return None;
+        }
+        from = to;
+    }
+    Some(winding)
+}
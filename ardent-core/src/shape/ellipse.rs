@@ -0,0 +1,55 @@
+use std::hash::{Hash, Hasher};
+
+use super::path::{Path, PathCommand};
+
+/// Handle length for approximating a quarter ellipse with a cubic Bézier
+/// curve, relative to the radius.
+pub(super) const KAPPA: f32 = 0.552_284_8;
+
+/// An ellipse given by its horizontal and vertical radius.
+///
+/// Like a [`Rect`](super::Rect), it fills the box from the origin to its
+/// size, so it is centered on `(rx, ry)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipse {
+    pub rx: f32,
+    pub ry: f32,
+}
+
+impl Ellipse {
+    pub fn new(rx: f32, ry: f32) -> Self {
+        Self { rx, ry }
+    }
+
+    /// Creates a circle of the given radius.
+    pub fn circle(radius: f32) -> Self {
+        Self::new(radius, radius)
+    }
+
+    /// Returns the outline of the ellipse as four cubic Bézier curves,
+    /// clockwise from its rightmost point.
+    pub fn to_path(&self) -> Path {
+        let (rx, ry) = (self.rx, self.ry);
+        let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+        let mut path = Path::new();
+        path.push(PathCommand::MoveTo(2.0 * rx, ry));
+        for (ctrl1, ctrl2, to) in [
+            ((2.0 * rx, ry + ky), (rx + kx, 2.0 * ry), (rx, 2.0 * ry)),
+            ((rx - kx, 2.0 * ry), (0.0, ry + ky), (0.0, ry)),
+            ((0.0, ry - ky), (rx - kx, 0.0), (rx, 0.0)),
+            ((rx + kx, 0.0), (2.0 * rx, ry - ky), (2.0 * rx, ry)),
+        ] {
+            path.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+        }
+        path.push(PathCommand::Close);
+        path
+    }
+}
+
+impl Hash for Ellipse {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rx.to_bits().hash(state);
+        self.ry.to_bits().hash(state);
+    }
+}
@@ -0,0 +1,79 @@
+use std::hash::{Hash, Hasher};
+
+use super::path::{Path, PathCommand};
+
+/// A straight line segment between two points.
+///
+/// Lines have no interior: they are drawn with the node's stroke only, and
+/// not at all without one.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
+    pub start: (f32, f32),
+    pub end: (f32, f32),
+}
+
+impl Line {
+    pub fn new(start: (f32, f32), end: (f32, f32)) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the line as an open path.
+    pub fn to_path(&self) -> Path {
+        Path::from_iter([
+            PathCommand::MoveTo(self.start.0, self.start.1),
+            PathCommand::LineTo(self.end.0, self.end.1),
+        ])
+    }
+}
+
+impl Hash for Line {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (x, y) in [self.start, self.end] {
+            x.to_bits().hash(state);
+            y.to_bits().hash(state);
+        }
+    }
+}
+
+/// A chain of straight segments through a list of points, e.g. the series
+/// of a line chart.
+///
+/// Like [`Line`], polylines are drawn with the node's stroke only. The
+/// last point is not joined back to the first.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline {
+    pub points: Vec<(f32, f32)>,
+}
+
+impl Polyline {
+    pub fn new(points: Vec<(f32, f32)>) -> Self {
+        Self { points }
+    }
+
+    /// Returns the polyline as an open path.
+    pub fn to_path(&self) -> Path {
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y))| {
+                if i == 0 {
+                    PathCommand::MoveTo(x, y)
+                } else {
+                    PathCommand::LineTo(x, y)
+                }
+            })
+            .collect()
+    }
+}
+
+impl Hash for Polyline {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.points.len().hash(state);
+        for &(x, y) in &self.points {
+            x.to_bits().hash(state);
+            y.to_bits().hash(state);
+        }
+    }
+}
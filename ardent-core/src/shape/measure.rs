@@ -0,0 +1,199 @@
+//! Measuring paths by arc length.
+//!
+//! Curves are flattened into short straight segments, so lengths and
+//! positions are accurate to [`TOLERANCE`] local units.
+
+use super::path::{Path, PathCommand};
+
+/// Largest distance between a curve and the segments approximating it.
+pub(super) const TOLERANCE: f32 = 0.01;
+
+/// Most segments a single curve is divided into.
+const MAX_CURVE_SEGMENTS: usize = 256;
+
+/// A flattened sub-path.
+#[derive(Clone)]
+pub(super) struct Contour {
+    pub(super) points: Vec<(f32, f32)>,
+    pub(super) closed: bool,
+}
+
+impl Contour {
+    /// Returns the segments of the contour in order, including the closing
+    /// segment of a closed one.
+    pub(super) fn segments(&self) -> impl Iterator<Item = ((f32, f32), (f32, f32))> + '_ {
+        let closing = match (self.closed, self.points.first(), self.points.last()) {
+            (true, Some(&first), Some(&last)) if first != last => Some((last, first)),
+            _ => None,
+        };
+        self.points
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .chain(closing)
+    }
+}
+
+/// Flattens the sub-paths of `path` into contours within `tolerance`.
+///
+/// Drawing commands without a preceding move-to start at the origin.
+pub(super) fn flatten(path: &Path, tolerance: f32) -> Vec<Contour> {
+    let mut contours: Vec<Contour> = Vec::new();
+    let mut current: Vec<(f32, f32)> = Vec::new();
+    // Where commands after a close continue from: the sub-path's start.
+    let mut restart = (0.0, 0.0);
+    let finish = |points: &mut Vec<(f32, f32)>, closed: bool, contours: &mut Vec<Contour>| {
+        if !points.is_empty() {
+            contours.push(Contour {
+                points: std::mem::take(points),
+                closed,
+            });
+        }
+    };
+    for command in path.commands() {
+        let from = current.last().copied().unwrap_or(restart);
+        if current.is_empty() && !matches!(command, PathCommand::MoveTo(..)) {
+            current.push(from);
+        }
+        match *command {
+            PathCommand::MoveTo(x, y) => {
+                finish(&mut current, false, &mut contours);
+                current.push((x, y));
+            }
+            PathCommand::LineTo(x, y) => current.push((x, y)),
+            PathCommand::QuadTo { ctrl, to } => {
+                let steps = steps(second_difference(from, ctrl, to) / 4.0, tolerance);
+                current.extend((1..=steps).map(|i| {
+                    let t = i as f32 / steps as f32;
+                    let s = 1.0 - t;
+                    (
+                        s * s * from.0 + 2.0 * s * t * ctrl.0 + t * t * to.0,
+                        s * s * from.1 + 2.0 * s * t * ctrl.1 + t * t * to.1,
+                    )
+                }));
+            }
+            PathCommand::CubicTo { ctrl1, ctrl2, to } => {
+                let steps = {
+                    let deviation = second_difference(from, ctrl1, ctrl2)
+                        .max(second_difference(ctrl1, ctrl2, to));
+                    steps(deviation * 0.75, tolerance)
+                };
+                current.extend((1..=steps).map(|i| {
+                    let t = i as f32 / steps as f32;
+                    let s = 1.0 - t;
+                    let (a, b, c, d) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+                    (
+                        a * from.0 + b * ctrl1.0 + c * ctrl2.0 + d * to.0,
+                        a * from.1 + b * ctrl1.1 + c * ctrl2.1 + d * to.1,
+                    )
+                }));
+            }
+            PathCommand::Close => {
+                restart = current.first().copied().unwrap_or(restart);
+                finish(&mut current, true, &mut contours);
+            }
+        }
+    }
+    // A move-to ending the path is not a contour of its own.
+    if current.len() > 1 || contours.is_empty() {
+        finish(&mut current, false, &mut contours);
+    }
+    contours
+}
+
+/// Returns the length of the second difference of three control points,
+/// which bounds how sharply a curve bends.
+fn second_difference(a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> f32 {
+    (a.0 - 2.0 * b.0 + c.0).hypot(a.1 - 2.0 * b.1 + c.1)
+}
+
+/// Returns how many segments keep a curve within `tolerance` of them,
+/// given how far it would stray from a single chord.
+fn steps(deviation: f32, tolerance: f32) -> usize {
+    let steps = (deviation / tolerance.max(1e-6)).sqrt().ceil();
+    if steps.is_finite() {
+        (steps as usize).clamp(1, MAX_CURVE_SEGMENTS)
+    } else {
+        1
+    }
+}
+
+/// A point on a path and the direction it travels in there.
+struct Sample {
+    point: (f32, f32),
+    tangent: Option<(f32, f32)>,
+}
+
+impl Path {
+    /// Returns the total length of the path, including the closing
+    /// segments of closed sub-paths.
+    pub fn length(&self) -> f32 {
+        flatten(self, TOLERANCE)
+            .iter()
+            .flat_map(Contour::segments)
+            .fold(0.0, |length, (a, b)| length + distance(a, b))
+    }
+
+    /// Returns the point at the fraction `t` of the path's length, e.g. to
+    /// place a label or move a node along a motion path.
+    ///
+    /// The fraction is clamped to [0.0, 1.0] and measured over all
+    /// sub-paths together, like [`Stroke`](crate::style::Stroke) trimming.
+    /// Returns `None` for an empty path.
+    pub fn point_at(&self, t: f32) -> Option<(f32, f32)> {
+        self.sample(t).map(|sample| sample.point)
+    }
+
+    /// Returns the unit direction the path travels in at the fraction `t`
+    /// of its length, e.g. to rotate a node following the path.
+    ///
+    /// Returns `None` for an empty path or one of zero length.
+    pub fn tangent_at(&self, t: f32) -> Option<(f32, f32)> {
+        self.sample(t).and_then(|sample| sample.tangent)
+    }
+
+    /// Returns the point and unit direction at the fraction `t` of the
+    /// length.
+    fn sample(&self, t: f32) -> Option<Sample> {
+        let contours = flatten(self, TOLERANCE);
+        let segments: Vec<_> = contours
+            .iter()
+            .flat_map(Contour::segments)
+            .filter(|&(a, b)| a != b)
+            .collect();
+        let total: f32 = segments.iter().map(|&(a, b)| distance(a, b)).sum();
+        let Some(&(last_from, last_to)) = segments.last() else {
+            return contours.first()?.points.first().map(|&point| Sample {
+                point,
+                tangent: None,
+            });
+        };
+        let target = t.clamp(0.0, 1.0) * total;
+        let mut offset = 0.0;
+        for &(a, b) in &segments {
+            let length = distance(a, b);
+            if offset + length >= target {
+                let f = ((target - offset) / length).clamp(0.0, 1.0);
+                let point = (a.0 + (b.0 - a.0) * f, a.1 + (b.1 - a.1) * f);
+                return Some(Sample {
+                    point,
+                    tangent: Some(direction(a, b)),
+                });
+            }
+            offset += length;
+        }
+        Some(Sample {
+            point: last_to,
+            tangent: Some(direction(last_from, last_to)),
+        })
+    }
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+/// Returns the unit vector from `a` towards `b`, which must differ.
+fn direction(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    let length = distance(a, b);
+    ((b.0 - a.0) / length, (b.1 - a.1) / length)
+}
@@ -0,0 +1,364 @@
+//! Interpolating between shapes.
+
+use super::measure::{self, Contour};
+use super::path::{Path, PathCommand};
+use super::{Ellipse, Line, Polygon, Polyline, Rect, Ring, Shape};
+
+/// Fewest points a contour is resampled into.
+const MIN_SAMPLES: usize = 3;
+
+impl Shape {
+    /// Interpolates between `self` (at `t = 0`) and `other` (at `t = 1`),
+    /// e.g. to morph a play icon into a pause icon.
+    ///
+    /// `t` may lie outside [0.0, 1.0] for overshooting easing curves.
+    /// Shapes of the same kind blend their parameters, so a rectangle stays
+    /// a rectangle, as do polygons and paths with matching points and
+    /// commands. Other pairs are flattened and resampled to the same number
+    /// of points spaced evenly along each outline, and blend into a path of
+    /// straight segments. Closed outlines are aligned to start at their
+    /// closest points, so they do not twist on the way.
+    pub fn lerp(&self, other: &Shape, t: f32) -> Shape {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        let mix_point = |a: (f32, f32), b: (f32, f32)| (mix(a.0, b.0), mix(a.1, b.1));
+        let mix_points = |a: &[(f32, f32)], b: &[(f32, f32)]| {
+            a.iter().zip(b).map(|(&a, &b)| mix_point(a, b)).collect()
+        };
+        match (self, other) {
+            (Shape::Rect(a), Shape::Rect(b)) => {
+                Shape::Rect(Rect::new(mix(a.width, b.width), mix(a.height, b.height)))
+            }
+            (Shape::Ellipse(a), Shape::Ellipse(b)) => {
+                Shape::Ellipse(Ellipse::new(mix(a.rx, b.rx), mix(a.ry, b.ry)))
+            }
+            (Shape::Ring(a), Shape::Ring(b)) => Shape::Ring(Ring::new(
+                mix(a.outer_radius, b.outer_radius),
+                mix(a.inner_radius, b.inner_radius),
+            )),
+            (Shape::Line(a), Shape::Line(b)) => Shape::Line(Line::new(
+                mix_point(a.start, b.start),
+                mix_point(a.end, b.end),
+            )),
+            (Shape::Polyline(a), Shape::Polyline(b)) if a.points.len() == b.points.len() => {
+                Shape::Polyline(Polyline::new(mix_points(&a.points, &b.points)))
+            }
+            (Shape::Polygon(a), Shape::Polygon(b))
+                if a.points.len() == b.points.len()
+                    && a.holes.len() == b.holes.len()
+                    && a.holes
+                        .iter()
+                        .zip(&b.holes)
+                        .all(|(a, b)| a.len() == b.len()) =>
+            {
+                Shape::Polygon(Polygon {
+                    points: mix_points(&a.points, &b.points),
+                    holes: a
+                        .holes
+                        .iter()
+                        .zip(&b.holes)
+                        .map(|(a, b)| mix_points(a, b))
+                        .collect(),
+                })
+            }
+            (Shape::Path(a), Shape::Path(b)) if same_structure(a, b) => Shape::Path(
+                a.commands()
+                    .iter()
+                    .zip(b.commands())
+                    .map(|(a, b)| lerp_command(*a, *b, t))
+                    .collect(),
+            ),
+            (a, b) if a.is_open() && b.is_open() => {
+                let resampled = resample_pair(&outline(a), &outline(b), t);
+                Shape::Polyline(Polyline::new(resampled))
+            }
+            (a, b) => {
+                let (a, b) = (contours(a), contours(b));
+                let mut path = Path::new();
+                for i in 0..a.len().max(b.len()) {
+                    let (a, b) = match (a.get(i), b.get(i)) {
+                        (Some(a), Some(b)) => (a.clone(), b.clone()),
+                        // Contours without a counterpart grow from or
+                        // shrink into their center.
+                        (Some(a), None) => (a.clone(), collapsed(a)),
+                        (None, Some(b)) => (collapsed(b), b.clone()),
+                        (None, None) => unreachable!(),
+                    };
+                    let mut points = resample_pair(&a, &b, t).into_iter();
+                    if let Some((x, y)) = points.next() {
+                        path.push(PathCommand::MoveTo(x, y));
+                        points.for_each(|(x, y)| path.push(PathCommand::LineTo(x, y)));
+                        if a.closed || b.closed {
+                            path.push(PathCommand::Close);
+                        }
+                    }
+                }
+                Shape::Path(path)
+            }
+        }
+    }
+}
+
+/// Returns `true` if both paths have the same kinds of commands in the same
+/// order, so their points can be blended pairwise.
+fn same_structure(a: &Path, b: &Path) -> bool {
+    a.commands().len() == b.commands().len()
+        && a.commands()
+            .iter()
+            .zip(b.commands())
+            .all(|(a, b)| std::mem::discriminant(a) == std::mem::discriminant(b))
+}
+
+/// Blends the points of two commands of the same kind.
+fn lerp_command(a: PathCommand, b: PathCommand, t: f32) -> PathCommand {
+    let mix = |a: (f32, f32), b: (f32, f32)| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+    match (a, b) {
+        (PathCommand::MoveTo(ax, ay), PathCommand::MoveTo(bx, by)) => {
+            let (x, y) = mix((ax, ay), (bx, by));
+            PathCommand::MoveTo(x, y)
+        }
+        (PathCommand::LineTo(ax, ay), PathCommand::LineTo(bx, by)) => {
+            let (x, y) = mix((ax, ay), (bx, by));
+            PathCommand::LineTo(x, y)
+        }
+        (PathCommand::QuadTo { ctrl: c1, to: t1 }, PathCommand::QuadTo { ctrl: c2, to: t2 }) => {
+            PathCommand::QuadTo {
+                ctrl: mix(c1, c2),
+                to: mix(t1, t2),
+            }
+        }
+        (
+            PathCommand::CubicTo {
+                ctrl1: a1,
+                ctrl2: a2,
+                to: at,
+            },
+            PathCommand::CubicTo {
+                ctrl1: b1,
+                ctrl2: b2,
+                to: bt,
+            },
+        ) => PathCommand::CubicTo {
+            ctrl1: mix(a1, b1),
+            ctrl2: mix(a2, b2),
+            to: mix(at, bt),
+        },
+        (a, _) => a,
+    }
+}
+
+/// Returns the flattened contours of a shape.
+fn contours(shape: &Shape) -> Vec<Contour> {
+    let mut contours = Vec::new();
+    shape.for_each_path(|path| contours.extend(measure::flatten(path, measure::TOLERANCE)));
+    contours
+}
+
+/// Returns the flattened outline of an open shape as a single contour.
+fn outline(shape: &Shape) -> Contour {
+    let points = contours(shape)
+        .into_iter()
+        .flat_map(|contour| contour.points)
+        .collect();
+    Contour {
+        points,
+        closed: false,
+    }
+}
+
+/// Returns a contour collapsed into the center of `contour`'s points.
+fn collapsed(contour: &Contour) -> Contour {
+    let n = contour.points.len().max(1) as f32;
+    let (x, y) = contour
+        .points
+        .iter()
+        .fold((0.0, 0.0), |(x, y), p| (x + p.0, y + p.1));
+    Contour {
+        points: vec![(x / n, y / n)],
+        closed: contour.closed,
+    }
+}
+
+/// Resamples two contours to the same number of points and blends them.
+fn resample_pair(a: &Contour, b: &Contour, t: f32) -> Vec<(f32, f32)> {
+    let count = a.points.len().max(b.points.len()).max(MIN_SAMPLES);
+    let closed = a.closed && b.closed;
+    let from = resample(a, count, closed);
+    let mut to = resample(b, count, closed);
+    if closed {
+        let shift = best_rotation(&from, &to);
+        to.rotate_left(shift);
+    }
+    from.iter()
+        .zip(&to)
+        .map(|(&a, &b)| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t))
+        .collect()
+}
+
+/// Returns `count` points spaced evenly along the contour.
+///
+/// Points of a closed contour are spread around it without repeating the
+/// start. Those of an open one run from its start to its end.
+fn resample(contour: &Contour, count: usize, closed: bool) -> Vec<(f32, f32)> {
+    let Some(&first) = contour.points.first() else {
+        return Vec::new();
+    };
+    let segments: Vec<_> = Contour {
+        points: contour.points.clone(),
+        closed,
+    }
+    .segments()
+    .map(|(a, b)| (a, b, (b.0 - a.0).hypot(b.1 - a.1)))
+    .collect();
+    let total: f32 = segments.iter().map(|s| s.2).sum();
+    if total <= 0.0 {
+        return vec![first; count];
+    }
+    let spacing = if closed {
+        total / count as f32
+    } else {
+        total / (count - 1) as f32
+    };
+    let mut points = Vec::with_capacity(count);
+    let mut segments = segments.iter().peekable();
+    let mut offset = 0.0;
+    for i in 0..count {
+        let target = i as f32 * spacing;
+        while let Some(&&(_, _, length)) = segments.peek() {
+            if offset + length >= target || segments.len() == 1 {
+                break;
+            }
+            offset += length;
+            segments.next();
+        }
+        let &&(a, b, length) = segments.peek().expect("contour has a segment");
+        let f = if length > 0.0 {
+            ((target - offset) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        points.push((a.0 + (b.0 - a.0) * f, a.1 + (b.1 - a.1) * f));
+    }
+    points
+}
+
+/// Returns how far `to` must be rotated for its points to be closest to
+/// those of `from` overall.
+fn best_rotation(from: &[(f32, f32)], to: &[(f32, f32)]) -> usize {
+    let n = to.len();
+    let costs: Vec<f32> = (0..n)
+        .map(|shift| {
+            from.iter()
+                .enumerate()
+                .map(|(i, a)| {
+                    let b = to[(i + shift) % n];
+                    (b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)
+                })
+                .sum()
+        })
+        .collect();
+    costs
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(shift, _)| shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: (f32, f32), b: (f32, f32)) -> bool {
+        (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3
+    }
+
+    /// Returns the points of a path of straight segments.
+    fn points(shape: &Shape) -> Vec<(f32, f32)> {
+        let Shape::Path(path) = shape else {
+            panic!("differing shapes blend into a path");
+        };
+        path.commands()
+            .iter()
+            .filter_map(|command| match *command {
+                PathCommand::MoveTo(x, y) | PathCommand::LineTo(x, y) => Some((x, y)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lerp_ends_at_either_shape() {
+        let (a, b) = (
+            Shape::Rect(Rect::new(10.0, 20.0)),
+            Shape::Rect(Rect::new(30.0, 40.0)),
+        );
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+
+        // Differing shapes are resampled, so their points lie on either
+        // outline at the ends.
+        let circle = Shape::Ellipse(Ellipse::new(20.0, 20.0));
+        let start = points(&a.lerp(&circle, 0.0));
+        assert!(start.len() >= MIN_SAMPLES);
+        assert!(start.iter().all(|&(x, y)| {
+            let on_side = |v: f32, max: f32| v.abs() < 1e-3 || (v - max).abs() < 1e-3;
+            (on_side(x, 10.0) && (0.0..=20.0).contains(&y))
+                || (on_side(y, 20.0) && (0.0..=10.0).contains(&x))
+        }));
+        let end = points(&a.lerp(&circle, 1.0));
+        assert!(
+            end.iter()
+                .all(|&(x, y)| ((x - 20.0).hypot(y - 20.0) - 20.0).abs() < 0.05)
+        );
+    }
+
+    #[test]
+    fn lerp_collapses_contours_without_a_counterpart() {
+        let ring = Shape::Ring(Ring::new(20.0, 10.0));
+        let rect = Shape::Rect(Rect::new(40.0, 40.0));
+        let Shape::Path(path) = ring.lerp(&rect, 1.0) else {
+            panic!("differing shapes blend into a path");
+        };
+        let sub_paths: Vec<&[PathCommand]> = path
+            .commands()
+            .split_inclusive(|command| *command == PathCommand::Close)
+            .collect();
+        assert_eq!(sub_paths.len(), 2);
+
+        // The inner circle shrinks into the center of its points.
+        let center = collapsed(&contours(&ring)[1]).points[0];
+        assert!(sub_paths[1].iter().all(|command| match *command {
+            PathCommand::MoveTo(x, y) | PathCommand::LineTo(x, y) => {
+                close((x, y), center)
+            }
+            _ => *command == PathCommand::Close,
+        }));
+    }
+
+    #[test]
+    fn resampling_blends_from_a_single_point() {
+        let point = Contour {
+            points: vec![(3.0, 4.0)],
+            closed: true,
+        };
+        assert_eq!(collapsed(&point).points, [(3.0, 4.0)]);
+
+        let square = Contour {
+            points: vec![(0.0, 0.0), (8.0, 0.0), (8.0, 8.0), (0.0, 8.0)],
+            closed: true,
+        };
+        let start = resample_pair(&point, &square, 0.0);
+        assert_eq!(start.len(), 4);
+        assert!(start.iter().all(|&p| p == (3.0, 4.0)));
+        let mut end = resample_pair(&point, &square, 1.0);
+        end.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        assert_eq!(end, [(0.0, 0.0), (0.0, 8.0), (8.0, 0.0), (8.0, 8.0)]);
+    }
+
+    #[test]
+    fn best_rotation_aligns_the_closest_points() {
+        let from = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let to = [(1.0, 1.0), (0.0, 1.0), (0.0, 0.0), (1.0, 0.0)];
+        assert_eq!(best_rotation(&from, &to), 2);
+        assert_eq!(best_rotation(&[], &[]), 0);
+    }
+}
@@ -0,0 +1,243 @@
+use std::hash::{Hash, Hasher};
+
+/// A single drawing command of a [`Path`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathCommand {
+    /// Starts a new sub-path at the given point.
+    MoveTo(f32, f32),
+
+    /// Draws a straight line to the given point.
+    LineTo(f32, f32),
+
+    /// Draws a quadratic Bézier curve through a control point to the end
+    /// point.
+    QuadTo { ctrl: (f32, f32), to: (f32, f32) },
+
+    /// Draws a cubic Bézier curve through two control points to the end
+    /// point.
+    CubicTo {
+        ctrl1: (f32, f32),
+        ctrl2: (f32, f32),
+        to: (f32, f32),
+    },
+
+    /// Closes the current sub-path with a line back to its start.
+    Close,
+}
+
+/// An arbitrary outline made of lines and curves.
+///
+/// A path consists of one or more sub-paths, each started by
+/// [`PathCommand::MoveTo`]. Coordinates are in the node's local space.
+///
+/// Paths are built by chaining drawing commands:
+///
+/// ```rust,ignore
+/// let leaf = Path::new()
+///     .move_to(0.0, 40.0)
+///     .quadratic_to((0.0, 0.0), (40.0, 0.0))
+///     .cubic_to((40.0, 20.0), (20.0, 40.0), (0.0, 40.0))
+///     .close();
+/// node.set_shape(Shape::Path(leaf));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Path {
+    commands: Vec<PathCommand>,
+}
+
+impl Path {
+    /// Creates an empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new sub-path at `(x, y)`.
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.push(PathCommand::MoveTo(x, y));
+        self
+    }
+
+    /// Draws a straight line to `(x, y)`.
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.push(PathCommand::LineTo(x, y));
+        self
+    }
+
+    /// Draws a quadratic Bézier curve through `ctrl` to `to`.
+    pub fn quadratic_to(mut self, ctrl: (f32, f32), to: (f32, f32)) -> Self {
+        self.push(PathCommand::QuadTo { ctrl, to });
+        self
+    }
+
+    /// Draws a cubic Bézier curve through `ctrl1` and `ctrl2` to `to`.
+    pub fn cubic_to(mut self, ctrl1: (f32, f32), ctrl2: (f32, f32), to: (f32, f32)) -> Self {
+        self.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+        self
+    }
+
+    /// Closes the current sub-path with a line back to its start.
+    pub fn close(mut self) -> Self {
+        self.push(PathCommand::Close);
+        self
+    }
+
+    /// Appends a command to the path.
+    pub fn push(&mut self, command: PathCommand) {
+        self.commands.push(command);
+    }
+
+    /// Returns the commands of the path in drawing order.
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    /// Returns `true` if the path has no commands.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Returns the minimum and maximum corner of the box enclosing all
+    /// points of the path, including curve control points.
+    pub fn bounds(&self) -> Option<((f32, f32), (f32, f32))> {
+        let mut points = self.commands.iter().flat_map(|command| match *command {
+            PathCommand::MoveTo(x, y) | PathCommand::LineTo(x, y) => vec![(x, y)],
+            PathCommand::QuadTo { ctrl, to } => vec![ctrl, to],
+            PathCommand::CubicTo { ctrl1, ctrl2, to } => vec![ctrl1, ctrl2, to],
+            PathCommand::Close => Vec::new(),
+        });
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), (x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+
+    /// Returns the minimum and maximum corner of the box enclosing the
+    /// outline itself, which unlike [`bounds`](Self::bounds) leaves out
+    /// control points the curves do not reach.
+    pub(super) fn tight_bounds(&self) -> Option<((f32, f32), (f32, f32))> {
+        let mut points = Vec::new();
+        let mut from = (0.0, 0.0);
+        let mut start = from;
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(x, y) => {
+                    start = (x, y);
+                    from = start;
+                    points.push(from);
+                }
+                PathCommand::LineTo(x, y) => {
+                    from = (x, y);
+                    points.push(from);
+                }
+                PathCommand::QuadTo { ctrl, to } => {
+                    let at = |t: f32| {
+                        let s = 1.0 - t;
+                        let axis = |a: f32, b: f32, c: f32| s * s * a + 2.0 * s * t * b + t * t * c;
+                        (axis(from.0, ctrl.0, to.0), axis(from.1, ctrl.1, to.1))
+                    };
+                    let extrema = [
+                        quad_extremum(from.0, ctrl.0, to.0),
+                        quad_extremum(from.1, ctrl.1, to.1),
+                    ];
+                    points.extend(extrema.into_iter().flatten().map(at));
+                    from = to;
+                    points.push(from);
+                }
+                PathCommand::CubicTo { ctrl1, ctrl2, to } => {
+                    let at = |t: f32| {
+                        let s = 1.0 - t;
+                        let axis = |a: f32, b: f32, c: f32, d: f32| {
+                            s * s * s * a
+                                + 3.0 * s * s * t * b
+                                + 3.0 * s * t * t * c
+                                + t * t * t * d
+                        };
+                        (
+                            axis(from.0, ctrl1.0, ctrl2.0, to.0),
+                            axis(from.1, ctrl1.1, ctrl2.1, to.1),
+                        )
+                    };
+                    let extrema = [
+                        cubic_extrema(from.0, ctrl1.0, ctrl2.0, to.0),
+                        cubic_extrema(from.1, ctrl1.1, ctrl2.1, to.1),
+                    ];
+                    points.extend(extrema.into_iter().flatten().flatten().map(at));
+                    from = to;
+                    points.push(from);
+                }
+                PathCommand::Close => from = start,
+            }
+        }
+        let (&first, rest) = points.split_first()?;
+        Some(rest.iter().fold((first, first), |(min, max), &(x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+}
+
+impl FromIterator<PathCommand> for Path {
+    fn from_iter<I: IntoIterator<Item = PathCommand>>(commands: I) -> Self {
+        Self {
+            commands: commands.into_iter().collect(),
+        }
+    }
+}
+
+impl Hash for Path {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash_point = |(x, y): (f32, f32), state: &mut H| {
+            x.to_bits().hash(state);
+            y.to_bits().hash(state);
+        };
+        self.commands.len().hash(state);
+        for command in &self.commands {
+            std::mem::discriminant(command).hash(state);
+            match *command {
+                PathCommand::MoveTo(x, y) | PathCommand::LineTo(x, y) => hash_point((x, y), state),
+                PathCommand::QuadTo { ctrl, to } => {
+                    hash_point(ctrl, state);
+                    hash_point(to, state);
+                }
+                PathCommand::CubicTo { ctrl1, ctrl2, to } => {
+                    hash_point(ctrl1, state);
+                    hash_point(ctrl2, state);
+                    hash_point(to, state);
+                }
+                PathCommand::Close => {}
+            }
+        }
+    }
+}
+
+/// Returns the parameter strictly inside (0, 1) at which one coordinate of
+/// a quadratic curve turns, if any.
+fn quad_extremum(a: f32, b: f32, c: f32) -> Option<f32> {
+    let denominator = a - 2.0 * b + c;
+    let t = (a - b) / denominator;
+    (denominator != 0.0 && t > 0.0 && t < 1.0).then_some(t)
+}
+
+/// Returns the parameters strictly inside (0, 1) at which one coordinate of
+/// a cubic curve turns.
+fn cubic_extrema(a: f32, b: f32, c: f32, d: f32) -> [Option<f32>; 2] {
+    // The derivative, divided by 3, is qa t² + qb t + qc.
+    let qa = -a + 3.0 * b - 3.0 * c + d;
+    let qb = 2.0 * (a - 2.0 * b + c);
+    let qc = b - a;
+    let inside = |t: f32| (t > 0.0 && t < 1.0).then_some(t);
+    if qa.abs() < 1e-6 {
+        return [(qb != 0.0).then(|| -qc / qb).and_then(inside), None];
+    }
+    let discriminant = qb * qb - 4.0 * qa * qc;
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+    let root = discriminant.sqrt();
+    [
+        inside((-qb + root) / (2.0 * qa)),
+        inside((-qb - root) / (2.0 * qa)),
+    ]
+}
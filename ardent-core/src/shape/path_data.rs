@@ -0,0 +1,510 @@
+//! Parsing of SVG path data, the mini-language of the `d` attribute.
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::fmt;
+use std::str::FromStr;
+
+use super::path::{Path, PathCommand};
+
+/// An error in SVG path data, with the byte offset at which it was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathDataError {
+    /// The data has drawing commands but does not start with a move-to
+    /// command.
+    MissingMoveTo,
+
+    /// A character that is neither a command, a number, nor a separator.
+    UnexpectedCharacter { offset: usize, found: char },
+
+    /// A command ended before all of its parameters were given, or a
+    /// number is malformed.
+    ExpectedNumber { offset: usize },
+
+    /// An arc flag other than `0` or `1`.
+    InvalidFlag { offset: usize },
+}
+
+impl fmt::Display for PathDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathDataError::MissingMoveTo => write!(f, "path data must start with a move-to"),
+            PathDataError::UnexpectedCharacter { offset, found } => {
+                write!(f, "unexpected {found:?} in path data at byte {offset}")
+            }
+            PathDataError::ExpectedNumber { offset } => {
+                write!(f, "expected a number in path data at byte {offset}")
+            }
+            PathDataError::InvalidFlag { offset } => {
+                write!(
+                    f,
+                    "expected an arc flag (0 or 1) in path data at byte {offset}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathDataError {}
+
+impl Path {
+    /// Parses SVG path data, the value of a `d` attribute, e.g. copied from
+    /// a design tool.
+    ///
+    /// All commands are supported, in absolute and relative form: move-to
+    /// (`M`), lines (`L`, `H`, `V`), cubic (`C`, `S`) and quadratic (`Q`,
+    /// `T`) curves, elliptical arcs (`A`), and close (`Z`). Horizontal and
+    /// vertical lines become line commands, smooth curves get their
+    /// reflected control point, and arcs are converted to cubic curves.
+    ///
+    /// ```rust,ignore
+    /// let heart = Path::from_svg_data("M 10 30 A 20 20 0 0 1 50 30 A 20 20 0 0 1 90 30 Q 90 60 50 90 Q 10 60 10 30 Z")?;
+    /// ```
+    pub fn from_svg_data(data: &str) -> Result<Self, PathDataError> {
+        Parser::new(data).parse()
+    }
+}
+
+impl FromStr for Path {
+    type Err = PathDataError;
+
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        Self::from_svg_data(data)
+    }
+}
+
+/// The state of parsing path data.
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+    path: Path,
+
+    /// The current point and the start of the current sub-path.
+    current: (f32, f32),
+    start: (f32, f32),
+
+    /// The second control point of the previous cubic or quadratic curve,
+    /// reflected by the smooth commands following it.
+    last_cubic: Option<(f32, f32)>,
+    last_quad: Option<(f32, f32)>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a str) -> Self {
+        Self {
+            data: data.as_bytes(),
+            pos: 0,
+            path: Path::new(),
+            current: (0.0, 0.0),
+            start: (0.0, 0.0),
+            last_cubic: None,
+            last_quad: None,
+        }
+    }
+
+    fn parse(mut self) -> Result<Path, PathDataError> {
+        let mut command: Option<u8> = None;
+        loop {
+            self.skip_separators();
+            let Some(&c) = self.data.get(self.pos) else {
+                break;
+            };
+            let next = if c.is_ascii_alphabetic() {
+                self.pos += 1;
+                c
+            } else if let (Some(previous), true) = (command, starts_number(c)) {
+                // Repeated parameters repeat the command; those after a
+                // move-to are lines.
+                match previous {
+                    b'M' => b'L',
+                    b'm' => b'l',
+                    b'Z' | b'z' => return Err(self.unexpected()),
+                    other => other,
+                }
+            } else {
+                return Err(self.unexpected());
+            };
+            if command.is_none() && !matches!(next, b'M' | b'm') {
+                return Err(PathDataError::MissingMoveTo);
+            }
+            self.command(next)?;
+            command = Some(next);
+        }
+        Ok(self.path)
+    }
+
+    /// Parses the parameters of one command and appends it to the path.
+    fn command(&mut self, command: u8) -> Result<(), PathDataError> {
+        let relative = command.is_ascii_lowercase();
+        let (cx, cy) = self.current;
+        let point = |(x, y): (f32, f32)| if relative { (cx + x, cy + y) } else { (x, y) };
+        let (mut cubic, mut quad) = (None, None);
+        match command.to_ascii_uppercase() {
+            b'M' => {
+                let to = point(self.pair()?);
+                self.path.push(PathCommand::MoveTo(to.0, to.1));
+                self.start = to;
+                self.current = to;
+            }
+            b'L' => {
+                let to = point(self.pair()?);
+                self.line_to(to);
+            }
+            b'H' => {
+                let x = self.number()?;
+                self.line_to((if relative { cx + x } else { x }, cy));
+            }
+            b'V' => {
+                let y = self.number()?;
+                self.line_to((cx, if relative { cy + y } else { y }));
+            }
+            b'C' => {
+                let ctrl1 = point(self.pair()?);
+                let ctrl2 = point(self.pair()?);
+                let to = point(self.pair()?);
+                self.cubic_to(ctrl1, ctrl2, to);
+                cubic = Some(ctrl2);
+            }
+            b'S' => {
+                let ctrl1 = reflect(self.last_cubic, self.current);
+                let ctrl2 = point(self.pair()?);
+                let to = point(self.pair()?);
+                self.cubic_to(ctrl1, ctrl2, to);
+                cubic = Some(ctrl2);
+            }
+            b'Q' => {
+                let ctrl = point(self.pair()?);
+                let to = point(self.pair()?);
+                self.path.push(PathCommand::QuadTo { ctrl, to });
+                self.current = to;
+                quad = Some(ctrl);
+            }
+            b'T' => {
+                let ctrl = reflect(self.last_quad, self.current);
+                let to = point(self.pair()?);
+                self.path.push(PathCommand::QuadTo { ctrl, to });
+                self.current = to;
+                quad = Some(ctrl);
+            }
+            b'A' => {
+                let radii = self.pair()?;
+                let rotation = self.number()?;
+                let large_arc = self.flag()?;
+                let sweep = self.flag()?;
+                let to = point(self.pair()?);
+                self.arc_to(radii, rotation, large_arc, sweep, to);
+            }
+            b'Z' => {
+                self.path.push(PathCommand::Close);
+                self.current = self.start;
+            }
+            _ => {
+                return Err(PathDataError::UnexpectedCharacter {
+                    offset: self.pos - 1,
+                    found: command as char,
+                });
+            }
+        }
+        self.last_cubic = cubic;
+        self.last_quad = quad;
+        Ok(())
+    }
+
+    fn line_to(&mut self, to: (f32, f32)) {
+        self.path.push(PathCommand::LineTo(to.0, to.1));
+        self.current = to;
+    }
+
+    fn cubic_to(&mut self, ctrl1: (f32, f32), ctrl2: (f32, f32), to: (f32, f32)) {
+        self.path.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+        self.current = to;
+    }
+
+    /// Appends an elliptical arc from the current point to `to` as cubic
+    /// curves, following the endpoint-to-center conversion of the SVG
+    /// specification.
+    fn arc_to(
+        &mut self,
+        (rx, ry): (f32, f32),
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: (f32, f32),
+    ) {
+        let from = self.current;
+        if from == to {
+            return;
+        }
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to(to);
+            return;
+        }
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        let (dx, dy) = ((from.0 - to.0) / 2.0, (from.1 - to.1) / 2.0);
+        let (x1, y1) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+
+        // Radii too small to reach the end point are scaled up.
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+        let (rx2, ry2) = (rx * rx, ry * ry);
+        let numerator = rx2 * ry2 - rx2 * y1 * y1 - ry2 * x1 * x1;
+        let denominator = rx2 * y1 * y1 + ry2 * x1 * x1;
+        let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+        let coef = sign * (numerator / denominator).max(0.0).sqrt();
+        let (ccx, ccy) = (coef * rx * y1 / ry, -coef * ry * x1 / rx);
+        let center = (
+            cos * ccx - sin * ccy + (from.0 + to.0) / 2.0,
+            sin * ccx + cos * ccy + (from.1 + to.1) / 2.0,
+        );
+
+        let start = angle((1.0, 0.0), ((x1 - ccx) / rx, (y1 - ccy) / ry));
+        let mut delta = angle(
+            ((x1 - ccx) / rx, (y1 - ccy) / ry),
+            ((-x1 - ccx) / rx, (-y1 - ccy) / ry),
+        );
+        if !sweep && delta > 0.0 {
+            delta -= TAU;
+        } else if sweep && delta < 0.0 {
+            delta += TAU;
+        }
+
+        // Each cubic spans at most a quarter turn.
+        let segments = (delta.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = delta / segments as f32;
+        let handle = 4.0 / 3.0 * (step / 4.0).tan();
+        let at = |t: f32| {
+            let (s, c) = t.sin_cos();
+            (
+                center.0 + rx * c * cos - ry * s * sin,
+                center.1 + rx * c * sin + ry * s * cos,
+            )
+        };
+        let tangent = |t: f32| {
+            let (s, c) = t.sin_cos();
+            (-rx * s * cos - ry * c * sin, -rx * s * sin + ry * c * cos)
+        };
+        for i in 0..segments {
+            let (a0, a1) = (start + step * i as f32, start + step * (i + 1) as f32);
+            let (p0, p1) = (at(a0), at(a1));
+            let (d0, d1) = (tangent(a0), tangent(a1));
+            let end = if i + 1 == segments { to } else { p1 };
+            self.cubic_to(
+                (p0.0 + handle * d0.0, p0.1 + handle * d0.1),
+                (p1.0 - handle * d1.0, p1.1 - handle * d1.1),
+                end,
+            );
+        }
+    }
+
+    /// Skips whitespace and at most one comma.
+    fn skip_separators(&mut self) {
+        self.skip_whitespace();
+        if self.data.get(self.pos) == Some(&b',') {
+            self.pos += 1;
+            self.skip_whitespace();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .data
+            .get(self.pos)
+            .is_some_and(|c| c.is_ascii_whitespace())
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn pair(&mut self) -> Result<(f32, f32), PathDataError> {
+        Ok((self.number()?, self.number()?))
+    }
+
+    /// Parses a number, e.g. `-1.5e3` or `.5`, after optional separators.
+    fn number(&mut self) -> Result<f32, PathDataError> {
+        self.skip_separators();
+        let start = self.pos;
+        let digits = |parser: &mut Self| {
+            let from = parser.pos;
+            while parser.data.get(parser.pos).is_some_and(u8::is_ascii_digit) {
+                parser.pos += 1;
+            }
+            parser.pos > from
+        };
+        if matches!(self.data.get(self.pos), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut valid = digits(self);
+        if self.data.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            valid |= digits(self);
+        }
+        if valid && matches!(self.data.get(self.pos), Some(b'e' | b'E')) {
+            let mantissa = self.pos;
+            self.pos += 1;
+            if matches!(self.data.get(self.pos), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if !digits(self) {
+                self.pos = mantissa;
+            }
+        }
+        let text = std::str::from_utf8(&self.data[start..self.pos]).unwrap_or_default();
+        match text.parse::<f32>() {
+            Ok(number) if valid && number.is_finite() => Ok(number),
+            _ => Err(PathDataError::ExpectedNumber { offset: start }),
+        }
+    }
+
+    /// Parses an arc flag, which needs no separator after it.
+    fn flag(&mut self) -> Result<bool, PathDataError> {
+        self.skip_separators();
+        let flag = match self.data.get(self.pos) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(PathDataError::InvalidFlag { offset: self.pos }),
+        };
+        self.pos += 1;
+        Ok(flag)
+    }
+
+    /// Returns the error for the character at the current position.
+    fn unexpected(&self) -> PathDataError {
+        let text = std::str::from_utf8(&self.data[self.pos..]).unwrap_or_default();
+        match text.chars().next() {
+            Some(found) => PathDataError::UnexpectedCharacter {
+                offset: self.pos,
+                found,
+            },
+            None => PathDataError::ExpectedNumber { offset: self.pos },
+        }
+    }
+}
+
+/// Returns `true` if `c` can start a number.
+fn starts_number(c: u8) -> bool {
+    c.is_ascii_digit() || matches!(c, b'+' | b'-' | b'.')
+}
+
+/// Returns the control point of a smooth curve: the previous curve's last
+/// control point reflected through the current point, or the current point
+/// if the previous command was not a curve of the same kind.
+fn reflect(control: Option<(f32, f32)>, (x, y): (f32, f32)) -> (f32, f32) {
+    control.map_or((x, y), |(cx, cy)| (2.0 * x - cx, 2.0 * y - cy))
+}
+
+/// Returns the signed angle from `u` to `v`.
+fn angle(u: (f32, f32), v: (f32, f32)) -> f32 {
+    (u.0 * v.1 - u.1 * v.0).atan2(u.0 * v.0 + u.1 * v.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands(data: &str) -> Vec<PathCommand> {
+        Path::from_svg_data(data).unwrap().commands().to_vec()
+    }
+
+    #[test]
+    fn repeated_parameters_repeat_the_command() {
+        assert_eq!(
+            commands("M0 0 L1 1 2 2 h3 3"),
+            [
+                PathCommand::MoveTo(0.0, 0.0),
+                PathCommand::LineTo(1.0, 1.0),
+                PathCommand::LineTo(2.0, 2.0),
+                PathCommand::LineTo(5.0, 2.0),
+                PathCommand::LineTo(8.0, 2.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn repeated_parameters_after_move_to_are_lines() {
+        assert_eq!(
+            commands("M1 1 2 2 m1 1 2 2"),
+            [
+                PathCommand::MoveTo(1.0, 1.0),
+                PathCommand::LineTo(2.0, 2.0),
+                PathCommand::MoveTo(3.0, 3.0),
+                PathCommand::LineTo(5.0, 5.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn relative_commands_after_close_start_at_the_sub_path_start() {
+        assert_eq!(
+            commands("M10 10 l5 0 0 5 z l0 5 z m1 1"),
+            [
+                PathCommand::MoveTo(10.0, 10.0),
+                PathCommand::LineTo(15.0, 10.0),
+                PathCommand::LineTo(15.0, 15.0),
+                PathCommand::Close,
+                PathCommand::LineTo(10.0, 15.0),
+                PathCommand::Close,
+                PathCommand::MoveTo(11.0, 11.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn compact_numbers() {
+        assert_eq!(
+            commands("M.5.5L-1-2l1e-3,2E1"),
+            [
+                PathCommand::MoveTo(0.5, 0.5),
+                PathCommand::LineTo(-1.0, -2.0),
+                PathCommand::LineTo(-0.999, 18.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn arc_flags_need_no_separators() {
+        let compact = commands("M0 0A5 5 0 1110 0");
+        assert_eq!(compact, commands("M0 0 A 5 5 0 1 1 10 0"));
+        assert!(matches!(
+            compact.last(),
+            Some(PathCommand::CubicTo {
+                to: (10.0, 0.0),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn errors_report_their_offset() {
+        let error = |data| Path::from_svg_data(data).unwrap_err();
+        assert_eq!(error("L0 0"), PathDataError::MissingMoveTo);
+        assert_eq!(
+            error("M0 0 L1"),
+            PathDataError::ExpectedNumber { offset: 7 }
+        );
+        assert_eq!(
+            error("M0 0 L1 x"),
+            PathDataError::ExpectedNumber { offset: 8 }
+        );
+        assert_eq!(
+            error("M0 0 X"),
+            PathDataError::UnexpectedCharacter {
+                offset: 5,
+                found: 'X'
+            }
+        );
+        assert_eq!(
+            error("M0 0 z 1"),
+            PathDataError::UnexpectedCharacter {
+                offset: 7,
+                found: '1'
+            }
+        );
+        assert_eq!(
+            error("M0 0 A1 1 0 2 0 5 5"),
+            PathDataError::InvalidFlag { offset: 12 }
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use std::hash::{Hash, Hasher};
+
+use super::path::{Path, PathCommand};
+
+/// A closed outline of straight edges through a list of points, which may
+/// be concave and have holes.
+///
+/// The outline and each hole are closed automatically. Holes are cut out
+/// regardless of the order their points are listed in or the fill rule:
+/// they are wound opposite to the outline in the [path](Self::to_path).
+/// Holes are expected to lie inside the outline without overlapping each
+/// other.
+///
+/// ```rust,ignore
+/// let frame = Polygon::new(vec![(0.0, 0.0), (100.0, 0.0), (100.0, 80.0), (0.0, 80.0)])
+///     .with_hole(vec![(20.0, 20.0), (80.0, 20.0), (80.0, 60.0), (20.0, 60.0)]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon {
+    /// Corners of the outline in order.
+    pub points: Vec<(f32, f32)>,
+
+    /// Corners of each hole in order.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub holes: Vec<Vec<(f32, f32)>>,
+}
+
+impl Polygon {
+    pub fn new(points: Vec<(f32, f32)>) -> Self {
+        Self {
+            points,
+            holes: Vec::new(),
+        }
+    }
+
+    /// Creates a regular polygon with `sides` corners on a circle of
+    /// `radius`, with a corner pointing straight up.
+    ///
+    /// Like an [`Ellipse`](super::Ellipse) of the same radius, it is
+    /// centered on `(radius, radius)`. Fewer than three sides give an empty
+    /// polygon.
+    pub fn regular(sides: usize, radius: f32) -> Self {
+        if sides < 3 {
+            return Self::default();
+        }
+        Self::new(
+            (0..sides)
+                .map(|i| corner(radius, radius, i as f32 / sides as f32))
+                .collect(),
+        )
+    }
+
+    /// Creates a star with `points` tips on a circle of `outer_radius`,
+    /// alternating with inner corners on a circle of `inner_radius`, with a
+    /// tip pointing straight up.
+    ///
+    /// The star is centered on `(outer_radius, outer_radius)`. Fewer than
+    /// two points give an empty polygon.
+    pub fn star(points: usize, inner_radius: f32, outer_radius: f32) -> Self {
+        if points < 2 {
+            return Self::default();
+        }
+        let corners = 2 * points;
+        Self::new(
+            (0..corners)
+                .map(|i| {
+                    let radius = if i % 2 == 0 {
+                        outer_radius
+                    } else {
+                        inner_radius
+                    };
+                    corner(outer_radius, radius, i as f32 / corners as f32)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the polygon with a hole cut out of it.
+    pub fn with_hole(mut self, hole: Vec<(f32, f32)>) -> Self {
+        self.holes.push(hole);
+        self
+    }
+
+    /// Returns the outline and holes as closed sub-paths, with the holes
+    /// wound opposite to the outline.
+    pub fn to_path(&self) -> Path {
+        let mut path = Path::new();
+        let outer = signed_area(&self.points);
+        push_contour(&mut path, &self.points, false);
+        for hole in &self.holes {
+            let reverse = signed_area(hole) * outer > 0.0;
+            push_contour(&mut path, hole, reverse);
+        }
+        path
+    }
+}
+
+impl Hash for Polygon {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash_points = |points: &[(f32, f32)], state: &mut H| {
+            points.len().hash(state);
+            for &(x, y) in points {
+                x.to_bits().hash(state);
+                y.to_bits().hash(state);
+            }
+        };
+        hash_points(&self.points, state);
+        self.holes.len().hash(state);
+        for hole in &self.holes {
+            hash_points(hole, state);
+        }
+    }
+}
+
+/// Returns the point at the fraction `turn` of a clockwise turn from the
+/// top of a circle of `radius` centered on `(center, center)`.
+fn corner(center: f32, radius: f32, turn: f32) -> (f32, f32) {
+    let (sin, cos) = (turn * std::f32::consts::TAU).sin_cos();
+    (center + radius * sin, center - radius * cos)
+}
+
+/// Returns twice the signed area of a closed contour, positive if it winds
+/// clockwise on screen.
+fn signed_area(points: &[(f32, f32)]) -> f32 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let ((x0, y0), (x1, y1)) = (points[i], points[(i + 1) % n]);
+            x0 * y1 - x1 * y0
+        })
+        .sum()
+}
+
+/// Appends `points` to `path` as a closed sub-path, in reverse order if
+/// `reverse` is set. Contours with fewer than three points are skipped.
+fn push_contour(path: &mut Path, points: &[(f32, f32)], reverse: bool) {
+    if points.len() < 3 {
+        return;
+    }
+    let mut ordered: Vec<(f32, f32)> = points.to_vec();
+    if reverse {
+        ordered.reverse();
+    }
+    for (i, &(x, y)) in ordered.iter().enumerate() {
+        path.push(if i == 0 {
+            PathCommand::MoveTo(x, y)
+        } else {
+            PathCommand::LineTo(x, y)
+        });
+    }
+    path.push(PathCommand::Close);
+}
@@ -1,4 +1,9 @@
-#[derive(Clone, Debug)]
+use std::hash::{Hash, Hasher};
+
+use super::path::{Path, PathCommand};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     pub width: f32,
     pub height: f32,
@@ -8,4 +13,22 @@ impl Rect {
     pub fn new(width: f32, height: f32) -> Self {
         Self { width, height }
     }
+
+    /// Returns the outline of the rectangle, clockwise from the origin.
+    pub fn to_path(&self) -> Path {
+        Path::from_iter([
+            PathCommand::MoveTo(0.0, 0.0),
+            PathCommand::LineTo(self.width, 0.0),
+            PathCommand::LineTo(self.width, self.height),
+            PathCommand::LineTo(0.0, self.height),
+            PathCommand::Close,
+        ])
+    }
+}
+
+impl Hash for Rect {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.to_bits().hash(state);
+        self.height.to_bits().hash(state);
+    }
 }
@@ -0,0 +1,75 @@
+use std::hash::{Hash, Hasher};
+
+use super::ellipse::KAPPA;
+use super::path::{Path, PathCommand};
+
+/// A ring between two concentric circles, e.g. for radial progress
+/// indicators and loading spinners.
+///
+/// Like an [`Ellipse`](super::Ellipse), it fills the box from the origin
+/// to its outer diameter, so it is centered on
+/// `(outer_radius, outer_radius)`. The area inside the inner circle is
+/// left empty, whatever the fill rule.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ring {
+    pub outer_radius: f32,
+    pub inner_radius: f32,
+}
+
+impl Ring {
+    pub fn new(outer_radius: f32, inner_radius: f32) -> Self {
+        Self {
+            outer_radius,
+            inner_radius,
+        }
+    }
+
+    /// Returns the width of the band between the circles.
+    pub fn thickness(&self) -> f32 {
+        (self.outer_radius - self.inner_radius).max(0.0)
+    }
+
+    /// Returns the outer circle clockwise and the inner circle
+    /// counterclockwise, each as four cubic Bézier curves.
+    pub fn to_path(&self) -> Path {
+        let c = self.outer_radius;
+        let mut path = Path::new();
+        push_circle(&mut path, c, self.outer_radius, false);
+        if self.inner_radius > 0.0 {
+            push_circle(&mut path, c, self.inner_radius.min(c), true);
+        }
+        path
+    }
+}
+
+impl Hash for Ring {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.outer_radius.to_bits().hash(state);
+        self.inner_radius.to_bits().hash(state);
+    }
+}
+
+/// Appends a closed circle of radius `r` centered on `(c, c)`, starting at
+/// its rightmost point.
+fn push_circle(path: &mut Path, c: f32, r: f32, counterclockwise: bool) {
+    let k = r * KAPPA;
+    // Mirroring the clockwise circle vertically reverses its direction.
+    let at = |x: f32, y: f32| {
+        if counterclockwise {
+            (x, 2.0 * c - y)
+        } else {
+            (x, y)
+        }
+    };
+    path.push(PathCommand::MoveTo(c + r, c));
+    for (ctrl1, ctrl2, to) in [
+        (at(c + r, c + k), at(c + k, c + r), at(c, c + r)),
+        (at(c - k, c + r), at(c - r, c + k), at(c - r, c)),
+        (at(c - r, c - k), at(c - k, c - r), at(c, c - r)),
+        (at(c + k, c - r), at(c + r, c - k), at(c + r, c)),
+    ] {
+        path.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+    }
+    path.push(PathCommand::Close);
+}
@@ -0,0 +1,68 @@
+use super::{Ellipse, Line, Path, Polygon, Polyline, Rect, Ring};
+
+/// Handles each kind of [`Shape`](super::Shape) without matching on it.
+///
+/// `Shape` is non-exhaustive, so crates outside `ardent_core` cannot match
+/// it exhaustively. A visitor only has to implement
+/// [`visit_path`](Self::visit_path); the methods for other shapes default to
+/// visiting their outline, and can be overridden where a shape is cheaper
+/// to handle directly. New shapes get such a default as well, so existing
+/// visitors keep working when shapes are added.
+///
+/// # Example
+/// ```rust,ignore
+/// struct Area;
+///
+/// impl ShapeVisitor for Area {
+///     type Output = f32;
+///
+///     fn visit_path(&mut self, path: &Path) -> f32 {
+///         path.bounds()
+///             .map_or(0.0, |(min, max)| (max.0 - min.0) * (max.1 - min.1))
+///     }
+///
+///     fn visit_rect(&mut self, rect: &Rect) -> f32 {
+///         rect.width * rect.height
+///     }
+/// }
+///
+/// let area = shape.accept(&mut Area);
+/// ```
+pub trait ShapeVisitor {
+    /// The value produced for a visited shape.
+    type Output;
+
+    /// Visits an arbitrary outline, and any shape without a method of its
+    /// own.
+    fn visit_path(&mut self, path: &Path) -> Self::Output;
+
+    /// Visits a rectangle.
+    fn visit_rect(&mut self, rect: &Rect) -> Self::Output {
+        self.visit_path(&rect.to_path())
+    }
+
+    /// Visits an ellipse.
+    fn visit_ellipse(&mut self, ellipse: &Ellipse) -> Self::Output {
+        self.visit_path(&ellipse.to_path())
+    }
+
+    /// Visits a line.
+    fn visit_line(&mut self, line: &Line) -> Self::Output {
+        self.visit_path(&line.to_path())
+    }
+
+    /// Visits a polyline.
+    fn visit_polyline(&mut self, polyline: &Polyline) -> Self::Output {
+        self.visit_path(&polyline.to_path())
+    }
+
+    /// Visits a polygon.
+    fn visit_polygon(&mut self, polygon: &Polygon) -> Self::Output {
+        self.visit_path(&polygon.to_path())
+    }
+
+    /// Visits a ring.
+    fn visit_ring(&mut self, ring: &Ring) -> Self::Output {
+        self.visit_path(&ring.to_path())
+    }
+}
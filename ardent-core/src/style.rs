@@ -1,22 +1,58 @@
 mod color;
+mod effect;
 mod fill;
 mod gradient;
+mod image;
+mod marker;
+mod mesh;
+mod noise;
+mod pattern;
+mod slice;
 mod stroke;
 
 pub use color::Color;
-pub use fill::Fill;
-pub use gradient::Gradient;
-pub use stroke::Stroke;
+pub use effect::PathEffect;
+pub use fill::{Fill, FillRule};
+pub use gradient::{Gradient, GradientStop};
+pub use image::{ImageData, ImageHandle, ImageState};
+pub use marker::{Marker, MarkerKind, Markers};
+pub use mesh::MeshGradient;
+pub use noise::{Noise, NoiseKind};
+pub use pattern::{Pattern, PatternKind};
+pub use slice::NineSlice;
+pub use stroke::{Stroke, StrokeAlign, StrokeCap, StrokeJoin, WidthProfile};
 
 /// Defines the overall appearance of a shape.
 ///
 /// A style combines fill and stroke settings to describe how a shape
 /// is rendered. If either is `None`, that visual aspect is omitted.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Style {
     /// Optional fill for the shape interior.
     pub fill: Option<Fill>,
 
     /// Optional stroke for the shape border.
     pub stroke: Option<Stroke>,
+
+    /// Overall opacity of the shape in the range [0.0, 1.0].
+    ///
+    /// Multiplied with the alpha of the fill and stroke colors.
+    pub opacity: f32,
+
+    /// Changes applied in order to the outline of the shape before it is
+    /// filled, such as rounded corners or a hand-drawn wobble.
+    pub effects: Vec<PathEffect>,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fill: None,
+            stroke: None,
+            opacity: 1.0,
+            effects: Vec::new(),
+        }
+    }
 }
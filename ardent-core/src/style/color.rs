@@ -1,7 +1,8 @@
 /// A color in RGBA format, with each component in the range [0.0, 1.0].
 ///
 /// Used across the system for fills, strokes, and effects.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color(pub f32, pub f32, pub f32, pub f32);
 
 impl Color {
@@ -24,4 +25,128 @@ impl Color {
     pub fn transparent() -> Self {
         Self(0.0, 0.0, 0.0, 0.0)
     }
+
+    /// Parses a CSS-style hex color: `#rgb`, `#rrggbb`, or `#rrggbbaa`.
+    ///
+    /// The leading `#` is optional. Returns `None` if the text is not a
+    /// valid hex color. Usable in constants, which is how the
+    /// [`scene!`](crate::scene!) macro checks color literals as it compiles.
+    pub const fn from_hex(text: &str) -> Option<Self> {
+        let hex = match text.as_bytes() {
+            [b'#', hex @ ..] => hex,
+            hex => hex,
+        };
+        let width = match hex.len() {
+            3 => 1,
+            6 | 8 => 2,
+            _ => return None,
+        };
+        let mut channels = [255u8; 4];
+        let mut i = 0;
+        while i * width < hex.len() {
+            let Some(high) = hex_digit(hex[i * width]) else {
+                return None;
+            };
+            channels[i] = if width == 1 {
+                high * 17
+            } else {
+                let Some(low) = hex_digit(hex[i * width + 1]) else {
+                    return None;
+                };
+                high * 16 + low
+            };
+            i += 1;
+        }
+        let [r, g, b, a] = channels;
+        Some(Self(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        ))
+    }
+
+    /// Interpolates between two colors in the perceptually uniform OKLab space.
+    ///
+    /// Unlike blending the sRGB components directly, midpoints keep their
+    /// brightness and saturation (e.g., blue to yellow does not pass through
+    /// a muddy gray). Alpha is interpolated linearly.
+    pub fn lerp_perceptual(self, other: Color, t: f32) -> Color {
+        let a = self.to_oklab();
+        let b = other.to_oklab();
+        let mix = |x: f32, y: f32| x + (y - x) * t;
+        let [r, g, bl] = oklab_to_srgb([mix(a[0], b[0]), mix(a[1], b[1]), mix(a[2], b[2])]);
+        Color(r, g, bl, mix(self.3, other.3))
+    }
+
+    /// Returns the color with its components decoded from sRGB to linear
+    /// light, e.g. for blending or for extended-range render targets.
+    /// Alpha is unchanged.
+    pub fn to_linear(self) -> Color {
+        Color(
+            srgb_to_linear(self.0),
+            srgb_to_linear(self.1),
+            srgb_to_linear(self.2),
+            self.3,
+        )
+    }
+
+    /// Converts the color to OKLab (lightness, green-red, blue-yellow).
+    fn to_oklab(self) -> [f32; 3] {
+        let (r, g, b) = (
+            srgb_to_linear(self.0),
+            srgb_to_linear(self.1),
+            srgb_to_linear(self.2),
+        );
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        [
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        ]
+    }
+}
+
+/// Returns the value of an ASCII hex digit.
+const fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Converts OKLab back to gamma-encoded sRGB components, clamped to [0.0, 1.0].
+fn oklab_to_srgb([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+    let (l3, m3, s3) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+    [
+        4.076_741_7 * l3 - 3.307_711_6 * m3 + 0.230_969_94 * s3,
+        -1.268_438 * l3 + 2.609_757_4 * m3 - 0.341_319_38 * s3,
+        -0.004_196_086_3 * l3 - 0.703_418_6 * m3 + 1.707_614_7 * s3,
+    ]
+    .map(|c| linear_to_srgb(c).clamp(0.0, 1.0))
+}
+
+/// Decodes a gamma-encoded sRGB component to linear light.
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a linear-light component with the sRGB transfer curve.
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.max(0.0).powf(1.0 / 2.4) - 0.055
+    }
 }
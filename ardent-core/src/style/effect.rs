@@ -0,0 +1,68 @@
+use std::hash::{Hash, Hasher};
+
+/// A change applied to a shape's outline before it is tessellated.
+///
+/// Effects listed in [`Style::effects`](super::Style::effects) are applied
+/// in order, each to the outline the previous one produced, so for example
+/// jitter followed by corner rounding gives a soft, hand-drawn blob. They
+/// work on the outline flattened into straight segments, in the local
+/// space of the node.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathEffect {
+    /// Replaces every corner with a curve of the given radius in local
+    /// units, limited to half the length of the shorter adjoining segment.
+    RoundCorners(f32),
+
+    /// Moves the outline outwards by the given distance in local units, or
+    /// inwards if it is negative. Holes, sub-paths inside an odd number of
+    /// others, move the opposite way, so they shrink as the shape grows;
+    /// sharp corners are mitered up to four times the distance.
+    Offset(f32),
+
+    /// Displaces points along the outline by random amounts, like a
+    /// sketchy, hand-drawn line.
+    Jitter {
+        /// Largest displacement in local units.
+        amount: f32,
+
+        /// Distance between displaced points along the outline, in local
+        /// units.
+        spacing: f32,
+
+        /// Selects one of many unrelated wobbles; the same seed always
+        /// gives the same outline.
+        seed: u32,
+    },
+}
+
+impl PathEffect {
+    /// Creates a hand-drawn wobble of up to `amount` local units every
+    /// `spacing` units.
+    pub fn jitter(amount: f32, spacing: f32, seed: u32) -> Self {
+        PathEffect::Jitter {
+            amount,
+            spacing,
+            seed,
+        }
+    }
+}
+
+impl Hash for PathEffect {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match *self {
+            PathEffect::RoundCorners(radius) => radius.to_bits().hash(state),
+            PathEffect::Offset(distance) => distance.to_bits().hash(state),
+            PathEffect::Jitter {
+                amount,
+                spacing,
+                seed,
+            } => {
+                amount.to_bits().hash(state);
+                spacing.to_bits().hash(state);
+                seed.hash(state);
+            }
+        }
+    }
+}
@@ -1,14 +1,85 @@
-use super::{Color, Gradient};
+use super::{Color, Gradient, ImageHandle, MeshGradient, NineSlice, Noise, Pattern};
 
 /// Describes how a shape is filled.
 ///
 /// A fill defines the interior appearance of a shape — typically using a solid
-/// color, but later extensible to include gradients or image patterns.
-#[derive(Clone, Debug)]
+/// color, or a linear or radial [`gradient`](Self::gradient) in its place.
+///
+/// An image fill stretches the image over the shape's bounding box, or
+/// scales it in nine slices if [`slice`](Self::slice) is set. The fill
+/// color is shown in its place while the image is loading or if it failed
+/// to load. A [`mesh`](Self::mesh) gradient fills its own patches in
+/// place of the shape. [`noise`](Self::noise) replaces the fill color or gradient with
+/// procedural noise. A [`pattern`](Self::pattern) draws hatching, checkers, or
+/// dots over the result.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fill {
     /// The fill color of the shape.
     pub color: Color,
 
-    /// Placeholder for future gradient support.
-    pub gradient: Option<Gradient>, // Currently unused
+    /// Gradient drawn instead of the fill color.
+    ///
+    /// The renderer uses up to eight stops; a gradient without stops leaves
+    /// the fill color.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gradient: Option<Gradient>,
+
+    /// Rule deciding which regions of a self-intersecting or nested outline
+    /// count as inside the shape.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rule: FillRule,
+
+    /// Image drawn over the shape instead of the fill color, once loaded.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub image: Option<ImageHandle>,
+
+    /// Scales the image in nine slices instead of stretching it as a whole.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slice: Option<NineSlice>,
+
+    /// Colors blended across a grid of patches, filling the patches
+    /// instead of the shape.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mesh: Option<MeshGradient>,
+
+    /// Procedural noise drawn instead of the fill color.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub noise: Option<Noise>,
+
+    /// Repeating marks drawn over the fill color or image.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pattern: Option<Pattern>,
+}
+
+impl Fill {
+    /// Creates a solid fill with the given color and the default fill rule.
+    pub fn solid(color: Color) -> Self {
+        Self {
+            color,
+            gradient: None,
+            rule: FillRule::NonZero,
+            image: None,
+            slice: None,
+            mesh: None,
+            noise: None,
+            pattern: None,
+        }
+    }
+}
+
+/// Determines the interior of a shape whose outline overlaps itself.
+///
+/// Mirrors the SVG `fill-rule` property so imported artwork renders as authored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FillRule {
+    /// A point is inside if the outline winds around it a non-zero number of
+    /// times. Holes must be wound opposite to their outer contour.
+    #[default]
+    NonZero,
+
+    /// A point is inside if a ray from it crosses the outline an odd number
+    /// of times. Any nested contour becomes a hole regardless of direction.
+    EvenOdd,
 }
@@ -1,6 +1,56 @@
+use super::Color;
+
+/// A color at a position along a gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GradientStop {
+    /// Position of the stop along the gradient, in the range [0.0, 1.0].
+    pub offset: f32,
+
+    /// Color at this position.
+    pub color: Color,
+}
+
+impl GradientStop {
+    pub fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
 /// Gradient kinds.
-#[derive(Clone, Debug)]
+///
+/// Coordinates are in the local space of the shape being filled.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Gradient {
-    Linear,
-    Radial,
+    /// Colors vary along the line from `start` to `end`.
+    Linear {
+        start: (f32, f32),
+        end: (f32, f32),
+        stops: Vec<GradientStop>,
+    },
+
+    /// Colors vary with the distance from `center`, reaching the last stop
+    /// at `radius`.
+    Radial {
+        center: (f32, f32),
+        radius: f32,
+        stops: Vec<GradientStop>,
+    },
+}
+
+impl Gradient {
+    /// Returns the color stops, ordered by offset.
+    pub fn stops(&self) -> &[GradientStop] {
+        match self {
+            Gradient::Linear { stops, .. } | Gradient::Radial { stops, .. } => stops,
+        }
+    }
+
+    /// Returns the color stops for modification.
+    pub fn stops_mut(&mut self) -> &mut Vec<GradientStop> {
+        match self {
+            Gradient::Linear { stops, .. } | Gradient::Radial { stops, .. } => stops,
+        }
+    }
 }
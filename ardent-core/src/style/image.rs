@@ -0,0 +1,169 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Decoded pixels of an image.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ImageData {
+    /// Width in pixels.
+    pub width: u32,
+
+    /// Height in pixels.
+    pub height: u32,
+
+    /// sRGB pixels with straight (non-premultiplied) alpha, as tightly
+    /// packed 8-bit RGBA rows from top to bottom.
+    pub pixels: Vec<u8>,
+}
+
+impl fmt::Debug for ImageData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageData")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The loading state of an image.
+#[derive(Clone, Debug)]
+pub enum ImageState {
+    /// The image is still being loaded or decoded.
+    Loading,
+
+    /// The image is available.
+    Ready(Arc<ImageData>),
+
+    /// The image could not be loaded.
+    Failed(String),
+
+    /// The pixels live in a GPU texture owned by the host application, e.g.
+    /// video frames or another renderer's output, and are supplied to the
+    /// renderer directly.
+    External,
+}
+
+/// Counter used to generate image IDs.
+static NEXT_IMAGE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A shared reference to an image that may still be loading.
+///
+/// Handles are cheap to clone and can be used in styles right away. Until
+/// the image is ready, shapes filled with it show their fill color as a
+/// placeholder. Loaders update the handle from any thread once decoding
+/// finishes; the renderer picks up the new pixels on the next frame.
+#[derive(Clone)]
+pub struct ImageHandle(Arc<ImageSlot>);
+
+struct ImageSlot {
+    id: u64,
+    state: Mutex<ImageState>,
+
+    /// Incremented whenever the state changes.
+    version: AtomicU64,
+}
+
+impl ImageHandle {
+    /// Creates a handle for an image that is still loading.
+    pub fn pending() -> Self {
+        Self(Arc::new(ImageSlot {
+            id: NEXT_IMAGE_ID.fetch_add(1, Ordering::Relaxed),
+            state: Mutex::new(ImageState::Loading),
+            version: AtomicU64::new(0),
+        }))
+    }
+
+    /// Creates a handle for already decoded pixels.
+    pub fn ready(data: ImageData) -> Self {
+        let handle = Self::pending();
+        handle.set_ready(data);
+        handle
+    }
+
+    /// Creates a handle for content supplied as a GPU texture by the host
+    /// application (e.g. `Renderer::set_external_texture` in
+    /// `ardent_render`). The fill color is shown until a texture is set.
+    pub fn external() -> Self {
+        let handle = Self::pending();
+        handle.set_state(ImageState::External);
+        handle
+    }
+
+    /// Returns an ID identifying this image across clones of the handle.
+    pub fn id(&self) -> u64 {
+        self.0.id
+    }
+
+    /// Returns a number that changes whenever the image state changes.
+    pub fn version(&self) -> u64 {
+        self.0.version.load(Ordering::Acquire)
+    }
+
+    /// Returns the current state of the image.
+    pub fn state(&self) -> ImageState {
+        self.lock().clone()
+    }
+
+    /// Returns the pixels if the image is ready.
+    pub fn data(&self) -> Option<Arc<ImageData>> {
+        match &*self.lock() {
+            ImageState::Ready(data) => Some(data.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the image is still loading.
+    pub fn is_loading(&self) -> bool {
+        matches!(*self.lock(), ImageState::Loading)
+    }
+
+    /// Returns `true` if the image is supplied as an external GPU texture.
+    pub fn is_external(&self) -> bool {
+        matches!(*self.lock(), ImageState::External)
+    }
+
+    /// Provides the decoded pixels.
+    pub fn set_ready(&self, data: ImageData) {
+        self.set_state(ImageState::Ready(Arc::new(data)));
+    }
+
+    /// Marks the image as failed, keeping the placeholder.
+    pub fn set_failed(&self, reason: impl Into<String>) {
+        self.set_state(ImageState::Failed(reason.into()));
+    }
+
+    fn set_state(&self, state: ImageState) {
+        *self.lock() = state;
+        self.0.version.fetch_add(1, Ordering::AcqRel);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ImageState> {
+        // The state is replaced as a whole, so a poisoned lock still holds
+        // a consistent value.
+        self.0
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl fmt::Debug for ImageHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ImageHandle").field(&self.id()).finish()
+    }
+}
+
+impl PartialEq for ImageHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for ImageHandle {}
+
+impl Hash for ImageHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
@@ -0,0 +1,104 @@
+use std::hash::{Hash, Hasher};
+
+use crate::shape::Path;
+
+/// The shape of a [`Marker`].
+#[derive(Clone, Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarkerKind {
+    /// A filled triangle whose tip lies on the path, pointing along it.
+    #[default]
+    Arrow,
+
+    /// A filled circle centered on the path.
+    Dot,
+
+    /// A custom outline, filled. Coordinates are multiples of the marker
+    /// size, with the origin on the path and the x-axis pointing along it.
+    Custom(Path),
+}
+
+/// A shape drawn at a vertex of a stroked path, such as an arrowhead.
+///
+/// Markers are filled with the stroke color, oriented along the path, and
+/// scale with the stroke width, so arrowheads keep their proportions on
+/// thick and thin connectors alike.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Marker {
+    /// The shape of the marker.
+    pub kind: MarkerKind,
+
+    /// Size of the marker as a multiple of the stroke width: the length of
+    /// an arrow, the diameter of a dot, or the unit of a custom outline.
+    pub size: f32,
+}
+
+impl Marker {
+    /// Creates an arrowhead `size` stroke widths long.
+    pub fn arrow(size: f32) -> Self {
+        Self {
+            kind: MarkerKind::Arrow,
+            size,
+        }
+    }
+
+    /// Creates a dot `size` stroke widths wide.
+    pub fn dot(size: f32) -> Self {
+        Self {
+            kind: MarkerKind::Dot,
+            size,
+        }
+    }
+
+    /// Creates a marker from a custom outline, scaled by `size` stroke
+    /// widths.
+    pub fn custom(path: Path, size: f32) -> Self {
+        Self {
+            kind: MarkerKind::Custom(path),
+            size,
+        }
+    }
+}
+
+/// The markers drawn on a stroked path.
+///
+/// The start and end markers are drawn at the first and last vertex of
+/// the whole path, and the mid marker at every vertex in between, where
+/// it bisects the angle between the neighboring segments. The start
+/// marker is turned around to point away from the path, so arrows at both
+/// ends point outwards.
+///
+/// ```rust,ignore
+/// let connector = Stroke::new(Color::black(), 2.0).with_markers(Markers {
+///     end: Some(Marker::arrow(4.0)),
+///     ..Markers::default()
+/// });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Markers {
+    /// Marker at the first vertex.
+    pub start: Option<Marker>,
+
+    /// Marker at every vertex between the first and last.
+    pub mid: Option<Marker>,
+
+    /// Marker at the last vertex.
+    pub end: Option<Marker>,
+}
+
+impl Markers {
+    /// Returns `true` if no marker is set.
+    pub fn is_empty(&self) -> bool {
+        self.start.is_none() && self.mid.is_none() && self.end.is_none()
+    }
+}
+
+impl Hash for Marker {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
+        self.size.to_bits().hash(state);
+    }
+}
@@ -0,0 +1,128 @@
+use std::hash::{Hash, Hasher};
+
+use super::Color;
+
+/// A gradient blending colors across a grid of Coons patches, like the
+/// mesh and freeform gradients of design tools.
+///
+/// The grid has [`columns`](Self::columns) by [`rows`](Self::rows)
+/// patches. Each patch is bounded by four cubic Bézier curves between its
+/// corners and blends the colors of its corners across its area, so
+/// bending the curves warps the colors with them. Neighboring patches
+/// share their edges and corner colors, so the blend is seamless.
+///
+/// Control points are stored row-major in a grid of `3 * columns + 1` by
+/// `3 * rows + 1` points. Points at multiples of three in both directions
+/// are patch corners, and the two points between neighboring corners are
+/// the handles of the curve joining them. Points inside a patch are not
+/// used. Colors are stored row-major, one per corner.
+///
+/// The filled area is the area of the patches, in the local space of the
+/// node; the node's shape is not drawn. Edges are not feathered, so enable
+/// MSAA for smooth outlines.
+///
+/// ```rust,ignore
+/// let mut mesh = MeshGradient::grid(1, 1, (300.0, 200.0));
+/// mesh.set_color(0, 0, Color::rgb(1.0, 0.4, 0.3));
+/// mesh.set_color(1, 1, Color::rgb(0.2, 0.4, 1.0));
+/// mesh.set_point(0, 1, (120.0, -40.0)); // bulge the top edge
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeshGradient {
+    /// Number of patches per row.
+    pub columns: usize,
+
+    /// Number of rows of patches.
+    pub rows: usize,
+
+    /// Control points in local units, as described above.
+    pub points: Vec<(f32, f32)>,
+
+    /// Colors at the patch corners.
+    pub colors: Vec<Color>,
+}
+
+impl MeshGradient {
+    /// Creates a grid of straight-edged patches evenly covering a box of
+    /// `size` at the origin, with white corners.
+    pub fn grid(columns: usize, rows: usize, (width, height): (f32, f32)) -> Self {
+        let (point_columns, point_rows) = (3 * columns + 1, 3 * rows + 1);
+        let points = (0..point_rows)
+            .flat_map(|row| {
+                (0..point_columns).map(move |column| {
+                    (
+                        width * column as f32 / (point_columns - 1).max(1) as f32,
+                        height * row as f32 / (point_rows - 1).max(1) as f32,
+                    )
+                })
+            })
+            .collect();
+        Self {
+            columns,
+            rows,
+            points,
+            colors: vec![Color::white(); (columns + 1) * (rows + 1)],
+        }
+    }
+
+    /// Returns `true` if the grid has at least one patch and as many
+    /// points and colors as it needs.
+    pub fn is_valid(&self) -> bool {
+        self.columns > 0
+            && self.rows > 0
+            && self.points.len() == (3 * self.columns + 1) * (3 * self.rows + 1)
+            && self.colors.len() == (self.columns + 1) * (self.rows + 1)
+    }
+
+    /// Returns the control point in the given row and column of the point
+    /// grid.
+    pub fn point(&self, row: usize, column: usize) -> Option<(f32, f32)> {
+        self.points
+            .get(row * (3 * self.columns + 1) + column)
+            .copied()
+    }
+
+    /// Moves the control point in the given row and column of the point
+    /// grid. Does nothing if it is outside the grid.
+    pub fn set_point(&mut self, row: usize, column: usize, position: (f32, f32)) {
+        if column <= 3 * self.columns
+            && let Some(point) = self.points.get_mut(row * (3 * self.columns + 1) + column)
+        {
+            *point = position;
+        }
+    }
+
+    /// Returns the color of the patch corner in the given row and column.
+    pub fn color(&self, row: usize, column: usize) -> Option<Color> {
+        self.colors.get(row * (self.columns + 1) + column).copied()
+    }
+
+    /// Changes the color of the patch corner in the given row and column.
+    /// Does nothing if it is outside the grid.
+    pub fn set_color(&mut self, row: usize, column: usize, color: Color) {
+        if column <= self.columns
+            && let Some(corner) = self.colors.get_mut(row * (self.columns + 1) + column)
+        {
+            *corner = color;
+        }
+    }
+}
+
+impl Hash for MeshGradient {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.columns.hash(state);
+        self.rows.hash(state);
+        self.points.len().hash(state);
+        for &(x, y) in &self.points {
+            x.to_bits().hash(state);
+            y.to_bits().hash(state);
+        }
+        self.colors.len().hash(state);
+        for &Color(r, g, b, a) in &self.colors {
+            for channel in [r, g, b, a] {
+                channel.to_bits().hash(state);
+            }
+        }
+    }
+}
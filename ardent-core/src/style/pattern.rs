@@ -0,0 +1,92 @@
+use super::Color;
+
+/// The repeating marks of a [`Pattern`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PatternKind {
+    /// Parallel lines, e.g. for hatching.
+    #[default]
+    Stripes,
+
+    /// A checkerboard of alternating squares.
+    Checkers,
+
+    /// Round dots on a square grid.
+    Dots,
+}
+
+/// Repeating marks drawn over a fill, such as hatching, checkers, or dots.
+///
+/// Patterns are computed per pixel by the renderer instead of from a
+/// texture, so they stay sharp at any scale and cost nothing to change.
+/// Marks are drawn in [`color`](Self::color) over the fill color or image,
+/// in the local space of the shape, so they move and scale with the node.
+///
+/// ```rust,ignore
+/// let hatched = Fill {
+///     pattern: Some(Pattern::stripes(Color::black(), 8.0, 2.0).rotated(FRAC_PI_4)),
+///     ..Fill::solid(Color::white())
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern {
+    /// The kind of marks repeated.
+    pub kind: PatternKind,
+
+    /// Color of the marks.
+    pub color: Color,
+
+    /// Distance between neighboring stripes or dots, or the side of a
+    /// checker square, in local units.
+    pub spacing: f32,
+
+    /// Thickness of stripes or diameter of dots, in local units. Unused by
+    /// checkers.
+    pub width: f32,
+
+    /// Clockwise rotation of the pattern in radians. Unrotated stripes are
+    /// horizontal.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub angle: f32,
+}
+
+impl Pattern {
+    /// Creates stripes `width` thick, `spacing` apart.
+    pub fn stripes(color: Color, spacing: f32, width: f32) -> Self {
+        Self {
+            kind: PatternKind::Stripes,
+            color,
+            spacing,
+            width,
+            angle: 0.0,
+        }
+    }
+
+    /// Creates a checkerboard of squares `size` wide.
+    pub fn checkers(color: Color, size: f32) -> Self {
+        Self {
+            kind: PatternKind::Checkers,
+            color,
+            spacing: size,
+            width: 0.0,
+            angle: 0.0,
+        }
+    }
+
+    /// Creates dots `diameter` wide, `spacing` apart.
+    pub fn dots(color: Color, spacing: f32, diameter: f32) -> Self {
+        Self {
+            kind: PatternKind::Dots,
+            color,
+            spacing,
+            width: diameter,
+            angle: 0.0,
+        }
+    }
+
+    /// Returns the pattern rotated clockwise by `angle` radians.
+    pub fn rotated(self, angle: f32) -> Self {
+        Self { angle, ..self }
+    }
+}
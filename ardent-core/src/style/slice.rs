@@ -0,0 +1,39 @@
+/// Insets dividing an image into a 3×3 grid for nine-slice scaling.
+///
+/// The corners keep their size, the edges stretch along one axis, and the
+/// center stretches in both, so textured panels and speech bubbles scale to
+/// any size without distorting their borders. Insets are in image pixels,
+/// and corners are drawn at one local unit per pixel. Shapes smaller than
+/// the opposing insets shrink the corners proportionally.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NineSlice {
+    /// Width of the left column.
+    pub left: f32,
+
+    /// Height of the top row.
+    pub top: f32,
+
+    /// Width of the right column.
+    pub right: f32,
+
+    /// Height of the bottom row.
+    pub bottom: f32,
+}
+
+impl NineSlice {
+    /// Creates slices with the given insets.
+    pub fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Creates slices with the same inset on every side.
+    pub fn uniform(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+}
@@ -1,9 +1,12 @@
-use super::Color;
+use std::hash::{Hash, Hasher};
+
+use super::{Color, Markers};
 
 /// Describes how a shape is outlined or stroked.
 ///
 /// The stroke defines the contour or border drawn around a shape.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stroke {
     /// The stroke color.
     pub color: Color,
@@ -11,14 +14,269 @@ pub struct Stroke {
     /// Width of the stroke in logical pixels.
     pub width: f32,
 
-    /// Placeholder for stroke alignment (inside/outside/center).
+    /// Where the stroke lies relative to a closed outline: centered on it,
+    /// or inside or outside of it.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub align: StrokeAlign,
+
+    /// How the ends of open outlines and of dashes are drawn.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cap: StrokeCap,
+
+    /// How corners between segments are drawn.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub join: StrokeJoin,
+
+    /// Longest a mitered corner may reach, as a multiple of the width,
+    /// before it is beveled instead.
+    #[cfg_attr(feature = "serde", serde(default = "default_miter_limit"))]
+    pub miter_limit: f32,
+
+    /// Lengths of alternating dashes and gaps along the outline, starting
+    /// with a dash, or empty for a solid stroke.
+    ///
+    /// As in SVG, a list of odd length is repeated to make it even, and
+    /// the pattern restarts on every sub-path. Zero-length dashes with
+    /// round or square caps draw dots. Patterns with negative lengths or
+    /// no length at all are drawn solid.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dashes: Vec<f32>,
+
+    /// Distance into the dash pattern at which the outline starts.
+    /// Animating it makes the dashes march along the outline, e.g. for
+    /// selection marquees.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dash_offset: f32,
+
+    /// Fraction of the outline length at which the stroke starts, in [0.0, 1.0].
+    ///
+    /// Together with `trim_end`, this draws only part of the outline.
+    /// Animating `trim_end` from 0.0 to 1.0 produces a "draw-on" effect.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub trim_start: f32,
+
+    /// Fraction of the outline length at which the stroke ends, in [0.0, 1.0].
+    #[cfg_attr(feature = "serde", serde(default = "untrimmed_end"))]
+    pub trim_end: f32,
+
+    /// How the width varies along the outline, or `None` for a constant
+    /// width.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub profile: Option<WidthProfile>,
+
+    /// Arrowheads or other shapes drawn at the vertices of the outline.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub markers: Markers,
+}
+
+impl Stroke {
+    /// The miter limit of strokes that do not set one, as in SVG.
+    pub const DEFAULT_MITER_LIMIT: f32 = 4.0;
+
+    /// Creates a centered, untrimmed stroke.
+    pub fn new(color: Color, width: f32) -> Self {
+        Self {
+            color,
+            width,
+            align: StrokeAlign::Center,
+            cap: StrokeCap::Butt,
+            join: StrokeJoin::Miter,
+            miter_limit: Self::DEFAULT_MITER_LIMIT,
+            dashes: Vec::new(),
+            dash_offset: 0.0,
+            trim_start: 0.0,
+            trim_end: 1.0,
+            profile: None,
+            markers: Markers::default(),
+        }
+    }
+
+    /// Returns the stroke with its width varying along the outline.
+    pub fn with_profile(self, profile: WidthProfile) -> Self {
+        Self {
+            profile: Some(profile),
+            ..self
+        }
+    }
+
+    /// Returns the stroke with its ends drawn in the given style.
+    pub fn with_cap(self, cap: StrokeCap) -> Self {
+        Self { cap, ..self }
+    }
+
+    /// Returns the stroke with its corners drawn in the given style.
+    pub fn with_join(self, join: StrokeJoin) -> Self {
+        Self { join, ..self }
+    }
+
+    /// Returns the stroke with mitered corners beveled once they reach
+    /// further than `limit` times the width.
+    pub fn with_miter_limit(self, limit: f32) -> Self {
+        Self {
+            miter_limit: limit,
+            ..self
+        }
+    }
+
+    /// Returns the stroke drawn in dashes and gaps of the given lengths,
+    /// starting `offset` into the pattern.
+    pub fn with_dashes(self, dashes: impl Into<Vec<f32>>, offset: f32) -> Self {
+        Self {
+            dashes: dashes.into(),
+            dash_offset: offset,
+            ..self
+        }
+    }
+
+    /// Returns the stroke with markers drawn at the vertices of the
+    /// outline.
+    pub fn with_markers(self, markers: Markers) -> Self {
+        Self { markers, ..self }
+    }
+
+    /// Returns the width at the fraction `t` of the outline length.
+    pub fn width_at(&self, t: f32) -> f32 {
+        self.profile
+            .as_ref()
+            .map_or(self.width, |profile| self.width * profile.factor_at(t))
+    }
+
+    /// Returns `true` if the stroke covers only part of the outline.
+    pub fn is_trimmed(&self) -> bool {
+        self.trim_start > 0.0 || self.trim_end < 1.0
+    }
+
+    /// Returns `true` if the stroke is drawn in dashes, i.e. its pattern is
+    /// not empty, has no negative lengths, and has some length.
+    pub fn is_dashed(&self) -> bool {
+        self.dashes.iter().all(|&length| length >= 0.0) && self.dashes.iter().sum::<f32>() > 0.0
+    }
+}
+
+/// The end of an untrimmed stroke, used when a deserialized stroke omits
+/// `trim_end`.
+#[cfg(feature = "serde")]
+fn untrimmed_end() -> f32 {
+    1.0
+}
+
+/// The miter limit used when a deserialized stroke omits `miter_limit`.
+#[cfg(feature = "serde")]
+fn default_miter_limit() -> f32 {
+    Stroke::DEFAULT_MITER_LIMIT
+}
+
+/// How the width of a stroke varies along its outline, e.g. for
+/// calligraphic strokes and ink.
+///
+/// Profiles scale [`Stroke::width`] by a factor depending on the fraction
+/// of the outline length, measured over all sub-paths together, so
+/// animating the width keeps the profile's shape.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WidthProfile {
+    /// Grows from nothing over the first `start` fraction of the length and
+    /// shrinks back to nothing over the last `end` fraction, like a brush
+    /// stroke.
+    Taper { start: f32, end: f32 },
+
+    /// Width factors at fractions of the length as `(offset, factor)`
+    /// pairs ordered by offset, e.g. pen pressure sampled along a
+    /// signature. Factors are interpolated linearly between the stops and
+    /// held beyond the first and last.
+    Stops(Vec<(f32, f32)>),
+}
+
+impl WidthProfile {
+    /// Returns the width factor at the fraction `t` of the outline length.
+    pub fn factor_at(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            WidthProfile::Taper { start, end } => {
+                let grow = if *start > 0.0 { t / start } else { 1.0 };
+                let shrink = if *end > 0.0 { (1.0 - t) / end } else { 1.0 };
+                grow.min(shrink).clamp(0.0, 1.0)
+            }
+            WidthProfile::Stops(stops) => {
+                let Some(&(first, first_factor)) = stops.first() else {
+                    return 1.0;
+                };
+                if t <= first {
+                    return first_factor;
+                }
+                for pair in stops.windows(2) {
+                    let ((a, fa), (b, fb)) = (pair[0], pair[1]);
+                    if t <= b {
+                        let span = b - a;
+                        return if span > 0.0 {
+                            fa + (fb - fa) * (t - a) / span
+                        } else {
+                            fb
+                        };
+                    }
+                }
+                stops.last().map_or(1.0, |&(_, factor)| factor)
+            }
+        }
+    }
+}
+
+impl Hash for WidthProfile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            WidthProfile::Taper { start, end } => {
+                start.to_bits().hash(state);
+                end.to_bits().hash(state);
+            }
+            WidthProfile::Stops(stops) => {
+                stops.len().hash(state);
+                for &(offset, factor) in stops {
+                    offset.to_bits().hash(state);
+                    factor.to_bits().hash(state);
+                }
+            }
+        }
+    }
 }
 
 /// Stroke alignment relative to the shape boundary.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StrokeAlign {
+    #[default]
     Center,
     Inside,
     Outside,
 }
+
+/// How the ends of an open stroke are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StrokeCap {
+    /// Ends flat at the end of the outline.
+    #[default]
+    Butt,
+
+    /// Ends in a half circle beyond the end of the outline.
+    Round,
+
+    /// Ends flat half the width beyond the end of the outline.
+    Square,
+}
+
+/// How the corners of a stroke are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StrokeJoin {
+    /// Extends the edges until they meet in a sharp point, up to the
+    /// stroke's miter limit.
+    #[default]
+    Miter,
+
+    /// Rounds the corner with an arc.
+    Round,
+
+    /// Cuts the corner off straight.
+    Bevel,
+}
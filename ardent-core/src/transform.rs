@@ -3,7 +3,9 @@
 /// Transforms allow nodes to be moved, resized, or rotated relative to
 /// their parent. These transformations are combined hierarchically in
 /// the scene graph, enabling complex compositions from simple building blocks.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Transform {
     /// The (x, y) offset from the parent's origin.
     pub translate: (f32, f32),
@@ -24,3 +26,31 @@ impl Default for Transform {
         }
     }
 }
+
+impl Transform {
+    /// Maps a point from the node's local space into its parent's space.
+    pub fn to_parent(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (x, y) = (x * self.scale.0, y * self.scale.1);
+        let (sin, cos) = self.rotate.sin_cos();
+        (
+            x * cos - y * sin + self.translate.0,
+            x * sin + y * cos + self.translate.1,
+        )
+    }
+
+    /// Maps a point from the parent's space into the node's local space.
+    ///
+    /// Returns `None` if the transform scales an axis to zero and cannot
+    /// be inverted.
+    pub fn to_local(&self, (x, y): (f32, f32)) -> Option<(f32, f32)> {
+        if self.scale.0 == 0.0 || self.scale.1 == 0.0 {
+            return None;
+        }
+        let (x, y) = (x - self.translate.0, y - self.translate.1);
+        let (sin, cos) = self.rotate.sin_cos();
+        Some((
+            (x * cos + y * sin) / self.scale.0,
+            (y * cos - x * sin) / self.scale.1,
+        ))
+    }
+}
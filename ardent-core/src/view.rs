@@ -0,0 +1,326 @@
+//! Declarative views reconciled against a retained scene.
+//!
+//! Instead of creating nodes once and mutating them as application state
+//! changes, application code can describe the whole UI as a [`View`] tree,
+//! built from scratch from the current state, and hand it to a
+//! [`ViewRoot`]. The root compares the description with the previous one
+//! and applies only the differences to the scene: unchanged nodes are left
+//! alone, so they keep their tessellated geometry and stay clean, much like
+//! a virtual DOM in React or Elm.
+//!
+//! Children are matched to the previous description by [`key`](View::key)
+//! or, without one, by position. Keys let items of a list keep their nodes
+//! when the list is reordered, filtered, or grows at the front.
+//!
+//! ```rust,ignore
+//! fn view(items: &[Item]) -> View {
+//!     View::group().display(Display::Grid(GridLayout::new().gap(4.0))).children(
+//!         items.iter().map(|item| {
+//!             View::rect()
+//!                 .key(item.id)
+//!                 .size(200.0, 24.0)
+//!                 .fill(if item.done { "#66aa66" } else { "#cccccc" })
+//!                 .label(item.title.clone())
+//!         }),
+//!     )
+//! }
+//!
+//! let mut root = ViewRoot::new(scene.root());
+//! scene.on_frame(move |scene, _| {
+//!     let items = model.items.get();
+//!     root.render(scene, view(&items)).expect("the parent node exists");
+//! });
+//! ```
+
+mod reconcile;
+
+use std::sync::Arc;
+
+use crate::event::Event;
+use crate::layout::{Dimension, Display, LayoutStyle};
+use crate::node::Role;
+use crate::scene::{IntoFill, SceneError};
+use crate::shape::{Ellipse, Line, Polygon, Polyline, Rect, Ring, Shape};
+use crate::style::{Stroke, Style};
+use crate::transform::Transform;
+
+pub use reconcile::ViewRoot;
+
+/// Identifies a view among its siblings across renders.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A numeric key, e.g. a database ID.
+    Id(u64),
+
+    /// A textual key, e.g. a file name.
+    Name(String),
+}
+
+impl From<u64> for Key {
+    fn from(id: u64) -> Self {
+        Key::Id(id)
+    }
+}
+
+impl From<u32> for Key {
+    fn from(id: u32) -> Self {
+        Key::Id(id.into())
+    }
+}
+
+impl From<usize> for Key {
+    fn from(id: usize) -> Self {
+        Key::Id(id as u64)
+    }
+}
+
+impl From<&str> for Key {
+    fn from(name: &str) -> Self {
+        Key::Name(name.to_owned())
+    }
+}
+
+impl From<String> for Key {
+    fn from(name: String) -> Self {
+        Key::Name(name)
+    }
+}
+
+/// An event handler shared between a view and the node it was applied to.
+type Handler = Arc<dyn Fn(Event) + Send + Sync>;
+
+/// The properties of a view compared between renders.
+#[derive(Clone, Default, PartialEq)]
+struct Props {
+    shape: Option<Shape>,
+    style: Style,
+    transform: Transform,
+    layout: LayoutStyle,
+    role: Role,
+    label: Option<String>,
+    value: Option<String>,
+    tooltip: Option<String>,
+}
+
+/// An immutable description of a node and its subtree.
+///
+/// Views are cheap to build and meant to be thrown away: describe the UI
+/// from the current state every time it changes and let a [`ViewRoot`]
+/// bring the scene up to date. Methods mirror those of
+/// [`NodeBuilder`](crate::scene::NodeBuilder).
+///
+/// Properties are applied when they differ from the previous description,
+/// not from the node, so the layout pass may still position nodes: a
+/// view's transform only overrides the node's when the view's changes.
+#[derive(Clone, Default)]
+pub struct View {
+    key: Option<Key>,
+    props: Props,
+    on_event: Option<Handler>,
+    children: Vec<View>,
+
+    /// The first error from setting a property, reported by `render`.
+    error: Option<SceneError>,
+}
+
+impl View {
+    /// Describes a node without a shape, e.g. a layout container.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Describes a node without a shape. Same as [`new`](Self::new).
+    pub fn group() -> Self {
+        Self::new()
+    }
+
+    /// Describes a rectangle, empty until given a [`size`](Self::size).
+    pub fn rect() -> Self {
+        Self::new().shape(Shape::Rect(Rect::new(0.0, 0.0)))
+    }
+
+    /// Describes an ellipse with the given radii, filling the box from the
+    /// origin to twice the radii.
+    pub fn ellipse(rx: f32, ry: f32) -> Self {
+        Self::new().shape(Shape::Ellipse(Ellipse::new(rx, ry)))
+    }
+
+    /// Describes a ring between circles of the given radii.
+    pub fn ring(outer_radius: f32, inner_radius: f32) -> Self {
+        Self::new().shape(Shape::Ring(Ring::new(outer_radius, inner_radius)))
+    }
+
+    /// Describes a line from `start` to `end`, drawn with the
+    /// [`stroke`](Self::stroke) only.
+    pub fn line(start: (f32, f32), end: (f32, f32)) -> Self {
+        Self::new().shape(Shape::Line(Line::new(start, end)))
+    }
+
+    /// Describes a chain of straight segments through `points`, drawn
+    /// with the [`stroke`](Self::stroke) only.
+    pub fn polyline(points: Vec<(f32, f32)>) -> Self {
+        Self::new().shape(Shape::Polyline(Polyline::new(points)))
+    }
+
+    /// Describes a closed polygon through `points`.
+    pub fn polygon(points: Vec<(f32, f32)>) -> Self {
+        Self::new().shape(Shape::Polygon(Polygon::new(points)))
+    }
+
+    /// Sets the key identifying the view among its siblings.
+    ///
+    /// Keys must be unique among siblings. A view whose key matches a child
+    /// of the previous description updates that child's node, wherever it
+    /// moved; a view without a key updates the node at its position.
+    pub fn key(mut self, key: impl Into<Key>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Sets the node's shape.
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.props.shape = Some(shape);
+        self
+    }
+
+    /// Makes the node's shape a rectangle of the given size.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        self.shape(Shape::Rect(Rect::new(width, height)))
+    }
+
+    /// Sets the fill, from a [`Fill`](crate::style::Fill), a
+    /// [`Color`](crate::style::Color), or a hex color string.
+    ///
+    /// A string that is not a valid hex color leaves the fill unchanged and
+    /// makes [`ViewRoot::render`] return [`SceneError::InvalidColor`].
+    pub fn fill(mut self, fill: impl IntoFill) -> Self {
+        match fill.into_fill() {
+            Ok(fill) => self.props.style.fill = Some(fill),
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+        self
+    }
+
+    /// Sets the stroke.
+    pub fn stroke(mut self, stroke: Stroke) -> Self {
+        self.props.style.stroke = Some(stroke);
+        self
+    }
+
+    /// Sets the opacity in the range [0.0, 1.0].
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.props.style.opacity = opacity;
+        self
+    }
+
+    /// Sets the offset from the parent's origin.
+    pub fn translate(mut self, x: f32, y: f32) -> Self {
+        self.props.transform.translate = (x, y);
+        self
+    }
+
+    /// Sets the scale factors.
+    pub fn scale(mut self, x: f32, y: f32) -> Self {
+        self.props.transform.scale = (x, y);
+        self
+    }
+
+    /// Sets the rotation in radians, clockwise.
+    pub fn rotate(mut self, radians: f32) -> Self {
+        self.props.transform.rotate = radians;
+        self
+    }
+
+    /// Replaces the node's layout properties.
+    pub fn layout(mut self, layout: LayoutStyle) -> Self {
+        self.props.layout = layout;
+        self
+    }
+
+    /// Sets how the node arranges its children.
+    pub fn display(mut self, display: Display) -> Self {
+        self.props.layout.display = display;
+        self
+    }
+
+    /// Sets the preferred width used by the layout pass.
+    pub fn width(mut self, width: Dimension) -> Self {
+        self.props.layout.width = width;
+        self
+    }
+
+    /// Sets the preferred height used by the layout pass.
+    pub fn height(mut self, height: Dimension) -> Self {
+        self.props.layout.height = height;
+        self
+    }
+
+    /// Sets the semantic role exposed to assistive technologies.
+    pub fn role(mut self, role: Role) -> Self {
+        self.props.role = role;
+        self
+    }
+
+    /// Sets the accessible name.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.props.label = Some(label.into());
+        self
+    }
+
+    /// Sets the accessible value.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.props.value = Some(value.into());
+        self
+    }
+
+    /// Sets the text shown in a tooltip while the pointer rests on the
+    /// node.
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.props.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Sets the event handler.
+    ///
+    /// Handlers are swapped without touching the node, so a new closure
+    /// capturing the current state can be passed on every render.
+    pub fn on_event<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(Event) + Send + Sync + 'static,
+    {
+        self.on_event = Some(Arc::new(handler));
+        self
+    }
+
+    /// Sets an event handler reacting to clicks only.
+    pub fn on_click<F>(self, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_event(move |event| {
+            if let Event::Click = event {
+                handler();
+            }
+        })
+    }
+
+    /// Appends a child.
+    pub fn child(mut self, child: View) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Appends several children, in order.
+    pub fn children(mut self, children: impl IntoIterator<Item = View>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Returns the first error from setting a property in the subtree.
+    fn first_error(&self) -> Option<SceneError> {
+        self.error
+            .or_else(|| self.children.iter().find_map(Self::first_error))
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use super::{Handler, Key, Props, View};
+use crate::node::{Node, NodeId};
+use crate::scene::{Scene, SceneError};
+
+/// How a view is matched to the previous render.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Slot {
+    Key(Key),
+    Index(usize),
+}
+
+/// The handler of a mounted view, read by the node's event handler so it
+/// can be replaced without modifying the node.
+type HandlerCell = Arc<RwLock<Option<Handler>>>;
+
+/// A view applied to the scene, remembered for the next render.
+struct Mounted {
+    id: NodeId,
+    slot: Slot,
+    props: Props,
+
+    /// The view's handler, or `None` if the node has no event handler.
+    handler: Option<HandlerCell>,
+    children: Vec<Mounted>,
+}
+
+/// Keeps a subtree of the scene in sync with a [`View`] description.
+///
+/// The first [`render`](Self::render) adds the view's nodes under the
+/// parent; later renders update them in place. Nodes whose views
+/// disappeared are removed, along with anything added below them.
+///
+/// The root assumes it is the only one changing the structure of its
+/// subtree. Properties can still be changed directly, e.g. by animations,
+/// and keep their value until the view's description of them changes.
+pub struct ViewRoot {
+    parent: NodeId,
+    mounted: Option<Mounted>,
+}
+
+impl ViewRoot {
+    /// Creates a root rendering views as the last child of `parent`.
+    pub fn new(parent: NodeId) -> Self {
+        Self {
+            parent,
+            mounted: None,
+        }
+    }
+
+    /// Returns the node of the rendered view, if rendered yet.
+    pub fn node(&self) -> Option<NodeId> {
+        self.mounted.as_ref().map(|mounted| mounted.id)
+    }
+
+    /// Brings the scene in line with `view` and returns the ID of its node.
+    ///
+    /// Only properties that differ from the previous render are set, so
+    /// nodes of unchanged views are not marked dirty.
+    ///
+    /// Returns an error if a property of `view` was invalid, such as a
+    /// malformed color, in which case the scene is left unchanged. Also
+    /// returns an error if the parent node no longer exists or other code
+    /// removed nodes below the rendered view, in which case the scene may be
+    /// partially updated.
+    pub fn render(&mut self, scene: &mut Scene, view: View) -> Result<NodeId, SceneError> {
+        if let Some(err) = view.first_error() {
+            return Err(err);
+        }
+        let mounted = match self.mounted.take() {
+            Some(mut mounted) if scene.get_node(mounted.id).is_some() => {
+                patch(scene, &mut mounted, view)?;
+                mounted
+            }
+            _ => mount(scene, self.parent, Slot::Index(0), view)?,
+        };
+        let id = mounted.id;
+        self.mounted = Some(mounted);
+        Ok(id)
+    }
+
+    /// Removes the rendered nodes from the scene. The next render starts
+    /// from scratch.
+    pub fn unmount(&mut self, scene: &mut Scene) {
+        if let Some(mounted) = self.mounted.take() {
+            let _ = scene.remove_node(mounted.id);
+        }
+    }
+}
+
+/// Adds the nodes of `view` under `parent`.
+fn mount(scene: &mut Scene, parent: NodeId, slot: Slot, view: View) -> Result<Mounted, SceneError> {
+    let View {
+        props,
+        on_event,
+        children,
+        ..
+    } = view;
+
+    let mut node = Node::new();
+    apply(&mut node, &Props::default(), &props);
+    let handler = on_event.map(|handler| install(&mut node, handler));
+    let id = scene.add_node(parent, node)?;
+
+    let mut mounted = Mounted {
+        id,
+        slot,
+        props,
+        handler,
+        children: Vec::new(),
+    };
+    reconcile_children(scene, id, &mut mounted.children, children)?;
+    Ok(mounted)
+}
+
+/// Updates the nodes of a mounted view to match `view`.
+fn patch(scene: &mut Scene, mounted: &mut Mounted, view: View) -> Result<(), SceneError> {
+    let View {
+        props,
+        mut on_event,
+        children,
+        ..
+    } = view;
+
+    let handler_changed = mounted.handler.is_some() != on_event.is_some();
+    if mounted.props != props || handler_changed {
+        let node = scene
+            .get_node_mut(mounted.id)
+            .ok_or(SceneError::NodeNotFound(mounted.id))?;
+        apply(node, &mounted.props, &props);
+        if handler_changed {
+            mounted.handler = on_event.take().map(|handler| install(node, handler));
+            if mounted.handler.is_none() {
+                node.clear_event_handler();
+            }
+        }
+    }
+    if let (Some(cell), Some(handler)) = (&mounted.handler, on_event) {
+        *cell.write().unwrap_or_else(|err| err.into_inner()) = Some(handler);
+    }
+    mounted.props = props;
+
+    reconcile_children(scene, mounted.id, &mut mounted.children, children)
+}
+
+/// Matches the mounted children of `parent` to `views` by key or position,
+/// patching matches, mounting new views, removing stale nodes, and putting
+/// the nodes in the order of `views`.
+fn reconcile_children(
+    scene: &mut Scene,
+    parent: NodeId,
+    mounted: &mut Vec<Mounted>,
+    views: Vec<View>,
+) -> Result<(), SceneError> {
+    let mut previous = HashMap::with_capacity(mounted.len());
+    for child in mounted.drain(..) {
+        if let Some(duplicate) = previous.insert(child.slot.clone(), child) {
+            let _ = scene.remove_node(duplicate.id);
+        }
+    }
+
+    for (index, view) in views.into_iter().enumerate() {
+        let slot = view.key.clone().map_or(Slot::Index(index), Slot::Key);
+        let child = match previous.remove(&slot) {
+            Some(mut child) => {
+                patch(scene, &mut child, view)?;
+                child
+            }
+            None => mount(scene, parent, slot, view)?,
+        };
+        mounted.push(child);
+    }
+    for (_, stale) in previous {
+        let _ = scene.remove_node(stale.id);
+    }
+
+    for (index, child) in mounted.iter().enumerate() {
+        let in_place = scene
+            .get_node(parent)
+            .is_some_and(|node| node.children().get(index) == Some(&child.id));
+        if !in_place {
+            scene.reorder(child.id, index)?;
+        }
+    }
+    Ok(())
+}
+
+/// Sets the properties of `node` that differ between the `old` and `new`
+/// description. New nodes match the default description.
+fn apply(node: &mut Node, old: &Props, new: &Props) {
+    if old.shape != new.shape {
+        match &new.shape {
+            Some(shape) => node.set_shape(shape.clone()),
+            None => node.clear_shape(),
+        }
+    }
+    if old.style != new.style {
+        *node.style_mut() = new.style.clone();
+    }
+    if old.transform != new.transform {
+        *node.transform_mut() = new.transform.clone();
+    }
+    if old.layout != new.layout {
+        *node.layout_mut() = new.layout.clone();
+    }
+    if old.role != new.role {
+        node.set_role(new.role);
+    }
+    if old.label != new.label {
+        match &new.label {
+            Some(label) => node.set_label(label.clone()),
+            None => node.clear_label(),
+        }
+    }
+    if old.value != new.value {
+        match &new.value {
+            Some(value) => node.set_value(value.clone()),
+            None => node.clear_value(),
+        }
+    }
+    if old.tooltip != new.tooltip {
+        match &new.tooltip {
+            Some(tooltip) => node.set_tooltip(tooltip.clone()),
+            None => node.clear_tooltip(),
+        }
+    }
+}
+
+/// Gives `node` an event handler forwarding to `handler`, and returns the
+/// cell through which later renders replace it.
+fn install(node: &mut Node, handler: Handler) -> HandlerCell {
+    let cell = Arc::new(RwLock::new(Some(handler)));
+    node.set_event_handler(Box::new({
+        let cell = cell.clone();
+        move |event| {
+            let handler = cell.read().unwrap_or_else(|err| err.into_inner()).clone();
+            if let Some(handler) = handler {
+                handler(event);
+            }
+        }
+    }));
+    cell
+}
@@ -0,0 +1,161 @@
+//! Property tests applying random sequences of structural operations to a
+//! scene and checking that the graph stays consistent.
+
+use std::collections::HashSet;
+
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::{Scene, SceneError};
+use proptest::prelude::*;
+
+/// A structural operation on nodes picked by index into the scene's nodes
+/// in traversal order, so every index refers to a live node.
+#[derive(Clone, Debug)]
+enum Op {
+    Add { parent: usize },
+    Remove { node: usize },
+    Reparent { node: usize, parent: usize },
+    Reorder { node: usize, index: usize },
+    Import { parent: usize, nodes: usize },
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => any::<usize>().prop_map(|parent| Op::Add { parent }),
+        1 => any::<usize>().prop_map(|node| Op::Remove { node }),
+        2 => (any::<usize>(), any::<usize>()).prop_map(|(node, parent)| Op::Reparent { node, parent }),
+        1 => (any::<usize>(), 0..8usize).prop_map(|(node, index)| Op::Reorder { node, index }),
+        1 => (any::<usize>(), 0..4usize).prop_map(|(parent, nodes)| Op::Import { parent, nodes }),
+    ]
+}
+
+fn ids(scene: &Scene) -> Vec<NodeId> {
+    let mut ids = Vec::new();
+    scene.traverse(|node| ids.push(node.id()));
+    ids
+}
+
+/// Returns every node's parent and children, to compare scenes before and
+/// after failed operations.
+fn structure(scene: &Scene) -> Vec<(NodeId, Option<NodeId>, Vec<NodeId>)> {
+    let mut nodes = Vec::new();
+    scene.traverse(|node| nodes.push((node.id(), node.parent(), node.children().to_vec())));
+    nodes
+}
+
+fn is_descendant(scene: &Scene, node: NodeId, ancestor: NodeId) -> bool {
+    let mut current = Some(node);
+    while let Some(id) = current {
+        if id == ancestor {
+            return true;
+        }
+        current = scene.get_node(id).and_then(Node::parent);
+    }
+    false
+}
+
+fn apply(scene: &mut Scene, op: &Op) -> Result<(), TestCaseError> {
+    let ids = ids(scene);
+    let pick = |index: usize| ids[index % ids.len()];
+    let before = structure(scene);
+
+    match *op {
+        Op::Add { parent } => {
+            let id = scene.add_node(pick(parent), Node::new()).unwrap();
+            prop_assert!(!ids.contains(&id), "reused ID {:?}", id);
+            prop_assert_eq!(
+                scene.get_node(id).and_then(Node::parent),
+                Some(pick(parent))
+            );
+        }
+        Op::Remove { node } => {
+            let node = pick(node);
+            let removed: Vec<NodeId> = ids
+                .iter()
+                .copied()
+                .filter(|&id| is_descendant(scene, id, node))
+                .collect();
+            match scene.remove_node(node) {
+                Ok(()) => {
+                    for id in removed {
+                        prop_assert!(scene.get_node(id).is_none(), "{:?} survived", id);
+                    }
+                }
+                Err(e) => {
+                    prop_assert_eq!(e, SceneError::RootNode);
+                    prop_assert_eq!(structure(scene), before);
+                }
+            }
+        }
+        Op::Reparent { node, parent } => {
+            let (node, parent) = (pick(node), pick(parent));
+            let cycle = is_descendant(scene, parent, node);
+            match scene.reparent(node, parent) {
+                Ok(()) => {
+                    prop_assert!(!cycle);
+                    let new_parent = scene.get_node(parent).unwrap();
+                    prop_assert_eq!(new_parent.children().last(), Some(&node));
+                }
+                Err(e) => {
+                    prop_assert!(cycle || node == scene.root(), "unexpected {}", e);
+                    prop_assert_eq!(structure(scene), before);
+                }
+            }
+        }
+        Op::Reorder { node, index } => {
+            let node = pick(node);
+            match scene.reorder(node, index) {
+                Ok(()) => {
+                    let parent = scene.get_node(node).and_then(Node::parent).unwrap();
+                    let siblings = scene.get_node(parent).unwrap().children();
+                    let position = siblings.iter().position(|&id| id == node);
+                    prop_assert_eq!(position, Some(index.min(siblings.len() - 1)));
+                }
+                Err(e) => {
+                    prop_assert_eq!(e, SceneError::RootNode);
+                    prop_assert_eq!(structure(scene), before);
+                }
+            }
+        }
+        Op::Import { parent, nodes } => {
+            let mut source = Scene::new();
+            for _ in 0..nodes {
+                source.add_to_root(Node::new()).unwrap();
+            }
+            let map = scene.import(pick(parent), source).unwrap();
+            prop_assert_eq!(map.len(), nodes + 1);
+            for id in map.values() {
+                prop_assert!(!ids.contains(id), "reused ID {:?}", id);
+            }
+        }
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn mutations_keep_scene_consistent(ops in prop::collection::vec(op(), 1..64)) {
+        let mut scene = Scene::new();
+        for op in &ops {
+            apply(&mut scene, op)?;
+            if let Err(e) = scene.check_integrity() {
+                return Err(TestCaseError::fail(format!("{e} after {op:?}")));
+            }
+
+            // Every stored node is reachable exactly once from the root.
+            let ids = ids(&scene);
+            let unique: HashSet<_> = ids.iter().collect();
+            prop_assert_eq!(unique.len(), ids.len());
+        }
+    }
+
+    #[test]
+    fn node_ids_are_deterministic(ops in prop::collection::vec(op(), 1..32)) {
+        let mut first = Scene::new();
+        let mut second = Scene::new();
+        for op in &ops {
+            apply(&mut first, op)?;
+            apply(&mut second, op)?;
+        }
+        prop_assert_eq!(structure(&first), structure(&second));
+    }
+}
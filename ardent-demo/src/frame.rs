@@ -1,38 +1,45 @@
 use ardent_core::prelude::*;
 
-pub struct Frame {
-    scene: Scene,
-}
-
-impl Frame {
-    pub fn new(width: u32, height: u32) -> Self {
-        // Create scene.
-        let mut scene = Scene::new();
-        let root = scene.root();
-
-        // Define a rectangle node.
-        let mut rect_node = Node::new();
-        rect_node.set_shape(Shape::Rect(Rect {
-            width: 200.0,
-            height: 100.0,
-        }));
+/// Builds the demo scene: a rectangle centered in the window that fades in.
+pub fn build(scene: &mut Scene, animator: &mut Animator) {
+    let root = scene.root();
 
-        // Set transform.
-        rect_node.transform_mut().translate =
-            ((width as f32 - 200.0) / 2.0, (height as f32 - 100.0) / 2.0);
+    // Center children of the root in a single cell filling the window.
+    if let Some(root_node) = scene.get_node_mut(root) {
+        root_node.layout_mut().display = Display::Grid(
+            GridLayout::new()
+                .columns([TrackSize::Fr(1.0)])
+                .rows([TrackSize::Fr(1.0)])
+                .align(Align::Center),
+        );
+    }
 
-        // Set style.
-        rect_node.style_mut().fill = Some(Fill {
-            color: Color::rgb(0.2, 0.5, 0.8),
-            gradient: None,
-        });
+    // Define a rectangle sized relative to the window.
+    let rect = scene! {
+        rect {
+            size: (200, 100),
+            fill: "#3380cc",
+            width: Dimension::Percent(40.0),
+            height: Dimension::Vh(20.0),
+            min_width: Dimension::Px(200.0),
+            min_height: Dimension::Px(100.0),
+        }
+    };
 
-        // Add rect node to scene.
-        scene.add_node(root, rect_node);
-        Self { scene }
-    }
+    // Add rect node to scene.
+    let rect = rect
+        .build(scene, root)
+        .expect("the root node is always in the scene");
 
-    pub fn scene(&self) -> &Scene {
-        &self.scene
-    }
+    // Fade the rectangle in.
+    animator.add(
+        Tween::new(
+            rect,
+            Property::Opacity,
+            Value::Float(0.0),
+            Value::Float(1.0),
+            1.0,
+        )
+        .with_easing(Easing::EaseOut),
+    );
 }
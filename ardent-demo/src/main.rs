@@ -1,16 +1,12 @@
-use app::Application;
-use winit::event_loop::{ControlFlow, EventLoop};
+use ardent_app::App;
 
-mod app;
 mod frame;
-mod state;
 
 fn main() {
-    let event_loop = EventLoop::new().unwrap();
-    event_loop.set_control_flow(ControlFlow::Wait);
-
-    let mut app = Application::default();
-    if let Err(e) = event_loop.run_app(&mut app) {
+    if let Err(e) = App::builder()
+        .title("Ardent Demo")
+        .run_with(|scene, app| frame::build(scene, app.animator()))
+    {
         eprintln!("{e}");
         std::process::exit(-1);
     }
@@ -0,0 +1,28 @@
+//! The `ardent_egui` crate lets ardent and `egui` share a GPU, for gradual
+//! adoption in existing tools.
+//!
+//! It works in both directions:
+//!
+//! - An [`EguiOverlay`] draws an egui debug or tooling UI on top of an
+//!   ardent scene, into the same surface and render pass.
+//! - A [`SceneTexture`] renders an ardent scene into a texture that egui
+//!   shows like any other image, e.g. inside a panel.
+//!
+//! ```rust,ignore
+//! let mut overlay = EguiOverlay::new(&context);
+//! let mut preview = SceneTexture::new(&context, overlay.renderer_mut(), 320, 240);
+//!
+//! // Each frame:
+//! preview.render(&preview_scene);
+//! overlay.render(&mut renderer, &scene, &context, input, |ctx| {
+//!     egui::Window::new("Preview").show(ctx, |ui| {
+//!         preview.show(ui);
+//!     });
+//! })?;
+//! ```
+
+mod overlay;
+mod texture;
+
+pub use overlay::EguiOverlay;
+pub use texture::SceneTexture;
@@ -0,0 +1,151 @@
+use ardent_core::scene::Scene;
+use ardent_render::{GpuContext, RenderError, Renderer};
+use egui::epaint::ClippedPrimitive;
+use egui::{PlatformOutput, RawInput, TextureId};
+use egui_wgpu::ScreenDescriptor;
+
+/// An egui UI drawn on top of ardent scenes.
+///
+/// Each frame is split in two steps, so the UI can be painted into any
+/// render pass: [`prepare`](Self::prepare) runs the UI and uploads its
+/// geometry and textures, and [`paint`](Self::paint) draws it. For the
+/// common case of presenting a scene with the overlay on top,
+/// [`render`](Self::render) does both around [`Renderer::render_to_pass`].
+pub struct EguiOverlay {
+    context: egui::Context,
+    renderer: egui_wgpu::Renderer,
+    frame: Option<PreparedFrame>,
+}
+
+/// The tessellated output of the last prepared frame.
+struct PreparedFrame {
+    primitives: Vec<ClippedPrimitive>,
+    screen: ScreenDescriptor,
+    free: Vec<TextureId>,
+}
+
+impl EguiOverlay {
+    /// Creates an overlay drawing into targets of the context's format.
+    pub fn new(gpu: &GpuContext) -> Self {
+        Self {
+            context: egui::Context::default(),
+            renderer: egui_wgpu::Renderer::new(&gpu.device, gpu.config.format, None, 1, false),
+            frame: None,
+        }
+    }
+
+    /// Returns the egui context, e.g. to change its style or fonts.
+    pub fn context(&self) -> &egui::Context {
+        &self.context
+    }
+
+    /// Returns the egui renderer, e.g. to register native textures.
+    pub fn renderer_mut(&mut self) -> &mut egui_wgpu::Renderer {
+        &mut self.renderer
+    }
+
+    /// Runs the UI for one frame and uploads its geometry and textures.
+    ///
+    /// Buffer uploads are recorded into `encoder`, which must be submitted
+    /// before the pass that paints the overlay. Returns the platform output
+    /// (cursor, clipboard, opened URLs) for the host to act on.
+    pub fn prepare(
+        &mut self,
+        gpu: &GpuContext,
+        encoder: &mut wgpu::CommandEncoder,
+        input: RawInput,
+        run_ui: impl FnMut(&egui::Context),
+    ) -> PlatformOutput {
+        let output = self.context.run(input, run_ui);
+
+        for (id, delta) in &output.textures_delta.set {
+            self.renderer
+                .update_texture(&gpu.device, &gpu.queue, *id, delta);
+        }
+
+        let primitives = self
+            .context
+            .tessellate(output.shapes, output.pixels_per_point);
+        let screen = ScreenDescriptor {
+            size_in_pixels: [gpu.size.0, gpu.size.1],
+            pixels_per_point: output.pixels_per_point,
+        };
+        let callbacks =
+            self.renderer
+                .update_buffers(&gpu.device, &gpu.queue, encoder, &primitives, &screen);
+        gpu.queue.submit(callbacks);
+
+        self.frame = Some(PreparedFrame {
+            primitives,
+            screen,
+            free: output.textures_delta.free,
+        });
+        output.platform_output
+    }
+
+    /// Paints the prepared frame into `pass`.
+    ///
+    /// Does nothing if no frame was prepared since the last paint.
+    pub fn paint(&mut self, pass: &mut wgpu::RenderPass<'static>) {
+        let Some(frame) = self.frame.take() else {
+            return;
+        };
+        self.renderer.render(pass, &frame.primitives, &frame.screen);
+        for id in &frame.free {
+            self.renderer.free_texture(id);
+        }
+    }
+
+    /// Presents `scene` with the overlay on top, in a single render pass on
+    /// the context's surface.
+    ///
+    /// Returns an error if the context has no surface or its next frame
+    /// cannot be acquired.
+    pub fn render(
+        &mut self,
+        renderer: &mut Renderer,
+        scene: &Scene,
+        gpu: &GpuContext,
+        input: RawInput,
+        run_ui: impl FnMut(&egui::Context),
+    ) -> Result<PlatformOutput, RenderError> {
+        let surface = gpu.surface.as_ref().ok_or(RenderError::NoSurface)?;
+        let output = surface.get_current_texture()?;
+        let view = output
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = gpu
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Ardent egui Encoder"),
+            });
+        let platform = self.prepare(gpu, &mut encoder, input, run_ui);
+
+        {
+            let mut pass = encoder
+                .begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Ardent egui Pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    occlusion_query_set: None,
+                    timestamp_writes: None,
+                })
+                .forget_lifetime();
+
+            renderer.render_to_pass(&mut pass, scene, gpu, gpu.config.format);
+            self.paint(&mut pass);
+        }
+
+        gpu.queue.submit(Some(encoder.finish()));
+        output.present();
+        Ok(platform)
+    }
+}
@@ -0,0 +1,133 @@
+use ardent_core::scene::Scene;
+use ardent_render::{GpuContext, Renderer};
+use egui::TextureId;
+
+/// Format of scene textures. egui expects sRGB-encoded native textures.
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+/// An ardent scene rendered into a texture shown by egui.
+///
+/// The texture has its own renderer and viewport, sharing the host's
+/// device and queue, so scene coordinates map to the texture's pixels
+/// rather than the window's. Areas not covered by the scene are
+/// transparent.
+pub struct SceneTexture {
+    gpu: GpuContext<'static>,
+    renderer: Renderer,
+    view: wgpu::TextureView,
+    id: TextureId,
+}
+
+impl SceneTexture {
+    /// Creates a texture of the given size in pixels and registers it with
+    /// the egui renderer.
+    pub fn new(
+        gpu: &GpuContext,
+        egui_renderer: &mut egui_wgpu::Renderer,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        let gpu =
+            GpuContext::from_device(gpu.device.clone(), gpu.queue.clone(), FORMAT, width, height);
+        let renderer = Renderer::new(&gpu);
+        let view = create_view(&gpu);
+        let id =
+            egui_renderer.register_native_texture(&gpu.device, &view, wgpu::FilterMode::Linear);
+
+        Self {
+            gpu,
+            renderer,
+            view,
+            id,
+        }
+    }
+
+    /// Returns the egui texture id of the scene.
+    pub fn id(&self) -> TextureId {
+        self.id
+    }
+
+    /// Returns the size of the texture in pixels.
+    pub fn size(&self) -> (u32, u32) {
+        self.gpu.size
+    }
+
+    /// Returns the renderer drawing the scene, e.g. to change tessellation
+    /// options.
+    pub fn renderer_mut(&mut self) -> &mut Renderer {
+        &mut self.renderer
+    }
+
+    /// Resizes the texture, keeping its egui texture id.
+    pub fn resize(&mut self, egui_renderer: &mut egui_wgpu::Renderer, width: u32, height: u32) {
+        if (width, height) == self.gpu.size || width == 0 || height == 0 {
+            return;
+        }
+        self.gpu.resize(width, height);
+        self.view = create_view(&self.gpu);
+        egui_renderer.update_egui_texture_from_wgpu_texture(
+            &self.gpu.device,
+            &self.view,
+            wgpu::FilterMode::Linear,
+            self.id,
+        );
+    }
+
+    /// Renders the scene into the texture.
+    pub fn render(&mut self, scene: &Scene) {
+        let mut encoder = self
+            .gpu
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Ardent Scene Texture Encoder"),
+            });
+
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Ardent Scene Texture Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            self.renderer
+                .render_to_pass(&mut pass, scene, &self.gpu, FORMAT);
+        }
+
+        self.gpu.queue.submit(Some(encoder.finish()));
+    }
+
+    /// Shows the texture at its size in points.
+    pub fn show(&self, ui: &mut egui::Ui) -> egui::Response {
+        let (width, height) = self.gpu.size;
+        let size = egui::vec2(width as f32, height as f32) / ui.ctx().pixels_per_point();
+        ui.image((self.id, size))
+    }
+}
+
+/// Creates the render target for a scene texture at the context's size.
+fn create_view(gpu: &GpuContext) -> wgpu::TextureView {
+    let (width, height) = gpu.size;
+    let texture = gpu.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Ardent Scene Texture"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+    texture.create_view(&wgpu::TextureViewDescriptor::default())
+}
@@ -0,0 +1,86 @@
+/*
+ * C interface to the ardent vector UI toolkit.
+ *
+ * Scenes and renderers are opaque handles owned by the library and must be
+ * released with their `_free` function. Nodes are addressed by 64-bit IDs;
+ * 0 never names a node and signals failure. Functions returning `bool`
+ * return `false` for null handles and unknown nodes.
+ */
+
+#ifndef ARDENT_H
+#define ARDENT_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct ArdentScene ArdentScene;
+typedef struct ArdentRenderer ArdentRenderer;
+
+typedef enum ArdentEventKind {
+    ARDENT_EVENT_CLICK = 0,
+    ARDENT_EVENT_POINTER_ENTER = 1,
+    ARDENT_EVENT_POINTER_LEAVE = 2,
+    ARDENT_EVENT_SCROLL = 3,      /* x, y: scroll distance */
+    ARDENT_EVENT_DRAG_START = 4,
+    ARDENT_EVENT_DRAG_UPDATE = 5, /* x, y: distance moved */
+    ARDENT_EVENT_DRAG_END = 6,
+} ArdentEventKind;
+
+typedef void (*ArdentEventCallback)(uint64_t node, ArdentEventKind kind, float x, float y,
+                                    void *user_data);
+
+/* Scenes */
+
+ArdentScene *ardent_scene_new(void);
+void ardent_scene_free(ArdentScene *scene);
+uint64_t ardent_scene_root(const ArdentScene *scene);
+uint64_t ardent_scene_add_node(ArdentScene *scene, uint64_t parent);
+bool ardent_scene_remove_node(ArdentScene *scene, uint64_t id);
+bool ardent_scene_set_scale_factor(ArdentScene *scene, float scale_factor);
+bool ardent_scene_layout(ArdentScene *scene, float width, float height);
+
+/* Nodes */
+
+bool ardent_node_set_rect(ArdentScene *scene, uint64_t id, float width, float height);
+bool ardent_node_clear_shape(ArdentScene *scene, uint64_t id);
+bool ardent_node_set_fill(ArdentScene *scene, uint64_t id, float r, float g, float b, float a);
+bool ardent_node_clear_fill(ArdentScene *scene, uint64_t id);
+bool ardent_node_set_stroke(ArdentScene *scene, uint64_t id, float r, float g, float b, float a,
+                            float width);
+bool ardent_node_clear_stroke(ArdentScene *scene, uint64_t id);
+bool ardent_node_set_opacity(ArdentScene *scene, uint64_t id, float opacity);
+bool ardent_node_set_transform(ArdentScene *scene, uint64_t id, float translate_x,
+                               float translate_y, float scale_x, float scale_y, float rotate);
+bool ardent_node_set_label(ArdentScene *scene, uint64_t id, const char *label);
+bool ardent_node_set_event_callback(ArdentScene *scene, uint64_t id,
+                                    ArdentEventCallback callback, void *user_data);
+bool ardent_node_dispatch_event(const ArdentScene *scene, uint64_t id, ArdentEventKind kind,
+                                float x, float y);
+
+/* Rendering */
+
+ArdentRenderer *ardent_renderer_new_headless(uint32_t width, uint32_t height);
+ArdentRenderer *ardent_renderer_new_win32(void *hwnd, void *hinstance, uint32_t width,
+                                          uint32_t height);
+ArdentRenderer *ardent_renderer_new_xlib(void *display, int screen, unsigned long window,
+                                         uint32_t width, uint32_t height);
+ArdentRenderer *ardent_renderer_new_wayland(void *display, void *surface, uint32_t width,
+                                            uint32_t height);
+ArdentRenderer *ardent_renderer_new_appkit(void *ns_view, uint32_t width, uint32_t height);
+void ardent_renderer_free(ArdentRenderer *renderer);
+bool ardent_renderer_resize(ArdentRenderer *renderer, uint32_t width, uint32_t height);
+bool ardent_renderer_set_scale_factor(ArdentRenderer *renderer, float scale_factor);
+bool ardent_renderer_render(ArdentRenderer *renderer, ArdentScene *scene);
+bool ardent_renderer_read_pixels(ArdentRenderer *renderer, const ArdentScene *scene,
+                                 uint8_t *pixels, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ARDENT_H */
@@ -0,0 +1,39 @@
+//! The `ardent_ffi` crate exposes ardent over a C ABI, so scenes can be
+//! built and rendered from C, C++, Python, or any language with a C FFI.
+//!
+//! Scenes and renderers are opaque handles created and freed by the
+//! library. Nodes are addressed by their 64-bit IDs, where 0 never names a
+//! node and signals failure. Functions report errors through their return
+//! value (`false`, 0, or a null handle) rather than unwinding into the host;
+//! the matching declarations are in `include/ardent.h`.
+//!
+//! ```c
+//! ArdentScene *scene = ardent_scene_new();
+//! uint64_t rect = ardent_scene_add_node(scene, ardent_scene_root(scene));
+//! ardent_node_set_rect(scene, rect, 200.0f, 100.0f);
+//! ardent_node_set_fill(scene, rect, 0.2f, 0.5f, 0.8f, 1.0f);
+//!
+//! ArdentRenderer *renderer = ardent_renderer_new_headless(800, 600);
+//! ardent_scene_layout(scene, 800.0f, 600.0f);
+//! ardent_renderer_read_pixels(renderer, scene, pixels, 800 * 600 * 4);
+//!
+//! ardent_renderer_free(renderer);
+//! ardent_scene_free(scene);
+//! ```
+
+mod node;
+mod render;
+mod scene;
+mod window;
+
+pub use node::*;
+pub use render::*;
+pub use scene::*;
+
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// Runs `f`, returning `fallback` instead of unwinding into the host if it
+/// panics. Every entry point runs its work through it.
+fn guard<T>(fallback: T, f: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(fallback)
+}
@@ -0,0 +1,268 @@
+use std::ffi::{CStr, c_char, c_void};
+
+use ardent_core::event::Event;
+use ardent_core::node::NodeId;
+use ardent_core::shape::{Rect, Shape};
+use ardent_core::style::{Color, Fill, Stroke};
+use ardent_core::transform::Transform;
+
+use crate::guard;
+use crate::scene::ArdentScene;
+
+/// The kind of an input event, mirroring `ardent_core::event::Event`.
+///
+/// Clipboard events are not forwarded to C handlers.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArdentEventKind {
+    Click = 0,
+    PointerEnter = 1,
+    PointerLeave = 2,
+    /// `x` and `y` hold the scroll distance.
+    Scroll = 3,
+    DragStart = 4,
+    /// `x` and `y` hold the distance moved.
+    DragUpdate = 5,
+    DragEnd = 6,
+}
+
+/// A C event handler, receiving the node, the event kind, the event's
+/// distances (or zero), and the user data given when it was registered.
+pub type ArdentEventCallback =
+    unsafe extern "C" fn(node: u64, kind: ArdentEventKind, x: f32, y: f32, user_data: *mut c_void);
+
+/// User data handed back to a C handler.
+struct UserData(*mut c_void);
+
+// SAFETY: the host guarantees that its user data may be used from the
+// threads that dispatch events, as documented on `ardent_node_set_event_callback`.
+unsafe impl Send for UserData {}
+unsafe impl Sync for UserData {}
+
+impl ArdentEventKind {
+    fn event(self, x: f32, y: f32) -> Event {
+        match self {
+            Self::Click => Event::Click,
+            Self::PointerEnter => Event::PointerEnter,
+            Self::PointerLeave => Event::PointerLeave,
+            Self::Scroll => Event::Scroll(x, y),
+            Self::DragStart => Event::DragStart,
+            Self::DragUpdate => Event::DragUpdate(x, y),
+            Self::DragEnd => Event::DragEnd,
+        }
+    }
+
+    fn of(event: &Event) -> Option<(Self, f32, f32)> {
+        Some(match *event {
+            Event::Click => (Self::Click, 0.0, 0.0),
+            Event::PointerEnter => (Self::PointerEnter, 0.0, 0.0),
+            Event::PointerLeave => (Self::PointerLeave, 0.0, 0.0),
+            Event::Scroll(x, y) => (Self::Scroll, x, y),
+            Event::DragStart => (Self::DragStart, 0.0, 0.0),
+            Event::DragUpdate(x, y) => (Self::DragUpdate, x, y),
+            Event::DragEnd => (Self::DragEnd, 0.0, 0.0),
+            Event::Copy(_) | Event::Cut(_) | Event::Paste(_) => return None,
+        })
+    }
+}
+
+/// Makes the node's shape a rectangle of the given size.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_set_rect(
+    scene: *mut ArdentScene,
+    id: u64,
+    width: f32,
+    height: f32,
+) -> bool {
+    unsafe {
+        ArdentScene::with_node(scene, id, |node| {
+            node.set_shape(Shape::Rect(Rect::new(width, height)))
+        })
+    }
+}
+
+/// Removes the node's shape.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_clear_shape(scene: *mut ArdentScene, id: u64) -> bool {
+    unsafe { ArdentScene::with_node(scene, id, |node| node.clear_shape()) }
+}
+
+/// Sets a solid fill with the given RGBA color, components in [0, 1].
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_set_fill(
+    scene: *mut ArdentScene,
+    id: u64,
+    r: f32,
+    g: f32,
+    b: f32,
+    a: f32,
+) -> bool {
+    unsafe {
+        ArdentScene::with_node(scene, id, |node| {
+            node.style_mut().fill = Some(Fill::solid(Color::rgba(r, g, b, a)));
+        })
+    }
+}
+
+/// Removes the node's fill.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_clear_fill(scene: *mut ArdentScene, id: u64) -> bool {
+    unsafe { ArdentScene::with_node(scene, id, |node| node.style_mut().fill = None) }
+}
+
+/// Sets a centered stroke with the given RGBA color and width.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_set_stroke(
+    scene: *mut ArdentScene,
+    id: u64,
+    r: f32,
+    g: f32,
+    b: f32,
+    a: f32,
+    width: f32,
+) -> bool {
+    unsafe {
+        ArdentScene::with_node(scene, id, |node| {
+            node.style_mut().stroke = Some(Stroke::new(Color::rgba(r, g, b, a), width));
+        })
+    }
+}
+
+/// Removes the node's stroke.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_clear_stroke(scene: *mut ArdentScene, id: u64) -> bool {
+    unsafe { ArdentScene::with_node(scene, id, |node| node.style_mut().stroke = None) }
+}
+
+/// Sets the node's opacity in [0, 1].
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_set_opacity(
+    scene: *mut ArdentScene,
+    id: u64,
+    opacity: f32,
+) -> bool {
+    unsafe { ArdentScene::with_node(scene, id, |node| node.style_mut().opacity = opacity) }
+}
+
+/// Sets the node's transform: scale, then clockwise rotation in radians,
+/// then translation.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_set_transform(
+    scene: *mut ArdentScene,
+    id: u64,
+    translate_x: f32,
+    translate_y: f32,
+    scale_x: f32,
+    scale_y: f32,
+    rotate: f32,
+) -> bool {
+    unsafe {
+        ArdentScene::with_node(scene, id, |node| {
+            *node.transform_mut() = Transform {
+                translate: (translate_x, translate_y),
+                scale: (scale_x, scale_y),
+                rotate,
+            };
+        })
+    }
+}
+
+/// Sets the node's accessible name from a NUL-terminated UTF-8 string, or
+/// removes it if `label` is null. Returns `false` for invalid UTF-8.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`, and
+/// `label` null or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_set_label(
+    scene: *mut ArdentScene,
+    id: u64,
+    label: *const c_char,
+) -> bool {
+    if label.is_null() {
+        return unsafe { ArdentScene::with_node(scene, id, |node| node.clear_label()) };
+    }
+    guard(false, || {
+        let Ok(label) = unsafe { CStr::from_ptr(label) }.to_str() else {
+            return false;
+        };
+        unsafe { ArdentScene::with_node(scene, id, |node| node.set_label(label)) }
+    })
+}
+
+/// Registers a C function handling the node's events, replacing any
+/// previous handler. Passing a null callback removes the handler.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+/// `callback` and `user_data` must stay valid while the handler is
+/// registered, and be safe to use from any thread dispatching events.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_set_event_callback(
+    scene: *mut ArdentScene,
+    id: u64,
+    callback: Option<ArdentEventCallback>,
+    user_data: *mut c_void,
+) -> bool {
+    let Some(callback) = callback else {
+        return unsafe { ArdentScene::with_node(scene, id, |node| node.clear_event_handler()) };
+    };
+    let user_data = UserData(user_data);
+    let handler = move |event| {
+        if let Some((kind, x, y)) = ArdentEventKind::of(&event) {
+            let user_data = &user_data;
+            unsafe { callback(id, kind, x, y, user_data.0) };
+        }
+    };
+    unsafe { ArdentScene::with_node(scene, id, |node| node.set_event_handler(Box::new(handler))) }
+}
+
+/// Dispatches an event to the node's handler. `x` and `y` are the
+/// distances of scroll and drag events, ignored otherwise.
+///
+/// Returns `true` if the node has a handler.
+///
+/// # Safety
+/// `scene` must be null or a live handle from `ardent_scene_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_node_dispatch_event(
+    scene: *const ArdentScene,
+    id: u64,
+    kind: ArdentEventKind,
+    x: f32,
+    y: f32,
+) -> bool {
+    let Some(handle) = (unsafe { scene.as_ref() }) else {
+        return false;
+    };
+    guard(false, || {
+        handle
+            .scene
+            .get_node(NodeId(id))
+            .is_some_and(|node| node.handle_event(kind.event(x, y)))
+    })
+}
@@ -0,0 +1,258 @@
+use std::ffi::{c_int, c_ulong, c_void};
+use std::num::NonZeroIsize;
+use std::ptr::{self, NonNull};
+
+use ardent_render::{GpuContext, Renderer};
+use pollster::FutureExt;
+use wgpu::rwh::{
+    AppKitDisplayHandle, AppKitWindowHandle, RawDisplayHandle, RawWindowHandle,
+    WaylandDisplayHandle, WaylandWindowHandle, Win32WindowHandle, WindowsDisplayHandle,
+    XlibDisplayHandle, XlibWindowHandle,
+};
+
+use crate::guard;
+use crate::scene::ArdentScene;
+use crate::window::RawWindow;
+
+/// An opaque handle to a GPU context and the renderer drawing into it.
+pub struct ArdentRenderer {
+    context: GpuContext<'static>,
+    renderer: Renderer,
+}
+
+impl ArdentRenderer {
+    fn boxed(context: GpuContext<'static>) -> *mut ArdentRenderer {
+        let renderer = Renderer::new(&context);
+        Box::into_raw(Box::new(Self { context, renderer }))
+    }
+}
+
+/// Creates a renderer drawing into a window given by raw handles.
+///
+/// # Safety
+/// The handles must be valid and outlive the renderer.
+unsafe fn window_renderer(
+    window: RawWindowHandle,
+    display: RawDisplayHandle,
+    width: u32,
+    height: u32,
+) -> *mut ArdentRenderer {
+    let window = unsafe { RawWindow::new(window, display) };
+    GpuContext::new(window, width, height)
+        .block_on()
+        .map_or(ptr::null_mut(), ArdentRenderer::boxed)
+}
+
+/// Creates a renderer without a window, for reading frames back with
+/// [`ardent_renderer_read_pixels`]. Returns null if no GPU is available.
+///
+/// The renderer must be released with [`ardent_renderer_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn ardent_renderer_new_headless(width: u32, height: u32) -> *mut ArdentRenderer {
+    guard(ptr::null_mut(), || {
+        GpuContext::headless(width, height)
+            .block_on()
+            .map_or(ptr::null_mut(), ArdentRenderer::boxed)
+    })
+}
+
+/// Creates a renderer drawing into a Win32 window. Returns null on failure.
+///
+/// # Safety
+/// `hwnd` and `hinstance` must be valid handles that outlive the renderer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_new_win32(
+    hwnd: *mut c_void,
+    hinstance: *mut c_void,
+    width: u32,
+    height: u32,
+) -> *mut ArdentRenderer {
+    guard(ptr::null_mut(), || {
+        let Some(hwnd) = NonZeroIsize::new(hwnd as isize) else {
+            return ptr::null_mut();
+        };
+        let mut window = Win32WindowHandle::new(hwnd);
+        window.hinstance = NonZeroIsize::new(hinstance as isize);
+        let display = WindowsDisplayHandle::new();
+        unsafe { window_renderer(window.into(), display.into(), width, height) }
+    })
+}
+
+/// Creates a renderer drawing into an X11 window through Xlib. Returns null
+/// on failure.
+///
+/// # Safety
+/// `display` and `window` must be valid and outlive the renderer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_new_xlib(
+    display: *mut c_void,
+    screen: c_int,
+    window: c_ulong,
+    width: u32,
+    height: u32,
+) -> *mut ArdentRenderer {
+    guard(ptr::null_mut(), || {
+        let window = XlibWindowHandle::new(window);
+        let display = XlibDisplayHandle::new(NonNull::new(display), screen);
+        unsafe { window_renderer(window.into(), display.into(), width, height) }
+    })
+}
+
+/// Creates a renderer drawing into a Wayland surface. Returns null on
+/// failure.
+///
+/// # Safety
+/// `display` and `surface` must be valid and outlive the renderer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_new_wayland(
+    display: *mut c_void,
+    surface: *mut c_void,
+    width: u32,
+    height: u32,
+) -> *mut ArdentRenderer {
+    guard(ptr::null_mut(), || {
+        let (Some(display), Some(surface)) = (NonNull::new(display), NonNull::new(surface)) else {
+            return ptr::null_mut();
+        };
+        let window = WaylandWindowHandle::new(surface);
+        let display = WaylandDisplayHandle::new(display);
+        unsafe { window_renderer(window.into(), display.into(), width, height) }
+    })
+}
+
+/// Creates a renderer drawing into a macOS `NSView`. Returns null on
+/// failure.
+///
+/// # Safety
+/// `ns_view` must be a valid view that outlives the renderer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_new_appkit(
+    ns_view: *mut c_void,
+    width: u32,
+    height: u32,
+) -> *mut ArdentRenderer {
+    guard(ptr::null_mut(), || {
+        let Some(ns_view) = NonNull::new(ns_view) else {
+            return ptr::null_mut();
+        };
+        let window = AppKitWindowHandle::new(ns_view);
+        let display = AppKitDisplayHandle::new();
+        unsafe { window_renderer(window.into(), display.into(), width, height) }
+    })
+}
+
+/// Releases a renderer. Null handles are ignored.
+///
+/// # Safety
+/// `renderer` must be null or a live renderer handle, and must not be used
+/// afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_free(renderer: *mut ArdentRenderer) {
+    if !renderer.is_null() {
+        guard((), || drop(unsafe { Box::from_raw(renderer) }));
+    }
+}
+
+/// Resizes the render target, e.g. when the window size changes.
+///
+/// # Safety
+/// `renderer` must be null or a live renderer handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_resize(
+    renderer: *mut ArdentRenderer,
+    width: u32,
+    height: u32,
+) -> bool {
+    let Some(handle) = (unsafe { renderer.as_mut() }) else {
+        return false;
+    };
+    guard(false, || {
+        handle.context.resize(width, height);
+        true
+    })
+}
+
+/// Sets the number of physical pixels per logical pixel of the display,
+/// e.g. 2.0 on retina displays. Scenes are drawn in logical pixels.
+///
+/// # Safety
+/// `renderer` must be null or a live renderer handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_set_scale_factor(
+    renderer: *mut ArdentRenderer,
+    scale_factor: f32,
+) -> bool {
+    let Some(handle) = (unsafe { renderer.as_mut() }) else {
+        return false;
+    };
+    guard(false, || {
+        handle.context.set_scale_factor(scale_factor);
+        true
+    })
+}
+
+/// Renders the scene and presents it in the renderer's window, then clears
+/// the dirty flags of the nodes drawn, so the next frame only revisits
+/// nodes changed since.
+///
+/// Returns `false` for headless renderers or if rendering failed.
+///
+/// # Safety
+/// `renderer` and `scene` must be null or live handles.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_render(
+    renderer: *mut ArdentRenderer,
+    scene: *mut ArdentScene,
+) -> bool {
+    let (Some(handle), Some(scene)) = (unsafe { renderer.as_mut() }, unsafe { scene.as_mut() })
+    else {
+        return false;
+    };
+    guard(false, || {
+        if handle
+            .renderer
+            .render(&scene.scene, &handle.context)
+            .is_err()
+        {
+            return false;
+        }
+        scene.scene.clear_dirty(handle.renderer.rendered_nodes(0));
+        true
+    })
+}
+
+/// Renders the scene offscreen and copies the frame into `pixels` as
+/// tightly packed 8-bit RGBA rows, top to bottom.
+///
+/// Returns `false` if `len` is smaller than `width * height * 4` of the
+/// render target or the frame could not be read back.
+///
+/// # Safety
+/// `renderer` and `scene` must be null or live handles, and `pixels` must
+/// point to at least `len` writable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_renderer_read_pixels(
+    renderer: *mut ArdentRenderer,
+    scene: *const ArdentScene,
+    pixels: *mut u8,
+    len: usize,
+) -> bool {
+    let (Some(handle), Some(scene)) = (unsafe { renderer.as_mut() }, unsafe { scene.as_ref() })
+    else {
+        return false;
+    };
+    guard(false, || {
+        let (width, height) = handle.context.size;
+        if pixels.is_null() || len < width as usize * height as usize * 4 {
+            return false;
+        }
+        let Ok(frame) = handle
+            .renderer
+            .render_offscreen(&scene.scene, &handle.context)
+        else {
+            return false;
+        };
+        unsafe { ptr::copy_nonoverlapping(frame.as_ptr(), pixels, frame.len()) };
+        true
+    })
+}
@@ -0,0 +1,143 @@
+use std::ptr;
+
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::Scene;
+use ardent_layout::LayoutExt;
+
+use crate::guard;
+
+/// An opaque handle to a scene graph.
+pub struct ArdentScene {
+    pub(crate) scene: Scene,
+}
+
+impl ArdentScene {
+    /// Runs `f` on the node `id`, returning `false` if the scene handle is
+    /// null or the node does not exist.
+    ///
+    /// # Safety
+    /// `scene` must be null or a live handle from [`ardent_scene_new`].
+    pub(crate) unsafe fn with_node(
+        scene: *mut ArdentScene,
+        id: u64,
+        f: impl FnOnce(&mut Node),
+    ) -> bool {
+        let Some(handle) = (unsafe { scene.as_mut() }) else {
+            return false;
+        };
+        guard(false, || match handle.scene.get_node_mut(NodeId(id)) {
+            Some(node) => {
+                f(node);
+                true
+            }
+            None => false,
+        })
+    }
+}
+
+/// Creates an empty scene containing only its root node.
+///
+/// The scene must be released with [`ardent_scene_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn ardent_scene_new() -> *mut ArdentScene {
+    guard(ptr::null_mut(), || {
+        Box::into_raw(Box::new(ArdentScene {
+            scene: Scene::new(),
+        }))
+    })
+}
+
+/// Releases a scene. Null handles are ignored.
+///
+/// # Safety
+/// `scene` must be null or a live handle from [`ardent_scene_new`], and
+/// must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_scene_free(scene: *mut ArdentScene) {
+    if !scene.is_null() {
+        guard((), || drop(unsafe { Box::from_raw(scene) }));
+    }
+}
+
+/// Returns the ID of the scene's root node, or 0 for a null handle.
+///
+/// # Safety
+/// `scene` must be null or a live handle from [`ardent_scene_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_scene_root(scene: *const ArdentScene) -> u64 {
+    let Some(handle) = (unsafe { scene.as_ref() }) else {
+        return 0;
+    };
+    guard(0, || handle.scene.root().0)
+}
+
+/// Adds an empty node under `parent` and returns its ID, or 0 if the
+/// parent does not exist.
+///
+/// # Safety
+/// `scene` must be null or a live handle from [`ardent_scene_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_scene_add_node(scene: *mut ArdentScene, parent: u64) -> u64 {
+    let Some(handle) = (unsafe { scene.as_mut() }) else {
+        return 0;
+    };
+    guard(0, || {
+        handle
+            .scene
+            .spawn(NodeId(parent))
+            .map_or(0, |node| node.id().0)
+    })
+}
+
+/// Removes a node and its subtree. Returns `false` if the node does not
+/// exist. The root node cannot be removed.
+///
+/// # Safety
+/// `scene` must be null or a live handle from [`ardent_scene_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_scene_remove_node(scene: *mut ArdentScene, id: u64) -> bool {
+    let Some(handle) = (unsafe { scene.as_mut() }) else {
+        return false;
+    };
+    guard(false, || handle.scene.remove_node(NodeId(id)).is_ok())
+}
+
+/// Sets the display scale factor layout snaps boxes to, matching
+/// `ardent_renderer_set_scale_factor`.
+///
+/// # Safety
+/// `scene` must be null or a live handle from [`ardent_scene_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_scene_set_scale_factor(
+    scene: *mut ArdentScene,
+    scale_factor: f32,
+) -> bool {
+    let Some(handle) = (unsafe { scene.as_mut() }) else {
+        return false;
+    };
+    guard(false, || {
+        handle.scene.set_scale_factor(scale_factor);
+        true
+    })
+}
+
+/// Lays the scene out for a viewport of the given size in logical pixels
+/// and notifies scene observers. Call once per frame before rendering.
+///
+/// # Safety
+/// `scene` must be null or a live handle from [`ardent_scene_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ardent_scene_layout(
+    scene: *mut ArdentScene,
+    width: f32,
+    height: f32,
+) -> bool {
+    let Some(handle) = (unsafe { scene.as_mut() }) else {
+        return false;
+    };
+    guard(false, || {
+        handle.scene.compute_layout((width, height));
+        handle.scene.notify_observers();
+        true
+    })
+}
@@ -0,0 +1,40 @@
+use wgpu::rwh::{
+    DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle,
+    RawWindowHandle, WindowHandle,
+};
+
+/// A native window given to the library as raw platform handles.
+pub(crate) struct RawWindow {
+    window: RawWindowHandle,
+    display: RawDisplayHandle,
+}
+
+// SAFETY: the handles are only used to create the surface; the host
+// guarantees they stay valid while the renderer lives.
+unsafe impl Send for RawWindow {}
+unsafe impl Sync for RawWindow {}
+
+impl RawWindow {
+    /// Wraps raw window and display handles.
+    ///
+    /// # Safety
+    /// The handles must be valid and outlive every surface created from the
+    /// window.
+    pub(crate) unsafe fn new(window: RawWindowHandle, display: RawDisplayHandle) -> Self {
+        Self { window, display }
+    }
+}
+
+impl HasWindowHandle for RawWindow {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        // SAFETY: validity is guaranteed by the caller of `RawWindow::new`.
+        Ok(unsafe { WindowHandle::borrow_raw(self.window) })
+    }
+}
+
+impl HasDisplayHandle for RawWindow {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        // SAFETY: validity is guaranteed by the caller of `RawWindow::new`.
+        Ok(unsafe { DisplayHandle::borrow_raw(self.display) })
+    }
+}
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use ardent_core::style::ImageData;
+
+use crate::error::ImageError;
+
+/// Decodes an encoded image, detecting its format from the content.
+///
+/// The pixels are converted to 8-bit sRGB with straight alpha.
+pub fn decode(bytes: &[u8]) -> Result<ImageData, ImageError> {
+    let image = image::load_from_memory(bytes)?.into_rgba8();
+    Ok(ImageData {
+        width: image.width(),
+        height: image.height(),
+        pixels: image.into_raw(),
+    })
+}
+
+/// Reads and decodes an image file on the calling thread.
+pub fn open(path: impl AsRef<Path>) -> Result<ImageData, ImageError> {
+    decode(&std::fs::read(path)?)
+}
@@ -0,0 +1,42 @@
+use std::fmt;
+use std::io;
+
+/// An error raised while loading an image.
+#[derive(Debug)]
+pub enum ImageError {
+    /// The file could not be read.
+    Io(io::Error),
+
+    /// The data is not a supported image or is corrupt.
+    Decode(image::ImageError),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Io(err) => write!(f, "failed to read image: {err}"),
+            ImageError::Decode(err) => write!(f, "failed to decode image: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ImageError::Io(err) => Some(err),
+            ImageError::Decode(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    fn from(err: io::Error) -> Self {
+        ImageError::Io(err)
+    }
+}
+
+impl From<image::ImageError> for ImageError {
+    fn from(err: image::ImageError) -> Self {
+        ImageError::Decode(err)
+    }
+}
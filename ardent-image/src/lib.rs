@@ -0,0 +1,27 @@
+//! The `ardent_image` crate loads and decodes raster images for image
+//! fills.
+//!
+//! PNG, JPEG, and WebP files are decoded on background threads by an
+//! [`ImageLoader`]. Loading returns an
+//! [`ImageHandle`](ardent_core::style::ImageHandle) immediately, which can
+//! be put into a fill right away: the fill color serves as a placeholder
+//! until decoding finishes, after which the renderer uploads the pixels on
+//! the next frame.
+//!
+//! ```rust,ignore
+//! let mut loader = ImageLoader::new();
+//! loader.on_loaded(move || proxy.send_event(()).unwrap_or_default());
+//!
+//! node.style_mut().fill = Some(Fill {
+//!     image: Some(loader.load("assets/photo.jpg")),
+//!     ..Fill::solid(Color::rgb(0.9, 0.9, 0.9)) // shown while loading
+//! });
+//! ```
+
+mod decode;
+mod error;
+mod loader;
+
+pub use decode::{decode, open};
+pub use error::ImageError;
+pub use loader::ImageLoader;
@@ -0,0 +1,180 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+
+use ardent_core::style::ImageHandle;
+
+use crate::decode::{decode, open};
+
+/// Upper bound on the number of decoding threads.
+const MAX_WORKERS: usize = 4;
+
+/// A callback invoked on a worker thread whenever an image finished
+/// loading, successfully or not.
+///
+/// Workers clone it out of its mutex before calling it, so a panicking
+/// callback cannot poison the mutex.
+type LoadedCallback = Arc<dyn Fn() + Send + Sync>;
+
+/// Where an image is loaded from.
+enum Source {
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+struct Job {
+    source: Source,
+    handle: ImageHandle,
+}
+
+/// State shared with the worker threads.
+#[derive(Default)]
+struct Shared {
+    /// Number of images queued or being decoded.
+    pending: AtomicUsize,
+    on_loaded: Mutex<Option<LoadedCallback>>,
+}
+
+/// Loads and decodes images on a pool of background threads.
+///
+/// Dropping the loader finishes the queued images before the threads exit.
+pub struct ImageLoader {
+    jobs: Option<Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+    shared: Arc<Shared>,
+}
+
+impl ImageLoader {
+    /// Creates a loader with one thread per core, up to four threads.
+    pub fn new() -> Self {
+        let workers = thread::available_parallelism().map_or(1, |n| n.get());
+        Self::with_workers(workers.min(MAX_WORKERS))
+    }
+
+    /// Creates a loader with the given number of decoding threads.
+    pub fn with_workers(count: usize) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let shared = Arc::new(Shared::default());
+        let workers = (0..count.max(1))
+            .map(|i| {
+                let receiver = receiver.clone();
+                let shared = shared.clone();
+                thread::Builder::new()
+                    .name(format!("ardent-image-{i}"))
+                    .spawn(move || work(&receiver, &shared))
+                    .expect("Failed to spawn image decoding thread")
+            })
+            .collect();
+
+        Self {
+            jobs: Some(sender),
+            workers,
+            shared,
+        }
+    }
+
+    /// Registers a callback invoked whenever an image finished loading.
+    ///
+    /// The callback runs on a worker thread. Use it to wake up the event
+    /// loop and request a redraw, since finished images do not mark nodes
+    /// dirty.
+    pub fn on_loaded<F>(&mut self, callback: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        *self
+            .shared
+            .on_loaded
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(callback));
+    }
+
+    /// Starts loading an image file and returns its handle.
+    pub fn load(&self, path: impl Into<PathBuf>) -> ImageHandle {
+        self.submit(Source::Path(path.into()))
+    }
+
+    /// Starts decoding an image from memory and returns its handle.
+    pub fn load_bytes(&self, bytes: impl Into<Vec<u8>>) -> ImageHandle {
+        self.submit(Source::Bytes(bytes.into()))
+    }
+
+    /// Returns the number of images still loading.
+    pub fn pending(&self) -> usize {
+        self.shared.pending.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if any image is still loading.
+    pub fn is_busy(&self) -> bool {
+        self.pending() > 0
+    }
+
+    fn submit(&self, source: Source) -> ImageHandle {
+        let handle = ImageHandle::pending();
+        self.shared.pending.fetch_add(1, Ordering::AcqRel);
+        let job = Job {
+            source,
+            handle: handle.clone(),
+        };
+        let sent = self
+            .jobs
+            .as_ref()
+            .is_some_and(|jobs| jobs.send(job).is_ok());
+        if !sent {
+            self.shared.pending.fetch_sub(1, Ordering::AcqRel);
+            handle.set_failed("image loader has shut down");
+        }
+        handle
+    }
+}
+
+impl Default for ImageLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ImageLoader {
+    fn drop(&mut self) {
+        // Closing the channel lets the workers exit once the queue is empty.
+        self.jobs = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Decodes queued images until the loader is dropped.
+fn work(receiver: &Mutex<Receiver<Job>>, shared: &Shared) {
+    loop {
+        let job = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        let Ok(Job { source, handle }) = job else {
+            return;
+        };
+
+        let result = match &source {
+            Source::Path(path) => open(path),
+            Source::Bytes(bytes) => decode(bytes),
+        };
+        match result {
+            Ok(data) => handle.set_ready(data),
+            Err(e) => handle.set_failed(e.to_string()),
+        }
+
+        shared.pending.fetch_sub(1, Ordering::AcqRel);
+        let callback = shared
+            .on_loaded
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let Some(callback) = callback {
+            callback();
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! A debug overlay visualizing hit testing.
+
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::{Scene, SceneError};
+use ardent_core::shape::{Rect, Shape};
+use ardent_core::style::{Color, Fill};
+
+use crate::hit::{handler_target, hit_region, hit_test};
+
+/// Thickness of region outlines, in logical pixels.
+const OUTLINE_WIDTH: f32 = 1.0;
+
+/// Tint of the hit region of the node under the pointer.
+const HIT_FILL: Color = Color(0.95, 0.25, 0.3, 0.25);
+
+/// Outline of the node under the pointer.
+const HIT_OUTLINE: Color = Color(0.95, 0.25, 0.3, 0.9);
+
+/// Outline of the node receiving the pointer's events, if not the node
+/// under the pointer itself.
+const HANDLER_OUTLINE: Color = Color(0.3, 0.8, 0.35, 0.9);
+
+/// Outline of the other ancestors of the node under the pointer.
+const ANCESTOR_OUTLINE: Color = Color(0.2, 0.45, 0.95, 0.5);
+
+/// A scene showing how a pointer position is routed through another scene.
+///
+/// The overlay tints the hit region of the topmost node under the pointer
+/// and outlines it, the node its events are delivered to, and every other
+/// ancestor. Ancestors without a shape are outlined by their layout box.
+/// Render it on top of the inspected scene, e.g. with
+/// `Renderer::render_layers`, and call [`update`](Self::update) with the
+/// pointer position after every layout pass.
+///
+/// ```rust,ignore
+/// overlay.update(&scene, dispatcher.position());
+/// renderer.render_layers(&[&scene, overlay.scene()], &context)?;
+/// ```
+pub struct HitOverlay {
+    scene: Scene,
+
+    /// The outermost overlay node, removed on the next update.
+    chain: Option<NodeId>,
+    target: Option<NodeId>,
+}
+
+impl HitOverlay {
+    /// Creates an empty overlay.
+    pub fn new() -> Self {
+        Self {
+            scene: Scene::new(),
+            chain: None,
+            target: None,
+        }
+    }
+
+    /// Returns the overlay scene to render.
+    pub fn scene(&self) -> &Scene {
+        &self.scene
+    }
+
+    /// Returns the overlay scene mutably, e.g. to clear the dirty flags of its
+    /// nodes once they are rendered.
+    pub fn scene_mut(&mut self) -> &mut Scene {
+        &mut self.scene
+    }
+
+    /// Returns the node of the inspected scene found under the pointer in
+    /// the last update.
+    pub fn target(&self) -> Option<NodeId> {
+        self.target
+    }
+
+    /// Shows the nodes of `source` hit at `pointer`, in the root's
+    /// coordinate space. Shows nothing if `pointer` is `None`.
+    pub fn update(&mut self, source: &Scene, pointer: Option<(f32, f32)>) {
+        if let Some(chain) = self.chain.take() {
+            let _ = self.scene.remove_node(chain);
+        }
+        self.target = pointer.and_then(|point| hit_test(source, point));
+        let Some(target) = self.target else {
+            return;
+        };
+        let handler = handler_target(source, target);
+
+        let mut ancestors = Vec::new();
+        let mut current = Some(target);
+        while let Some(id) = current {
+            let Some(node) = source.get_node(id) else {
+                return;
+            };
+            ancestors.push((id, node));
+            current = node.parent();
+        }
+
+        let mut parent = self.scene.root();
+        for (id, node) in ancestors.into_iter().rev() {
+            let outline = if id == target {
+                HIT_OUTLINE
+            } else if Some(id) == handler {
+                HANDLER_OUTLINE
+            } else {
+                ANCESTOR_OUTLINE
+            };
+            match mirror(&mut self.scene, parent, node, id == target, outline) {
+                Ok(group) => {
+                    self.chain.get_or_insert(group);
+                    parent = group;
+                }
+                Err(_) => return,
+            }
+        }
+    }
+}
+
+impl Default for HitOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adds an overlay group carrying the node's transform, with the outline
+/// of its region and, for the hit node, a tint over it. Returns the group.
+fn mirror(
+    scene: &mut Scene,
+    parent: NodeId,
+    node: &Node,
+    hit: bool,
+    outline: Color,
+) -> Result<NodeId, SceneError> {
+    let mut group = Node::new();
+    *group.transform_mut() = node.transform().clone();
+    let group = scene.add_node(parent, group)?;
+
+    let layout = node.computed_layout();
+    let Some(((x, y), (max_x, max_y))) = hit_region(node)
+        .or_else(|| (layout.width > 0.0).then_some(((0.0, 0.0), (layout.width, layout.height))))
+    else {
+        return Ok(group);
+    };
+    let (width, height) = (max_x - x, max_y - y);
+    let line = OUTLINE_WIDTH;
+
+    if hit {
+        band(scene, group, (x, y, width, height), HIT_FILL)?;
+    }
+    for area in [
+        (x, y, width, line),
+        (x, max_y - line, width, line),
+        (x, y, line, height),
+        (max_x - line, y, line, height),
+    ] {
+        band(scene, group, area, outline)?;
+    }
+    Ok(group)
+}
+
+/// Adds a filled rectangle at `(x, y, width, height)`.
+fn band(
+    scene: &mut Scene,
+    parent: NodeId,
+    (x, y, width, height): (f32, f32, f32, f32),
+    color: Color,
+) -> Result<NodeId, SceneError> {
+    let mut band = Node::new();
+    band.transform_mut().translate = (x, y);
+    band.set_shape(Shape::Rect(Rect::new(width.max(0.0), height.max(0.0))));
+    band.style_mut().fill = Some(Fill::solid(color));
+    scene.add_node(parent, band)
+}
@@ -0,0 +1,169 @@
+use ardent_core::event::Event;
+use ardent_core::node::NodeId;
+use ardent_core::scene::Scene;
+
+use crate::hit::{handler_target, hit_test};
+
+/// Distance in logical pixels the pointer must move while pressed before a
+/// press turns into a drag.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// A pointer press that has not been released yet.
+struct Press {
+    /// The node receiving the click or drag, if any.
+    target: Option<NodeId>,
+
+    /// Where the pointer was pressed.
+    origin: (f32, f32),
+
+    /// Whether the pointer moved far enough to start a drag.
+    dragging: bool,
+}
+
+/// Routes pointer input to the event handlers of the nodes under it.
+///
+/// Events go to the hit node or, if it has no handler, to its nearest
+/// ancestor with one, so a button made of several shapes handles clicks on
+/// any of them. The dispatcher keeps track of the hovered node to send
+/// [`Event::PointerEnter`] and [`Event::PointerLeave`], and turns presses
+/// into either a click or, once the pointer moves a few pixels, a drag.
+///
+/// Positions are in logical pixels in the root's coordinate space.
+#[derive(Default)]
+pub struct Dispatcher {
+    /// The last known pointer position, `None` outside the window.
+    position: Option<(f32, f32)>,
+
+    /// The node currently receiving hover events.
+    hovered: Option<NodeId>,
+
+    /// The active press, if a button is held.
+    press: Option<Press>,
+}
+
+impl Dispatcher {
+    /// Creates a dispatcher with the pointer outside the scene.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the last known pointer position.
+    pub fn position(&self) -> Option<(f32, f32)> {
+        self.position
+    }
+
+    /// Returns the node currently hovered by the pointer.
+    pub fn hovered(&self) -> Option<NodeId> {
+        self.hovered
+    }
+
+    /// Moves the pointer to `position`, updating hover state and driving
+    /// an active drag.
+    pub fn pointer_moved(&mut self, scene: &Scene, position: (f32, f32)) {
+        let previous = self.position.replace(position);
+        self.refresh(scene);
+
+        let Some(press) = &mut self.press else {
+            return;
+        };
+        let Some(target) = press.target else {
+            return;
+        };
+        let delta = if press.dragging {
+            let previous = previous.unwrap_or(position);
+            (position.0 - previous.0, position.1 - previous.1)
+        } else {
+            let delta = (position.0 - press.origin.0, position.1 - press.origin.1);
+            if delta.0.hypot(delta.1) < DRAG_THRESHOLD {
+                return;
+            }
+            press.dragging = true;
+            send(scene, target, Event::DragStart);
+            delta
+        };
+        send(scene, target, Event::DragUpdate(delta.0, delta.1));
+    }
+
+    /// Records that the pointer left the window, ending hover.
+    pub fn pointer_left(&mut self, scene: &Scene) {
+        self.position = None;
+        self.refresh(scene);
+    }
+
+    /// Starts a press at the current pointer position.
+    pub fn pointer_pressed(&mut self, scene: &Scene) {
+        let Some(position) = self.position else {
+            return;
+        };
+        self.press = Some(Press {
+            target: target_at(scene, position),
+            origin: position,
+            dragging: false,
+        });
+    }
+
+    /// Ends the active press. Sends [`Event::DragEnd`] after a drag, or
+    /// [`Event::Click`] if the pointer is still over the pressed node.
+    pub fn pointer_released(&mut self, scene: &Scene) {
+        let Some(Press {
+            target: Some(target),
+            dragging,
+            ..
+        }) = self.press.take()
+        else {
+            return;
+        };
+        if dragging {
+            send(scene, target, Event::DragEnd);
+        } else if self
+            .position
+            .and_then(|position| target_at(scene, position))
+            == Some(target)
+        {
+            send(scene, target, Event::Click);
+        }
+    }
+
+    /// Sends a scroll of `delta` logical pixels to the node under the
+    /// pointer.
+    ///
+    /// Returns the hit node, whether or not it handled the event, so
+    /// callers can also scroll the containers around it.
+    pub fn scroll(&mut self, scene: &Scene, delta: (f32, f32)) -> Option<NodeId> {
+        let hit = hit_test(scene, self.position?)?;
+        if let Some(target) = handler_target(scene, hit) {
+            send(scene, target, Event::Scroll(delta.0, delta.1));
+        }
+        Some(hit)
+    }
+
+    /// Updates hover state for the current pointer position, e.g. after
+    /// the scene was laid out again or nodes moved under the pointer.
+    pub fn refresh(&mut self, scene: &Scene) {
+        let hovered = self
+            .position
+            .and_then(|position| target_at(scene, position));
+        if hovered == self.hovered {
+            return;
+        }
+        if let Some(previous) = self.hovered {
+            send(scene, previous, Event::PointerLeave);
+        }
+        if let Some(hovered) = hovered {
+            send(scene, hovered, Event::PointerEnter);
+        }
+        self.hovered = hovered;
+    }
+}
+
+/// Returns the node receiving events for a hit at `position`.
+fn target_at(scene: &Scene, position: (f32, f32)) -> Option<NodeId> {
+    hit_test(scene, position).and_then(|hit| handler_target(scene, hit))
+}
+
+/// Delivers `event` to the node `id`, if it still exists.
+fn send(scene: &Scene, id: NodeId, event: Event) {
+    if let Some(node) = scene.get_node(id) {
+        node.handle_event(event);
+    }
+}
@@ -0,0 +1,65 @@
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::Scene;
+
+/// Returns the topmost node whose shape contains `point`, given in the
+/// root's coordinate space.
+///
+/// Nodes are tested in reverse draw order, so later children win over
+/// earlier ones and children over their parents. Content clipped away by a
+/// container cannot be hit. Closed shapes are tested against their outline
+/// with the node's fill rule, lines against their bounding box.
+pub fn hit_test(scene: &Scene, point: (f32, f32)) -> Option<NodeId> {
+    hit_node(scene, scene.root(), point)
+}
+
+/// Returns the node that receives events for a hit on `id`: the node itself
+/// or its nearest ancestor with an event handler.
+pub fn handler_target(scene: &Scene, id: NodeId) -> Option<NodeId> {
+    let mut current = Some(id);
+    while let Some(id) = current {
+        let node = scene.get_node(id)?;
+        if node.has_event_handler() {
+            return Some(id);
+        }
+        current = node.parent();
+    }
+    None
+}
+
+fn hit_node(scene: &Scene, id: NodeId, point: (f32, f32)) -> Option<NodeId> {
+    let node = scene.get_node(id)?;
+    let local = node.transform().to_local(point)?;
+    if node.layout().clips() && !in_box(node, local) {
+        return None;
+    }
+    node.children()
+        .iter()
+        .rev()
+        .find_map(|&child| hit_node(scene, child, local))
+        .or_else(|| contains(node, local).then_some(id))
+}
+
+/// Returns `true` if the node's shape contains the local point.
+fn contains(node: &Node, (x, y): (f32, f32)) -> bool {
+    match node.shape() {
+        Some(shape) if !shape.is_open() => {
+            let rule = node.style().fill.as_ref().map(|fill| fill.rule);
+            shape.contains((x, y), rule.unwrap_or_default())
+        }
+        _ => hit_region(node).is_some_and(|((min_x, min_y), (max_x, max_y))| {
+            (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y)
+        }),
+    }
+}
+
+/// Returns the minimum and maximum corner of the local box in which the
+/// node can be hit, or `None` if it has no shape.
+pub(crate) fn hit_region(node: &Node) -> Option<((f32, f32), (f32, f32))> {
+    node.shape()?.bounds()
+}
+
+/// Returns `true` if the local point lies in the node's layout box.
+fn in_box(node: &Node, (x, y): (f32, f32)) -> bool {
+    let layout = node.computed_layout();
+    (0.0..=layout.width).contains(&x) && (0.0..=layout.height).contains(&y)
+}
@@ -1,14 +1,30 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! The `ardent_input` crate turns raw pointer input into node events.
+//!
+//! [`hit_test`] finds the node under a point, and [`Dispatcher`] tracks the
+//! pointer across frames to deliver clicks, hover changes, scrolling, and
+//! drags to the nodes' event handlers. Windowing backends feed it positions
+//! and button state in logical pixels:
+//!
+//! ```rust,ignore
+//! let mut dispatcher = Dispatcher::new();
+//! dispatcher.pointer_moved(&scene, (x, y));
+//! dispatcher.pointer_pressed(&scene);
+//! dispatcher.pointer_released(&scene);
+//! ```
+//!
+//! [`HitOverlay`] draws the node under the pointer, its hit region, and its
+//! ancestors on top of the scene, to diagnose events going to the wrong
+//! node.
+//!
+//! [`Tooltips`] shows a node's tooltip once the pointer has rested on it,
+//! in a scene of its own drawn on top of everything else.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+mod debug;
+mod dispatch;
+mod hit;
+mod tooltip;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use debug::HitOverlay;
+pub use dispatch::Dispatcher;
+pub use hit::{handler_target, hit_test};
+pub use tooltip::Tooltips;
@@ -0,0 +1,257 @@
+//! Tooltips shown while the pointer rests on a node.
+
+use std::time::{Duration, Instant};
+
+use ardent_core::node::{NodeId, Role};
+use ardent_core::scene::{NodeBuilder, Scene};
+use ardent_core::style::Color;
+
+use crate::hit::hit_test;
+
+/// How long the pointer has to rest on a node before its tooltip shows.
+const DEFAULT_DELAY: Duration = Duration::from_millis(500);
+
+/// Offset of a tooltip from the pointer, clear of a typical cursor.
+const CURSOR_OFFSET: (f32, f32) = (12.0, 18.0);
+
+/// Smallest distance between a tooltip and the edges of the viewport.
+const EDGE_MARGIN: f32 = 4.0;
+
+/// Space between the edge of a tooltip and its text.
+const PADDING: (f32, f32) = (8.0, 4.0);
+
+/// Estimated average advance of a glyph, until text can be measured.
+const CHAR_WIDTH: f32 = 7.0;
+
+/// Height of a line of tooltip text.
+const LINE_HEIGHT: f32 = 16.0;
+
+/// Background of the tooltip bubble.
+const BACKGROUND: Color = Color(0.12, 0.12, 0.14, 0.95);
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    /// The pointer is not over a node with a tooltip.
+    Idle,
+
+    /// The pointer rests on `owner`, whose tooltip shows at `deadline`.
+    Waiting { owner: NodeId, deadline: Instant },
+
+    /// The tooltip of `owner` is shown.
+    Shown { owner: NodeId },
+
+    /// The tooltip of `owner` was dismissed and stays hidden until the
+    /// pointer leaves the node.
+    Dismissed { owner: NodeId },
+}
+
+/// Shows the [tooltip](ardent_core::node::Node::tooltip) of the node under
+/// the pointer after the pointer has rested on it for a while.
+///
+/// The tooltip belongs to the node under the pointer or its nearest
+/// ancestor with one. Moving the pointer within that node restarts the
+/// delay; leaving it hides the tooltip. Once shown, the tooltip stays in
+/// place below and to the right of where the pointer rested, moved inside
+/// the viewport if it would cross an edge.
+///
+/// Tooltips are drawn into a scene of their own, to be rendered on top of
+/// everything else. Call [`update`](Self::update) before every frame, and
+/// wake up for [`next_change`](Self::next_change) so the tooltip appears
+/// even if the pointer stays still.
+///
+/// ```rust,ignore
+/// tooltips.update(&scene, dispatcher.position(), viewport, Instant::now());
+/// renderer.render_layers(&[&scene, tooltips.scene()], &context)?;
+/// ```
+///
+/// Text rendering is not available yet: the bubble is sized for its text
+/// and carries it as its label, but the text itself is not drawn.
+pub struct Tooltips {
+    scene: Scene,
+    delay: Duration,
+    state: State,
+
+    /// The pointer position in the last update.
+    pointer: Option<(f32, f32)>,
+
+    /// The shown tooltip bubble.
+    bubble: Option<NodeId>,
+}
+
+impl Tooltips {
+    /// Creates a controller showing tooltips after half a second.
+    pub fn new() -> Self {
+        Self {
+            scene: Scene::new(),
+            delay: DEFAULT_DELAY,
+            state: State::Idle,
+            pointer: None,
+            bubble: None,
+        }
+    }
+
+    /// Sets how long the pointer has to rest on a node before its tooltip
+    /// shows.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the tooltip scene to render.
+    pub fn scene(&self) -> &Scene {
+        &self.scene
+    }
+
+    /// Returns the tooltip scene mutably, e.g. to clear the dirty flags of its
+    /// nodes once they are rendered.
+    pub fn scene_mut(&mut self) -> &mut Scene {
+        &mut self.scene
+    }
+
+    /// Returns the node whose tooltip is shown, if any.
+    pub fn owner(&self) -> Option<NodeId> {
+        match self.state {
+            State::Shown { owner } => Some(owner),
+            _ => None,
+        }
+    }
+
+    /// Returns when a tooltip is due to show, if the pointer is resting on
+    /// a node with one.
+    pub fn next_change(&self) -> Option<Instant> {
+        match self.state {
+            State::Waiting { deadline, .. } => Some(deadline),
+            _ => None,
+        }
+    }
+
+    /// Hides the shown or pending tooltip until the pointer leaves its
+    /// node, e.g. when the pointer is pressed.
+    pub fn dismiss(&mut self) {
+        if let State::Waiting { owner, .. } | State::Shown { owner } = self.state {
+            self.hide();
+            self.state = State::Dismissed { owner };
+        }
+    }
+
+    /// Tracks the pointer over `source`, showing or hiding the tooltip as
+    /// due at `now`.
+    ///
+    /// `pointer` is given in the root's coordinate space, or `None` if the
+    /// pointer is outside the window. `viewport` is the logical size of the
+    /// window, which the tooltip is kept within.
+    pub fn update(
+        &mut self,
+        source: &Scene,
+        pointer: Option<(f32, f32)>,
+        viewport: (f32, f32),
+        now: Instant,
+    ) {
+        let moved = pointer != self.pointer;
+        self.pointer = pointer;
+        let owner = pointer
+            .and_then(|point| hit_test(source, point))
+            .and_then(|hit| tooltip_owner(source, hit));
+        let Some(owner) = owner else {
+            self.hide();
+            self.state = State::Idle;
+            return;
+        };
+
+        let state = match self.state {
+            State::Shown { owner: shown } | State::Dismissed { owner: shown } if shown == owner => {
+                return;
+            }
+            State::Waiting {
+                owner: waiting,
+                deadline,
+            } if waiting == owner && !moved => State::Waiting { owner, deadline },
+            _ => State::Waiting {
+                owner,
+                deadline: now + self.delay,
+            },
+        };
+        self.hide();
+        self.state = state;
+
+        if let State::Waiting { owner, deadline } = state
+            && deadline <= now
+            && let Some(pointer) = pointer
+            && let Some(text) = source.get_node(owner).and_then(|node| node.tooltip())
+        {
+            self.show(text, pointer, viewport);
+            self.state = State::Shown { owner };
+        }
+    }
+
+    /// Adds the bubble showing `text` near `pointer`.
+    fn show(&mut self, text: &str, pointer: (f32, f32), viewport: (f32, f32)) {
+        let size = bubble_size(text);
+        let (x, y) = place(pointer, size, viewport);
+        let root = self.scene.root();
+        self.bubble = NodeBuilder::rect()
+            .size(size.0, size.1)
+            .translate(x, y)
+            .fill(BACKGROUND)
+            .role(Role::Label)
+            .label(text)
+            .build(&mut self.scene, root)
+            .ok();
+    }
+
+    /// Removes the bubble, if shown.
+    fn hide(&mut self) {
+        if let Some(bubble) = self.bubble.take() {
+            let _ = self.scene.remove_node(bubble);
+        }
+    }
+}
+
+impl Default for Tooltips {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the node providing the tooltip for a hit on `id`: the node
+/// itself or its nearest ancestor with a tooltip.
+fn tooltip_owner(scene: &Scene, id: NodeId) -> Option<NodeId> {
+    let mut current = Some(id);
+    while let Some(id) = current {
+        let node = scene.get_node(id)?;
+        if node.tooltip().is_some() {
+            return Some(id);
+        }
+        current = node.parent();
+    }
+    None
+}
+
+/// Estimates the size of the bubble showing `text`, one line per line
+/// break.
+fn bubble_size(text: &str) -> (f32, f32) {
+    let (lines, longest) = text.lines().fold((0, 0), |(lines, longest), line| {
+        (lines + 1, longest.max(line.chars().count()))
+    });
+    (
+        longest as f32 * CHAR_WIDTH + 2.0 * PADDING.0,
+        lines.max(1) as f32 * LINE_HEIGHT + 2.0 * PADDING.1,
+    )
+}
+
+/// Positions a bubble of `size` below and to the right of `pointer`.
+///
+/// A bubble that would cross the right edge of the viewport is moved left
+/// until it fits; one that would cross the bottom edge is shown above the
+/// pointer instead, so it does not cover what the pointer points at.
+fn place(pointer: (f32, f32), size: (f32, f32), viewport: (f32, f32)) -> (f32, f32) {
+    let mut x = pointer.0 + CURSOR_OFFSET.0;
+    let mut y = pointer.1 + CURSOR_OFFSET.1;
+    if x + size.0 > viewport.0 - EDGE_MARGIN {
+        x = viewport.0 - EDGE_MARGIN - size.0;
+    }
+    if y + size.1 > viewport.1 - EDGE_MARGIN {
+        y = pointer.1 - EDGE_MARGIN - size.1;
+    }
+    (x.max(EDGE_MARGIN), y.max(EDGE_MARGIN))
+}
@@ -0,0 +1,76 @@
+//! Placement of items within the areas assigned by their containers.
+
+use ardent_core::layout::{Align, ComputedLayout, Edges, LayoutStyle};
+
+use crate::pass::{aspect_ratio, clamp};
+
+/// Sizes and positions an item of `preferred` size within an area.
+///
+/// The item keeps its margins free. Stretched items without an explicit
+/// size fill the rest of the area along that axis, within their min/max
+/// constraints; an aspect ratio takes precedence over stretching the height.
+/// `available` is the container's content size, for resolving constraints.
+pub(crate) fn fit(
+    style: &LayoutStyle,
+    preferred: (f32, f32),
+    area: ComputedLayout,
+    (justify, align): (Align, Align),
+    available: (Option<f32>, Option<f32>),
+    viewport: (f32, f32),
+) -> ComputedLayout {
+    let Edges {
+        top,
+        right,
+        bottom,
+        left,
+    } = style.margin;
+    let inner = (
+        (area.width - left - right).max(0.0),
+        (area.height - top - bottom).max(0.0),
+    );
+
+    let mut width = match (justify, style.width.is_auto()) {
+        (Align::Stretch, true) => clamp(
+            inner.0,
+            style.min_width,
+            style.max_width,
+            available.0,
+            viewport,
+        ),
+        _ => preferred.0,
+    };
+    let mut height = match (align, style.height.is_auto()) {
+        (Align::Stretch, true) => clamp(
+            inner.1,
+            style.min_height,
+            style.max_height,
+            available.1,
+            viewport,
+        ),
+        _ => preferred.1,
+    };
+    if let Some(ratio) = aspect_ratio(style) {
+        if style.height.is_auto() {
+            height = width / ratio;
+        } else if style.width.is_auto() {
+            width = height * ratio;
+        }
+    }
+
+    ComputedLayout {
+        x: area.x + left + offset(inner.0, width, justify),
+        y: area.y + top + offset(inner.1, height, align),
+        width,
+        height,
+    }
+}
+
+/// Returns the offset of an item of the given size within an area along
+/// one axis.
+fn offset(area: f32, size: f32, align: Align) -> f32 {
+    match align {
+        Align::Start | Align::Stretch => 0.0,
+        Align::Center => (area - size) / 2.0,
+        Align::End => area - size,
+    }
+}
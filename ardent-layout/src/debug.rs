@@ -0,0 +1,197 @@
+//! A debug overlay visualizing computed layout.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use ardent_core::layout::{ComputedLayout, Edges};
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::{Scene, SceneError};
+use ardent_core::shape::{Rect, Shape};
+use ardent_core::style::{Color, Fill};
+
+/// Thickness of layout box outlines, in logical pixels.
+const OUTLINE_WIDTH: f32 = 1.0;
+
+/// Overlay nodes mirroring one node of the inspected scene.
+struct Mirror {
+    /// Carries the inspected node's transform; all bands are its children.
+    group: NodeId,
+
+    /// Margin, padding, and outline bands, four of each.
+    bands: [NodeId; 12],
+}
+
+/// A scene drawing the computed layout of another scene.
+///
+/// For every node, the overlay outlines the layout box and tints the margin
+/// and padding regions, like the box model view of browser developer tools.
+/// Text baselines are not shown yet, since no content reports one.
+/// Render it on top of the inspected scene, e.g. with
+/// `Renderer::render_layers`, and call [`update`](Self::update) after every
+/// layout pass. Overlay nodes are reused across updates, so unchanged
+/// layouts cost no re-tessellation.
+///
+/// ```rust,ignore
+/// overlay.update(&scene);
+/// renderer.render_layers(&[&scene, overlay.scene()], &context)?;
+/// ```
+pub struct LayoutOverlay {
+    scene: Scene,
+    mirrors: HashMap<NodeId, Mirror>,
+}
+
+impl LayoutOverlay {
+    /// Creates an empty overlay.
+    pub fn new() -> Self {
+        Self {
+            scene: Scene::new(),
+            mirrors: HashMap::new(),
+        }
+    }
+
+    /// Returns the overlay scene to render.
+    pub fn scene(&self) -> &Scene {
+        &self.scene
+    }
+
+    /// Returns the overlay scene mutably, e.g. to clear the dirty flags of its
+    /// nodes once they are rendered.
+    pub fn scene_mut(&mut self) -> &mut Scene {
+        &mut self.scene
+    }
+
+    /// Synchronizes the overlay with the layout of `source`.
+    pub fn update(&mut self, source: &Scene) {
+        let mut live = HashSet::new();
+        let root = self.scene.root();
+        self.sync(source, source.root(), root, &mut live);
+
+        let stale: Vec<NodeId> = self
+            .mirrors
+            .keys()
+            .filter(|id| !live.contains(id))
+            .copied()
+            .collect();
+        for id in stale {
+            // Mirrors of removed subtrees are already gone with their
+            // parent's mirror.
+            if let Some(mirror) = self.mirrors.remove(&id) {
+                let _ = self.scene.remove_node(mirror.group);
+            }
+        }
+    }
+
+    /// Mirrors a node and its subtree under the given overlay parent.
+    fn sync(&mut self, source: &Scene, id: NodeId, parent: NodeId, live: &mut HashSet<NodeId>) {
+        let Some(node) = source.get_node(id) else {
+            return;
+        };
+        live.insert(id);
+
+        let mirror = match self.mirrors.entry(id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match create_mirror(&mut self.scene, parent) {
+                Ok(mirror) => entry.insert(mirror),
+                Err(_) => return,
+            },
+        };
+        let group = mirror.group;
+        let bands = mirror.bands;
+
+        if let Some(overlay) = self.scene.get_node_mut(group)
+            && overlay.transform() != node.transform()
+        {
+            *overlay.transform_mut() = node.transform().clone();
+        }
+        for (band, area) in bands.iter().zip(band_areas(node)) {
+            if let Some(overlay) = self.scene.get_node_mut(*band) {
+                set_area(overlay, area);
+            }
+        }
+
+        for &child in node.children() {
+            self.sync(source, child, group, live);
+        }
+    }
+}
+
+impl Default for LayoutOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adds the overlay nodes for one inspected node.
+fn create_mirror(scene: &mut Scene, parent: NodeId) -> Result<Mirror, SceneError> {
+    let group = scene.add_node(parent, Node::new())?;
+
+    let colors = [
+        Color::rgba(0.97, 0.65, 0.2, 0.35),
+        Color::rgba(0.45, 0.8, 0.35, 0.35),
+        Color::rgba(0.2, 0.45, 0.95, 0.9),
+    ];
+    let mut bands = [group; 12];
+    for (i, id) in bands.iter_mut().enumerate() {
+        let mut band = Node::new();
+        band.style_mut().fill = Some(Fill::solid(colors[i / 4]));
+        *id = scene.add_node(group, band)?;
+    }
+
+    Ok(Mirror { group, bands })
+}
+
+/// Returns the margin, padding, and outline bands of a node, in the node's
+/// local coordinates.
+fn band_areas(node: &Node) -> [ComputedLayout; 12] {
+    let ComputedLayout { width, height, .. } = *node.computed_layout();
+    let style = node.layout();
+    let Edges {
+        top,
+        right,
+        bottom,
+        left,
+    } = style.margin;
+    let outer_width = width + left + right;
+    let inner = style.padding;
+    let inner_height = height - inner.top - inner.bottom;
+    let line = OUTLINE_WIDTH;
+
+    [
+        // Margin
+        area(-left, -top, outer_width, top),
+        area(-left, height, outer_width, bottom),
+        area(-left, 0.0, left, height),
+        area(width, 0.0, right, height),
+        // Padding
+        area(0.0, 0.0, width, inner.top),
+        area(0.0, height - inner.bottom, width, inner.bottom),
+        area(0.0, inner.top, inner.left, inner_height),
+        area(width - inner.right, inner.top, inner.right, inner_height),
+        // Outline
+        area(0.0, 0.0, width, line),
+        area(0.0, height - line, width, line),
+        area(0.0, 0.0, line, height),
+        area(width - line, 0.0, line, height),
+    ]
+}
+
+fn area(x: f32, y: f32, width: f32, height: f32) -> ComputedLayout {
+    ComputedLayout {
+        x,
+        y,
+        width: width.max(0.0),
+        height: height.max(0.0),
+    }
+}
+
+/// Moves and resizes an overlay band, touching only what changed.
+fn set_area(band: &mut Node, area: ComputedLayout) {
+    if band.transform().translate != (area.x, area.y) {
+        band.transform_mut().translate = (area.x, area.y);
+    }
+    let rect = Rect::new(area.width, area.height);
+    match band.shape() {
+        Some(Shape::Rect(current)) if *current == rect => {}
+        _ => band.set_shape(Shape::Rect(rect)),
+    }
+}
@@ -0,0 +1,374 @@
+//! Grid container layout, modeled after CSS grid.
+
+use ardent_core::layout::{
+    Align, AvailableSpace, ComputedLayout, GridLayout, GridPlacement, LayoutStyle, TrackSize,
+};
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::Scene;
+
+use crate::align::fit;
+use crate::pass::measure_in;
+
+/// Most tracks an explicit position or span may reach past the explicit
+/// tracks along either axis, so oversized placements cannot allocate
+/// without bound.
+const MAX_IMPLICIT_TRACKS: usize = 1000;
+
+/// An item with its resolved cell area.
+struct Item<'a> {
+    id: NodeId,
+    node: &'a Node,
+    style: &'a LayoutStyle,
+    column: usize,
+    column_span: usize,
+    row: usize,
+    row_span: usize,
+    preferred: (f32, f32),
+    justify: Align,
+    align: Align,
+}
+
+impl Item<'_> {
+    /// Returns the preferred size including margins.
+    fn outer_size(&self) -> (f32, f32) {
+        let margin = self.style.margin;
+        (
+            self.preferred.0 + margin.horizontal(),
+            self.preferred.1 + margin.vertical(),
+        )
+    }
+}
+
+/// Items placed into resolved tracks.
+struct Tracks<'a> {
+    items: Vec<Item<'a>>,
+    columns: Vec<f32>,
+    rows: Vec<f32>,
+}
+
+/// Returns the size a grid needs to fit its children, excluding the
+/// container's padding.
+///
+/// Along axes where the available size is unknown, flexible tracks are sized
+/// like auto tracks.
+pub(crate) fn measure(
+    scene: &Scene,
+    layout: &GridLayout,
+    children: &[NodeId],
+    available: (Option<f32>, Option<f32>),
+    viewport: (f32, f32),
+) -> (f32, f32) {
+    let tracks = resolve(scene, layout, children, available, viewport);
+    (
+        total(&tracks.columns, layout.column_gap),
+        total(&tracks.rows, layout.row_gap),
+    )
+}
+
+/// Assigns a box to every child of a grid container whose content box has
+/// the given size.
+pub(crate) fn arrange(
+    scene: &Scene,
+    layout: &GridLayout,
+    children: &[NodeId],
+    size: (f32, f32),
+    viewport: (f32, f32),
+) -> Vec<(NodeId, ComputedLayout)> {
+    let available = (Some(size.0), Some(size.1));
+    let tracks = resolve(scene, layout, children, available, viewport);
+    let column_offsets = offsets(&tracks.columns, layout.column_gap);
+    let row_offsets = offsets(&tracks.rows, layout.row_gap);
+
+    tracks
+        .items
+        .iter()
+        .map(|item| {
+            let area = ComputedLayout {
+                x: column_offsets[item.column],
+                y: row_offsets[item.row],
+                width: span_size(
+                    &tracks.columns,
+                    item.column,
+                    item.column_span,
+                    layout.column_gap,
+                ),
+                height: span_size(&tracks.rows, item.row, item.row_span, layout.row_gap),
+            };
+            let alignment = (item.justify, item.align);
+            let layout = fit(
+                item.style,
+                item.preferred,
+                area,
+                alignment,
+                available,
+                viewport,
+            );
+            (item.id, layout)
+        })
+        .collect()
+}
+
+/// Places the children and resolves track sizes for the available size.
+fn resolve<'a>(
+    scene: &'a Scene,
+    layout: &GridLayout,
+    children: &[NodeId],
+    available: (Option<f32>, Option<f32>),
+    viewport: (f32, f32),
+) -> Tracks<'a> {
+    let columns = children
+        .iter()
+        .filter_map(|&id| scene.get_node(id))
+        .filter_map(|node| {
+            let (start, span) = clamp(node.layout().grid_column, column_limit(layout));
+            start.map(|start| start + span)
+        })
+        .fold(layout.columns.len().max(1), usize::max);
+
+    let items = place(scene, layout, children, columns, available, viewport);
+    let rows = items
+        .iter()
+        .map(|item| item.row.saturating_add(item.row_span))
+        .fold(layout.rows.len(), usize::max);
+
+    let column_tracks: Vec<TrackSize> = (0..columns)
+        .map(|i| layout.columns.get(i).copied().unwrap_or(TrackSize::Auto))
+        .collect();
+    let row_tracks: Vec<TrackSize> = (0..rows)
+        .map(|i| layout.rows.get(i).copied().unwrap_or(layout.auto_rows))
+        .collect();
+
+    let columns = resolve_tracks(
+        &column_tracks,
+        available.0,
+        layout.column_gap,
+        items
+            .iter()
+            .filter(|item| item.column_span == 1)
+            .map(|item| (item.column, item.outer_size().0)),
+    );
+
+    // Content like wrapping text gets taller as it gets narrower, so measure
+    // heights again now that column widths are known.
+    let mut items = items;
+    for item in &mut items {
+        let area = span_size(&columns, item.column, item.column_span, layout.column_gap)
+            - item.style.margin.horizontal();
+        let width = match item.justify {
+            Align::Stretch => area,
+            _ => item.preferred.0.min(area),
+        };
+        let space = AvailableSpace::Definite(width.max(0.0));
+        item.preferred.1 = measure_in(scene, item.node, available, space, viewport).1;
+    }
+
+    let rows = resolve_tracks(
+        &row_tracks,
+        available.1,
+        layout.row_gap,
+        items
+            .iter()
+            .filter(|item| item.row_span == 1)
+            .map(|item| (item.row, item.outer_size().1)),
+    );
+
+    Tracks {
+        items,
+        columns,
+        rows,
+    }
+}
+
+/// Places every child into grid cells.
+///
+/// Items with explicit positions are honored; the rest are auto-placed into
+/// the next free area in row-major order. Rows grow as needed. Positions and
+/// spans reaching more than [`MAX_IMPLICIT_TRACKS`] tracks past the explicit
+/// ones are pulled back within them.
+fn place<'a>(
+    scene: &'a Scene,
+    layout: &GridLayout,
+    children: &[NodeId],
+    columns: usize,
+    available: (Option<f32>, Option<f32>),
+    viewport: (f32, f32),
+) -> Vec<Item<'a>> {
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let mut cursor = (0, 0);
+    let mut items = Vec::new();
+
+    for &id in children {
+        let Some(node) = scene.get_node(id) else {
+            continue;
+        };
+        let style = node.layout();
+        let (column_start, column_span) = clamp(style.grid_column, column_limit(layout));
+        let (row_start, row_span) = clamp(style.grid_row, layout.rows.len() + MAX_IMPLICIT_TRACKS);
+        let column_span = column_span.min(columns);
+
+        let (row, column) = match (row_start, column_start) {
+            (Some(row), Some(column)) => (row, column),
+            (None, Some(column)) => {
+                let row = (0..)
+                    .find(|&r| is_free(&occupied, r, column, row_span, column_span))
+                    .unwrap_or_default();
+                (row, column)
+            }
+            (Some(row), None) => {
+                let column = (0..=columns - column_span)
+                    .find(|&c| is_free(&occupied, row, c, row_span, column_span))
+                    .unwrap_or(0);
+                (row, column)
+            }
+            (None, None) => {
+                let (mut r, mut c) = cursor;
+                while c + column_span > columns || !is_free(&occupied, r, c, row_span, column_span)
+                {
+                    c += 1;
+                    if c + column_span > columns {
+                        c = 0;
+                        r += 1;
+                    }
+                }
+                cursor = (r, c + column_span);
+                (r, c)
+            }
+        };
+
+        occupy(&mut occupied, row, column, row_span, column_span);
+        let space = match layout.columns.get(column) {
+            Some(TrackSize::MinContent) if column_span == 1 => AvailableSpace::MinContent,
+            _ => AvailableSpace::MaxContent,
+        };
+        items.push(Item {
+            id,
+            node,
+            style,
+            column,
+            column_span,
+            row,
+            row_span,
+            preferred: measure_in(scene, node, available, space, viewport),
+            justify: style.justify_self.unwrap_or(layout.justify_items),
+            align: style.align_self.unwrap_or(layout.align_items),
+        });
+    }
+
+    items
+}
+
+/// Returns the most columns a grid creates.
+fn column_limit(layout: &GridLayout) -> usize {
+    layout.columns.len().max(1) + MAX_IMPLICIT_TRACKS
+}
+
+/// Returns the start and span of a placement, clamped so that it ends
+/// within `limit` tracks.
+fn clamp(placement: GridPlacement, limit: usize) -> (Option<usize>, usize) {
+    let span = placement.span.clamp(1, limit);
+    (placement.start.map(|start| start.min(limit - span)), span)
+}
+
+/// Returns `true` if all cells of the given area are unoccupied.
+fn is_free(occupied: &[Vec<bool>], row: usize, column: usize, rows: usize, columns: usize) -> bool {
+    (row..row.saturating_add(rows)).all(|r| {
+        (column..column.saturating_add(columns)).all(|c| {
+            !occupied
+                .get(r)
+                .and_then(|cells| cells.get(c))
+                .copied()
+                .unwrap_or(false)
+        })
+    })
+}
+
+/// Marks all cells of the given area as occupied.
+fn occupy(occupied: &mut Vec<Vec<bool>>, row: usize, column: usize, rows: usize, columns: usize) {
+    let end = column.saturating_add(columns);
+    for r in row..row.saturating_add(rows) {
+        if occupied.len() <= r {
+            occupied.resize_with(r + 1, Vec::new);
+        }
+        let cells = &mut occupied[r];
+        if cells.len() < end {
+            cells.resize(end, false);
+        }
+        cells[column..end].fill(true);
+    }
+}
+
+/// Resolves track sizes along one axis.
+///
+/// Fixed tracks take their size, auto tracks the largest preferred size of
+/// the single-track items in them, and flexible tracks share what remains.
+/// When the available size is unknown, flexible tracks are sized like auto
+/// tracks.
+fn resolve_tracks(
+    tracks: &[TrackSize],
+    available: Option<f32>,
+    gap: f32,
+    items: impl Iterator<Item = (usize, f32)>,
+) -> Vec<f32> {
+    let mut content = vec![0.0_f32; tracks.len()];
+    for (track, size) in items {
+        if let Some(max) = content.get_mut(track) {
+            *max = max.max(size);
+        }
+    }
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .zip(&content)
+        .map(|(track, &content)| match track {
+            TrackSize::Px(px) => *px,
+            TrackSize::Auto | TrackSize::MinContent | TrackSize::MaxContent => content,
+            TrackSize::Fr(_) if available.is_none() => content,
+            TrackSize::Fr(_) => 0.0,
+        })
+        .collect();
+
+    let Some(available) = available else {
+        return sizes;
+    };
+    let gaps = gap * tracks.len().saturating_sub(1) as f32;
+    let remaining = (available - gaps - sizes.iter().sum::<f32>()).max(0.0);
+    let fractions: f32 = tracks
+        .iter()
+        .map(|track| match track {
+            TrackSize::Fr(fr) => *fr,
+            _ => 0.0,
+        })
+        .sum();
+    if fractions > 0.0 {
+        for (size, track) in sizes.iter_mut().zip(tracks) {
+            if let TrackSize::Fr(fr) = track {
+                *size = remaining * fr / fractions;
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the total size of all tracks, including gaps.
+fn total(sizes: &[f32], gap: f32) -> f32 {
+    sizes.iter().sum::<f32>() + gap * sizes.len().saturating_sub(1) as f32
+}
+
+/// Returns the start offset of every track.
+fn offsets(sizes: &[f32], gap: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let start = *offset;
+            *offset += size + gap;
+            Some(start)
+        })
+        .collect()
+}
+
+/// Returns the size of `span` tracks starting at `start`, including gaps.
+fn span_size(sizes: &[f32], start: usize, span: usize, gap: f32) -> f32 {
+    let end = (start + span).min(sizes.len());
+    sizes[start..end].iter().sum::<f32>() + gap * (end - start).saturating_sub(1) as f32
+}
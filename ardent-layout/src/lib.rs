@@ -1,14 +1,114 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
+//! The `ardent_layout` crate computes the size and position of scene nodes.
+//!
+//! Layout properties are stored on each node as a
+//! [`LayoutStyle`](ardent_core::layout::LayoutStyle). The layout pass walks
+//! the scene from the root, lets every container arrange its children, and
+//! writes the result back into the scene: positions go into node transforms
+//! and boxes into each node's computed layout.
+//!
+//! Layout is driven by the dirty system: changing a node's layout style,
+//! shape, or children marks it layout-dirty, and the next pass only re-solves
+//! the affected part of the tree. Calling it every frame is cheap when
+//! nothing changed.
+//!
+//! ```rust,ignore
+//! use ardent_layout::LayoutExt;
+//!
+//! scene.compute_layout((width as f32, height as f32));
+//! ```
+//!
+//! With the `taffy` feature enabled, layout is solved by the
+//! [`taffy`](https://docs.rs/taffy) crate instead of the built-in solver.
+//!
+//! [`VirtualList`] scrolls through long lists of equally tall rows while
+//! keeping nodes only for the rows in view.
+
+mod align;
+mod debug;
+mod grid;
+mod pass;
+mod scroll;
+mod scroller;
+mod stack;
+#[cfg(feature = "taffy")]
+pub mod taffy_solver;
+mod virtual_list;
+
+use ardent_core::scene::Scene;
+
+pub use debug::LayoutOverlay;
+pub use pass::compute_layout;
+pub use scroller::{Scroller, scroll_by, scroll_offset, scroll_to};
+pub use virtual_list::VirtualList;
+
+/// Adds layout computation to [`Scene`].
+pub trait LayoutExt {
+    /// Lays out the whole scene for a viewport of the given size in
+    /// logical pixels. The root node fills the viewport.
+    ///
+    /// With the `taffy` feature, the built-in solver lays out the scene
+    /// instead if Taffy fails; call `taffy_solver::compute_layout` to
+    /// get Taffy's error.
+    fn compute_layout(&mut self, viewport: (f32, f32));
+}
+
+impl LayoutExt for Scene {
+    fn compute_layout(&mut self, viewport: (f32, f32)) {
+        #[cfg(feature = "taffy")]
+        if taffy_solver::compute_layout(self, viewport).is_err() {
+            // The built-in solver cannot fail, so the scene is still laid out.
+            pass::compute_layout(self, viewport);
+        }
+        #[cfg(not(feature = "taffy"))]
+        pass::compute_layout(self, viewport);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use std::sync::{Arc, Mutex};
+
+    use ardent_core::layout::{Display, GridLayout, StackLayout, TrackSize};
+    use ardent_core::scene::{NodeBuilder, SceneChange};
+
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn unchanged_layout_reports_no_changes() {
+        let mut scene = Scene::new();
+        let root = scene.root();
+        let grid = GridLayout::new()
+            .columns([TrackSize::Fr(1.0), TrackSize::Fr(1.0)])
+            .gap(8.0);
+        NodeBuilder::group()
+            .display(Display::Grid(grid))
+            .children([
+                NodeBuilder::rect(),
+                NodeBuilder::ellipse(10.0, 10.0),
+                NodeBuilder::group()
+                    .display(Display::Stack(StackLayout::new()))
+                    .child(NodeBuilder::rect()),
+            ])
+            .build(&mut scene, root)
+            .unwrap();
+        scene.compute_layout((400.0, 300.0));
+
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let observed = changes.clone();
+        scene.observe(move |_, changes: &[SceneChange]| {
+            observed.lock().unwrap().extend_from_slice(changes);
+        });
+
+        // Changing the scale factor makes every node layout-dirty, so the
+        // whole tree is solved again with the same result.
+        scene.set_scale_factor(2.0);
+        scene.set_scale_factor(1.0);
+        scene.compute_layout((400.0, 300.0));
+        scene.notify_observers();
+        assert_eq!(*changes.lock().unwrap(), []);
+
+        scene.compute_layout((200.0, 300.0));
+        scene.notify_observers();
+        assert!(!changes.lock().unwrap().is_empty());
     }
 }
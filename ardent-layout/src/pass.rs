@@ -0,0 +1,385 @@
+use std::collections::HashSet;
+
+use ardent_core::layout::{AvailableSpace, ComputedLayout, Dimension, Display, Edges, LayoutStyle};
+use ardent_core::node::{DirtyFlags, Node, NodeId};
+use ardent_core::scene::Scene;
+use ardent_core::shape::{Ellipse, Rect, Shape};
+
+use crate::{grid, scroll, stack};
+
+/// Lays out the scene for a viewport of the given size.
+///
+/// The root node is sized to the viewport. Every container then assigns
+/// boxes to its children according to its [`Display`] mode, recursively.
+///
+/// Layout is incremental: only containers with layout-dirty descendants, and
+/// nodes whose box changed, are solved again. A viewport change lays out the
+/// whole tree, since relative sizes may change anywhere.
+pub fn compute_layout(scene: &mut Scene, viewport: (f32, f32)) {
+    let root = scene.root();
+    let Some(root_node) = scene.get_node(root) else {
+        return;
+    };
+    let previous = *root_node.computed_layout();
+    let resized = (previous.width, previous.height) != viewport;
+    let dirty = dirty_subtrees(scene);
+    if !resized && dirty.is_empty() {
+        return;
+    }
+
+    let (width, height) = viewport;
+    apply(
+        scene,
+        root,
+        ComputedLayout {
+            x: 0.0,
+            y: 0.0,
+            width,
+            height,
+        },
+        false,
+    );
+    let pass = Pass {
+        viewport,
+        scale_factor: scene.scale_factor(),
+        force: resized,
+        dirty: &dirty,
+    };
+    layout_children(scene, root, viewport, &pass);
+    clear_layout_dirty(scene, &dirty);
+}
+
+/// State shared by all containers in a layout pass.
+struct Pass<'a> {
+    viewport: (f32, f32),
+
+    /// Physical pixels per logical pixel, for snapping boxes.
+    scale_factor: f32,
+
+    /// Lay out every node, regardless of dirty state.
+    force: bool,
+
+    /// Nodes that are layout-dirty or have layout-dirty descendants.
+    dirty: &'a HashSet<NodeId>,
+}
+
+/// Returns all layout-dirty nodes along with their ancestors, whose sizes
+/// may depend on them.
+pub(crate) fn dirty_subtrees(scene: &Scene) -> HashSet<NodeId> {
+    let mut changed = Vec::new();
+    scene.traverse(|node| {
+        if node.dirty_flags().contains(DirtyFlags::LAYOUT) {
+            changed.push(node.id());
+        }
+    });
+
+    let mut dirty = HashSet::new();
+    for id in changed {
+        let mut current = Some(id);
+        while let Some(id) = current {
+            if !dirty.insert(id) {
+                break;
+            }
+            current = scene.get_node(id).and_then(Node::parent);
+        }
+    }
+    dirty
+}
+
+/// Clears the layout-dirty flag of the given nodes without reporting them
+/// to change observers.
+pub(crate) fn clear_layout_dirty(scene: &mut Scene, nodes: &HashSet<NodeId>) {
+    scene.clear_layout_dirty(nodes.iter().copied());
+}
+
+/// Arranges the children of a container of the given size.
+///
+/// Children are laid out recursively if their box changed or their subtree
+/// is dirty.
+fn layout_children(scene: &mut Scene, id: NodeId, size: (f32, f32), pass: &Pass) {
+    let Some(node) = scene.get_node(id) else {
+        return;
+    };
+    let viewport = pass.viewport;
+    let children = node.children().to_vec();
+    let padding = node.layout().padding;
+    let content = (
+        (size.0 - padding.horizontal()).max(0.0),
+        (size.1 - padding.vertical()).max(0.0),
+    );
+
+    let (boxes, positioned): (Vec<_>, _) = match &node.layout().display {
+        Display::Manual => {
+            let boxes = children
+                .iter()
+                .filter_map(|&child| {
+                    Some((
+                        child,
+                        manual_box(scene, scene.get_node(child)?, content, viewport),
+                    ))
+                })
+                .collect();
+            (boxes, false)
+        }
+        Display::Grid(layout) => {
+            let boxes = grid::arrange(scene, layout, &children, content, viewport);
+            (inset(boxes, padding), true)
+        }
+        Display::Stack(layout) => {
+            let boxes = stack::arrange(scene, layout, &children, content, viewport);
+            (inset(boxes, padding), true)
+        }
+        Display::Scroll(layout) => {
+            let boxes = scroll::arrange(scene, layout, &children, content, viewport);
+            (inset(boxes, padding), true)
+        }
+    };
+
+    for (child, layout) in boxes {
+        let layout = snap(layout, pass.scale_factor);
+        let changed = scene
+            .get_node(child)
+            .is_none_or(|node| *node.computed_layout() != layout);
+        apply(scene, child, layout, positioned);
+        if pass.force || changed || pass.dirty.contains(&child) {
+            layout_children(scene, child, (layout.width, layout.height), pass);
+        }
+    }
+}
+
+/// Rounds a box's edges to the physical pixel grid of a display with the
+/// given scale factor, so edges are drawn crisply instead of smeared across
+/// two pixels.
+///
+/// Edges rather than sizes are rounded, so adjacent boxes stay adjacent.
+/// Boxes are relative to their parent, so a snapped box inside a snapped
+/// parent also lands on the grid.
+pub(crate) fn snap(layout: ComputedLayout, scale_factor: f32) -> ComputedLayout {
+    let round = |value: f32| (value * scale_factor).round() / scale_factor;
+    let (x, y) = (round(layout.x), round(layout.y));
+    ComputedLayout {
+        x,
+        y,
+        width: round(layout.x + layout.width) - x,
+        height: round(layout.y + layout.height) - y,
+    }
+}
+
+/// Moves boxes computed within a content box by the container's padding.
+fn inset(boxes: Vec<(NodeId, ComputedLayout)>, padding: Edges) -> Vec<(NodeId, ComputedLayout)> {
+    boxes
+        .into_iter()
+        .map(|(id, layout)| {
+            let layout = ComputedLayout {
+                x: layout.x + padding.left,
+                y: layout.y + padding.top,
+                ..layout
+            };
+            (id, layout)
+        })
+        .collect()
+}
+
+/// Computes the box of a manually positioned node inside a parent content
+/// box of the given size.
+fn manual_box(
+    scene: &Scene,
+    node: &Node,
+    parent: (f32, f32),
+    viewport: (f32, f32),
+) -> ComputedLayout {
+    let (x, y) = node.transform().translate;
+    let (width, height) = measure(scene, node, (Some(parent.0), Some(parent.1)), viewport);
+    ComputedLayout {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// Returns the size a node would like to have, including padding but not
+/// margin.
+///
+/// Explicit sizes are resolved against the parent's content size, where it
+/// is known, or the viewport. A missing size is derived from the other one
+/// through the aspect ratio, if set, and otherwise from the node's content.
+/// The result is clamped to the node's min/max constraints.
+pub(crate) fn measure(
+    scene: &Scene,
+    node: &Node,
+    parent: (Option<f32>, Option<f32>),
+    viewport: (f32, f32),
+) -> (f32, f32) {
+    measure_in(scene, node, parent, AvailableSpace::MaxContent, viewport)
+}
+
+/// Like [`measure`], but with the given horizontal space for content when the
+/// node's width is auto.
+pub(crate) fn measure_in(
+    scene: &Scene,
+    node: &Node,
+    parent: (Option<f32>, Option<f32>),
+    space: AvailableSpace,
+    viewport: (f32, f32),
+) -> (f32, f32) {
+    let style = node.layout();
+    let mut width = style.width.resolve(parent.0, viewport);
+    let mut height = style.height.resolve(parent.1, viewport);
+    if let Some(ratio) = aspect_ratio(style) {
+        match (width, height) {
+            (Some(width), None) => height = Some(width / ratio),
+            (None, Some(height)) => width = Some(height * ratio),
+            _ => {}
+        }
+    }
+
+    let (width, height) = match (width, height) {
+        (Some(width), Some(height)) => (width, height),
+        _ => {
+            let padding = style.padding;
+            let inner = (
+                width.map(|width| (width - padding.horizontal()).max(0.0)),
+                height.map(|height| (height - padding.vertical()).max(0.0)),
+            );
+            let (content_width, content_height) = content_size(
+                scene,
+                node,
+                inner,
+                inner.0.map_or(space, AvailableSpace::Definite),
+                viewport,
+            );
+            let width = width.unwrap_or(content_width + padding.horizontal());
+            let height = match aspect_ratio(style) {
+                Some(ratio) => width / ratio,
+                None => content_height + padding.vertical(),
+            };
+            (width, height)
+        }
+    };
+
+    (
+        clamp(width, style.min_width, style.max_width, parent.0, viewport),
+        clamp(
+            height,
+            style.min_height,
+            style.max_height,
+            parent.1,
+            viewport,
+        ),
+    )
+}
+
+/// Returns the node's aspect ratio, if it has a usable one.
+pub(crate) fn aspect_ratio(style: &LayoutStyle) -> Option<f32> {
+    style
+        .aspect_ratio
+        .filter(|ratio| ratio.is_finite() && *ratio > 0.0)
+}
+
+/// Clamps a size to min/max constraints resolved against the parent size.
+/// The minimum wins over the maximum.
+pub(crate) fn clamp(
+    value: f32,
+    min: Dimension,
+    max: Dimension,
+    parent: Option<f32>,
+    viewport: (f32, f32),
+) -> f32 {
+    let value = max
+        .resolve(parent, viewport)
+        .map_or(value, |max| value.min(max));
+    min.resolve(parent, viewport)
+        .map_or(value, |min| value.max(min))
+}
+
+/// Returns the size of a node's content, excluding padding.
+///
+/// Containers are sized around their children. Leaves use their intrinsic
+/// size.
+fn content_size(
+    scene: &Scene,
+    node: &Node,
+    available: (Option<f32>, Option<f32>),
+    space: AvailableSpace,
+    viewport: (f32, f32),
+) -> (f32, f32) {
+    if node.children().is_empty() {
+        return intrinsic_size(node, space);
+    }
+
+    match &node.layout().display {
+        Display::Manual => node
+            .children()
+            .iter()
+            .filter_map(|&child| scene.get_node(child))
+            .fold((0.0_f32, 0.0_f32), |(width, height), child| {
+                let (x, y) = child.transform().translate;
+                let (child_width, child_height) =
+                    measure_in(scene, child, available, space, viewport);
+                let margin = child.layout().margin;
+                (
+                    width.max(x + child_width + margin.right),
+                    height.max(y + child_height + margin.bottom),
+                )
+            }),
+        Display::Grid(layout) => grid::measure(scene, layout, node.children(), available, viewport),
+        Display::Stack(_) => stack::measure(scene, node.children(), available, viewport),
+        Display::Scroll(_) => scroll::measure(scene, node.children(), available, viewport),
+    }
+}
+
+/// Returns the natural size of a leaf node's content.
+///
+/// Nodes with a measure function are asked for their size in the given
+/// space; others take the bounds of their shape.
+pub(crate) fn intrinsic_size(node: &Node, space: AvailableSpace) -> (f32, f32) {
+    match (node.measure(), node.shape()) {
+        (Some(measure), _) => measure(space),
+        (None, Some(shape)) => shape.size(),
+        (None, None) => (0.0, 0.0),
+    }
+}
+
+/// Stores a computed box on a node.
+///
+/// Positioned nodes get their translation updated. Rectangles and ellipses
+/// are resized to match the box. Nodes are only written, and reported to
+/// change observers as modified, when their box changes, so unchanged nodes
+/// are not marked dirty.
+pub(crate) fn apply(scene: &mut Scene, id: NodeId, layout: ComputedLayout, positioned: bool) {
+    let Some(node) = scene.get_node(id) else {
+        return;
+    };
+    let moved = positioned && node.transform().translate != (layout.x, layout.y);
+    let resized = match node.shape() {
+        Some(Shape::Rect(rect)) if (rect.width, rect.height) != (layout.width, layout.height) => {
+            Some(Shape::Rect(Rect::new(layout.width, layout.height)))
+        }
+        Some(Shape::Ellipse(ellipse))
+            if (2.0 * ellipse.rx, 2.0 * ellipse.ry) != (layout.width, layout.height) =>
+        {
+            Some(Shape::Ellipse(Ellipse::new(
+                layout.width / 2.0,
+                layout.height / 2.0,
+            )))
+        }
+        _ => None,
+    };
+    if *node.computed_layout() == layout && !moved && resized.is_none() {
+        return;
+    }
+
+    let Some(node) = scene.get_node_mut(id) else {
+        return;
+    };
+    node.set_computed_layout(layout);
+    if moved {
+        node.transform_mut().translate = (layout.x, layout.y);
+    }
+    if let Some(shape) = resized {
+        node.set_shape(shape);
+        // The new size comes from layout itself and needs no further pass.
+        node.clear_dirty_flags(DirtyFlags::LAYOUT);
+    }
+}
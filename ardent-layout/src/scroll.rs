@@ -0,0 +1,177 @@
+//! Scroll container layout.
+
+use ardent_core::layout::{Align, AvailableSpace, Axis, ComputedLayout, Dimension, ScrollLayout};
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::Scene;
+
+use crate::align::fit;
+use crate::pass::measure_in;
+use crate::stack;
+
+/// Thickness of scrollbar thumbs without an explicit size.
+const THUMB_THICKNESS: f32 = 6.0;
+
+/// Shortest length of a scrollbar thumb, so it stays easy to grab.
+const MIN_THUMB_LENGTH: f32 = 16.0;
+
+/// Returns the size a scroll container needs to fit its content without
+/// scrolling, excluding the container's padding.
+pub(crate) fn measure(
+    scene: &Scene,
+    children: &[NodeId],
+    available: (Option<f32>, Option<f32>),
+    viewport: (f32, f32),
+) -> (f32, f32) {
+    let content: Vec<NodeId> = children
+        .iter()
+        .copied()
+        .filter(|&id| scene.get_node(id).is_some_and(|node| !is_thumb(node)))
+        .collect();
+    stack::measure(scene, &content, available, viewport)
+}
+
+/// Assigns a box to every child of a scroll container whose content box has
+/// the given size.
+///
+/// Content is shifted by the clamped scroll offset; scrollbar thumbs are
+/// placed along the right and bottom edges.
+pub(crate) fn arrange(
+    scene: &Scene,
+    layout: &ScrollLayout,
+    children: &[NodeId],
+    size: (f32, f32),
+    viewport: (f32, f32),
+) -> Vec<(NodeId, ComputedLayout)> {
+    let available = (Some(size.0), Some(size.1));
+    let area = ComputedLayout {
+        x: 0.0,
+        y: 0.0,
+        width: size.0,
+        height: size.1,
+    };
+
+    let mut boxes = Vec::new();
+    let mut thumbs = Vec::new();
+    let mut extent = size;
+    for node in children.iter().filter_map(|&id| scene.get_node(id)) {
+        if is_thumb(node) {
+            thumbs.push(node);
+            continue;
+        }
+
+        let style = node.layout();
+        let margin = style.margin;
+        let justify = if layout.horizontal {
+            Align::Start
+        } else {
+            style.justify_self.unwrap_or_default()
+        };
+        let align = if layout.vertical {
+            Align::Start
+        } else {
+            style.align_self.unwrap_or_default()
+        };
+        let space = if layout.horizontal {
+            AvailableSpace::MaxContent
+        } else {
+            AvailableSpace::Definite((size.0 - margin.horizontal()).max(0.0))
+        };
+        let parent = (
+            available.0.filter(|_| !layout.horizontal),
+            available.1.filter(|_| !layout.vertical),
+        );
+        let preferred = measure_in(scene, node, parent, space, viewport);
+        let child = fit(
+            style,
+            preferred,
+            area,
+            (justify, align),
+            available,
+            viewport,
+        );
+
+        extent.0 = extent.0.max(child.x + child.width + margin.right);
+        extent.1 = extent.1.max(child.y + child.height + margin.bottom);
+        boxes.push((node.id(), child));
+    }
+
+    let max = ((extent.0 - size.0).max(0.0), (extent.1 - size.1).max(0.0));
+    let offset = (
+        if layout.horizontal {
+            layout.offset.0.clamp(0.0, max.0)
+        } else {
+            0.0
+        },
+        if layout.vertical {
+            layout.offset.1.clamp(0.0, max.1)
+        } else {
+            0.0
+        },
+    );
+    for (_, child) in &mut boxes {
+        child.x -= offset.0;
+        child.y -= offset.1;
+    }
+
+    for node in thumbs {
+        let thumb = thumb(node, size, extent, offset, max, viewport);
+        boxes.push((node.id(), thumb));
+    }
+
+    boxes
+}
+
+/// Returns `true` if the node is a scrollbar thumb.
+fn is_thumb(node: &Node) -> bool {
+    node.layout().scrollbar.is_some()
+}
+
+/// Computes the box of a scrollbar thumb.
+///
+/// The thumb's length is proportional to the visible part of the content and
+/// its position to the scroll offset. Thumbs along axes without overflow are
+/// collapsed to zero size.
+fn thumb(
+    node: &Node,
+    size: (f32, f32),
+    extent: (f32, f32),
+    offset: (f32, f32),
+    max: (f32, f32),
+    viewport: (f32, f32),
+) -> ComputedLayout {
+    let style = node.layout();
+    let margin = style.margin;
+    let thickness = |dimension: Dimension, parent: f32| {
+        dimension
+            .resolve(Some(parent), viewport)
+            .unwrap_or(THUMB_THICKNESS)
+    };
+    let along = |size: f32, extent: f32, offset: f32, max: f32| {
+        let length = (size * size / extent).max(MIN_THUMB_LENGTH).min(size);
+        (offset / max * (size - length), length)
+    };
+
+    match style.scrollbar {
+        Some(Axis::Vertical) if max.1 > 0.0 => {
+            let width = thickness(style.width, size.0);
+            let (y, height) = along(size.1, extent.1, offset.1, max.1);
+            ComputedLayout {
+                x: size.0 - width - margin.right,
+                y,
+                width,
+                height,
+            }
+        }
+        Some(Axis::Horizontal) if max.0 > 0.0 => {
+            let height = thickness(style.height, size.1);
+            let (x, width) = along(size.0, extent.0, offset.0, max.0);
+            ComputedLayout {
+                x,
+                y: size.1 - height - margin.bottom,
+                width,
+                height,
+            }
+        }
+        _ => ComputedLayout::default(),
+    }
+}
@@ -0,0 +1,205 @@
+//! Input handling and inertial scrolling for scroll containers.
+
+use std::collections::HashMap;
+
+use ardent_core::event::Event;
+use ardent_core::layout::Display;
+use ardent_core::node::NodeId;
+use ardent_core::scene::Scene;
+
+/// Rate at which inertial scrolling slows down, per second.
+const DECELERATION: f32 = 4.0;
+
+/// Speed in logical pixels per second below which inertia stops.
+const MIN_VELOCITY: f32 = 10.0;
+
+/// Scrolling state of a single container.
+#[derive(Default)]
+struct Momentum {
+    /// Current speed in logical pixels per second.
+    velocity: (f32, f32),
+
+    /// Whether the pointer is dragging the content.
+    dragging: bool,
+
+    /// Distance dragged since the last tick.
+    dragged: (f32, f32),
+}
+
+/// Drives scroll containers from wheel and drag input.
+///
+/// Wheel input scrolls immediately. Dragging moves the content with the
+/// pointer and, once released, lets it coast to a stop. Call [`tick`] once
+/// per frame to advance inertia; like the animator, it reports whether more
+/// frames are needed.
+///
+/// Scroll offsets are written into each container's [`ScrollLayout`], so
+/// the next layout pass moves the content.
+///
+/// [`tick`]: Scroller::tick
+/// [`ScrollLayout`]: ardent_core::layout::ScrollLayout
+#[derive(Default)]
+pub struct Scroller {
+    states: HashMap<NodeId, Momentum>,
+}
+
+impl Scroller {
+    /// Creates a scroller with no active scrolling.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies an input event targeting the scroll container `id`.
+    ///
+    /// Returns `true` if the event was a scroll or drag event.
+    pub fn handle_event(&mut self, scene: &mut Scene, id: NodeId, event: Event) -> bool {
+        match event {
+            Event::Scroll(dx, dy) => {
+                self.states.remove(&id);
+                scroll_by(scene, id, (dx, dy));
+            }
+            Event::DragStart => {
+                self.states.insert(
+                    id,
+                    Momentum {
+                        dragging: true,
+                        ..Default::default()
+                    },
+                );
+            }
+            Event::DragUpdate(dx, dy) => {
+                scroll_by(scene, id, (-dx, -dy));
+                if let Some(state) = self.states.get_mut(&id) {
+                    state.dragged.0 -= dx;
+                    state.dragged.1 -= dy;
+                }
+            }
+            Event::DragEnd => {
+                if let Some(state) = self.states.get_mut(&id) {
+                    state.dragging = false;
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Advances inertial scrolling by `dt` seconds.
+    ///
+    /// Returns `true` while any container is being dragged or is still
+    /// coasting.
+    pub fn tick(&mut self, scene: &mut Scene, dt: f32) -> bool {
+        self.states.retain(|&id, state| {
+            if state.dragging {
+                if dt > 0.0 {
+                    state.velocity = (state.dragged.0 / dt, state.dragged.1 / dt);
+                }
+                state.dragged = (0.0, 0.0);
+                return true;
+            }
+
+            let (vx, vy) = state.velocity;
+            let moved = scroll_by(scene, id, (vx * dt, vy * dt));
+            let decay = (-DECELERATION * dt).exp();
+            state.velocity = (vx * decay, vy * decay);
+            moved && vx.hypot(vy) * decay >= MIN_VELOCITY
+        });
+        self.is_active()
+    }
+
+    /// Returns `true` while any container is being dragged or is coasting.
+    pub fn is_active(&self) -> bool {
+        !self.states.is_empty()
+    }
+}
+
+/// Scrolls a container by the given distance, clamped to its content.
+///
+/// Returns `true` if the offset changed. Nodes that are not scroll
+/// containers are left alone.
+pub fn scroll_by(scene: &mut Scene, id: NodeId, delta: (f32, f32)) -> bool {
+    let Some((offset, _)) = scroll_offset(scene, id) else {
+        return false;
+    };
+    scroll_to(scene, id, (offset.0 + delta.0, offset.1 + delta.1))
+}
+
+/// Scrolls a container to the given offset, clamped to its content.
+///
+/// Returns `true` if the offset changed. The content moves on the next
+/// layout pass.
+pub fn scroll_to(scene: &mut Scene, id: NodeId, offset: (f32, f32)) -> bool {
+    let Some((current, max)) = scroll_offset(scene, id) else {
+        return false;
+    };
+    let offset = (offset.0.clamp(0.0, max.0), offset.1.clamp(0.0, max.1));
+    if offset == current {
+        return false;
+    }
+
+    if let Some(node) = scene.get_node_mut(id)
+        && let Display::Scroll(scroll) = &mut node.layout_mut().display
+    {
+        scroll.offset = offset;
+    }
+    true
+}
+
+/// Returns the current and the largest scroll offset of a container, based
+/// on the most recent layout.
+pub fn scroll_offset(scene: &Scene, id: NodeId) -> Option<((f32, f32), (f32, f32))> {
+    let node = scene.get_node(id)?;
+    let style = node.layout();
+    let Display::Scroll(scroll) = &style.display else {
+        return None;
+    };
+
+    let bounds = node.computed_layout();
+    let padding = style.padding;
+    let size = (
+        bounds.width - padding.horizontal(),
+        bounds.height - padding.vertical(),
+    );
+    let current = (
+        if scroll.horizontal {
+            scroll.offset.0
+        } else {
+            0.0
+        },
+        if scroll.vertical {
+            scroll.offset.1
+        } else {
+            0.0
+        },
+    );
+
+    // Children were shifted by the offset; undo it to find the content's extent.
+    let extent = node
+        .children()
+        .iter()
+        .filter_map(|&child| scene.get_node(child))
+        .filter(|child| child.layout().scrollbar.is_none())
+        .fold(size, |(width, height), child| {
+            let layout = child.computed_layout();
+            let margin = child.layout().margin;
+            (
+                width.max(layout.x - padding.left + current.0 + layout.width + margin.right),
+                height.max(layout.y - padding.top + current.1 + layout.height + margin.bottom),
+            )
+        });
+
+    let max = (
+        if scroll.horizontal {
+            (extent.0 - size.0).max(0.0)
+        } else {
+            0.0
+        },
+        if scroll.vertical {
+            (extent.1 - size.1).max(0.0)
+        } else {
+            0.0
+        },
+    );
+    let current = (current.0.clamp(0.0, max.0), current.1.clamp(0.0, max.1));
+    Some((current, max))
+}
@@ -0,0 +1,84 @@
+//! Stack container layout, layering children on top of each other.
+
+use ardent_core::layout::{Align, AvailableSpace, ComputedLayout, StackLayout};
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::Scene;
+
+use crate::align::fit;
+use crate::pass::{measure as measure_node, measure_in};
+
+/// Returns the size a stack needs to fit its largest child, excluding the
+/// container's padding.
+pub(crate) fn measure(
+    scene: &Scene,
+    children: &[NodeId],
+    available: (Option<f32>, Option<f32>),
+    viewport: (f32, f32),
+) -> (f32, f32) {
+    children.iter().filter_map(|&id| scene.get_node(id)).fold(
+        (0.0_f32, 0.0_f32),
+        |(width, height), node| {
+            let (child_width, child_height) = measure_node(scene, node, available, viewport);
+            let margin = node.layout().margin;
+            (
+                width.max(child_width + margin.horizontal()),
+                height.max(child_height + margin.vertical()),
+            )
+        },
+    )
+}
+
+/// Assigns a box to every child of a stack whose content box has the given
+/// size. Every child is aligned within the whole content box.
+pub(crate) fn arrange(
+    scene: &Scene,
+    layout: &StackLayout,
+    children: &[NodeId],
+    size: (f32, f32),
+    viewport: (f32, f32),
+) -> Vec<(NodeId, ComputedLayout)> {
+    let available = (Some(size.0), Some(size.1));
+    let area = ComputedLayout {
+        x: 0.0,
+        y: 0.0,
+        width: size.0,
+        height: size.1,
+    };
+
+    children
+        .iter()
+        .filter_map(|&id| scene.get_node(id))
+        .map(|node| {
+            let style = node.layout();
+            let justify = style.justify_self.unwrap_or(layout.justify_items);
+            let align = style.align_self.unwrap_or(layout.align_items);
+            let preferred = preferred_size(scene, node, justify, available, viewport);
+            let layout = fit(
+                style,
+                preferred,
+                area,
+                (justify, align),
+                available,
+                viewport,
+            );
+            (node.id(), layout)
+        })
+        .collect()
+}
+
+/// Measures a child, wrapping stretched content at the stack's width.
+fn preferred_size(
+    scene: &Scene,
+    node: &Node,
+    justify: Align,
+    available: (Option<f32>, Option<f32>),
+    viewport: (f32, f32),
+) -> (f32, f32) {
+    let space = match (justify, available.0) {
+        (Align::Stretch, Some(width)) => {
+            AvailableSpace::Definite((width - node.layout().margin.horizontal()).max(0.0))
+        }
+        _ => AvailableSpace::MaxContent,
+    };
+    measure_in(scene, node, available, space, viewport)
+}
@@ -0,0 +1,463 @@
+//! Layout backed by the [`taffy`] crate.
+//!
+//! The scene is mirrored into a Taffy tree, with each node's [`LayoutStyle`]
+//! translated into a Taffy style. The tree is kept with the scene between
+//! passes and only layout-dirty and new nodes are written to it again, so
+//! Taffy reuses what it solved for unchanged subtrees. Taffy solves the
+//! layout and the results are written back into the scene like the
+//! built-in solver does.
+
+use std::collections::{HashMap, HashSet};
+
+use ardent_core::layout::{
+    Align, AvailableSpace as Space, ComputedLayout, Dimension, Display, Edges, GridLayout,
+    GridPlacement, LayoutStyle, MeasureFn, StackLayout, TrackSize,
+};
+use ardent_core::node::{DirtyFlags, Node, NodeId};
+use ardent_core::scene::Scene;
+use taffy::TaffyError;
+use taffy::prelude::{
+    AlignItems, AvailableSpace, Line, NodeId as TaffyId, Position, Rect, Size, Style, TaffyTree,
+    auto, fr, length, line, percent, span,
+};
+
+use crate::pass::{apply, clear_layout_dirty, dirty_subtrees, intrinsic_size, snap};
+
+/// The content of a leaf node, measured by Taffy.
+enum Content {
+    /// A fixed size, such as a shape's bounds.
+    Size((f32, f32)),
+
+    /// Content measured on demand.
+    Measure(MeasureFn),
+}
+
+/// The Taffy tree mirroring a scene, kept with the scene between passes.
+struct Mirror {
+    tree: TaffyTree<Content>,
+
+    /// The Taffy node of each scene node.
+    ids: HashMap<NodeId, TaffyId>,
+
+    /// The viewport the styles were resolved for.
+    viewport: (f32, f32),
+}
+
+/// What changed since the last pass.
+struct Changes {
+    /// Whether the viewport changed, which changes the style of every node.
+    viewport: bool,
+
+    /// The scene nodes visited so far, with whether their position is
+    /// computed by the layout.
+    visited: Vec<(NodeId, bool)>,
+}
+
+/// Lays out the whole scene with Taffy for a viewport of the given size.
+///
+/// The root node is sized to the viewport. Grid and stack containers map to
+/// Taffy grids; children of manual containers are absolutely positioned at their
+/// current translation.
+///
+/// The scene is only solved again if a node is layout-dirty or the viewport
+/// changed. Returns Taffy's error if the tree cannot be updated or solved;
+/// the nodes are then left dirty and the tree is rebuilt on the next pass.
+pub fn compute_layout(scene: &mut Scene, viewport: (f32, f32)) -> Result<(), TaffyError> {
+    let root = scene.root();
+    let Some(root_node) = scene.get_node(root) else {
+        return Ok(());
+    };
+    let previous = *root_node.computed_layout();
+    let dirty = dirty_subtrees(scene);
+    if (previous.width, previous.height) == viewport && dirty.is_empty() {
+        return Ok(());
+    }
+
+    let mut mirror = match scene
+        .take_layout_state()
+        .and_then(|state| state.downcast::<Mirror>().ok())
+    {
+        Some(mirror) => *mirror,
+        None => Mirror::new(viewport),
+    };
+    mirror.solve(scene, viewport)?;
+    scene.set_layout_state(Box::new(mirror));
+    clear_layout_dirty(scene, &dirty);
+    Ok(())
+}
+
+impl Mirror {
+    fn new(viewport: (f32, f32)) -> Self {
+        let mut tree = TaffyTree::new();
+        // Boxes are snapped to physical rather than logical pixels below.
+        tree.disable_rounding();
+        Self {
+            tree,
+            ids: HashMap::new(),
+            viewport,
+        }
+    }
+
+    /// Brings the tree up to date with the scene, solves it, and writes the
+    /// results back into the scene.
+    fn solve(&mut self, scene: &mut Scene, viewport: (f32, f32)) -> Result<(), TaffyError> {
+        let root = scene.root();
+        let mut changes = Changes {
+            viewport: self.viewport != viewport,
+            visited: Vec::new(),
+        };
+        self.viewport = viewport;
+        let Some(taffy_root) = self.sync(scene, root, None, false, &mut changes)? else {
+            return Ok(());
+        };
+
+        let visited: HashSet<NodeId> = changes.visited.iter().map(|&(id, _)| id).collect();
+        let removed: Vec<NodeId> = self
+            .ids
+            .keys()
+            .filter(|id| !visited.contains(id))
+            .copied()
+            .collect();
+        for id in removed {
+            if let Some(taffy_id) = self.ids.remove(&id) {
+                self.tree.remove(taffy_id)?;
+            }
+        }
+
+        let available = Size {
+            width: AvailableSpace::Definite(viewport.0),
+            height: AvailableSpace::Definite(viewport.1),
+        };
+        self.tree.compute_layout_with_measure(
+            taffy_root,
+            available,
+            |known, available, _id, context, _style| {
+                let (width, height) = match context {
+                    Some(Content::Measure(measure)) => measure(match known.width {
+                        Some(width) => Space::Definite(width),
+                        None => space(available.width),
+                    }),
+                    Some(Content::Size(size)) => *size,
+                    None => (0.0, 0.0),
+                };
+                Size {
+                    width: known.width.unwrap_or(width),
+                    height: known.height.unwrap_or(height),
+                }
+            },
+        )?;
+
+        for (id, positioned) in changes.visited {
+            let layout = self.tree.layout(self.ids[&id])?;
+            let (dx, dy) = self.scroll_shift(scene, id);
+            let computed = ComputedLayout {
+                x: layout.location.x - dx,
+                y: layout.location.y - dy,
+                width: layout.size.width,
+                height: layout.size.height,
+            };
+            let computed = snap(computed, scene.scale_factor());
+            apply(scene, id, computed, positioned && id != root);
+        }
+        Ok(())
+    }
+
+    /// Brings the Taffy node of a scene node and its subtree up to date.
+    ///
+    /// A node's style is written again if it is new, layout-dirty, or its
+    /// parent's style was, since children of stacks and manual containers
+    /// are placed through their own style.
+    fn sync(
+        &mut self,
+        scene: &Scene,
+        id: NodeId,
+        parent: Option<&Display>,
+        parent_changed: bool,
+        changes: &mut Changes,
+    ) -> Result<Option<TaffyId>, TaffyError> {
+        let Some(node) = scene.get_node(id) else {
+            return Ok(None);
+        };
+        let existing = self.ids.get(&id).copied();
+        let changed = existing.is_none()
+            || parent_changed
+            || changes.viewport
+            || node.dirty_flags().contains(DirtyFlags::LAYOUT);
+
+        let display = &node.layout().display;
+        let mut children = Vec::with_capacity(node.children().len());
+        for &child in node.children() {
+            if let Some(taffy_id) = self.sync(scene, child, Some(display), changed, changes)? {
+                children.push(taffy_id);
+            }
+        }
+
+        let absolute = matches!(parent, Some(Display::Manual));
+        changes.visited.push((id, !absolute));
+        let taffy_id = match existing {
+            Some(taffy_id) if !changed => {
+                // Absolutely positioned nodes are placed at their
+                // translation, which changes without making them dirty.
+                if absolute {
+                    let style = node_style(node, parent, self.viewport);
+                    if self.tree.style(taffy_id)? != &style {
+                        self.tree.set_style(taffy_id, style)?;
+                    }
+                }
+                if self.tree.children(taffy_id)? != children {
+                    self.tree.set_children(taffy_id, &children)?;
+                }
+                taffy_id
+            }
+            Some(taffy_id) => {
+                self.tree
+                    .set_style(taffy_id, node_style(node, parent, self.viewport))?;
+                self.tree.set_children(taffy_id, &children)?;
+                self.tree.set_node_context(taffy_id, content(node))?;
+                taffy_id
+            }
+            None => {
+                let style = node_style(node, parent, self.viewport);
+                let taffy_id = match content(node) {
+                    Some(content) => self.tree.new_leaf_with_context(style, content)?,
+                    None => self.tree.new_with_children(style, &children)?,
+                };
+                self.ids.insert(id, taffy_id);
+                taffy_id
+            }
+        };
+        Ok(Some(taffy_id))
+    }
+
+    /// Returns how far a node is shifted by its parent's scroll offset,
+    /// clamped to the parent's scrollable range.
+    fn scroll_shift(&self, scene: &Scene, id: NodeId) -> (f32, f32) {
+        let Some(parent_id) = scene.get_node(id).and_then(|node| node.parent()) else {
+            return (0.0, 0.0);
+        };
+        let Some(parent) = scene.get_node(parent_id) else {
+            return (0.0, 0.0);
+        };
+        let Display::Scroll(scroll) = &parent.layout().display else {
+            return (0.0, 0.0);
+        };
+        let Some(layout) = self
+            .ids
+            .get(&parent_id)
+            .and_then(|&taffy_id| self.tree.layout(taffy_id).ok())
+        else {
+            return (0.0, 0.0);
+        };
+
+        let max = (
+            (layout.content_size.width - layout.size.width).max(0.0),
+            (layout.content_size.height - layout.size.height).max(0.0),
+        );
+        (
+            if scroll.horizontal {
+                scroll.offset.0.clamp(0.0, max.0)
+            } else {
+                0.0
+            },
+            if scroll.vertical {
+                scroll.offset.1.clamp(0.0, max.1)
+            } else {
+                0.0
+            },
+        )
+    }
+}
+
+/// Returns the content Taffy measures for a leaf node, or `None` for
+/// containers.
+fn content(node: &Node) -> Option<Content> {
+    if !node.children().is_empty() {
+        return None;
+    }
+    Some(match node.measure() {
+        Some(measure) => Content::Measure(measure.clone()),
+        None => Content::Size(intrinsic_size(node, Space::MaxContent)),
+    })
+}
+
+/// Returns the Taffy style of a node placed in a parent with the given
+/// display, or of the root, which is sized to the viewport.
+fn node_style(node: &Node, parent: Option<&Display>, viewport: (f32, f32)) -> Style {
+    let mut style = style(node.layout(), viewport);
+    match parent {
+        None => {
+            style.size = Size {
+                width: length(viewport.0),
+                height: length(viewport.1),
+            };
+        }
+        Some(Display::Manual) => {
+            let (x, y) = node.transform().translate;
+            style.position = Position::Absolute;
+            style.inset = Rect {
+                left: length(x),
+                top: length(y),
+                right: auto(),
+                bottom: auto(),
+            };
+        }
+        Some(Display::Stack(_) | Display::Scroll(_)) => {
+            style.grid_row = placement(GridPlacement::at(0));
+            style.grid_column = placement(GridPlacement::at(0));
+        }
+        Some(Display::Grid(_)) => {}
+    }
+    if let Some(Display::Scroll(_)) = parent
+        && node.layout().scrollbar.is_some()
+    {
+        // Scrollbar thumbs are only sized by the built-in solver.
+        style.display = taffy::Display::None;
+    }
+    style
+}
+
+/// Translates a node's layout style into a Taffy style.
+fn style(layout: &LayoutStyle, viewport: (f32, f32)) -> Style {
+    let mut style = Style {
+        size: Size {
+            width: dimension(layout.width, viewport),
+            height: dimension(layout.height, viewport),
+        },
+        min_size: Size {
+            width: dimension(layout.min_width, viewport),
+            height: dimension(layout.min_height, viewport),
+        },
+        max_size: Size {
+            width: dimension(layout.max_width, viewport),
+            height: dimension(layout.max_height, viewport),
+        },
+        aspect_ratio: layout.aspect_ratio,
+        padding: edges(layout.padding),
+        margin: edges(layout.margin),
+        grid_column: placement(layout.grid_column),
+        grid_row: placement(layout.grid_row),
+        justify_self: layout.justify_self.map(align),
+        align_self: layout.align_self.map(align),
+        ..Style::default()
+    };
+
+    match &layout.display {
+        Display::Manual => style.display = taffy::Display::Block,
+        Display::Grid(grid) => grid_style(&mut style, grid),
+        Display::Stack(stack) => stack_style(&mut style, stack),
+        Display::Scroll(scroll) => {
+            stack_style(&mut style, &StackLayout::new().align(Align::Start));
+            let overflow = |scrolls| {
+                if scrolls {
+                    taffy::Overflow::Scroll
+                } else {
+                    taffy::Overflow::Visible
+                }
+            };
+            style.overflow = taffy::Point {
+                x: overflow(scroll.horizontal),
+                y: overflow(scroll.vertical),
+            };
+        }
+    }
+
+    style
+}
+
+/// Fills in the grid container properties of a Taffy style.
+fn grid_style(style: &mut Style, grid: &GridLayout) {
+    style.display = taffy::Display::Grid;
+    style.grid_template_columns = grid.columns.iter().copied().map(track).collect();
+    style.grid_template_rows = grid.rows.iter().copied().map(track).collect();
+    style.grid_auto_rows = vec![track(grid.auto_rows)];
+    style.gap = Size {
+        width: length(grid.column_gap),
+        height: length(grid.row_gap),
+    };
+    style.justify_items = Some(align(grid.justify_items));
+    style.align_items = Some(align(grid.align_items));
+}
+
+/// Fills in the container properties of a Taffy style for a stack, which is
+/// a single-cell grid with every child placed in the cell.
+fn stack_style(style: &mut Style, stack: &StackLayout) {
+    style.display = taffy::Display::Grid;
+    style.grid_template_columns = vec![fr(1.0)];
+    style.grid_template_rows = vec![fr(1.0)];
+    style.justify_items = Some(align(stack.justify_items));
+    style.align_items = Some(align(stack.align_items));
+}
+
+/// Translates a track size. Generic over Taffy's repeated and non-repeated
+/// track sizing functions.
+fn track<T>(size: TrackSize) -> T
+where
+    T: taffy::style_helpers::FromLength
+        + taffy::style_helpers::FromFlex
+        + taffy::style_helpers::TaffyAuto
+        + taffy::style_helpers::TaffyMinContent
+        + taffy::style_helpers::TaffyMaxContent,
+{
+    match size {
+        TrackSize::Px(px) => length(px),
+        TrackSize::Fr(share) => fr(share),
+        TrackSize::Auto => T::AUTO,
+        TrackSize::MinContent => T::MIN_CONTENT,
+        TrackSize::MaxContent => T::MAX_CONTENT,
+    }
+}
+
+/// Translates the space Taffy offers a leaf into the space passed to
+/// measure functions.
+fn space(available: AvailableSpace) -> Space {
+    match available {
+        AvailableSpace::Definite(width) => Space::Definite(width),
+        AvailableSpace::MinContent => Space::MinContent,
+        AvailableSpace::MaxContent => Space::MaxContent,
+    }
+}
+
+/// Translates a dimension. Viewport-relative sizes are resolved up front,
+/// since Taffy has no notion of the viewport.
+fn dimension(dimension: Dimension, viewport: (f32, f32)) -> taffy::Dimension {
+    match dimension {
+        Dimension::Auto => auto(),
+        Dimension::Px(px) => length(px),
+        Dimension::Percent(value) => percent(value / 100.0),
+        Dimension::Vw(value) => length(viewport.0 * value / 100.0),
+        Dimension::Vh(value) => length(viewport.1 * value / 100.0),
+    }
+}
+
+fn edges<T>(edges: Edges) -> Rect<T>
+where
+    T: taffy::style_helpers::FromLength,
+{
+    Rect {
+        left: length(edges.left),
+        right: length(edges.right),
+        top: length(edges.top),
+        bottom: length(edges.bottom),
+    }
+}
+
+/// Translates a placement. Taffy grid lines are one-based.
+fn placement(placement: GridPlacement) -> Line<taffy::GridPlacement> {
+    let start = match placement.start {
+        Some(start) => line(start as i16 + 1),
+        None => auto(),
+    };
+    Line {
+        start,
+        end: span(placement.span as u16),
+    }
+}
+
+fn align(align: Align) -> AlignItems {
+    match align {
+        Align::Start => AlignItems::Start,
+        Align::Center => AlignItems::Center,
+        Align::End => AlignItems::End,
+        Align::Stretch => AlignItems::Stretch,
+    }
+}
@@ -0,0 +1,226 @@
+//! Scrolling lists that only keep nodes for the rows in view.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use ardent_core::layout::{Dimension, Display, ScrollLayout};
+use ardent_core::node::NodeId;
+use ardent_core::scene::{NodeBuilder, Scene, SceneError};
+
+use crate::scroller::scroll_offset;
+
+/// Rows kept beyond each edge of the viewport by default.
+const DEFAULT_OVERSCAN: usize = 4;
+
+/// Creates the node of a row, before it is filled with an item.
+type CreateRow = Box<dyn Fn() -> NodeBuilder + Send + Sync>;
+
+/// Fills a row node with the item at the given index.
+type BindRow = Box<dyn FnMut(&mut Scene, NodeId, usize) + Send + Sync>;
+
+/// A vertically scrolling list of equally tall rows that only has nodes for
+/// the rows in view.
+///
+/// The list is a scroll container with a content node as tall as all rows
+/// together, so scrolling and scrollbars work as for any other container.
+/// [`update`](Self::update) then keeps nodes only for the visible rows and
+/// a few beyond each edge, the overscan, which hides rows appearing a frame
+/// late while scrolling fast. Rows scrolled out of view are not removed
+/// but recycled for rows scrolling in, so a list of ten thousand items
+/// stays at a few dozen nodes.
+///
+/// Row nodes are created by `create_row` and filled by `bind_row` with the
+/// item at an index, every time the node is used for another item. Rows are
+/// placed at the left edge, one `row_height` below the other; give them
+/// `Dimension::Percent(100.0)` as width to span the list.
+///
+/// ```rust,ignore
+/// let mut list = VirtualList::new(
+///     names.len(),
+///     24.0,
+///     || NodeBuilder::rect().size(300.0, 24.0),
+///     move |scene, row, index| {
+///         if let Some(node) = scene.get_node_mut(row) {
+///             node.set_label(names[index].clone());
+///         }
+///     },
+/// );
+/// let container = list.build(&mut scene, root)?;
+/// scene.get_node_mut(container).unwrap().layout_mut().height = Dimension::Px(400.0);
+///
+/// scene.on_frame(move |scene, _| {
+///     let _ = list.update(scene);
+/// });
+/// ```
+pub struct VirtualList {
+    len: usize,
+    row_height: f32,
+    overscan: usize,
+    create_row: CreateRow,
+    bind_row: BindRow,
+
+    /// The scroll container and the content node holding the rows, once
+    /// built.
+    nodes: Option<(NodeId, NodeId)>,
+
+    /// Live row nodes, by the index of the item they show.
+    rows: HashMap<usize, NodeId>,
+
+    /// Whether all live rows need to be filled again.
+    stale: bool,
+}
+
+impl VirtualList {
+    /// Creates a list of `len` items in rows `row_height` logical pixels
+    /// tall.
+    pub fn new<C, B>(len: usize, row_height: f32, create_row: C, bind_row: B) -> Self
+    where
+        C: Fn() -> NodeBuilder + Send + Sync + 'static,
+        B: FnMut(&mut Scene, NodeId, usize) + Send + Sync + 'static,
+    {
+        Self {
+            len,
+            row_height: row_height.max(1.0),
+            overscan: DEFAULT_OVERSCAN,
+            create_row: Box::new(create_row),
+            bind_row: Box::new(bind_row),
+            nodes: None,
+            rows: HashMap::new(),
+            stale: false,
+        }
+    }
+
+    /// Sets how many rows are kept beyond each edge of the viewport.
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+
+    /// Adds the list's scroll container under `parent` and returns its ID.
+    ///
+    /// The container has no size of its own; set its layout width and
+    /// height, or place it in a container that sizes it. Rows appear with
+    /// the first [`update`](Self::update) after a layout pass.
+    pub fn build(&mut self, scene: &mut Scene, parent: NodeId) -> Result<NodeId, SceneError> {
+        let container = NodeBuilder::group()
+            .display(Display::Scroll(ScrollLayout::vertical()))
+            .build(scene, parent)?;
+        let content = NodeBuilder::group()
+            .height(Dimension::Px(self.content_height()))
+            .build(scene, container)?;
+        self.nodes = Some((container, content));
+        self.rows.clear();
+        Ok(container)
+    }
+
+    /// Returns the scroll container, once built.
+    pub fn container(&self) -> Option<NodeId> {
+        self.nodes.map(|(container, _)| container)
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list has no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Changes the number of items and refills all rows on the next
+    /// update, since items may have moved.
+    pub fn set_len(&mut self, scene: &mut Scene, len: usize) {
+        self.len = len;
+        self.stale = true;
+        let height = self.content_height();
+        if let Some((_, content)) = self.nodes
+            && let Some(node) = scene.get_node_mut(content)
+        {
+            node.layout_mut().height = Dimension::Px(height);
+        }
+    }
+
+    /// Refills all rows on the next update, e.g. after items changed.
+    pub fn invalidate(&mut self) {
+        self.stale = true;
+    }
+
+    /// Returns the node showing the item at `index`, if it is live.
+    pub fn row(&self, index: usize) -> Option<NodeId> {
+        self.rows.get(&index).copied()
+    }
+
+    /// Returns the number of live row nodes.
+    pub fn live_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Creates, recycles, and removes row nodes so that exactly the rows in
+    /// view and the overscan have one.
+    ///
+    /// The visible range is taken from the container's current scroll
+    /// offset and its size in the last layout pass. Call it once per frame
+    /// before the layout pass, e.g. from a frame callback.
+    ///
+    /// Returns an error if the list's nodes were removed from the scene.
+    pub fn update(&mut self, scene: &mut Scene) -> Result<(), SceneError> {
+        let Some((container, content)) = self.nodes else {
+            return Ok(());
+        };
+        let viewport = scene
+            .get_node(container)
+            .ok_or(SceneError::NodeNotFound(container))?
+            .computed_layout()
+            .height;
+        let offset = scroll_offset(scene, container).map_or(0.0, |(offset, _)| offset.1);
+        let range = self.range(offset, viewport);
+
+        let mut free = Vec::new();
+        self.rows.retain(|index, id| {
+            let keep = range.contains(index);
+            if !keep {
+                free.push(*id);
+            }
+            keep
+        });
+        if std::mem::take(&mut self.stale) {
+            for (&index, &id) in &self.rows {
+                (self.bind_row)(scene, id, index);
+            }
+        }
+
+        for index in range {
+            if self.rows.contains_key(&index) {
+                continue;
+            }
+            let id = match free.pop() {
+                Some(id) => id,
+                None => (self.create_row)().build(scene, content)?,
+            };
+            if let Some(node) = scene.get_node_mut(id) {
+                node.transform_mut().translate = (0.0, index as f32 * self.row_height);
+            }
+            (self.bind_row)(scene, id, index);
+            self.rows.insert(index, id);
+        }
+        for id in free {
+            let _ = scene.remove_node(id);
+        }
+        Ok(())
+    }
+
+    /// Returns the indices of the rows overlapping the viewport scrolled to
+    /// `offset`, extended by the overscan.
+    fn range(&self, offset: f32, viewport: f32) -> Range<usize> {
+        let first = (offset / self.row_height).floor().max(0.0) as usize;
+        let last = ((offset + viewport) / self.row_height).ceil().max(0.0) as usize;
+        let start = first.saturating_sub(self.overscan).min(self.len);
+        let end = last.saturating_add(self.overscan).min(self.len);
+        start..end.max(start)
+    }
+
+    fn content_height(&self) -> f32 {
+        self.len as f32 * self.row_height
+    }
+}
@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use ardent_core::layout::LayoutStyle;
+use ardent_core::node::Node;
+use ardent_core::shape::Shape;
+use ardent_core::style::Style;
+use ardent_core::transform::Transform;
+use serde::{Deserialize, Serialize};
+
+use crate::error::LoadError;
+
+/// The description of a node and its subtree, as stored in a scene file.
+///
+/// Every field is optional in the file and falls back to the node
+/// defaults.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NodeDescription {
+    /// Name used to look the node up after loading. Named nodes are also
+    /// matched by name when a file is reloaded.
+    pub name: Option<String>,
+
+    /// Position, scale, and rotation relative to the parent.
+    pub transform: Transform,
+
+    /// The shape drawn for the node, if any.
+    pub shape: Option<Shape>,
+
+    /// Fill, stroke, and opacity of the shape.
+    pub style: Style,
+
+    /// How the node takes part in layout.
+    pub layout: LayoutStyle,
+
+    /// Child nodes, in draw order.
+    pub children: Vec<NodeDescription>,
+}
+
+impl NodeDescription {
+    /// Parses a description from text in the given format.
+    pub fn parse(text: &str, format: Format) -> Result<Self, LoadError> {
+        Ok(match format {
+            Format::Ron => ron::from_str(text)?,
+            Format::Json => serde_json::from_str(text)?,
+        })
+    }
+
+    /// Reads and parses a description file, choosing the format by the
+    /// file extension.
+    pub fn read(path: &Path) -> Result<Self, LoadError> {
+        let format =
+            Format::from_path(path).ok_or_else(|| LoadError::UnknownFormat(path.to_owned()))?;
+        Self::parse(&std::fs::read_to_string(path)?, format)
+    }
+
+    /// Creates a node with the described properties, without children.
+    pub(crate) fn to_node(&self) -> Node {
+        let mut node = Node::new();
+        *node.transform_mut() = self.transform.clone();
+        if let Some(shape) = &self.shape {
+            node.set_shape(shape.clone());
+        }
+        *node.style_mut() = self.style.clone();
+        *node.layout_mut() = self.layout.clone();
+        node
+    }
+}
+
+/// A supported scene file format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Rusty Object Notation, with the `.ron` extension.
+    Ron,
+
+    /// JSON, with the `.json` extension.
+    Json,
+}
+
+impl Format {
+    /// Returns the format matching the extension of `path`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ron" => Some(Format::Ron),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use ardent_core::scene::SceneError;
+
+/// An error raised while loading or watching a scene file.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read.
+    Io(io::Error),
+
+    /// The file is not a valid JSON scene description.
+    Json(serde_json::Error),
+
+    /// The file is not a valid RON scene description.
+    Ron(ron::error::SpannedError),
+
+    /// The file extension does not name a supported format.
+    UnknownFormat(PathBuf),
+
+    /// The file could not be watched for changes.
+    Watch(notify::Error),
+
+    /// The described nodes could not be added to the scene.
+    Scene(SceneError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "failed to read scene file: {err}"),
+            LoadError::Json(err) => write!(f, "failed to parse JSON scene: {err}"),
+            LoadError::Ron(err) => write!(f, "failed to parse RON scene: {err}"),
+            LoadError::UnknownFormat(path) => {
+                write!(f, "unknown scene file format: {}", path.display())
+            }
+            LoadError::Watch(err) => write!(f, "failed to watch scene file: {err}"),
+            LoadError::Scene(err) => write!(f, "failed to build scene: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io(err) => Some(err),
+            LoadError::Json(err) => Some(err),
+            LoadError::Ron(err) => Some(err),
+            LoadError::UnknownFormat(_) => None,
+            LoadError::Watch(err) => Some(err),
+            LoadError::Scene(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(err: io::Error) -> Self {
+        LoadError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(err: serde_json::Error) -> Self {
+        LoadError::Json(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for LoadError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        LoadError::Ron(err)
+    }
+}
+
+impl From<notify::Error> for LoadError {
+    fn from(err: notify::Error) -> Self {
+        LoadError::Watch(err)
+    }
+}
+
+impl From<SceneError> for LoadError {
+    fn from(err: SceneError) -> Self {
+        LoadError::Scene(err)
+    }
+}
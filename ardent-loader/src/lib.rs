@@ -0,0 +1,43 @@
+//! The `ardent_loader` crate builds scenes from declarative description
+//! files.
+//!
+//! A scene file describes a tree of nodes with their transform, shape,
+//! style, and layout, in either RON or JSON. The file is loaded into a
+//! [`Scene`](ardent_core::scene::Scene) as a subtree, and can be watched
+//! with [`HotReload`] so edits are applied to the running scene without
+//! recompiling. Only the properties that changed are applied, so runtime
+//! state such as animations on untouched nodes is kept.
+//!
+//! ```ron
+//! (
+//!     name: Some("root"),
+//!     layout: (display: Grid((columns: [Fr(1.0)], rows: [Px(48.0), Fr(1.0)]))),
+//!     children: [
+//!         (
+//!             name: Some("toolbar"),
+//!             shape: Some(Rect((width: 0.0, height: 0.0))),
+//!             style: (fill: Some((color: (0.2, 0.2, 0.25, 1.0)))),
+//!         ),
+//!     ],
+//! )
+//! ```
+//!
+//! ```rust,ignore
+//! let mut reload = HotReload::new(&mut scene, scene.root(), "ui/main.ron")?;
+//! let toolbar = reload.loaded().node("toolbar");
+//!
+//! // Once per frame:
+//! if let Err(e) = reload.poll(&mut scene) {
+//!     eprintln!("{e}");
+//! }
+//! ```
+
+mod description;
+mod error;
+mod load;
+mod reload;
+
+pub use description::{Format, NodeDescription};
+pub use error::LoadError;
+pub use load::{LoadedScene, load, load_file};
+pub use reload::HotReload;
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use ardent_core::node::NodeId;
+use ardent_core::scene::{Scene, SceneError};
+
+use crate::description::NodeDescription;
+use crate::error::LoadError;
+
+/// A scene file loaded into a scene.
+///
+/// Keeps the description the subtree was built from, so a changed
+/// description can later be applied as a set of differences.
+#[derive(Debug)]
+pub struct LoadedScene {
+    description: NodeDescription,
+    mounted: Mounted,
+    names: HashMap<String, NodeId>,
+}
+
+/// The IDs of the nodes built from a description, mirroring its shape.
+#[derive(Debug)]
+struct Mounted {
+    id: NodeId,
+    children: Vec<Mounted>,
+}
+
+/// Builds the described subtree as a child of `parent`.
+///
+/// Returns an error if `parent` is not in the scene.
+pub fn load(
+    scene: &mut Scene,
+    parent: NodeId,
+    description: &NodeDescription,
+) -> Result<LoadedScene, LoadError> {
+    let mounted = mount(scene, parent, description)?;
+    let mut loaded = LoadedScene {
+        description: description.clone(),
+        mounted,
+        names: HashMap::new(),
+    };
+    loaded.index();
+    Ok(loaded)
+}
+
+/// Reads a scene file and builds it as a child of `parent`.
+///
+/// The format is chosen by the file extension (`.ron` or `.json`).
+pub fn load_file(
+    scene: &mut Scene,
+    parent: NodeId,
+    path: impl AsRef<Path>,
+) -> Result<LoadedScene, LoadError> {
+    let description = NodeDescription::read(path.as_ref())?;
+    load(scene, parent, &description)
+}
+
+impl LoadedScene {
+    /// Returns the ID of the node built from the top-level description.
+    pub fn root(&self) -> NodeId {
+        self.mounted.id
+    }
+
+    /// Returns the ID of the node with the given name.
+    ///
+    /// IDs of nodes may change when a reload rebuilds them, so look them up
+    /// again after applying changes.
+    pub fn node(&self, name: &str) -> Option<NodeId> {
+        self.names.get(name).copied()
+    }
+
+    /// Returns the description the subtree currently reflects.
+    pub fn description(&self) -> &NodeDescription {
+        &self.description
+    }
+
+    /// Updates the subtree to match a changed description.
+    ///
+    /// Only properties that differ from the previous description are
+    /// written, so nodes that did not change are not marked dirty. Children
+    /// are matched by position as long as their names agree; from the
+    /// first mismatch on, the remaining children are rebuilt.
+    ///
+    /// Returns an error if rebuilt children cannot be added because their
+    /// parent was removed from the scene behind the loader's back.
+    pub fn apply(
+        &mut self,
+        scene: &mut Scene,
+        description: NodeDescription,
+    ) -> Result<(), LoadError> {
+        let result = patch(scene, &self.description, &description, &mut self.mounted);
+        self.description = description;
+        self.index();
+        Ok(result?)
+    }
+
+    /// Rebuilds the name lookup table.
+    fn index(&mut self) {
+        fn visit(
+            description: &NodeDescription,
+            mounted: &Mounted,
+            names: &mut HashMap<String, NodeId>,
+        ) {
+            if let Some(name) = &description.name {
+                names.insert(name.clone(), mounted.id);
+            }
+            for (child, mounted) in description.children.iter().zip(&mounted.children) {
+                visit(child, mounted, names);
+            }
+        }
+
+        self.names.clear();
+        visit(&self.description, &self.mounted, &mut self.names);
+    }
+}
+
+/// Builds a node and its subtree from a description.
+fn mount(
+    scene: &mut Scene,
+    parent: NodeId,
+    description: &NodeDescription,
+) -> Result<Mounted, SceneError> {
+    let id = scene.add_node(parent, description.to_node())?;
+    let children = description
+        .children
+        .iter()
+        .map(|child| mount(scene, id, child))
+        .collect::<Result<_, _>>()?;
+    Ok(Mounted { id, children })
+}
+
+/// Applies the differences between two descriptions to a mounted subtree.
+fn patch(
+    scene: &mut Scene,
+    old: &NodeDescription,
+    new: &NodeDescription,
+    mounted: &mut Mounted,
+) -> Result<(), SceneError> {
+    let id = mounted.id;
+    if let Some(node) = scene.get_node_mut(id) {
+        if old.transform != new.transform {
+            *node.transform_mut() = new.transform.clone();
+        }
+        if old.shape != new.shape {
+            match &new.shape {
+                Some(shape) => node.set_shape(shape.clone()),
+                None => node.clear_shape(),
+            }
+        }
+        if old.style != new.style {
+            *node.style_mut() = new.style.clone();
+        }
+        if old.layout != new.layout {
+            *node.layout_mut() = new.layout.clone();
+        }
+    }
+
+    let kept = old
+        .children
+        .iter()
+        .zip(&new.children)
+        .take_while(|(old, new)| old.name == new.name)
+        .count();
+    for (i, child) in mounted.children.iter_mut().enumerate().take(kept) {
+        patch(scene, &old.children[i], &new.children[i], child)?;
+    }
+
+    // Nodes the application already removed need no removal.
+    for removed in mounted.children.drain(kept..) {
+        let _ = scene.remove_node(removed.id);
+    }
+    for child in &new.children[kept..] {
+        mounted.children.push(mount(scene, id, child)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+
+use ardent_core::node::NodeId;
+use ardent_core::scene::Scene;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::description::NodeDescription;
+use crate::error::LoadError;
+use crate::load::{LoadedScene, load};
+
+/// A scene file that is reloaded whenever it changes on disk.
+///
+/// The file's directory is watched rather than the file itself, so editors
+/// that save by replacing the file are picked up as well. Changes are
+/// collected in the background and applied by [`HotReload::poll`], which
+/// should be called once per frame.
+pub struct HotReload {
+    path: PathBuf,
+    loaded: LoadedScene,
+    events: Receiver<notify::Result<notify::Event>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl HotReload {
+    /// Loads the scene file as a child of `parent` and starts watching it.
+    pub fn new(
+        scene: &mut Scene,
+        parent: NodeId,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, LoadError> {
+        let path = path.as_ref().canonicalize()?;
+        let description = NodeDescription::read(&path)?;
+
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        let directory = path.parent().unwrap_or(Path::new("."));
+        watcher.watch(directory, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            loaded: load(scene, parent, &description)?,
+            path,
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// Returns the loaded subtree.
+    pub fn loaded(&self) -> &LoadedScene {
+        &self.loaded
+    }
+
+    /// Applies pending changes to the scene file.
+    ///
+    /// Returns `true` if the scene was updated. If the changed file cannot
+    /// be read or parsed, the error is returned and the scene keeps its
+    /// previous state until the file is fixed.
+    pub fn poll(&mut self, scene: &mut Scene) -> Result<bool, LoadError> {
+        let mut changed = false;
+        for event in self.events.try_iter() {
+            let event = event?;
+            changed |= !event.kind.is_access() && event.paths.contains(&self.path);
+        }
+        if !changed {
+            return Ok(false);
+        }
+
+        let description = NodeDescription::read(&self.path)?;
+        if description == *self.loaded.description() {
+            return Ok(false);
+        }
+        self.loaded.apply(scene, description)?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,42 @@
+use ardent_core::animation::{AnimationId, Animator, Repeat, Timeline};
+use ardent_core::node::NodeId;
+
+/// A Lottie animation imported into a scene.
+///
+/// Holds one timeline per animated node. All timelines span the whole
+/// composition, so they stay in sync when looping.
+#[derive(Clone, Debug)]
+pub struct LottieAnimation {
+    pub(crate) root: NodeId,
+    pub(crate) size: (f32, f32),
+    pub(crate) duration: f32,
+    pub(crate) timelines: Vec<(NodeId, Timeline)>,
+}
+
+impl LottieAnimation {
+    /// Returns the node holding the imported composition.
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    /// Returns the width and height of the composition.
+    pub fn size(&self) -> (f32, f32) {
+        self.size
+    }
+
+    /// Returns the length of the animation in seconds.
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Starts playing the animation from the beginning.
+    ///
+    /// Returns the IDs of the started animations, which can be used to
+    /// cancel playback.
+    pub fn play(&self, animator: &mut Animator, repeat: Repeat) -> Vec<AnimationId> {
+        self.timelines
+            .iter()
+            .map(|(node, timeline)| animator.add(timeline.clone().repeat(repeat).play(*node)))
+            .collect()
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+use ardent_core::scene::SceneError;
+
+/// An error raised while importing a Lottie document.
+#[derive(Debug)]
+pub enum LottieError {
+    /// The document is not a valid Lottie JSON file.
+    Parse(serde_json::Error),
+
+    /// The animation could not be added to the scene.
+    Scene(SceneError),
+}
+
+impl fmt::Display for LottieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LottieError::Parse(err) => write!(f, "failed to parse Lottie document: {err}"),
+            LottieError::Scene(err) => write!(f, "failed to add animation to scene: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for LottieError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LottieError::Parse(err) => Some(err),
+            LottieError::Scene(err) => Some(err),
+        }
+    }
+}
+
+impl From<serde_json::Error> for LottieError {
+    fn from(err: serde_json::Error) -> Self {
+        LottieError::Parse(err)
+    }
+}
+
+impl From<SceneError> for LottieError {
+    fn from(err: SceneError) -> Self {
+        LottieError::Scene(err)
+    }
+}
@@ -0,0 +1,576 @@
+use std::collections::HashMap;
+
+use ardent_core::animation::{Property, Timeline, Track, Value};
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::{Scene, SceneError};
+use ardent_core::shape::{Path, PathCommand, Rect, Shape};
+use ardent_core::style::{Color, Fill, FillRule, Stroke, StrokeCap, StrokeJoin};
+use serde_json::Value as Json;
+
+use crate::animation::LottieAnimation;
+use crate::model::{self, Composition, Layer, SHAPE_LAYER, SOLID_LAYER, ShapeItem};
+use crate::property::{Animated, Timing};
+
+/// Distance of the control points of a cubic Bézier approximating a quarter
+/// circle, relative to the radius.
+const KAPPA: f32 = 0.552_284_8;
+
+/// Mirrors a composition into the scene and converts its animated
+/// properties into timelines.
+pub(crate) fn import(
+    scene: &mut Scene,
+    parent: NodeId,
+    composition: &Composition,
+) -> Result<LottieAnimation, SceneError> {
+    let timing = Timing {
+        frame_rate: composition.frame_rate.max(1.0),
+        start: composition.in_point,
+        end: composition.out_point,
+    };
+    let root_id = scene.add_node(parent, Node::new())?;
+
+    let mut importer = Importer {
+        scene,
+        timing,
+        layers: &composition.layers,
+        root: root_id,
+        built: HashMap::new(),
+        timelines: Vec::new(),
+    };
+    // Layers are listed topmost first, while later children are drawn on top.
+    for index in (0..composition.layers.len()).rev() {
+        importer.layer(index)?;
+    }
+
+    Ok(LottieAnimation {
+        root: root_id,
+        size: (composition.width, composition.height),
+        duration: timing.duration(),
+        timelines: importer.timelines,
+    })
+}
+
+struct Importer<'a> {
+    scene: &'a mut Scene,
+    timing: Timing,
+    layers: &'a [Layer],
+
+    /// Node holding the composition.
+    root: NodeId,
+
+    /// Content node of each layer, or `None` while the layer is being built.
+    built: HashMap<usize, Option<NodeId>>,
+
+    timelines: Vec<(NodeId, Timeline)>,
+}
+
+/// The opacities multiplied into the nodes of a layer.
+///
+/// Node opacity does not apply to children, so the opacity of layers and
+/// groups is folded into every painted node below them.
+#[derive(Clone)]
+struct Opacity<'a> {
+    /// Opacity properties, in percent.
+    factors: Vec<&'a Animated>,
+
+    /// Frames between which the layer is visible.
+    window: (f32, f32),
+}
+
+/// The start and end of a trim, in percent.
+type Trim<'a> = (&'a Animated, &'a Animated);
+
+impl<'a> Importer<'a> {
+    /// Builds a layer and returns the node its content and child layers
+    /// are added to.
+    ///
+    /// Parent layers are built first, so child layers are nested in their
+    /// parent's content and follow its transform. A layer may therefore be
+    /// drawn above layers that precede it in the document.
+    fn layer(&mut self, index: usize) -> Result<NodeId, SceneError> {
+        if let Some(&Some(content)) = self.built.get(&index) {
+            return Ok(content);
+        }
+        self.built.insert(index, None);
+
+        let layers = self.layers;
+        let layer = &layers[index];
+        let parent = layer
+            .parent
+            .and_then(|ind| layers.iter().position(|other| other.ind == Some(ind)));
+        let parent = match parent {
+            // A parent that is still being built means the parenting is
+            // cyclic; such layers are attached to the root instead.
+            Some(parent) if self.built.get(&parent) != Some(&None) => self.layer(parent)?,
+            _ => self.root,
+        };
+
+        let content = self.transform(parent, &layer.transform)?;
+        self.built.insert(index, Some(content));
+        if layer.hd {
+            return Ok(content);
+        }
+
+        let opacity = Opacity {
+            factors: layer.transform.opacity.iter().collect(),
+            window: (layer.in_point, layer.out_point),
+        };
+        match layer.ty {
+            SHAPE_LAYER => self.items(content, &layer.shapes, &[], &[], &opacity)?,
+            SOLID_LAYER => self.solid(content, layer, &opacity)?,
+            _ => {}
+        }
+        Ok(content)
+    }
+
+    /// Creates a node for a layer or group transform and returns the child
+    /// its content is added to, which compensates for the anchor point.
+    fn transform(
+        &mut self,
+        parent: NodeId,
+        transform: &model::Transform,
+    ) -> Result<NodeId, SceneError> {
+        let timing = self.timing;
+        let start = timing.start;
+
+        let mut node = Node::new();
+        let local = node.transform_mut();
+        if let Some(position) = &transform.position {
+            local.translate = vec2(&position.sample(start));
+        }
+        if let Some(scale) = &transform.scale {
+            local.scale = percent2(&scale.sample(start));
+        }
+        if let Some(rotation) = &transform.rotation {
+            local.rotate = rotation.sample_scalar(start).to_radians();
+        }
+        let id = self.scene.add_node(parent, node)?;
+
+        let tracks = [
+            transform.position.as_ref().and_then(|position| {
+                position.track(Property::Translate, &timing, |v| {
+                    let (x, y) = vec2(v);
+                    Value::Vec2(x, y)
+                })
+            }),
+            transform.scale.as_ref().and_then(|scale| {
+                scale.track(Property::Scale, &timing, |v| {
+                    let (x, y) = percent2(v);
+                    Value::Vec2(x, y)
+                })
+            }),
+            transform.rotation.as_ref().and_then(|rotation| {
+                rotation.track(Property::Rotate, &timing, |v| {
+                    Value::Float(first(v).to_radians())
+                })
+            }),
+        ];
+        self.animate(id, tracks);
+
+        let mut content = Node::new();
+        if let Some(anchor) = &transform.anchor {
+            let (x, y) = vec2(&anchor.sample(start));
+            content.transform_mut().translate = (-x, -y);
+        }
+        self.scene.add_node(id, content)
+    }
+
+    /// Builds the items of a shape layer or group.
+    ///
+    /// All geometry of the group is merged into one path, which is painted
+    /// by each fill and stroke of the group. Groups without their own fills
+    /// and strokes use those of the enclosing group.
+    fn items(
+        &mut self,
+        parent: NodeId,
+        items: &'a [ShapeItem],
+        inherited: &[&'a ShapeItem],
+        trims: &[Trim<'a>],
+        opacity: &Opacity<'a>,
+    ) -> Result<(), SceneError> {
+        let start = self.timing.start;
+        let mut path = Path::new();
+        let mut styles = Vec::new();
+        let mut trims = trims.to_vec();
+        for item in items {
+            match item {
+                ShapeItem::Rect {
+                    position,
+                    size,
+                    roundness,
+                } => rect(
+                    &mut path,
+                    &position.sample(start),
+                    &size.sample(start),
+                    roundness.as_ref().map_or(0.0, |r| r.sample_scalar(start)),
+                ),
+                ShapeItem::Ellipse { position, size } => {
+                    ellipse(&mut path, &position.sample(start), &size.sample(start))
+                }
+                ShapeItem::Path { shape } => bezier(&mut path, shape.initial_json()),
+                ShapeItem::Fill { .. } | ShapeItem::Stroke { .. } => styles.push(item),
+                ShapeItem::Trim { start, end } => trims.push((start, end)),
+                _ => {}
+            }
+        }
+
+        if styles.is_empty() {
+            if !path.is_empty() {
+                for &style in inherited.iter().rev() {
+                    self.paint(parent, &path, style, &trims, opacity)?;
+                }
+            }
+            styles = inherited.to_vec();
+        }
+
+        // Items are listed topmost first, while later children are drawn
+        // on top.
+        for item in items.iter().rev() {
+            match item {
+                ShapeItem::Group { items, hd: false } => {
+                    self.group(parent, items, &styles, &trims, opacity)?
+                }
+                ShapeItem::Fill { .. } | ShapeItem::Stroke { .. } if !path.is_empty() => {
+                    self.paint(parent, &path, item, &trims, opacity)?
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn group(
+        &mut self,
+        parent: NodeId,
+        items: &'a [ShapeItem],
+        styles: &[&'a ShapeItem],
+        trims: &[Trim<'a>],
+        opacity: &Opacity<'a>,
+    ) -> Result<(), SceneError> {
+        let transform = items.iter().find_map(|item| match item {
+            ShapeItem::Transform(transform) => Some(transform),
+            _ => None,
+        });
+        let content = match transform {
+            Some(transform) => self.transform(parent, transform)?,
+            None => self.transform(parent, &model::Transform::default())?,
+        };
+        let opacity = opacity.with(transform.and_then(|t| t.opacity.as_ref()));
+        self.items(content, items, styles, trims, &opacity)
+    }
+
+    /// Adds a node drawing `path` with a fill or stroke.
+    fn paint(
+        &mut self,
+        parent: NodeId,
+        path: &Path,
+        style: &'a ShapeItem,
+        trims: &[Trim<'a>],
+        opacity: &Opacity<'a>,
+    ) -> Result<(), SceneError> {
+        let timing = self.timing;
+        let start = timing.start;
+
+        let mut node = Node::new();
+        node.set_shape(Shape::Path(path.clone()));
+        let mut tracks = Vec::new();
+
+        match style {
+            ShapeItem::Fill {
+                color,
+                opacity: fill_opacity,
+                rule,
+            } => {
+                let rule = if *rule == Some(2) {
+                    FillRule::EvenOdd
+                } else {
+                    FillRule::NonZero
+                };
+                node.style_mut().fill = Some(Fill {
+                    rule,
+                    ..Fill::solid(color_of(&color.sample(start)))
+                });
+                tracks
+                    .push(color.track(Property::FillColor, &timing, |v| Value::Color(color_of(v))));
+                tracks.push(self.opacity(&mut node, &opacity.with(Some(fill_opacity))));
+            }
+            ShapeItem::Stroke {
+                color,
+                opacity: stroke_opacity,
+                width,
+                cap,
+                join,
+                miter_limit,
+                dashes,
+            } => {
+                let mut stroke =
+                    Stroke::new(color_of(&color.sample(start)), width.sample_scalar(start));
+                stroke.cap = match cap {
+                    Some(2) => StrokeCap::Round,
+                    Some(3) => StrokeCap::Square,
+                    _ => StrokeCap::Butt,
+                };
+                stroke.join = match join {
+                    Some(2) => StrokeJoin::Round,
+                    Some(3) => StrokeJoin::Bevel,
+                    _ => StrokeJoin::Miter,
+                };
+                if let Some(limit) = miter_limit {
+                    stroke.miter_limit = *limit;
+                }
+                for dash in dashes {
+                    let length = dash.length.sample_scalar(start);
+                    match dash.kind.as_str() {
+                        "o" => stroke.dash_offset = length,
+                        _ => stroke.dashes.push(length),
+                    }
+                }
+                // Later trims override earlier ones.
+                if let Some((trim_start, trim_end)) = trims.last() {
+                    stroke.trim_start = trim_start.sample_scalar(start) / 100.0;
+                    stroke.trim_end = trim_end.sample_scalar(start) / 100.0;
+                    tracks.push(trim_start.track(Property::TrimStart, &timing, |v| {
+                        Value::Float(first(v) / 100.0)
+                    }));
+                    tracks.push(trim_end.track(Property::TrimEnd, &timing, |v| {
+                        Value::Float(first(v) / 100.0)
+                    }));
+                }
+                node.style_mut().stroke = Some(stroke);
+                tracks.push(self.opacity(&mut node, &opacity.with(Some(stroke_opacity))));
+            }
+            _ => return Ok(()),
+        }
+
+        let id = self.scene.add_node(parent, node)?;
+        self.animate(id, tracks);
+        Ok(())
+    }
+
+    /// Adds a node drawing the rectangle of a solid layer.
+    fn solid(
+        &mut self,
+        parent: NodeId,
+        layer: &Layer,
+        opacity: &Opacity<'a>,
+    ) -> Result<(), SceneError> {
+        let Some(color) = layer.solid_color.as_deref().and_then(hex_color) else {
+            return Ok(());
+        };
+        let mut node = Node::new();
+        node.set_shape(Shape::Rect(Rect::new(
+            layer.solid_width,
+            layer.solid_height,
+        )));
+        node.style_mut().fill = Some(Fill::solid(color));
+        let track = self.opacity(&mut node, opacity);
+        let id = self.scene.add_node(parent, node)?;
+        self.animate(id, [track]);
+        Ok(())
+    }
+
+    /// Sets the node's opacity at the start of the animation and returns a
+    /// track if it changes over time.
+    ///
+    /// Combined opacities are sampled once per frame.
+    fn opacity(&self, node: &mut Node, opacity: &Opacity<'a>) -> Option<Track> {
+        let timing = self.timing;
+        node.style_mut().opacity = opacity.at(timing.start);
+
+        let (shown, hidden) = opacity.window;
+        let always_visible = shown <= timing.start && hidden >= timing.end;
+        if always_visible && !opacity.factors.iter().any(|f| f.is_animated()) {
+            return None;
+        }
+        let mut track = Track::new(Property::Opacity);
+        let mut frame = timing.start;
+        while frame <= timing.end {
+            track = track.key(timing.seconds(frame), Value::Float(opacity.at(frame)));
+            frame += 1.0;
+        }
+        Some(track)
+    }
+
+    /// Plays the given tracks on a node, if there are any.
+    fn animate(&mut self, node: NodeId, tracks: impl IntoIterator<Item = Option<Track>>) {
+        let tracks: Vec<Track> = tracks.into_iter().flatten().collect();
+        if !tracks.is_empty() {
+            let timeline = tracks.into_iter().fold(Timeline::new(), Timeline::track);
+            self.timelines.push((node, timeline));
+        }
+    }
+}
+
+impl<'a> Opacity<'a> {
+    /// Returns a copy with an additional opacity factor.
+    fn with(&self, factor: Option<&'a Animated>) -> Self {
+        let mut opacity = self.clone();
+        opacity.factors.extend(factor);
+        opacity
+    }
+
+    /// Returns the combined opacity at the given frame.
+    fn at(&self, frame: f32) -> f32 {
+        if frame < self.window.0 || frame >= self.window.1 {
+            return 0.0;
+        }
+        self.factors
+            .iter()
+            .map(|factor| factor.sample_scalar(frame) / 100.0)
+            .product()
+    }
+}
+
+/// Appends a rectangle around `center`, with corners rounded by `radius`.
+fn rect(path: &mut Path, center: &[f32], size: &[f32], radius: f32) {
+    let (cx, cy) = vec2(center);
+    let (width, height) = vec2(size);
+    let (left, top) = (cx - width / 2.0, cy - height / 2.0);
+    let (right, bottom) = (cx + width / 2.0, cy + height / 2.0);
+    let radius = radius.min(width / 2.0).min(height / 2.0).max(0.0);
+
+    if radius == 0.0 {
+        path.push(PathCommand::MoveTo(right, top));
+        path.push(PathCommand::LineTo(right, bottom));
+        path.push(PathCommand::LineTo(left, bottom));
+        path.push(PathCommand::LineTo(left, top));
+        path.push(PathCommand::Close);
+        return;
+    }
+
+    let k = radius * (1.0 - KAPPA);
+    path.push(PathCommand::MoveTo(right, top + radius));
+    path.push(PathCommand::LineTo(right, bottom - radius));
+    path.push(PathCommand::CubicTo {
+        ctrl1: (right, bottom - k),
+        ctrl2: (right - k, bottom),
+        to: (right - radius, bottom),
+    });
+    path.push(PathCommand::LineTo(left + radius, bottom));
+    path.push(PathCommand::CubicTo {
+        ctrl1: (left + k, bottom),
+        ctrl2: (left, bottom - k),
+        to: (left, bottom - radius),
+    });
+    path.push(PathCommand::LineTo(left, top + radius));
+    path.push(PathCommand::CubicTo {
+        ctrl1: (left, top + k),
+        ctrl2: (left + k, top),
+        to: (left + radius, top),
+    });
+    path.push(PathCommand::LineTo(right - radius, top));
+    path.push(PathCommand::CubicTo {
+        ctrl1: (right - k, top),
+        ctrl2: (right, top + k),
+        to: (right, top + radius),
+    });
+    path.push(PathCommand::Close);
+}
+
+/// Appends an ellipse around `center` as four cubic Bézier arcs.
+fn ellipse(path: &mut Path, center: &[f32], size: &[f32]) {
+    let (cx, cy) = vec2(center);
+    let (width, height) = vec2(size);
+    let (rx, ry) = (width / 2.0, height / 2.0);
+    let (ox, oy) = (rx * KAPPA, ry * KAPPA);
+
+    path.push(PathCommand::MoveTo(cx, cy - ry));
+    path.push(PathCommand::CubicTo {
+        ctrl1: (cx + ox, cy - ry),
+        ctrl2: (cx + rx, cy - oy),
+        to: (cx + rx, cy),
+    });
+    path.push(PathCommand::CubicTo {
+        ctrl1: (cx + rx, cy + oy),
+        ctrl2: (cx + ox, cy + ry),
+        to: (cx, cy + ry),
+    });
+    path.push(PathCommand::CubicTo {
+        ctrl1: (cx - ox, cy + ry),
+        ctrl2: (cx - rx, cy + oy),
+        to: (cx - rx, cy),
+    });
+    path.push(PathCommand::CubicTo {
+        ctrl1: (cx - rx, cy - oy),
+        ctrl2: (cx - ox, cy - ry),
+        to: (cx, cy - ry),
+    });
+    path.push(PathCommand::Close);
+}
+
+/// Appends a Lottie Bézier shape.
+///
+/// Shapes store their vertices with in and out tangents relative to each
+/// vertex.
+fn bezier(path: &mut Path, shape: &Json) {
+    let points = |name: &str| -> Vec<(f32, f32)> {
+        shape
+            .get(name)
+            .and_then(Json::as_array)
+            .map_or_else(Vec::new, |points| points.iter().map(point).collect())
+    };
+    let vertices = points("v");
+    let ins = points("i");
+    let outs = points("o");
+    let closed = shape.get("c").and_then(Json::as_bool).unwrap_or(false);
+    let Some(&(x, y)) = vertices.first() else {
+        return;
+    };
+
+    let tangent = |list: &[(f32, f32)], i: usize| list.get(i).copied().unwrap_or((0.0, 0.0));
+    path.push(PathCommand::MoveTo(x, y));
+    let segments = if closed {
+        vertices.len()
+    } else {
+        vertices.len() - 1
+    };
+    for i in 0..segments {
+        let j = (i + 1) % vertices.len();
+        let (from, to) = (vertices[i], vertices[j]);
+        let (out, inward) = (tangent(&outs, i), tangent(&ins, j));
+        path.push(PathCommand::CubicTo {
+            ctrl1: (from.0 + out.0, from.1 + out.1),
+            ctrl2: (to.0 + inward.0, to.1 + inward.1),
+            to,
+        });
+    }
+    if closed {
+        path.push(PathCommand::Close);
+    }
+}
+
+fn point(value: &Json) -> (f32, f32) {
+    let component = |i: usize| value.get(i).and_then(Json::as_f64).unwrap_or(0.0) as f32;
+    (component(0), component(1))
+}
+
+fn first(value: &[f32]) -> f32 {
+    value.first().copied().unwrap_or(0.0)
+}
+
+fn vec2(value: &[f32]) -> (f32, f32) {
+    (first(value), value.get(1).copied().unwrap_or(0.0))
+}
+
+/// Converts a scale in percent into a factor.
+fn percent2(value: &[f32]) -> (f32, f32) {
+    let (x, y) = vec2(value);
+    (x / 100.0, y / 100.0)
+}
+
+/// Converts an RGB color. Alpha is ignored, since Lottie controls
+/// transparency through opacity properties.
+fn color_of(value: &[f32]) -> Color {
+    let channel = |i: usize| value.get(i).copied().unwrap_or(0.0);
+    Color::rgb(channel(0), channel(1), channel(2))
+}
+
+/// Parses a `#rrggbb` color.
+fn hex_color(text: &str) -> Option<Color> {
+    let hex = text.strip_prefix('#').unwrap_or(text);
+    let channel = |i: usize| {
+        let value = u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()?;
+        Some(value as f32 / 255.0)
+    };
+    Some(Color::rgb(channel(0)?, channel(2)?, channel(4)?))
+}
@@ -0,0 +1,57 @@
+//! The `ardent_lottie` crate plays Lottie (bodymovin) animations natively.
+//!
+//! A Lottie document is imported as a scene subtree: layers and shape
+//! groups become nodes carrying their transforms, and each fill or stroke
+//! becomes a node with a [`Shape::Path`](ardent_core::shape::Shape::Path).
+//! Animated properties are converted into keyframed
+//! [`Timeline`](ardent_core::animation::Timeline)s, which are played by the
+//! scene's [`Animator`](ardent_core::animation::Animator) like any other
+//! animation.
+//!
+//! ```rust,ignore
+//! let data = std::fs::read("spinner.json")?;
+//! let lottie = ardent_lottie::load(&mut scene, scene.root(), &data)?;
+//! lottie.play(&mut animator, Repeat::Forever);
+//! ```
+//!
+//! Supported are shape, solid, and null layers with parenting and in/out
+//! points, groups, rectangles, ellipses, and paths, solid fills and
+//! strokes, and trim paths. Transforms, opacity, fill color, and trim
+//! ranges may be animated. Animated geometry, stroke color and width keep
+//! their value at the start of the animation, and precompositions, images,
+//! text, masks, mattes, and effects are skipped.
+
+mod animation;
+mod error;
+mod import;
+mod model;
+mod property;
+
+pub use animation::LottieAnimation;
+pub use error::LottieError;
+
+use ardent_core::node::NodeId;
+use ardent_core::scene::Scene;
+
+/// Parses a Lottie document and adds it to `scene` as a child of `parent`.
+///
+/// Returns the imported animation, which still has to be played, or an
+/// error if the document is invalid or `parent` is not in the scene.
+pub fn load(
+    scene: &mut Scene,
+    parent: NodeId,
+    data: &[u8],
+) -> Result<LottieAnimation, LottieError> {
+    let composition = serde_json::from_slice(data)?;
+    Ok(import::import(scene, parent, &composition)?)
+}
+
+/// Parses a Lottie document from a string and adds it to `scene` as a child
+/// of `parent`.
+pub fn load_str(
+    scene: &mut Scene,
+    parent: NodeId,
+    text: &str,
+) -> Result<LottieAnimation, LottieError> {
+    load(scene, parent, text.as_bytes())
+}
@@ -0,0 +1,228 @@
+//! The subset of the Lottie JSON schema understood by the importer.
+//!
+//! Field names follow the abbreviated keys of the format.
+
+use serde::Deserialize;
+
+use crate::property::Animated;
+
+/// The top-level composition of a Lottie document.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Composition {
+    /// Frames per second.
+    #[serde(rename = "fr")]
+    pub frame_rate: f32,
+
+    /// First frame of the animation.
+    #[serde(rename = "ip")]
+    pub in_point: f32,
+
+    /// Frame at which the animation ends.
+    #[serde(rename = "op")]
+    pub out_point: f32,
+
+    #[serde(rename = "w")]
+    pub width: f32,
+
+    #[serde(rename = "h")]
+    pub height: f32,
+
+    /// Layers, topmost first.
+    #[serde(default)]
+    pub layers: Vec<Layer>,
+}
+
+/// Layer types, as stored in [`Layer::ty`].
+pub(crate) const SOLID_LAYER: u32 = 1;
+pub(crate) const SHAPE_LAYER: u32 = 4;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Layer {
+    /// The kind of layer, e.g. [`SHAPE_LAYER`].
+    pub ty: u32,
+
+    /// Index referenced by the `parent` of other layers.
+    #[serde(default)]
+    pub ind: Option<f64>,
+
+    /// Index of the layer whose transform this layer inherits.
+    #[serde(default)]
+    pub parent: Option<f64>,
+
+    #[serde(rename = "ks", default)]
+    pub transform: Transform,
+
+    /// Frame at which the layer appears.
+    #[serde(rename = "ip", default)]
+    pub in_point: f32,
+
+    /// Frame at which the layer disappears.
+    #[serde(rename = "op", default = "never")]
+    pub out_point: f32,
+
+    /// Whether the layer is hidden.
+    #[serde(default)]
+    pub hd: bool,
+
+    /// Contents of shape layers.
+    #[serde(default)]
+    pub shapes: Vec<ShapeItem>,
+
+    /// Color of solid layers, as `#rrggbb`.
+    #[serde(rename = "sc", default)]
+    pub solid_color: Option<String>,
+
+    #[serde(rename = "sw", default)]
+    pub solid_width: f32,
+
+    #[serde(rename = "sh", default)]
+    pub solid_height: f32,
+}
+
+/// The transform of a layer or shape group.
+///
+/// Points are mapped by translating by `-anchor`, scaling, rotating, and
+/// finally translating by `position`.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct Transform {
+    #[serde(rename = "a")]
+    pub anchor: Option<Animated>,
+
+    #[serde(rename = "p")]
+    pub position: Option<Animated>,
+
+    /// Scale in percent.
+    #[serde(rename = "s")]
+    pub scale: Option<Animated>,
+
+    /// Clockwise rotation in degrees.
+    #[serde(rename = "r")]
+    pub rotation: Option<Animated>,
+
+    /// Opacity in percent.
+    #[serde(rename = "o")]
+    pub opacity: Option<Animated>,
+}
+
+/// An element of a shape layer or group.
+///
+/// Items are listed topmost first. Fills and strokes paint the geometry
+/// listed before them.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "ty")]
+pub(crate) enum ShapeItem {
+    #[serde(rename = "gr")]
+    Group {
+        #[serde(rename = "it", default)]
+        items: Vec<ShapeItem>,
+
+        #[serde(default)]
+        hd: bool,
+    },
+
+    /// A rectangle centered on `position`.
+    #[serde(rename = "rc")]
+    Rect {
+        #[serde(rename = "p")]
+        position: Animated,
+
+        #[serde(rename = "s")]
+        size: Animated,
+
+        /// Corner radius.
+        #[serde(rename = "r")]
+        roundness: Option<Animated>,
+    },
+
+    /// An ellipse centered on `position`.
+    #[serde(rename = "el")]
+    Ellipse {
+        #[serde(rename = "p")]
+        position: Animated,
+
+        #[serde(rename = "s")]
+        size: Animated,
+    },
+
+    /// A Bézier path.
+    #[serde(rename = "sh")]
+    Path {
+        #[serde(rename = "ks")]
+        shape: Animated,
+    },
+
+    #[serde(rename = "fl")]
+    Fill {
+        /// RGB color with components in [0, 1].
+        #[serde(rename = "c")]
+        color: Animated,
+
+        /// Opacity in percent.
+        #[serde(rename = "o")]
+        opacity: Animated,
+
+        /// 1 for non-zero, 2 for even-odd.
+        #[serde(rename = "r", default)]
+        rule: Option<u8>,
+    },
+
+    #[serde(rename = "st")]
+    Stroke {
+        #[serde(rename = "c")]
+        color: Animated,
+
+        #[serde(rename = "o")]
+        opacity: Animated,
+
+        #[serde(rename = "w")]
+        width: Animated,
+
+        /// 1 for butt, 2 for round, 3 for square caps.
+        #[serde(rename = "lc", default)]
+        cap: Option<u8>,
+
+        /// 1 for miter, 2 for round, 3 for bevel joins.
+        #[serde(rename = "lj", default)]
+        join: Option<u8>,
+
+        #[serde(rename = "ml", default)]
+        miter_limit: Option<f32>,
+
+        /// Dashes, gaps, and the offset of the dash pattern, in any order.
+        #[serde(rename = "d", default)]
+        dashes: Vec<Dash>,
+    },
+
+    /// Trims the strokes of the group, with start and end in percent.
+    #[serde(rename = "tm")]
+    Trim {
+        #[serde(rename = "s")]
+        start: Animated,
+
+        #[serde(rename = "e")]
+        end: Animated,
+    },
+
+    /// The transform of the enclosing group.
+    #[serde(rename = "tr")]
+    Transform(Transform),
+
+    #[serde(other)]
+    Unsupported,
+}
+
+/// An entry of a stroke's dash pattern.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Dash {
+    /// "d" for a dash, "g" for a gap, or "o" for the offset.
+    #[serde(rename = "n")]
+    pub kind: String,
+
+    #[serde(rename = "v")]
+    pub length: Animated,
+}
+
+/// The out point of layers that never disappear.
+fn never() -> f32 {
+    f32::MAX
+}
@@ -0,0 +1,203 @@
+use ardent_core::animation::{Easing, Property, Track, Value};
+use serde::Deserialize;
+use serde_json::Value as Json;
+
+static NULL: Json = Json::Null;
+
+/// A property that is either static or keyframed.
+///
+/// The raw value is kept as JSON, since its shape depends on the property:
+/// a number, a list of numbers, a Bézier shape, or a list of keyframes.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Animated {
+    #[serde(rename = "k")]
+    value: Json,
+}
+
+/// A keyframe of a numeric property.
+#[derive(Clone, Debug)]
+struct Key {
+    /// Time in frames.
+    frame: f32,
+    value: Vec<f32>,
+
+    /// Easing of the segment leading to the next keyframe.
+    easing: Easing,
+
+    /// Holds the value until the next keyframe instead of interpolating.
+    hold: bool,
+}
+
+/// Converts frame numbers of the composition into timeline seconds.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Timing {
+    pub frame_rate: f32,
+
+    /// First frame of the composition, played at time zero.
+    pub start: f32,
+
+    /// Frame at which the composition ends.
+    pub end: f32,
+}
+
+impl Timing {
+    /// Returns the timeline time of a frame.
+    pub fn seconds(&self, frame: f32) -> f32 {
+        (frame - self.start) / self.frame_rate
+    }
+
+    /// Returns the length of the composition in seconds.
+    pub fn duration(&self) -> f32 {
+        self.seconds(self.end)
+    }
+}
+
+impl Animated {
+    /// Returns `true` if the property has keyframes.
+    pub fn is_animated(&self) -> bool {
+        keyframes(&self.value).is_some()
+    }
+
+    /// Returns the value at the given frame.
+    pub fn sample(&self, frame: f32) -> Vec<f32> {
+        let Some(keys) = self.keys() else {
+            return numbers(&self.value).unwrap_or_default();
+        };
+        let next = keys.partition_point(|k| k.frame <= frame);
+        match (next.checked_sub(1), keys.get(next)) {
+            (Some(prev), Some(next)) => {
+                let prev = &keys[prev];
+                if prev.hold {
+                    return prev.value.clone();
+                }
+                let span = next.frame - prev.frame;
+                let progress = if span > 0.0 {
+                    (frame - prev.frame) / span
+                } else {
+                    1.0
+                };
+                let t = prev.easing.apply(progress);
+                prev.value
+                    .iter()
+                    .zip(&next.value)
+                    .map(|(a, b)| a + (b - a) * t)
+                    .collect()
+            }
+            (Some(prev), None) => keys[prev].value.clone(),
+            (None, next) => next.map(|k| k.value.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// Returns the first component of the value at the given frame.
+    pub fn sample_scalar(&self, frame: f32) -> f32 {
+        self.sample(frame).first().copied().unwrap_or(0.0)
+    }
+
+    /// Converts the keyframes into a track of the given property, mapping
+    /// each raw value with `convert`.
+    ///
+    /// Returns `None` for static properties. The track is extended to the
+    /// end of the composition so looping timelines stay in sync.
+    pub fn track(
+        &self,
+        property: Property,
+        timing: &Timing,
+        convert: impl Fn(&[f32]) -> Value,
+    ) -> Option<Track> {
+        let keys = self.keys()?;
+        let mut track = Track::new(property);
+        let mut previous: Option<&Key> = None;
+        for key in &keys {
+            let time = timing.seconds(key.frame);
+            track = match previous {
+                // Two keyframes at the same time make the value jump.
+                Some(prev) if prev.hold => track
+                    .key(time, convert(&prev.value))
+                    .key(time, convert(&key.value)),
+                Some(prev) => track.key_eased(time, convert(&key.value), prev.easing),
+                None => track.key(time, convert(&key.value)),
+            };
+            previous = Some(key);
+        }
+        if let Some(last) = previous
+            && track.duration() < timing.duration()
+        {
+            track = track.key(timing.duration(), convert(&last.value));
+        }
+        Some(track)
+    }
+
+    /// Returns the raw JSON value at the start of the animation, for
+    /// properties that are not numeric (e.g. Bézier shapes).
+    pub fn initial_json(&self) -> &Json {
+        match keyframes(&self.value).and_then(|keys| keys.first()) {
+            Some(key) => match key.get("s") {
+                // Shape keyframes wrap their value in a list.
+                Some(Json::Array(values)) if !values.is_empty() => &values[0],
+                Some(value) => value,
+                None => &NULL,
+            },
+            None => &self.value,
+        }
+    }
+
+    /// Parses the keyframes of an animated property.
+    fn keys(&self) -> Option<Vec<Key>> {
+        let raw = keyframes(&self.value)?;
+        let mut keys: Vec<Key> = Vec::with_capacity(raw.len());
+        for key in raw {
+            // Older documents store the end value of a segment in the
+            // keyframe itself and omit the start value of the last one.
+            let value = key
+                .get("s")
+                .and_then(numbers)
+                .or_else(|| raw_end(raw, keys.len()))
+                .unwrap_or_default();
+            keys.push(Key {
+                frame: key.get("t").and_then(Json::as_f64).unwrap_or(0.0) as f32,
+                value,
+                easing: easing(key),
+                hold: key.get("h").and_then(Json::as_f64).unwrap_or(0.0) != 0.0,
+            });
+        }
+        Some(keys)
+    }
+}
+
+/// Returns the end value stored in the keyframe before `index`.
+fn raw_end(raw: &[Json], index: usize) -> Option<Vec<f32>> {
+    raw.get(index.checked_sub(1)?)?.get("e").and_then(numbers)
+}
+
+/// Returns the keyframes if the value is a list of keyframe objects.
+fn keyframes(value: &Json) -> Option<&Vec<Json>> {
+    match value {
+        Json::Array(keys) if keys.first().is_some_and(|k| k.get("t").is_some()) => Some(keys),
+        _ => None,
+    }
+}
+
+/// Reads the Bézier easing of the segment leading away from a keyframe.
+///
+/// Handles are stored per dimension; the first dimension is used.
+fn easing(key: &Json) -> Easing {
+    let handle = |name: &str| {
+        let handle = key.get(name)?;
+        let x = numbers(handle.get("x")?)?.first().copied()?;
+        let y = numbers(handle.get("y")?)?.first().copied()?;
+        Some((x, y))
+    };
+    match (handle("o"), handle("i")) {
+        (Some((x1, y1)), Some((x2, y2))) => Easing::CubicBezier(x1, y1, x2, y2),
+        _ => Easing::Linear,
+    }
+}
+
+/// Reads a number or a list of numbers.
+fn numbers(value: &Json) -> Option<Vec<f32>> {
+    match value {
+        Json::Number(n) => Some(vec![n.as_f64()? as f32]),
+        Json::Array(items) => items.iter().map(|n| Some(n.as_f64()? as f32)).collect(),
+        _ => None,
+    }
+}
@@ -0,0 +1,80 @@
+//! Tessellation and full-frame rendering of synthetic scenes.
+//!
+//! Run with `cargo bench -p ardent-render`. Frame benchmarks need a GPU
+//! adapter and are skipped without one.
+
+use ardent_core::scene::{Scene, SyntheticScene};
+use ardent_core::shape::Shape;
+use ardent_render::geometry::Geometry;
+use ardent_render::tesselate::{Tesselate, TesselateOptions};
+use ardent_render::{GpuContext, Renderer};
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use lyon::tessellation::FillTessellator;
+
+const SIZES: [usize; 2] = [1_000, 10_000];
+
+fn shapes(scene: &Scene) -> Vec<Shape> {
+    let mut shapes = Vec::new();
+    scene.traverse(|node| shapes.extend(node.shape().cloned()));
+    shapes
+}
+
+fn tessellation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tessellate");
+    let options = TesselateOptions::default();
+    for nodes in SIZES {
+        let shapes = shapes(&SyntheticScene::new(nodes).build());
+        group.throughput(Throughput::Elements(shapes.len() as u64));
+        group.bench_function(BenchmarkId::from_parameter(nodes), |b| {
+            let mut tessellator = FillTessellator::new();
+            b.iter(|| {
+                let mut geometry = Geometry::new();
+                for shape in &shapes {
+                    let _ = shape.tesselate(&mut geometry, &mut tessellator, &options);
+                }
+                black_box(geometry.indices.len())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn frame(c: &mut Criterion) {
+    let context = match pollster::block_on(GpuContext::headless(1024, 768)) {
+        Ok(context) => context,
+        Err(e) => {
+            eprintln!("Skipping frame benchmarks: {e}");
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group("frame");
+    group.sample_size(20);
+    for nodes in SIZES {
+        for ratio in [0.0, 0.1, 1.0] {
+            let generator = SyntheticScene::new(nodes).dirty_ratio(ratio);
+            let mut scene = generator.build();
+            let mut renderer = Renderer::new(&context);
+
+            // Tessellate and upload everything once, so iterations measure
+            // steady-state frames with the configured share of changes.
+            if renderer.render_offscreen(&scene, &context).is_err() {
+                continue;
+            }
+            scene.clear_dirty(renderer.rendered_nodes(0));
+
+            group.bench_function(BenchmarkId::new(format!("dirty {ratio}"), nodes), |b| {
+                b.iter(|| {
+                    generator.touch(&mut scene);
+                    let pixels = renderer.render_offscreen(&scene, &context);
+                    scene.clear_dirty(renderer.rendered_nodes(0));
+                    black_box(pixels.map(|pixels| pixels.len()))
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, tessellation, frame);
+criterion_main!(benches);
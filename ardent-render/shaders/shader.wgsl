@@ -1,11 +1,334 @@
-// A minimal passthrough shader for 2D geometry rendering.
+// A minimal shader for 2D geometry rendering.
+//
+// Each node's geometry is tessellated once in local coordinates. The node's
+// world transform (already combined with the viewport projection) and its
+// fill color are supplied through a per-node uniform buffer, so moving or
+// recoloring a node never requires re-tessellation.
+//
+// Image fills sample a texture stretched over the shape's local bounds. The
+// color then only carries the node opacity. Nine-slice fills keep the image
+// borders given by the insets at their pixel size and stretch only the
+// middle of the image.
+//
+// Pattern fills draw stripes, checkers, or dots over the color or image,
+// computed from the local position so they need no texture. Their edges
+// are smoothed over one pixel, measured with screen-space derivatives.
+//
+// Noise fills replace the color with value or simplex noise, summed over
+// octaves and mapped to a color ramp of up to four stops.
+//
+// Vertex colors multiply the fill. They are white for ordinary shapes;
+// mesh gradients carry their blended colors this way.
+//
+// Stroke vertices are drawn in the stroke color instead of the fill, and
+// are tessellated after the fill so the stroke covers its edge.
+//
+// Extended-range targets hold linear light rather than sRGB. For them,
+// colors are decoded to linear light before blending, scaled so white is
+// drawn at the requested level, and tone mapped to the display's peak.
+// These settings are override constants fixed when the pipeline is built.
+
+// Whether the target holds linear light, e.g. a half-float HDR surface.
+override linear_output: bool = false;
+// Brightness of white and of the display's peak, relative to standard white.
+override white_level: f32 = 1.0;
+override peak_level: f32 = 1.0;
+// How values above the peak are handled (0 clamp, 1 Reinhard).
+override tone_mapping: u32 = 0u;
+
+struct NodeUniforms {
+    transform: mat4x4<f32>,
+    color: vec4<f32>,
+    stroke_color: vec4<f32>,
+    // Local-space box the image is stretched over, as (x, y, width, height).
+    // A zero width means the node has no image.
+    image_bounds: vec4<f32>,
+    // Nine-slice insets in image pixels as (left, top, right, bottom), all
+    // zero for stretched images.
+    image_slice: vec4<f32>,
+    // Image size in pixels in xy.
+    image_size: vec4<f32>,
+    // Color of the pattern marks; zero alpha means no pattern.
+    pattern_color: vec4<f32>,
+    // Pattern kind (1 stripes, 2 checkers, 3 dots), spacing, width, and
+    // angle in radians.
+    pattern: vec4<f32>,
+    // Noise kind (0 none, 1 value, 2 simplex), scale, seed as raw bits, and
+    // octaves.
+    noise: vec4<f32>,
+    // Offsets of the four noise ramp stops.
+    noise_offsets: vec4<f32>,
+    // Colors of the noise ramp stops; unused stops repeat the last one.
+    noise_colors: array<vec4<f32>, 4>,
+    // Gradient kind in x (0 none, 1 linear, 2 radial).
+    gradient: vec4<f32>,
+    // Start and end of a linear gradient, or center and radius of a radial
+    // one.
+    gradient_points: vec4<f32>,
+    // Offsets of the eight gradient stops, four per element.
+    gradient_offsets: array<vec4<f32>, 2>,
+    // Colors of the gradient stops; unused stops repeat the last one.
+    gradient_colors: array<vec4<f32>, 8>,
+    // Feather width in device pixels, and the size of a device pixel in
+    // clip space in yz; zero without feathering.
+    feather: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) coverage: f32,
+    @location(1) local: vec2<f32>,
+    @location(2) color: vec4<f32>,
+    @location(3) stroke: f32,
+};
+
+@group(0) @binding(0)
+var<uniform> node: NodeUniforms;
+
+@group(0) @binding(1)
+var image: texture_2d<f32>;
+
+@group(0) @binding(2)
+var image_sampler: sampler;
 
 @vertex
-fn vs_main(@location(0) in_pos: vec2<f32>) -> @builtin(position) vec4<f32> {
-    return vec4<f32>(in_pos, 0.0, 1.0);
+fn vs_main(
+    @location(0) in_pos: vec2<f32>,
+    @location(1) coverage: f32,
+    @location(2) color: vec4<f32>,
+    @location(3) stroke: f32,
+    @location(4) offset: vec2<f32>,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = node.transform * vec4<f32>(in_pos, 0.0, 1.0);
+    // Push the outer edge of feathered fringes out by the feather width in
+    // device pixels, along the direction the offset points in on screen.
+    let pixel = node.feather.yz;
+    let screen = (node.transform * vec4<f32>(offset, 0.0, 0.0)).xy / max(pixel, vec2<f32>(1e-9));
+    if (dot(screen, screen) > 0.0) {
+        let push = normalize(screen) * node.feather.x * length(offset);
+        out.position = vec4<f32>(out.position.xy + push * pixel, out.position.zw);
+    }
+    out.coverage = coverage;
+    out.local = in_pos;
+    out.color = color;
+    out.stroke = stroke;
+    return out;
+}
+
+// Maps a local offset `p` along one axis of a box of length `size` to a
+// texture coordinate, keeping the insets `start` and `end` (in pixels of an
+// image `pixels` long) at their size. Without insets, the image is
+// stretched over the whole box.
+fn slice_coord(p: f32, size: f32, start: f32, end: f32, pixels: f32) -> f32 {
+    // Boxes smaller than both insets shrink them proportionally.
+    let scale = min(1.0, size / max(start + end, 1e-6));
+    let first = start * scale;
+    let last = end * scale;
+    let texels = max(pixels, 1e-6);
+    let middle = max(size - first - last, 1e-6);
+    let stretched = (start + (p - first) / middle * (pixels - start - end)) / texels;
+    let head = p / scale / texels;
+    let tail = 1.0 - (size - p) / scale / texels;
+    return select(select(stretched, tail, p > size - last), head, p < first);
+}
+
+// Returns a pseudo-random number in [0, 1) for a lattice cell.
+fn random(cell: vec2<i32>, seed: u32) -> f32 {
+    var h = (bitcast<u32>(cell.x) * 0x8da6b343u) ^ (bitcast<u32>(cell.y) * 0xd8163841u)
+        ^ (seed * 0xcb1ab31fu);
+    h = (h ^ (h >> 16u)) * 0x7feb352du;
+    h = (h ^ (h >> 15u)) * 0x846ca68bu;
+    h = h ^ (h >> 16u);
+    return f32(h >> 8u) / 16777216.0;
+}
+
+// Smoothly interpolates random values at the corners of the cell around
+// `p`, returning a value in [0, 1].
+fn value_noise(p: vec2<f32>, seed: u32) -> f32 {
+    let cell = vec2<i32>(floor(p));
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+    let a = random(cell, seed);
+    let b = random(cell + vec2<i32>(1, 0), seed);
+    let c = random(cell + vec2<i32>(0, 1), seed);
+    let d = random(cell + vec2<i32>(1, 1), seed);
+    return mix(mix(a, b, u.x), mix(c, d, u.x), u.y);
+}
+
+// Returns the contribution of the simplex corner `cell` at offset `x`.
+fn simplex_corner(cell: vec2<f32>, x: vec2<f32>, seed: u32) -> f32 {
+    let angle = random(vec2<i32>(cell), seed) * 6.2831853;
+    let t = max(0.5 - dot(x, x), 0.0);
+    let t2 = t * t;
+    return t2 * t2 * dot(vec2<f32>(cos(angle), sin(angle)), x);
+}
+
+// Sums the gradients of the three corners of the simplex around `p`,
+// returning a value in [0, 1].
+fn simplex_noise(p: vec2<f32>, seed: u32) -> f32 {
+    let skew = 0.36602540;
+    let unskew = 0.21132487;
+    let cell = floor(p + (p.x + p.y) * skew);
+    let x0 = p - cell + (cell.x + cell.y) * unskew;
+    let step = select(vec2<f32>(0.0, 1.0), vec2<f32>(1.0, 0.0), x0.x > x0.y);
+    let x1 = x0 - step + unskew;
+    let x2 = x0 - 1.0 + 2.0 * unskew;
+    let n = simplex_corner(cell, x0, seed)
+        + simplex_corner(cell + step, x1, seed)
+        + simplex_corner(cell + 1.0, x2, seed);
+    return clamp(n * 35.0 + 0.5, 0.0, 1.0);
+}
+
+// Decodes sRGB components to linear light. Components outside [0, 1] keep
+// their sign, so wide-gamut colors given in extended sRGB survive.
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let x = abs(c);
+    let decoded = select(pow((x + 0.055) / 1.055, vec3<f32>(2.4)), x / 12.92, x <= vec3<f32>(0.04045));
+    return sign(c) * decoded;
+}
+
+// Returns `color` in the working space colors are blended in: linear light
+// for extended-range targets, sRGB otherwise.
+fn working(color: vec4<f32>) -> vec4<f32> {
+    if linear_output {
+        return vec4<f32>(srgb_to_linear(color.rgb), color.a);
+    }
+    return color;
+}
+
+// Maps linear light to the range of the display.
+fn tone_map(rgb: vec3<f32>) -> vec3<f32> {
+    let scaled = rgb * white_level;
+    if tone_mapping == 1u {
+        // Values up to white are kept; brighter ones follow a Reinhard
+        // curve from white towards the peak, with no kink at white.
+        let knee = min(white_level, peak_level);
+        let range = max(peak_level - knee, 1e-6);
+        let t = max(scaled - knee, vec3<f32>(0.0)) / range;
+        return select(scaled, knee + range * t / (1.0 + t), scaled > vec3<f32>(knee));
+    }
+    return min(scaled, vec3<f32>(peak_level));
+}
+
+// Returns the node's gradient color at local position `local`. Positions
+// before the first stop or past the last take the color of that stop.
+fn gradient_color(local: vec2<f32>) -> vec4<f32> {
+    let points = node.gradient_points;
+    let along = points.zw - points.xy;
+    let linear = dot(local - points.xy, along) / max(dot(along, along), 1e-6);
+    let radial = length(local - points.xy) / max(points.z, 1e-6);
+    let t = select(linear, radial, node.gradient.x > 1.5);
+    var color = node.gradient_colors[0];
+    for (var i = 1; i < 8; i++) {
+        let start = node.gradient_offsets[(i - 1) / 4][(i - 1) % 4];
+        let span = max(node.gradient_offsets[i / 4][i % 4] - start, 1e-6);
+        color = mix(color, node.gradient_colors[i], clamp((t - start) / span, 0.0, 1.0));
+    }
+    return color;
+}
+
+// Returns the node's noise color at local position `local`.
+fn noise_color(local: vec2<f32>) -> vec4<f32> {
+    let seed = bitcast<u32>(node.noise.z);
+    let octaves = u32(node.noise.w);
+    var p = local / node.noise.y;
+    var amplitude = 1.0;
+    var total = 0.0;
+    var value = 0.0;
+    for (var i = 0u; i < octaves; i++) {
+        let octave_seed = seed + i * 0x9e3779b9u;
+        let n = select(simplex_noise(p, octave_seed), value_noise(p, octave_seed), node.noise.x < 1.5);
+        value += n * amplitude;
+        total += amplitude;
+        amplitude *= 0.5;
+        p *= 2.0;
+    }
+    value /= max(total, 1e-6);
+
+    // Mixing towards each stop in turn yields the piecewise-linear ramp,
+    // since stops past the value mix in nothing.
+    var color = node.noise_colors[0];
+    for (var i = 1; i < 4; i++) {
+        let start = node.noise_offsets[i - 1];
+        let span = max(node.noise_offsets[i] - start, 1e-6);
+        color = mix(color, node.noise_colors[i], clamp((value - start) / span, 0.0, 1.0));
+    }
+    return color;
+}
+
+// Returns how much of the pixel at local position `local` is covered by the
+// node's pattern marks.
+fn pattern_coverage(local: vec2<f32>) -> f32 {
+    let kind = node.pattern.x;
+    let spacing = max(node.pattern.y, 1e-3);
+    let half_width = node.pattern.z * 0.5;
+    let c = cos(node.pattern.w);
+    let s = sin(node.pattern.w);
+    let p = vec2<f32>(c * local.x + s * local.y, c * local.y - s * local.x);
+    // Size of a pixel in pattern units; derivatives need uniform control
+    // flow, so every kind is evaluated.
+    let pixel = max(length(fwidth(p)) * 0.7071, 1e-4);
+
+    // Distance to the nearest stripe center line.
+    let stripe = abs(fract(p.y / spacing + 0.5) - 0.5) * spacing;
+    let stripes = clamp((half_width - stripe) / pixel + 0.5, 0.0, 1.0);
+
+    // Signed distance to the edges of alternating squares along each axis,
+    // positive inside the squares centered on the origin's row and column.
+    let square = (abs(fract(p / (2.0 * spacing)) - 0.5) * 2.0 - 0.5) * spacing;
+    let inside = clamp(square / pixel + 0.5, vec2<f32>(0.0), vec2<f32>(1.0));
+    let checkers = inside.x * (1.0 - inside.y) + inside.y * (1.0 - inside.x);
+
+    // Distance to the nearest dot center.
+    let dot = length((fract(p / spacing + 0.5) - 0.5) * spacing);
+    let dots = clamp((half_width - dot) / pixel + 0.5, 0.0, 1.0);
+
+    if kind < 0.5 {
+        return 0.0;
+    }
+    if kind < 1.5 {
+        return stripes;
+    }
+    if kind < 2.5 {
+        return checkers;
+    }
+    return dots;
 }
 
 @fragment
-fn fs_main() -> @location(0) vec4<f32> {
-    return vec4<f32>(0.8, 0.3, 0.2, 1.0); // temporary hardcoded fill color
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Sample unconditionally; texture sampling requires uniform control flow.
+    let size = max(node.image_bounds.zw, vec2<f32>(1e-6));
+    let p = in.local - node.image_bounds.xy;
+    let sliced = any(node.image_slice != vec4<f32>(0.0));
+    let uv = select(
+        p / size,
+        vec2<f32>(
+            slice_coord(p.x, size.x, node.image_slice.x, node.image_slice.z, node.image_size.x),
+            slice_coord(p.y, size.y, node.image_slice.y, node.image_slice.w, node.image_size.y),
+        ),
+        sliced,
+    );
+    let texel = textureSample(image, image_sampler, uv);
+    // Sampled texels of sRGB textures are already linear.
+    let color = working(node.color);
+    let painted = select(color, working(gradient_color(in.local)), node.gradient.x > 0.5);
+    let fill = select(painted, working(noise_color(in.local)), node.noise.x > 0.5);
+    let filled = select(fill, texel * color, node.image_bounds.z > 0.0) * working(in.color);
+    let stroked = in.stroke > 0.5;
+    let base = select(filled, working(node.stroke_color), stroked);
+
+    // Composite the pattern marks over the base color of the fill.
+    let pattern_color = working(node.pattern_color);
+    let mark = select(pattern_color.a * pattern_coverage(in.local), 0.0, stroked);
+    let alpha = mark + base.a * (1.0 - mark);
+    var rgb = (pattern_color.rgb * mark + base.rgb * base.a * (1.0 - mark))
+        / max(alpha, 1e-6);
+    if linear_output {
+        rgb = tone_map(rgb);
+    }
+
+    // Feathered fringe vertices ramp coverage to zero at the outer edge.
+    return vec4<f32>(rgb, alpha * in.coverage);
 }
@@ -0,0 +1,161 @@
+use std::fmt;
+
+/// An error raised while creating a [`GpuContext`](crate::GpuContext).
+#[derive(Debug)]
+pub enum GpuInitError {
+    /// No surface could be created for the window.
+    Surface(wgpu::CreateSurfaceError),
+
+    /// No GPU adapter matches the requested backends and surface.
+    Adapter(wgpu::RequestAdapterError),
+
+    /// The adapter refused to create a device.
+    Device(wgpu::RequestDeviceError),
+
+    /// The adapter cannot present to the window's surface.
+    UnsupportedSurface,
+}
+
+impl fmt::Display for GpuInitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuInitError::Surface(err) => write!(f, "failed to create surface: {err}"),
+            GpuInitError::Adapter(err) => write!(f, "failed to find GPU adapter: {err}"),
+            GpuInitError::Device(err) => write!(f, "failed to create device: {err}"),
+            GpuInitError::UnsupportedSurface => {
+                write!(f, "GPU adapter does not support the surface")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GpuInitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GpuInitError::Surface(err) => Some(err),
+            GpuInitError::Adapter(err) => Some(err),
+            GpuInitError::Device(err) => Some(err),
+            GpuInitError::UnsupportedSurface => None,
+        }
+    }
+}
+
+impl From<wgpu::CreateSurfaceError> for GpuInitError {
+    fn from(err: wgpu::CreateSurfaceError) -> Self {
+        GpuInitError::Surface(err)
+    }
+}
+
+impl From<wgpu::RequestAdapterError> for GpuInitError {
+    fn from(err: wgpu::RequestAdapterError) -> Self {
+        GpuInitError::Adapter(err)
+    }
+}
+
+impl From<wgpu::RequestDeviceError> for GpuInitError {
+    fn from(err: wgpu::RequestDeviceError) -> Self {
+        GpuInitError::Device(err)
+    }
+}
+
+/// An error raised while rendering or reading back a frame.
+#[derive(Debug)]
+pub enum RenderError {
+    /// The context has no surface to present to; headless and embedded
+    /// contexts render offscreen or into the host's passes instead.
+    NoSurface,
+
+    /// The surface frame could not be acquired. After
+    /// `wgpu::SurfaceError::Lost` or `Outdated`, resizing the context
+    /// reconfigures the surface; a timeout can simply skip the frame.
+    Surface(wgpu::SurfaceError),
+
+    /// Waiting for the GPU to finish the frame failed.
+    Poll(wgpu::PollError),
+
+    /// The frame could not be mapped for reading.
+    Readback(wgpu::BufferAsyncError),
+
+    /// The MSAA sample count is not 1 or 4.
+    UnsupportedSampleCount(u32),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::NoSurface => write!(f, "cannot present a frame without a surface"),
+            RenderError::Surface(err) => write!(f, "failed to acquire surface frame: {err}"),
+            RenderError::Poll(err) => write!(f, "failed to wait for the GPU: {err}"),
+            RenderError::Readback(err) => write!(f, "failed to read back frame: {err}"),
+            RenderError::UnsupportedSampleCount(count) => {
+                write!(f, "unsupported MSAA sample count {count}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RenderError::Surface(err) => Some(err),
+            RenderError::Poll(err) => Some(err),
+            RenderError::Readback(err) => Some(err),
+            RenderError::NoSurface | RenderError::UnsupportedSampleCount(_) => None,
+        }
+    }
+}
+
+impl From<wgpu::SurfaceError> for RenderError {
+    fn from(err: wgpu::SurfaceError) -> Self {
+        RenderError::Surface(err)
+    }
+}
+
+impl From<wgpu::PollError> for RenderError {
+    fn from(err: wgpu::PollError) -> Self {
+        RenderError::Poll(err)
+    }
+}
+
+impl From<wgpu::BufferAsyncError> for RenderError {
+    fn from(err: wgpu::BufferAsyncError) -> Self {
+        RenderError::Readback(err)
+    }
+}
+
+/// An error raised when an image cannot be used as a texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureError {
+    /// The image is empty or larger than the GPU's texture size limit.
+    Size { width: u32, height: u32 },
+
+    /// The pixel buffer does not hold `width * height` RGBA pixels.
+    PixelBuffer,
+
+    /// The texture cannot be sampled by the image fill shader: it is not a
+    /// 2D, single-sampled, filterable float texture with `TEXTURE_BINDING`
+    /// usage.
+    Unsampleable(wgpu::TextureFormat),
+}
+
+impl fmt::Display for TextureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TextureError::Size { width, height } => {
+                write!(
+                    f,
+                    "image of size {width}x{height} cannot be used as a texture"
+                )
+            }
+            TextureError::PixelBuffer => write!(f, "image has an invalid pixel buffer"),
+            TextureError::Unsampleable(format) => {
+                write!(
+                    f,
+                    "texture of format {format:?} cannot be sampled as an image"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TextureError {}
@@ -1,24 +1,74 @@
+use lyon::math::Vector;
 use lyon::tessellation::{FillVertex, VertexBuffers};
 
 /// A single 2D vertex to be sent to the GPU.
 ///
 /// This is the lowest-level geometric primitive used in rendering.
-/// Each vertex contains a 2D position (x, y) in local node coordinates.
-/// Additional attributes like color or texture coordinates can be added later.
+/// Each vertex contains a 2D position (x, y) in local node coordinates, a
+/// coverage value used for anti-aliased edges, a color multiplied with the
+/// fill, e.g. for mesh gradients, whether it belongs to the stroke, and the
+/// direction it is moved in by the feather width.
+/// Additional attributes like texture coordinates can be added later.
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Vertex {
     /// Position in logical (device-independent) pixels.
     pub position: [f32; 2],
+
+    /// Fraction of the pixel covered by the shape, in `[0.0, 1.0]`.
+    ///
+    /// Interior vertices are fully covered; vertices on the outer edge of a
+    /// feathered fringe have zero coverage. The shader multiplies the fill
+    /// alpha by this value.
+    pub coverage: f32,
+
+    /// Color multiplied with the node's fill as linear RGBA, white unless
+    /// the geometry carries its own colors.
+    pub color: [f32; 4],
+
+    /// 1.0 for vertices of the stroke, which are drawn in the node's stroke
+    /// color instead of its fill, and 0.0 for those of the fill.
+    pub stroke: f32,
+
+    /// Direction, in local units, the vertex is moved in by the feather
+    /// width in device pixels, scaled up at sharp corners.
+    ///
+    /// Zero except on the outer edge of a feathered fringe, whose vertices
+    /// are placed on the shape edge and pushed outwards by the shader.
+    pub offset: [f32; 2],
 }
 
 impl Vertex {
+    /// Creates a white vertex at the given position with the given coverage.
+    pub fn new(x: f32, y: f32, coverage: f32) -> Self {
+        Self::colored(x, y, coverage, [1.0; 4])
+    }
+
+    /// Creates a vertex at the given position with the given coverage and
+    /// color.
+    pub fn colored(x: f32, y: f32, coverage: f32, color: [f32; 4]) -> Self {
+        Vertex {
+            position: [x, y],
+            coverage,
+            color,
+            stroke: 0.0,
+            offset: [0.0; 2],
+        }
+    }
+
+    /// Creates an uncovered vertex on the outer edge of a feathered fringe,
+    /// moved from the given position in the given direction.
+    pub fn fringe(x: f32, y: f32, direction: Vector) -> Self {
+        Vertex {
+            offset: [direction.x, direction.y],
+            ..Self::new(x, y, 0.0)
+        }
+    }
+
     /// Converts a `lyon` tessellated vertex into an `ardent` vertex.
     pub fn from_fill_vertex(v: FillVertex) -> Self {
         let pos = v.position();
-        Vertex {
-            position: [pos.x, pos.y],
-        }
+        Vertex::new(pos.x, pos.y, 1.0)
     }
 }
 
@@ -1,7 +1,10 @@
 mod buffers;
 mod context;
+mod hdr;
 mod pipeline;
 
-pub use buffers::VertexBuffer;
-pub use context::GpuContext;
+pub use buffers::{IndexBuffer, VertexBuffer};
+pub use context::{GpuContext, GpuOptions};
+pub use hdr::{HdrOptions, ToneMapping, is_extended_range};
 pub use pipeline::RenderPipelineBuilder;
+pub use wgpu::{Backends, PowerPreference, SurfaceError};
@@ -6,7 +6,7 @@
 //! and submits it for rendering via a WGPU command encoder.
 
 use crate::geometry::Vertex;
-use wgpu::{Buffer, BufferUsages, Device, RenderPass, util::DeviceExt};
+use wgpu::{Buffer, BufferUsages, Device, IndexFormat, RenderPass, util::DeviceExt};
 
 /// Wraps a GPU vertex buffer prepared for rendering.
 pub struct VertexBuffer {
@@ -18,7 +18,7 @@ impl VertexBuffer {
     /// Uploads vertex data to a GPU buffer.
     ///
     /// The data must be tightly packed (no padding) and match the layout
-    /// expected by the shader (`vec2<f32>` at location 0).
+    /// expected by the shader (`vec2<f32>` at location 0, `f32` at location 1).
     pub fn from_vertices(device: &Device, vertices: &[Vertex]) -> Self {
         let data = bytemuck::cast_slice(vertices);
         let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -37,8 +37,44 @@ impl VertexBuffer {
     ///
     /// This must be called within an active render pass that has already
     /// set the render pipeline.
-    pub fn draw<'a>(&'a self, pass: &mut RenderPass<'a>) {
+    pub fn draw(&self, pass: &mut RenderPass<'_>) {
         pass.set_vertex_buffer(0, self.buffer.slice(..));
         pass.draw(0..self.vertex_count, 0..1);
     }
 }
+
+/// Wraps a GPU index buffer describing how vertices form triangles.
+///
+/// Indices are produced by `lyon` alongside the vertices and allow shared
+/// vertices to be reused across triangles, which is required for concave
+/// shapes to render correctly.
+pub struct IndexBuffer {
+    pub buffer: Buffer,
+    pub index_count: u32,
+}
+
+impl IndexBuffer {
+    /// Uploads 16-bit index data to a GPU buffer.
+    pub fn from_indices(device: &Device, indices: &[u16]) -> Self {
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Ardent Index Buffer"),
+            contents: bytemuck::cast_slice(indices),
+            usage: BufferUsages::INDEX,
+        });
+
+        Self {
+            buffer,
+            index_count: indices.len() as u32,
+        }
+    }
+
+    /// Issues an indexed draw call using the given vertex buffer.
+    ///
+    /// This must be called within an active render pass that has already
+    /// set the render pipeline.
+    pub fn draw(&self, vertices: &VertexBuffer, pass: &mut RenderPass<'_>) {
+        pass.set_vertex_buffer(0, vertices.buffer.slice(..));
+        pass.set_index_buffer(self.buffer.slice(..), IndexFormat::Uint16);
+        pass.draw_indexed(0..self.index_count, 0, 0..1);
+    }
+}
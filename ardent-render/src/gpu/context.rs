@@ -4,14 +4,57 @@
 //!
 //! This module sets up the WGPU instance, device, queue, and swapchain surface.
 //! It forms the foundation for all GPU rendering in `ardent`.
+//!
+//! A context can also be created without a window for headless rendering,
+//! e.g. to produce screenshots or thumbnails, or around a device owned by a
+//! host application that renders ardent into its own passes.
 
-use std::sync::Arc;
-
+use wgpu::rwh::{HasDisplayHandle, HasWindowHandle};
 use wgpu::{
-    Backends, Device, DeviceDescriptor, Instance, InstanceDescriptor, Queue, Surface,
-    SurfaceConfiguration,
+    Backends, CompositeAlphaMode, Device, DeviceDescriptor, Instance, InstanceDescriptor,
+    PowerPreference, PresentMode, Queue, Surface, SurfaceConfiguration, TextureFormat,
+    TextureUsages, WasmNotSendSync,
 };
-use winit::window::Window;
+
+use super::hdr::is_extended_range;
+use crate::error::GpuInitError;
+
+/// Surface format requested for HDR output: half floats holding linear
+/// light, with standard white at 1.0 (scRGB on Windows, extended linear
+/// sRGB on Apple platforms).
+const HDR_FORMAT: TextureFormat = TextureFormat::Rgba16Float;
+
+/// Options for choosing and configuring the GPU.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpuOptions {
+    /// Graphics APIs wgpu may use, e.g. `Backends::VULKAN` to rule out
+    /// OpenGL. Defaults to all of them.
+    pub backends: Backends,
+
+    /// Whether to prefer an integrated (low power) or discrete GPU.
+    pub power_preference: PowerPreference,
+
+    /// Whether presenting waits for the display's vertical blank, which
+    /// avoids tearing and caps the frame rate at the refresh rate.
+    pub vsync: bool,
+
+    /// Whether to draw into an extended-range surface on HDR and
+    /// wide-gamut displays, if the platform offers one. Colors are then
+    /// carried in linear light and mapped to the display with the
+    /// renderer's [`HdrOptions`](super::HdrOptions). Defaults to `false`.
+    pub hdr: bool,
+}
+
+impl Default for GpuOptions {
+    fn default() -> Self {
+        Self {
+            backends: Backends::all(),
+            power_preference: PowerPreference::HighPerformance,
+            vsync: true,
+            hdr: false,
+        }
+    }
+}
 
 /// Holds the essential GPU components needed for rendering.
 pub struct GpuContext<'a> {
@@ -21,77 +64,218 @@ pub struct GpuContext<'a> {
     /// The queue used to submit rendering commands to the GPU.
     pub queue: Queue,
 
-    /// The surface (usually a window) that we render into.
-    pub surface: Surface<'a>,
+    /// The surface (usually a window) that we render into, or `None` for a
+    /// headless or embedded context.
+    pub surface: Option<Surface<'a>>,
 
     /// The surface configuration (format, usage, present mode, etc.)
+    ///
+    /// Headless contexts use it to describe their offscreen render target.
     pub config: SurfaceConfiguration,
 
     /// The size of the surface (width, height in pixels).
     pub size: (u32, u32),
+
+    /// The number of physical pixels per logical pixel, e.g. 2.0 on retina
+    /// displays. Scenes are drawn in logical pixels and scaled by this
+    /// factor, so they keep their intended size on any display.
+    pub scale_factor: f32,
 }
 
-impl GpuContext<'_> {
+impl<'a> GpuContext<'a> {
     /// Creates a new GPU context bound to the given window.
     ///
     /// This initializes the GPU instance, chooses an adapter and device,
     /// creates a swapchain surface, and configures it for rendering.
-    pub async fn new(window: Arc<Window>) -> Self {
-        let size = window.inner_size();
+    ///
+    /// Any window exposing raw window and display handles is accepted, so
+    /// ardent can be hosted by winit, SDL, Tauri, or custom windowing
+    /// stacks. Pass an `Arc` of the window for a `'static` context.
+    /// `width`/`height` is the window's inner size in physical pixels.
+    ///
+    /// Returns an error if no GPU can present to the window.
+    pub async fn new<W>(window: W, width: u32, height: u32) -> Result<Self, GpuInitError>
+    where
+        W: HasWindowHandle + HasDisplayHandle + WasmNotSendSync + 'a,
+    {
+        Self::with_options(window, width, height, GpuOptions::default()).await
+    }
+
+    /// Creates a GPU context bound to the given window, choosing and
+    /// configuring the GPU according to `options`.
+    pub async fn with_options<W>(
+        window: W,
+        width: u32,
+        height: u32,
+        options: GpuOptions,
+    ) -> Result<Self, GpuInitError>
+    where
+        W: HasWindowHandle + HasDisplayHandle + WasmNotSendSync + 'a,
+    {
+        let (width, height) = (width.max(1), height.max(1));
 
         // 1. Create instace.
         let instance = Instance::new(&InstanceDescriptor {
-            backends: Backends::all(),
+            backends: options.backends,
             ..Default::default()
         });
 
         // 2. Create surface for the instance.
-        let surface = instance
-            .create_surface(window)
-            .expect("Failed to create surface");
-
-        // 3. Request the adapter.
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: wgpu::PowerPreference::HighPerformance,
-                compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
-            })
-            .await
-            .expect("Failed to find GPU adapter");
-
-        // 4. Request device and queue.
-        let (device, queue) = adapter
-            .request_device(&DeviceDescriptor {
-                required_features: wgpu::Features::empty(),
-                required_limits: wgpu::Limits::default(),
-                ..Default::default()
-            })
-            .await
-            .expect("Failed to create device");
-
-        // 5. Configure the surface.
-        let config = surface
-            .get_default_config(&adapter, size.width, size.height)
-            .expect("Failed to configure surface");
+        let surface = instance.create_surface(window)?;
+
+        // 3. Request the adapter, device, and queue.
+        let (adapter, device, queue) = request_device(&instance, Some(&surface), &options).await?;
+
+        // 4. Configure the surface.
+        let mut config = surface
+            .get_default_config(&adapter, width, height)
+            .ok_or(GpuInitError::UnsupportedSurface)?;
+        config.present_mode = if options.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        };
+        if options.hdr
+            && surface
+                .get_capabilities(&adapter)
+                .formats
+                .contains(&HDR_FORMAT)
+        {
+            config.format = HDR_FORMAT;
+        }
         surface.configure(&device, &config);
 
+        Ok(Self {
+            device,
+            queue,
+            surface: Some(surface),
+            config,
+            size: (width, height),
+            scale_factor: 1.0,
+        })
+    }
+
+    /// Creates a GPU context without a window, rendering into offscreen
+    /// textures of the given size.
+    ///
+    /// Frames are rendered with `Renderer::render_offscreen`, which returns
+    /// their pixels instead of presenting them. Returns an error if no GPU
+    /// is available.
+    pub async fn headless(width: u32, height: u32) -> Result<Self, GpuInitError> {
+        let instance = Instance::new(&InstanceDescriptor {
+            backends: Backends::all(),
+            ..Default::default()
+        });
+        let (_, device, queue) = request_device(&instance, None, &GpuOptions::default()).await?;
+
+        Ok(Self::from_device(
+            device,
+            queue,
+            TextureFormat::Rgba8UnormSrgb,
+            width,
+            height,
+        ))
+    }
+
+    /// Creates a GPU context from a device and queue owned by the host
+    /// application, e.g. a game or visualization engine embedding ardent as
+    /// a UI layer.
+    ///
+    /// The context has no surface: frames are drawn into the host's own
+    /// render passes with `Renderer::render_to_pass`, or offscreen. `format`
+    /// is the format of the host's render target and `width`/`height` its
+    /// size in pixels.
+    pub fn from_device(
+        device: Device,
+        queue: Queue,
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        let (width, height) = (width.max(1), height.max(1));
+        let config = SurfaceConfiguration {
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+            format,
+            width,
+            height,
+            present_mode: PresentMode::Fifo,
+            desired_maximum_frame_latency: 2,
+            alpha_mode: CompositeAlphaMode::Auto,
+            view_formats: Vec::new(),
+        };
+
         Self {
             device,
             queue,
-            surface,
+            surface: None,
             config,
-            size: (size.width, size.height),
+            size: (width, height),
+            scale_factor: 1.0,
+        }
+    }
+
+    /// Returns the size of the surface in logical pixels, the viewport
+    /// scenes should be laid out for.
+    pub fn logical_size(&self) -> (f32, f32) {
+        (
+            self.size.0 as f32 / self.scale_factor,
+            self.size.1 as f32 / self.scale_factor,
+        )
+    }
+
+    /// Returns `true` if the surface holds extended-range linear light,
+    /// i.e. HDR output was requested and the platform supports it.
+    pub fn is_hdr(&self) -> bool {
+        is_extended_range(self.config.format)
+    }
+
+    /// Changes the number of physical pixels per logical pixel, e.g. when
+    /// the window moves to a display with a different pixel density.
+    ///
+    /// Non-positive and non-finite factors are ignored.
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        if scale_factor.is_finite() && scale_factor > 0.0 {
+            self.scale_factor = scale_factor;
         }
     }
 
     /// Resizes the surface when the window size changes.
+    ///
+    /// For headless contexts, this changes the size of subsequent frames.
     pub fn resize(&mut self, width: u32, height: u32) {
         if width > 0 && height > 0 {
             self.config.width = width;
             self.config.height = height;
             self.size = (width, height);
-            self.surface.configure(&self.device, &self.config);
+            if let Some(surface) = &self.surface {
+                surface.configure(&self.device, &self.config);
+            }
         }
     }
 }
+
+/// Chooses an adapter, compatible with `surface` if one is given, and
+/// requests a device and queue from it.
+async fn request_device(
+    instance: &Instance,
+    surface: Option<&Surface<'_>>,
+    options: &GpuOptions,
+) -> Result<(wgpu::Adapter, Device, Queue), GpuInitError> {
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: options.power_preference,
+            compatible_surface: surface,
+            force_fallback_adapter: false,
+        })
+        .await?;
+
+    let (device, queue) = adapter
+        .request_device(&DeviceDescriptor {
+            required_features: wgpu::Features::empty(),
+            required_limits: wgpu::Limits::default(),
+            ..Default::default()
+        })
+        .await?;
+
+    Ok((adapter, device, queue))
+}
@@ -0,0 +1,65 @@
+//! Output settings for HDR and wide-gamut displays.
+//!
+//! Colors in scenes are sRGB-encoded, with white at 1.0. On ordinary
+//! targets they are written as they are. Extended-range targets, such as
+//! the half-float surfaces HDR displays are driven with, hold linear light
+//! instead, where 1.0 is the brightness of standard white (about 80 nits)
+//! and larger values are brighter. For those, the shader decodes colors to
+//! linear light, blends in linear space, scales white to the configured
+//! level, and tone maps values beyond the display's peak. Components
+//! outside [0.0, 1.0] are kept, so wide-gamut colors given in extended sRGB
+//! reach displays that can show them.
+
+use wgpu::TextureFormat;
+
+/// How colors brighter than the display's peak are brought into range on
+/// extended-range targets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ToneMapping {
+    /// Clamps each component to the peak, which keeps colors in range
+    /// exact but flattens highlights beyond it.
+    #[default]
+    Clamp,
+
+    /// Keeps values up to the white level as they are and compresses
+    /// brighter ones smoothly towards the peak with a Reinhard curve, so
+    /// highlights keep their detail.
+    Reinhard,
+}
+
+/// Output settings for extended-range render targets.
+///
+/// Ignored when drawing into ordinary 8-bit targets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HdrOptions {
+    /// Brightness of the scene's white, relative to standard white. Raise
+    /// it so UI composited over HDR video is not dim next to it, e.g. 2.5
+    /// for the 200 nits of a typical HDR paper white. Defaults to 1.0.
+    pub white_level: f32,
+
+    /// Brightest value the display can show, relative to standard white,
+    /// e.g. 12.5 for a display peaking at 1000 nits. Defaults to 1.0.
+    pub peak_level: f32,
+
+    /// How values above the peak are brought into range.
+    pub tone_mapping: ToneMapping,
+}
+
+impl Default for HdrOptions {
+    fn default() -> Self {
+        Self {
+            white_level: 1.0,
+            peak_level: 1.0,
+            tone_mapping: ToneMapping::default(),
+        }
+    }
+}
+
+/// Returns `true` if `format` holds linear light beyond standard white,
+/// so colors are drawn into it with the [`HdrOptions`].
+pub fn is_extended_range(format: TextureFormat) -> bool {
+    matches!(
+        format,
+        TextureFormat::Rgba16Float | TextureFormat::Rgba32Float
+    )
+}
@@ -5,16 +5,27 @@
 //! The pipeline binds vertex buffers and shaders, and configures how
 //! the GPU rasterizes geometry into pixels.
 
-use wgpu::{Device, FragmentState, RenderPipeline, SurfaceConfiguration, VertexState};
+use super::hdr::{HdrOptions, ToneMapping, is_extended_range};
+use crate::geometry::Vertex;
+
+use wgpu::{
+    BindGroupLayout, Device, FragmentState, RenderPipeline, SurfaceConfiguration, TextureFormat,
+    VertexState,
+};
 
 /// Builds and stores a render pipeline used for drawing vector UI.
 ///
 /// This object handles the creation of shaders and the graphics pipeline.
 /// For now, it uses a very simple vertex + fragment shader pair and assumes
-/// a single vertex buffer with 2D positions.
+/// a single vertex buffer with 2D positions, coverage, and colors, plus one per-node
+/// bind group holding a uniform buffer and the image texture.
 pub struct RenderPipelineBuilder {
     /// The compiled WGPU render pipeline.
     pub pipeline: RenderPipeline,
+
+    /// Layout of the per-node bind group (group 0): uniforms, image texture,
+    /// and sampler.
+    pub bind_group_layout: BindGroupLayout,
 }
 
 impl RenderPipelineBuilder {
@@ -22,28 +33,106 @@ impl RenderPipelineBuilder {
     ///
     /// The shaders are currently hardcoded to a basic passthrough program.
     pub fn new(device: &Device, config: &SurfaceConfiguration) -> Self {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Ardent Node Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline = Self::pipeline(
+            device,
+            &bind_group_layout,
+            config.format,
+            1,
+            &HdrOptions::default(),
+        );
+
+        Self {
+            pipeline,
+            bind_group_layout,
+        }
+    }
+
+    /// Creates a pipeline drawing into targets of the given format and
+    /// number of samples per pixel.
+    ///
+    /// Pipelines sharing `bind_group_layout` can be used with the same node
+    /// bind groups, e.g. to render into surfaces of different formats.
+    /// Pipelines for extended-range formats output linear light according
+    /// to `hdr`.
+    pub fn pipeline(
+        device: &Device,
+        bind_group_layout: &BindGroupLayout,
+        format: TextureFormat,
+        sample_count: u32,
+        hdr: &HdrOptions,
+    ) -> RenderPipeline {
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Ardent Basic Shader"),
             source: wgpu::ShaderSource::Wgsl(include_str!("../../shaders/shader.wgsl").into()),
         });
 
         let vertex_layout = wgpu::VertexBufferLayout {
-            array_stride: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+            array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
             step_mode: wgpu::VertexStepMode::Vertex,
-            attributes: &[wgpu::VertexAttribute {
-                format: wgpu::VertexFormat::Float32x2,
-                offset: 0,
-                shader_location: 0,
-            }],
+            attributes: &wgpu::vertex_attr_array![
+                0 => Float32x2,
+                1 => Float32,
+                2 => Float32x4,
+                3 => Float32,
+                4 => Float32x2
+            ],
         };
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Ardent Pipeline Layout"),
-            bind_group_layouts: &[],
+            bind_group_layouts: &[bind_group_layout],
             push_constant_ranges: &[],
         });
 
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        // Output settings are fixed per pipeline as override constants, so
+        // ordinary targets pay nothing for them.
+        let tone_mapping = match hdr.tone_mapping {
+            ToneMapping::Clamp => 0.0,
+            ToneMapping::Reinhard => 1.0,
+        };
+        let constants = [
+            (
+                "linear_output",
+                f64::from(u8::from(is_extended_range(format))),
+            ),
+            ("white_level", f64::from(hdr.white_level.max(0.0))),
+            ("peak_level", f64::from(hdr.peak_level.max(1e-3))),
+            ("tone_mapping", tone_mapping),
+        ];
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Ardent Render Pipeline"),
             layout: Some(&pipeline_layout),
             cache: None,
@@ -56,9 +145,12 @@ impl RenderPipelineBuilder {
             fragment: Some(FragmentState {
                 module: &shader,
                 entry_point: Some("fs_main"),
-                compilation_options: Default::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &constants,
+                    ..Default::default()
+                },
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: config.format,
+                    format,
                     blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
@@ -67,16 +159,18 @@ impl RenderPipelineBuilder {
                 topology: wgpu::PrimitiveTopology::TriangleList,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
-                cull_mode: Some(wgpu::Face::Back),
+                // Negative scales flip winding, so 2D geometry is never culled.
+                cull_mode: None,
                 polygon_mode: wgpu::PolygonMode::Fill,
                 unclipped_depth: false,
                 conservative: false,
             },
             depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
             multiview: None,
-        });
-
-        Self { pipeline }
+        })
     }
 }
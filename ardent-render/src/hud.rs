@@ -0,0 +1,349 @@
+//! An on-screen overlay showing how fast frames are rendered.
+
+use std::collections::VecDeque;
+
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::{Scene, SceneError};
+use ardent_core::shape::{Rect, Shape};
+use ardent_core::style::{Color, Fill};
+
+use crate::renderer::RenderStats;
+
+/// Number of frames shown in the frame time graph and averaged for the
+/// frame rate.
+const HISTORY: usize = 60;
+
+/// Distance of the panel from the top left corner of the window.
+const MARGIN: f32 = 8.0;
+
+/// Space between the edge of the panel and its contents.
+const PADDING: f32 = 8.0;
+
+/// Width of one bar of the frame time graph.
+const BAR_WIDTH: f32 = 3.0;
+
+/// Height of the frame time graph.
+const GRAPH_HEIGHT: f32 = 50.0;
+
+/// Frame time filling the graph's full height, in milliseconds.
+const GRAPH_MAX_MS: f32 = 50.0;
+
+/// Frame time budget at 60 Hz, marked in the graph, in milliseconds.
+const BUDGET_MS: f32 = 1000.0 / 60.0;
+
+/// Height of a readout row, including the space below it.
+const ROW_HEIGHT: f32 = 20.0;
+
+/// Width of a readout column.
+const COLUMN_WIDTH: f32 = 108.0;
+
+/// Number of characters a readout can show.
+const DIGITS: usize = 7;
+
+/// Horizontal distance between readout characters.
+const DIGIT_ADVANCE: f32 = 11.0;
+
+/// Segments of a readout character as (x, y, width, height): top, upper
+/// right, lower right, bottom, lower left, upper left, middle, and point.
+const SEGMENTS: [(f32, f32, f32, f32); 8] = [
+    (0.0, 0.0, 8.0, 2.0),
+    (6.0, 0.0, 2.0, 8.0),
+    (6.0, 6.0, 2.0, 8.0),
+    (0.0, 12.0, 8.0, 2.0),
+    (0.0, 6.0, 2.0, 8.0),
+    (0.0, 0.0, 2.0, 8.0),
+    (0.0, 6.0, 8.0, 2.0),
+    (3.0, 12.0, 2.0, 2.0),
+];
+
+const PANEL: Color = Color(0.05, 0.06, 0.08, 0.8);
+const DIGIT: Color = Color(0.92, 0.94, 0.96, 1.0);
+const BUDGET_LINE: Color = Color(1.0, 1.0, 1.0, 0.35);
+const FAST: Color = Color(0.3, 0.8, 0.35, 1.0);
+const SLOW: Color = Color(0.97, 0.75, 0.2, 1.0);
+const DROPPED: Color = Color(0.95, 0.25, 0.3, 1.0);
+
+/// The metrics shown below the graph, with the key color marking them.
+const METRICS: [(&str, Color); 6] = [
+    ("FPS", Color(0.3, 0.8, 0.35, 1.0)),
+    ("Frame time (ms)", Color(0.97, 0.75, 0.2, 1.0)),
+    ("Draw calls", Color(0.2, 0.6, 0.95, 1.0)),
+    ("Tessellated vertices", Color(0.7, 0.45, 0.95, 1.0)),
+    ("Mesh cache hits", Color(0.3, 0.85, 0.85, 1.0)),
+    ("Mesh cache misses", Color(0.95, 0.4, 0.6, 1.0)),
+];
+
+/// A number drawn with seven-segment characters.
+struct Readout {
+    /// The node labeled with the metric and its value.
+    group: NodeId,
+    cells: [[NodeId; 8]; DIGITS],
+    text: String,
+}
+
+/// A scene showing frame timing and renderer statistics.
+///
+/// The panel in the top left corner graphs the time of the last 60 frames,
+/// green within the 60 Hz budget, yellow within 30 Hz, and red beyond,
+/// with a line marking the budget. Below it, six readouts show the frame
+/// rate, the last frame time in milliseconds, draw calls, vertices
+/// tessellated, and mesh cache hits and misses, in two columns from top to
+/// bottom. Until text can be drawn, each readout is marked by a key color
+/// and its group node is labeled with the metric's name and value.
+///
+/// Render it as the topmost layer, e.g. with `Renderer::render_layers`,
+/// and call [`update`](Self::update) every frame with the statistics of
+/// the previous one. Its own nodes count towards those statistics.
+///
+/// ```rust,ignore
+/// hud.update(renderer.stats(), dt);
+/// renderer.render_layers(&[&scene, hud.scene()], &context)?;
+/// ```
+pub struct PerfHud {
+    scene: Scene,
+    frames: VecDeque<f32>,
+    bars: Vec<NodeId>,
+    readouts: Vec<Readout>,
+}
+
+impl PerfHud {
+    /// Creates the overlay with an empty graph.
+    pub fn new() -> Self {
+        let mut scene = Scene::new();
+        let (bars, readouts) = build(&mut scene).expect("the overlay's root is always present");
+        Self {
+            scene,
+            frames: VecDeque::with_capacity(HISTORY),
+            bars,
+            readouts,
+        }
+    }
+
+    /// Returns the overlay scene to render.
+    pub fn scene(&self) -> &Scene {
+        &self.scene
+    }
+
+    /// Returns the overlay scene mutably, e.g. to clear the dirty flags of its
+    /// nodes once they are rendered.
+    pub fn scene_mut(&mut self) -> &mut Scene {
+        &mut self.scene
+    }
+
+    /// Records a frame that took `dt` seconds and shows `stats`.
+    ///
+    /// Frames with a zero delta, such as the first after the app was idle,
+    /// update the readouts but are left out of the graph and frame rate.
+    pub fn update(&mut self, stats: &RenderStats, dt: f32) {
+        if dt > 0.0 {
+            if self.frames.len() == HISTORY {
+                self.frames.pop_front();
+            }
+            self.frames.push_back(dt * 1000.0);
+        }
+
+        for (i, &bar) in self.bars.iter().enumerate() {
+            // The newest frame is drawn at the right edge.
+            let ms = (i + self.frames.len())
+                .checked_sub(HISTORY)
+                .and_then(|frame| self.frames.get(frame).copied());
+            if let Some(node) = self.scene.get_node_mut(bar) {
+                set_bar(node, ms);
+            }
+        }
+
+        let total: f32 = self.frames.iter().sum();
+        let fps = if total > 0.0 {
+            self.frames.len() as f32 * 1000.0 / total
+        } else {
+            0.0
+        };
+        let last = self.frames.back().copied().unwrap_or(0.0);
+        let values = [
+            format!("{fps:.0}"),
+            format!("{last:.1}"),
+            stats.nodes_drawn.to_string(),
+            stats.vertices_tessellated.to_string(),
+            stats.cache_hits.to_string(),
+            stats.cache_misses.to_string(),
+        ];
+        for (readout, (value, (name, _))) in self
+            .readouts
+            .iter_mut()
+            .zip(values.into_iter().zip(METRICS))
+        {
+            show(&mut self.scene, readout, value, name);
+        }
+    }
+}
+
+impl Default for PerfHud {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adds the panel, the graph's bars, and the readouts.
+fn build(scene: &mut Scene) -> Result<(Vec<NodeId>, Vec<Readout>), SceneError> {
+    let graph_width = HISTORY as f32 * BAR_WIDTH;
+    let rows = METRICS.len().div_ceil(2);
+    let width = graph_width.max(2.0 * COLUMN_WIDTH) + 2.0 * PADDING;
+    let top = PADDING + GRAPH_HEIGHT + PADDING;
+    let height = top + rows as f32 * ROW_HEIGHT;
+    let root = scene.root();
+    let panel = rect(scene, root, (MARGIN, MARGIN, width, height), PANEL)?;
+
+    let graph = group(scene, panel, (PADDING, PADDING))?;
+    let bars = (0..HISTORY)
+        .map(|i| {
+            let id = rect(scene, graph, (i as f32 * BAR_WIDTH, 0.0, 0.0, 0.0), FAST)?;
+            if let Some(node) = scene.get_node_mut(id) {
+                node.clear_shape();
+            }
+            Ok(id)
+        })
+        .collect::<Result<Vec<_>, SceneError>>()?;
+    let budget = GRAPH_HEIGHT * (1.0 - BUDGET_MS / GRAPH_MAX_MS);
+    rect(scene, graph, (0.0, budget, graph_width, 1.0), BUDGET_LINE)?;
+
+    let readouts = METRICS
+        .iter()
+        .enumerate()
+        .map(|(i, &(_, key))| {
+            let position = (
+                PADDING + (i / rows) as f32 * COLUMN_WIDTH,
+                top + (i % rows) as f32 * ROW_HEIGHT,
+            );
+            readout(scene, panel, position, key)
+        })
+        .collect::<Result<Vec<_>, SceneError>>()?;
+    Ok((bars, readouts))
+}
+
+/// Adds a readout with its key color at `position` within `parent`.
+fn readout(
+    scene: &mut Scene,
+    parent: NodeId,
+    position: (f32, f32),
+    key: Color,
+) -> Result<Readout, SceneError> {
+    let row = group(scene, parent, position)?;
+    rect(scene, row, (0.0, 0.0, 4.0, 14.0), key)?;
+    let mut cells = [[row; 8]; DIGITS];
+    for (i, cell) in cells.iter_mut().enumerate() {
+        let origin = group(scene, row, (10.0 + i as f32 * DIGIT_ADVANCE, 0.0))?;
+        for (segment, id) in SEGMENTS.iter().zip(cell.iter_mut()) {
+            *id = rect(scene, origin, *segment, DIGIT)?;
+            if let Some(node) = scene.get_node_mut(*id) {
+                node.clear_shape();
+            }
+        }
+    }
+    Ok(Readout {
+        group: row,
+        cells,
+        text: String::new(),
+    })
+}
+
+/// Shows `value` in a readout, touching only segments that changed.
+fn show(scene: &mut Scene, readout: &mut Readout, value: String, name: &str) {
+    if readout.text == value {
+        return;
+    }
+    let masks = segments(&value);
+    for (cell, mask) in readout.cells.iter().zip(masks) {
+        for (i, (&id, area)) in cell.iter().zip(SEGMENTS).enumerate() {
+            let Some(node) = scene.get_node_mut(id) else {
+                continue;
+            };
+            let lit = mask & (1 << i) != 0;
+            match (lit, node.shape().is_some()) {
+                (true, false) => node.set_shape(Shape::Rect(Rect::new(area.2, area.3))),
+                (false, true) => node.clear_shape(),
+                _ => {}
+            }
+        }
+    }
+    if let Some(node) = scene.get_node_mut(readout.group) {
+        node.set_label(format!("{name}: {value}"));
+    }
+    readout.text = value;
+}
+
+/// Returns the segments lit for each character cell of `text`, as bit
+/// masks over [`SEGMENTS`]. Text too long for the readout shows dashes.
+fn segments(text: &str) -> [u8; DIGITS] {
+    let mut masks = [0; DIGITS];
+    if text.len() > DIGITS {
+        return [0x40; DIGITS];
+    }
+    for (mask, c) in masks.iter_mut().zip(text.chars()) {
+        *mask = match c {
+            '0' => 0x3f,
+            '1' => 0x06,
+            '2' => 0x5b,
+            '3' => 0x4f,
+            '4' => 0x66,
+            '5' => 0x6d,
+            '6' => 0x7d,
+            '7' => 0x07,
+            '8' => 0x7f,
+            '9' => 0x6f,
+            '.' => 0x80,
+            _ => 0x40,
+        };
+    }
+    masks
+}
+
+/// Resizes a bar of the graph to a frame time, or hides it without one.
+fn set_bar(bar: &mut Node, ms: Option<f32>) {
+    let Some(ms) = ms else {
+        if bar.shape().is_some() {
+            bar.clear_shape();
+        }
+        return;
+    };
+    let height = (ms / GRAPH_MAX_MS).clamp(0.0, 1.0) * GRAPH_HEIGHT;
+    let color = if ms <= BUDGET_MS {
+        FAST
+    } else if ms <= 2.0 * BUDGET_MS {
+        SLOW
+    } else {
+        DROPPED
+    };
+    let x = bar.transform().translate.0;
+    if bar.transform().translate.1 != GRAPH_HEIGHT - height {
+        bar.transform_mut().translate = (x, GRAPH_HEIGHT - height);
+    }
+    let rect = Rect::new(BAR_WIDTH - 1.0, height);
+    match bar.shape() {
+        Some(Shape::Rect(current)) if *current == rect => {}
+        _ => bar.set_shape(Shape::Rect(rect)),
+    }
+    if bar.style().fill.as_ref().map(|fill| fill.color) != Some(color) {
+        bar.style_mut().fill = Some(Fill::solid(color));
+    }
+}
+
+/// Adds a node without a shape at `position` within `parent`.
+fn group(scene: &mut Scene, parent: NodeId, position: (f32, f32)) -> Result<NodeId, SceneError> {
+    let mut node = Node::new();
+    node.transform_mut().translate = position;
+    scene.add_node(parent, node)
+}
+
+/// Adds a filled rectangle given as (x, y, width, height) within `parent`.
+fn rect(
+    scene: &mut Scene,
+    parent: NodeId,
+    (x, y, width, height): (f32, f32, f32, f32),
+    color: Color,
+) -> Result<NodeId, SceneError> {
+    let mut node = Node::new();
+    node.transform_mut().translate = (x, y);
+    node.set_shape(Shape::Rect(Rect::new(width, height)));
+    node.style_mut().fill = Some(Fill::solid(color));
+    scene.add_node(parent, node)
+}
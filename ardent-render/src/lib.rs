@@ -6,11 +6,57 @@
 //! triangles are then passed to the GPU for drawing.
 //!
 //! At its core, `ardent_render` acts as the visual backend of the system.
+//!
+//! The crate is layered. [`gpu`] is the low-level layer: the
+//! [`GpuContext`], vertex and index buffers, and pipeline setup. On top of
+//! it, [`Renderer`] is the single entry point for drawing scenes; it owns
+//! the mesh cache, per-node uniforms, and textures, and draws into a
+//! window, an offscreen target, or a render pass of the host application.
+//!
+//! Failures to set up the GPU are reported as [`GpuInitError`] and failures
+//! to render or read back a frame as [`RenderError`], so applications can
+//! decide whether to retry, skip the frame, or give up. Shapes that fail to
+//! tessellate and images that cannot be used as textures do not fail the
+//! frame; they are listed in the [`RenderStats`] of the frame instead.
+//!
+//! Frames can also be rendered without a window. With the `png` feature,
+//! [`render_to_png`] saves a scene as an image in a single call.
+//!
+//! With the `tracing` feature, scene traversal, tessellation, GPU uploads,
+//! and render pass encoding are wrapped in `tracing` spans, so frames can be
+//! profiled with tools such as Tracy or Chrome's trace viewer.
+//!
+//! [`PerfHud`] draws frame timing and renderer statistics on top of a
+//! scene, so frames can be profiled without external tools.
+//!
+//! On HDR and wide-gamut displays, [`GpuOptions::hdr`] requests an
+//! extended-range surface. Colors are then blended in linear light and
+//! mapped to the display with the renderer's [`HdrOptions`], so UI drawn
+//! over HDR video is not crushed to sRGB.
+//!
+//! The [`prelude`] brings the renderer, GPU context, and tessellation types
+//! into scope with a single import.
 
+mod error;
 pub mod geometry;
 pub mod gpu;
+mod hud;
+#[cfg(feature = "png")]
+pub mod png;
 pub mod renderer;
 pub mod tesselate;
 
-pub use gpu::GpuContext;
-pub use renderer::Renderer;
+pub mod prelude {
+    pub use crate::geometry::{Geometry, Vertex};
+    pub use crate::gpu::{GpuContext, GpuOptions};
+    pub use crate::renderer::{MemoryStats, RenderStats, Renderer};
+    pub use crate::tesselate::{Tesselate, TesselateOptions};
+    pub use crate::{GpuInitError, RenderError, TextureError};
+}
+
+pub use error::{GpuInitError, RenderError, TextureError};
+pub use gpu::{GpuContext, GpuOptions, HdrOptions, ToneMapping};
+pub use hud::PerfHud;
+#[cfg(feature = "png")]
+pub use png::{PngError, render_to_png};
+pub use renderer::{MemoryStats, RenderStats, Renderer};
@@ -0,0 +1,98 @@
+//! One-call export of rendered frames to PNG files.
+//!
+//! Enabled by the `png` feature.
+
+use std::fmt;
+use std::path::Path;
+
+use ardent_core::scene::Scene;
+use image::error::{ParameterError, ParameterErrorKind};
+use image::{ImageError, ImageFormat, RgbaImage};
+use pollster::FutureExt;
+
+use crate::error::{GpuInitError, RenderError};
+use crate::gpu::GpuContext;
+use crate::renderer::Renderer;
+
+/// An error raised while rendering a scene to a PNG file.
+#[derive(Debug)]
+pub enum PngError {
+    /// No GPU is available for headless rendering.
+    Gpu(GpuInitError),
+
+    /// The frame could not be rendered or read back.
+    Render(RenderError),
+
+    /// The image could not be encoded or written.
+    Image(ImageError),
+}
+
+impl fmt::Display for PngError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PngError::Gpu(err) => write!(f, "failed to set up GPU: {err}"),
+            PngError::Render(err) => write!(f, "failed to render frame: {err}"),
+            PngError::Image(err) => write!(f, "failed to save PNG: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PngError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PngError::Gpu(err) => Some(err),
+            PngError::Render(err) => Some(err),
+            PngError::Image(err) => Some(err),
+        }
+    }
+}
+
+impl From<GpuInitError> for PngError {
+    fn from(err: GpuInitError) -> Self {
+        PngError::Gpu(err)
+    }
+}
+
+impl From<RenderError> for PngError {
+    fn from(err: RenderError) -> Self {
+        PngError::Render(err)
+    }
+}
+
+impl From<ImageError> for PngError {
+    fn from(err: ImageError) -> Self {
+        PngError::Image(err)
+    }
+}
+
+/// Renders `scene` headlessly at `size` (in pixels) and saves it as a PNG.
+///
+/// A GPU context and renderer are created for this call only, which makes
+/// it convenient for thumbnails and documentation screenshots. To capture
+/// many frames, create a headless [`GpuContext`] once and call
+/// [`Renderer::render_offscreen`] instead.
+///
+/// Layout is not computed here; run it on the scene beforehand.
+///
+/// # Example
+/// ```rust,ignore
+/// scene.compute_layout((800.0, 600.0));
+/// ardent_render::render_to_png(&scene, (800, 600), "screenshot.png")?;
+/// ```
+pub fn render_to_png(
+    scene: &Scene,
+    size: (u32, u32),
+    path: impl AsRef<Path>,
+) -> Result<(), PngError> {
+    let context = GpuContext::headless(size.0, size.1).block_on()?;
+    let mut renderer = Renderer::new(&context);
+    let pixels = renderer.render_offscreen(scene, &context)?;
+
+    let (width, height) = context.size;
+    let image = RgbaImage::from_raw(width, height, pixels).ok_or_else(|| {
+        ImageError::Parameter(ParameterError::from_kind(
+            ParameterErrorKind::DimensionMismatch,
+        ))
+    })?;
+    Ok(image.save_with_format(path, ImageFormat::Png)?)
+}
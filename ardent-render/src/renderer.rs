@@ -1,21 +1,183 @@
-use std::collections::HashMap;
+mod cache;
+mod clip;
+mod msaa;
+mod readback;
+mod stats;
+mod textures;
+mod uniforms;
+mod worker;
 
-use ardent_core::node::NodeId;
+use std::collections::{HashMap, HashSet};
+
+use ardent_core::node::{DirtyFlags, LodLevel, Node, NodeId};
 use ardent_core::scene::Scene;
 use ardent_core::shape::Shape;
-use lyon::tessellation::VertexBuffers;
+use ardent_core::style::{Color, FillRule, ImageHandle, MeshGradient, PathEffect, Stroke, Style};
+use ardent_core::transform::Transform;
 
-use crate::geometry::Vertex;
-use crate::gpu::GpuContext;
+use crate::error::{RenderError, TextureError};
+use crate::geometry::Geometry;
 use crate::gpu::RenderPipelineBuilder;
-use crate::gpu::VertexBuffer;
-use crate::tesselate::Tesselate;
+use crate::gpu::{GpuContext, HdrOptions, is_extended_range};
+use crate::tesselate::{Effected, Outlined, Stroked, Tesselate, TesselateOptions};
+
+use cache::{CachedMesh, MeshCache, ShapeKey};
+use clip::ClipRect;
+use msaa::MsaaTarget;
+use readback::Readback;
+use textures::{ImageFill, TextureCache};
+use uniforms::{NodeBindings, NodeUniforms};
+use worker::TessellationWorker;
+
+pub use stats::{MemoryStats, RenderStats};
+
+use lyon::math::{Angle, Transform as Affine, vector};
+use lyon::tessellation::{FillTessellator, TessellationError};
+use rayon::prelude::*;
+
+/// Minimum number of dirty shapes before tessellation is spread across
+/// worker threads. Below this, the overhead of rayon outweighs the gain.
+const PARALLEL_TESSELLATION_THRESHOLD: usize = 32;
+
+/// Identifies a node within one of the scenes rendered as layers.
+///
+/// Node IDs are only unique within their scene, so GPU resources are keyed
+/// by the layer as well.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct DrawId {
+    layer: usize,
+    node: NodeId,
+}
+
+/// What the interior of a node's mesh is filled from.
+#[derive(Clone, Copy)]
+enum FillSource<'a> {
+    /// The node's shape.
+    Shape(&'a Shape),
+
+    /// The node's shape with its outline changed by path effects.
+    Effected(&'a Shape, &'a [PathEffect]),
+
+    /// The patches of the node's mesh gradient, drawn instead of its shape.
+    Gradient(&'a MeshGradient),
+}
+
+impl FillSource<'_> {
+    /// Appends the triangles of the fill to `geometry`.
+    fn tessellate(
+        self,
+        geometry: &mut Geometry,
+        tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        match self {
+            FillSource::Shape(shape) => shape.tesselate(geometry, tessellator, options),
+            FillSource::Effected(shape, effects) => {
+                let effected = Effected {
+                    shape: shape.clone(),
+                    effects: effects.to_vec(),
+                };
+                effected.tesselate(geometry, tessellator, options)
+            }
+            FillSource::Gradient(mesh) => mesh.tesselate(geometry, tessellator, options),
+        }
+    }
+
+    /// Returns an owned copy of the fill for the background worker.
+    fn to_boxed(self) -> Box<dyn Tesselate + Send> {
+        match self {
+            FillSource::Shape(shape) => Box::new(shape.clone()),
+            FillSource::Effected(shape, effects) => Box::new(Effected {
+                shape: shape.clone(),
+                effects: effects.to_vec(),
+            }),
+            FillSource::Gradient(mesh) => Box::new(mesh.clone()),
+        }
+    }
+}
+
+/// What a node's mesh is tessellated from: its fill, and the stroke drawn
+/// over it.
+#[derive(Clone, Copy)]
+struct MeshSource<'a> {
+    /// The fill, or `None` for open shapes, which have no interior.
+    fill: Option<FillSource<'a>>,
+
+    /// The shape outlined with the stroke, and the path effects changing
+    /// its outline, or `None` without a stroke.
+    stroke: Option<(&'a Shape, &'a Stroke, &'a [PathEffect])>,
+}
+
+impl MeshSource<'_> {
+    /// Tessellates the source using the given lyon tessellator.
+    ///
+    /// Both vertices and indices are returned so that shared vertices are
+    /// preserved and concave shapes are triangulated correctly.
+    fn tessellate(
+        self,
+        tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<Geometry, TessellationError> {
+        let mut geometry = Geometry::new();
+        if let Some(fill) = self.fill {
+            fill.tessellate(&mut geometry, tessellator, options)?;
+        }
+        if let Some(stroked) = self.stroked() {
+            stroked.tesselate(&mut geometry, tessellator, options)?;
+        }
+        Ok(geometry)
+    }
 
-use lyon::tessellation::FillTessellator;
+    /// Returns an owned copy of the source for the background worker.
+    fn to_boxed(self) -> Box<dyn Tesselate + Send> {
+        Box::new(Outlined {
+            fill: self.fill.map(FillSource::to_boxed),
+            stroke: self.stroked(),
+        })
+    }
 
-/// Stores a GPU vertex buffer representing a single node's geometry.
-struct CachedMesh {
-    vertex_buffer: VertexBuffer,
+    /// Returns an owned copy of the stroke, if there is one.
+    fn stroked(self) -> Option<Stroked> {
+        self.stroke.map(|(shape, stroke, effects)| Stroked {
+            shape: shape.clone(),
+            stroke: stroke.clone(),
+            effects: effects.to_vec(),
+        })
+    }
+}
+
+/// Per-frame bookkeeping gathered while walking the scene graph.
+#[derive(Default)]
+struct FrameNodes {
+    /// Maps logical pixels to clip space for the current viewport and
+    /// scale factor.
+    projection: Affine,
+
+    /// Forces every node's uniforms to be rewritten (e.g., after a resize).
+    refresh_all: bool,
+
+    /// Index of the layer currently being traversed.
+    layer: usize,
+
+    /// Nodes with a shape in draw order, with the clip rectangle inherited
+    /// from clipping ancestors.
+    draw_list: Vec<(DrawId, Option<ClipRect>)>,
+
+    /// Nodes whose geometry changed or is not yet cached.
+    pending_meshes: Vec<(ShapeKey, DrawId)>,
+
+    /// Nodes whose transform, style, or image changed.
+    pending_uniforms: Vec<(DrawId, NodeUniforms, Option<ImageFill>)>,
+
+    /// Images referenced by image fills.
+    images: HashSet<u64>,
+
+    /// Every node reached by the traversal.
+    visited: Vec<DrawId>,
+
+    /// Nodes drawn with the fallback shape of their level-of-detail
+    /// settings instead of their own shape.
+    fallbacks: HashSet<DrawId>,
 }
 
 /// The rendering engine that tessellates and prepares UI geometry for GPU rendering.
@@ -24,58 +186,407 @@ struct CachedMesh {
 /// the scene graph, extracting shape data, and turning it into a list of
 /// triangles that can be uploaded to the GPU.
 ///
-/// For now, only filled rectangles are supported. Future versions will handle
-/// strokes, paths, and text as vector geometry.
+/// Every shape is drawn as vector geometry. Rectangles, ellipses, rings,
+/// polygons, and paths are filled with a solid color, gradient, mesh
+/// gradient, image, pattern, or noise. Any shape, including lines and
+/// polylines, can be outlined with a stroke, which may be dashed, trimmed,
+/// or vary in width. Path effects change outlines before they are
+/// tessellated.
 pub struct Renderer {
     tessellator: FillTessellator,
-    pipeline: wgpu::RenderPipeline,
-    cache: HashMap<NodeId, CachedMesh>,
+    options: TesselateOptions,
+    pipelines: HashMap<(wgpu::TextureFormat, u32), wgpu::RenderPipeline>,
+    bind_group_layout: wgpu::BindGroupLayout,
+    cache: MeshCache,
+    bindings: NodeBindings,
+    textures: TextureCache,
+    failed: HashMap<ShapeKey, TessellationError>,
+    worker: Option<TessellationWorker>,
+    stats: RenderStats,
+    viewport: (u32, u32),
+    scale_factor: f32,
+    clear_color: Color,
+    hdr: HdrOptions,
+    sample_count: u32,
+    msaa: Option<MsaaTarget>,
+    rendered: Vec<DrawId>,
 }
 
 impl Renderer {
     /// Initializes the renderer and internal GPU pipeline.
     pub fn new(context: &GpuContext) -> Self {
         let tessellator = FillTessellator::new();
-        let pipeline = RenderPipelineBuilder::new(&context.device, &context.config).pipeline;
+        let RenderPipelineBuilder {
+            pipeline,
+            bind_group_layout,
+        } = RenderPipelineBuilder::new(&context.device, &context.config);
 
         Self {
             tessellator,
-            pipeline,
-            cache: HashMap::new(),
+            options: TesselateOptions::default(),
+            pipelines: HashMap::from([((context.config.format, 1), pipeline)]),
+            bind_group_layout,
+            cache: MeshCache::default(),
+            bindings: NodeBindings::default(),
+            textures: TextureCache::new(context),
+            failed: HashMap::new(),
+            worker: None,
+            stats: RenderStats::default(),
+            viewport: (0, 0),
+            scale_factor: 1.0,
+            clear_color: Color::white(),
+            hdr: HdrOptions::default(),
+            sample_count: 1,
+            msaa: None,
+            rendered: Vec::new(),
+        }
+    }
+
+    /// Returns the color frames are cleared to before drawing.
+    pub fn clear_color(&self) -> Color {
+        self.clear_color
+    }
+
+    /// Changes the color frames are cleared to before drawing, white by
+    /// default.
+    pub fn set_clear_color(&mut self, color: Color) {
+        self.clear_color = color;
+    }
+
+    /// Returns how colors are mapped to extended-range targets.
+    pub fn hdr_options(&self) -> &HdrOptions {
+        &self.hdr
+    }
+
+    /// Changes how colors are mapped to extended-range targets, such as
+    /// the surfaces of contexts created with [`GpuOptions::hdr`](crate::GpuOptions::hdr).
+    ///
+    /// Pipelines are rebuilt on the next frame; cached meshes are kept.
+    pub fn set_hdr_options(&mut self, options: HdrOptions) {
+        if self.hdr != options {
+            self.hdr = options;
+            self.pipelines.clear();
+        }
+    }
+
+    /// Returns the number of samples per pixel frames are drawn with.
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// Enables multisample anti-aliasing with 4 samples per pixel, or
+    /// disables it with 1. Other counts are not supported by every GPU and
+    /// are rejected.
+    ///
+    /// MSAA smooths edges that feathering cannot, such as overlapping
+    /// shapes and thin slivers, at the cost of memory and fill rate.
+    pub fn set_sample_count(&mut self, sample_count: u32) -> Result<(), RenderError> {
+        if !matches!(sample_count, 1 | 4) {
+            return Err(RenderError::UnsupportedSampleCount(sample_count));
+        }
+        self.sample_count = sample_count;
+        if sample_count == 1 {
+            self.msaa = None;
+        }
+        Ok(())
+    }
+
+    /// Returns the options used when tessellating shapes.
+    pub fn tessellation_options(&self) -> &TesselateOptions {
+        &self.options
+    }
+
+    /// Changes the options used when tessellating shapes.
+    ///
+    /// All cached meshes are discarded, so every shape is tessellated again
+    /// with the new options on the next frame. A non-finite or non-positive
+    /// tolerance is ignored, keeping the current one, and a tolerance below
+    /// [`Node::MIN_TOLERANCE`] is raised to it.
+    pub fn set_tessellation_options(&mut self, mut options: TesselateOptions) {
+        options.tolerance = if options.tolerance.is_finite() && options.tolerance > 0.0 {
+            options.tolerance.max(Node::MIN_TOLERANCE)
+        } else {
+            self.options.tolerance
+        };
+        if self.options != options {
+            // The feather width is drawn from the node uniforms.
+            if self.options.feather != options.feather {
+                self.bindings = NodeBindings::default();
+            }
+            self.options = options;
+            self.discard_meshes();
+        }
+    }
+
+    /// Drops all cached meshes so every shape is tessellated again.
+    fn discard_meshes(&mut self) {
+        self.cache = MeshCache::default();
+        self.failed.clear();
+        // Restart the worker so results computed with old options are dropped.
+        if self.worker.is_some() {
+            self.worker = Some(TessellationWorker::spawn());
+        }
+    }
+
+    /// Enables or disables tessellation on a background thread.
+    ///
+    /// When enabled, dirty shapes are tessellated off the render thread and
+    /// their meshes are uploaded on a later frame; until then the affected
+    /// nodes are not drawn. Use `has_pending_tessellation` to keep requesting
+    /// redraws while results are outstanding.
+    pub fn set_background_tessellation(&mut self, enabled: bool) {
+        match (enabled, self.worker.is_some()) {
+            (true, false) => self.worker = Some(TessellationWorker::spawn()),
+            (false, true) => self.worker = None,
+            _ => {}
+        }
+    }
+
+    /// Returns `true` if background tessellation results are still outstanding.
+    pub fn has_pending_tessellation(&self) -> bool {
+        self.worker
+            .as_ref()
+            .is_some_and(TessellationWorker::is_busy)
+    }
+
+    /// Uses `texture` as the contents of an external image, e.g. created with
+    /// `ImageHandle::external`, replacing any previous texture.
+    ///
+    /// The texture is sampled wherever the image fills a shape. Its contents
+    /// may change every frame (e.g. by rendering into it) without calling
+    /// this again. It must be a 2D, single-sampled, filterable float texture
+    /// with `TEXTURE_BINDING` usage; returns an error otherwise.
+    pub fn set_external_texture(
+        &mut self,
+        handle: &ImageHandle,
+        texture: &wgpu::Texture,
+    ) -> Result<(), TextureError> {
+        self.textures.set_external(handle, texture)
+    }
+
+    /// Writes a frame of sRGB RGBA pixels into an external image, e.g. a
+    /// decoded video frame.
+    ///
+    /// The image's texture is reused while the frame size stays the same,
+    /// so streaming frames does not allocate or rebuild the scene. Returns
+    /// an error if the frame is empty, larger than the GPU supports, or
+    /// `pixels` does not hold `width * height` pixels.
+    pub fn write_external_texture(
+        &mut self,
+        context: &GpuContext,
+        handle: &ImageHandle,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+    ) -> Result<(), TextureError> {
+        self.textures
+            .write_external(context, handle, width, height, pixels)
+    }
+
+    /// Releases the texture of an external image. Shapes filled with it
+    /// show their fill color again.
+    pub fn remove_external_texture(&mut self, handle: &ImageHandle) {
+        self.textures.remove_external(handle);
+    }
+
+    /// Returns statistics about the most recently rendered frame.
+    pub fn stats(&self) -> &RenderStats {
+        &self.stats
+    }
+
+    /// Returns the GPU memory currently held by the renderer's cached
+    /// meshes, uniform buffers, textures, and render targets.
+    ///
+    /// Unlike [`stats`](Self::stats), this describes everything retained
+    /// across frames, so sampling it periodically shows whether a
+    /// long-running app's GPU memory keeps growing.
+    pub fn memory_stats(&self) -> MemoryStats {
+        MemoryStats {
+            mesh_buffers: self.cache.memory(),
+            uniform_buffers: self.bindings.memory(),
+            textures: self.textures.memory(),
+            render_targets: self.msaa.as_ref().map_or(0, MsaaTarget::memory),
         }
     }
 
+    /// Returns the IDs of the nodes of the scene at index `layer` whose
+    /// changes are fully reflected in the most recently rendered frame.
+    ///
+    /// Pass them to `Scene::clear_dirty` once the frame is rendered, so the
+    /// next frame only revisits nodes that changed since. Nodes whose mesh is
+    /// still being tessellated in the background are left out and keep
+    /// their dirty flags. The scene passed to `render` is layer 0.
+    pub fn rendered_nodes(&self, layer: usize) -> impl Iterator<Item = NodeId> + '_ {
+        self.rendered
+            .iter()
+            .filter(move |id| id.layer == layer)
+            .map(|id| id.node)
+    }
+
     /// Renders the given scene graph into the provided surface.
     ///
     /// Performs dirty checking, GPU upload, and draw call submission.
-    pub fn render(&mut self, scene: &Scene, context: &GpuContext) {
-        let output = match context.surface.get_current_texture() {
-            Ok(frame) => frame,
-            Err(e) => {
-                eprintln!("Failed to acquire surface frame: {:?}", e);
-                return;
-            }
-        };
+    /// Returns an error if the context has no surface or its next frame
+    /// cannot be acquired; nothing is drawn in that case.
+    pub fn render(&mut self, scene: &Scene, context: &GpuContext) -> Result<(), RenderError> {
+        self.render_layers(&[scene], context)
+    }
+
+    /// Renders several scenes on top of each other into the provided
+    /// surface, e.g. an application scene followed by a debug overlay.
+    ///
+    /// Scenes are drawn in order within a single render pass and share the
+    /// mesh cache.
+    pub fn render_layers(
+        &mut self,
+        layers: &[&Scene],
+        context: &GpuContext,
+    ) -> Result<(), RenderError> {
+        let surface = context.surface.as_ref().ok_or(RenderError::NoSurface)?;
+        let output = surface.get_current_texture()?;
 
         let view = output
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
+        let encoder = self.encode(layers, context, &view, context.config.format);
+        context.queue.submit(Some(encoder.finish()));
+        output.present();
+        Ok(())
+    }
 
+    /// Renders the scene into an offscreen texture and reads it back.
+    ///
+    /// Returns the pixels of the frame as tightly packed 8-bit RGBA rows,
+    /// top to bottom, at the size of the context. Works with both headless
+    /// and windowed contexts; the latter's surface is left untouched.
+    /// Frames of HDR contexts are drawn in sRGB, clipped to standard white.
+    pub fn render_offscreen(
+        &mut self,
+        scene: &Scene,
+        context: &GpuContext,
+    ) -> Result<Vec<u8>, RenderError> {
+        let (width, height) = context.size;
+        // Readback only handles 8-bit pixels.
+        let format = if is_extended_range(context.config.format) {
+            wgpu::TextureFormat::Rgba8UnormSrgb
+        } else {
+            context.config.format
+        };
+        let texture = context.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Ardent Offscreen Target"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder = self.encode(&[scene], context, &view, format);
+        let readback = Readback::copy(context, &mut encoder, &texture);
+        context.queue.submit(Some(encoder.finish()));
+        readback.read(context)
+    }
+
+    /// Draws the scene into a render pass owned by the caller, e.g. as the UI
+    /// layer of a game or visualization engine.
+    ///
+    /// `target_format` is the format of the pass's color attachment; a
+    /// pipeline is created for it the first time it is seen. The context's
+    /// size must match the attachment, and its sample count the renderer's
+    /// [`sample_count`](Self::sample_count). The pass's pipeline, bind
+    /// group, and scissor state are left as ardent set them.
+    pub fn render_to_pass(
+        &mut self,
+        pass: &mut wgpu::RenderPass<'_>,
+        scene: &Scene,
+        context: &GpuContext,
+        target_format: wgpu::TextureFormat,
+    ) {
+        pass.set_pipeline(self.pipeline(context, target_format));
+        self.draw_scene(&[scene], context, pass);
+    }
+
+    /// Returns the pipeline drawing into targets of `format` with the
+    /// current sample count, creating it on first use.
+    fn pipeline(
+        &mut self,
+        context: &GpuContext,
+        format: wgpu::TextureFormat,
+    ) -> &wgpu::RenderPipeline {
+        let sample_count = self.sample_count;
+        self.pipelines
+            .entry((format, sample_count))
+            .or_insert_with(|| {
+                RenderPipelineBuilder::pipeline(
+                    &context.device,
+                    &self.bind_group_layout,
+                    format,
+                    sample_count,
+                    &self.hdr,
+                )
+            })
+    }
+
+    /// Records a render pass drawing the given scenes into `view`, a
+    /// texture of `format`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    fn encode(
+        &mut self,
+        layers: &[&Scene],
+        context: &GpuContext,
+        view: &wgpu::TextureView,
+        format: wgpu::TextureFormat,
+    ) -> wgpu::CommandEncoder {
         let mut encoder = context
             .device
             .create_command_encoder(&wgpu::CommandEncoderDescriptor {
                 label: Some("Ardent Frame Encoder"),
             });
 
+        // With MSAA, draw into the multisampled target and resolve into
+        // `view` at the end of the pass.
+        let msaa = (self.sample_count > 1)
+            .then(|| MsaaTarget::view(&mut self.msaa, context, format, self.sample_count));
+        let (target, resolve_target) = match &msaa {
+            Some(msaa) => (msaa, Some(view)),
+            None => (view, None),
+        };
+        // Extended-range targets are cleared in linear light at the white
+        // level, like the colors drawn over them.
+        let Color(r, g, b, a) = if is_extended_range(format) {
+            let Color(r, g, b, a) = self.clear_color.to_linear();
+            let white = self.hdr.white_level;
+            Color(r * white, g * white, b * white, a)
+        } else {
+            self.clear_color
+        };
+
         {
             let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Ardent Render Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
-                    resolve_target: None,
+                    view: target,
+                    resolve_target,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
-                        store: wgpu::StoreOp::Store,
+                        load: wgpu::LoadOp::Clear(wgpu::Color {
+                            r: r as f64,
+                            g: g as f64,
+                            b: b as f64,
+                            a: a as f64,
+                        }),
+                        // Multisampled pixels are only needed until resolved.
+                        store: if msaa.is_some() {
+                            wgpu::StoreOp::Discard
+                        } else {
+                            wgpu::StoreOp::Store
+                        },
                     },
                 })],
                 depth_stencil_attachment: None,
@@ -83,54 +594,480 @@ impl Renderer {
                 timestamp_writes: None,
             });
 
-            pass.set_pipeline(&self.pipeline);
-            self.draw_scene(scene, context, &mut pass);
+            pass.set_pipeline(self.pipeline(context, format));
+            self.draw_scene(layers, context, &mut pass);
         }
 
-        context.queue.submit(Some(encoder.finish()));
-        output.present();
+        encoder
     }
 
-    /// Internal helper: draws all renderable nodes in the scene.
-    fn draw_scene<'a>(
-        &'a mut self,
-        scene: &'a Scene,
+    /// Internal helper: draws all renderable nodes in the given scenes.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    fn draw_scene(
+        &mut self,
+        layers: &[&Scene],
         context: &GpuContext,
-        pass: &mut wgpu::RenderPass<'a>,
+        pass: &mut wgpu::RenderPass<'_>,
     ) {
-        let mut draw_list = Vec::new();
+        let (width, height) = context.size;
+        let scale = context.scale_factor;
+        let rescaled = scale != self.scale_factor;
+        self.scale_factor = scale;
+        let mut frame = FrameNodes {
+            projection: Affine::scale(
+                2.0 * scale / width.max(1) as f32,
+                -2.0 * scale / height.max(1) as f32,
+            )
+            .then_translate(vector(-1.0, 1.0)),
+            refresh_all: rescaled || self.viewport != context.size,
+            ..Default::default()
+        };
+        self.viewport = context.size;
+
+        // Traverse scene graph and collect nodes needing a mesh or new uniforms
+        #[cfg(feature = "tracing")]
+        let traversal = tracing::debug_span!("collect_nodes").entered();
+        for (layer, scene) in layers.iter().enumerate() {
+            frame.layer = layer;
+            self.collect_node(
+                scene,
+                scene.root(),
+                &Affine::identity(),
+                false,
+                None,
+                &mut frame,
+            );
+        }
+        #[cfg(feature = "tracing")]
+        drop(traversal);
+        let FrameNodes {
+            draw_list,
+            pending_meshes: pending,
+            pending_uniforms,
+            images,
+            visited,
+            fallbacks,
+            ..
+        } = frame;
 
-        // Traverse scene graph and prepare dirty meshes
-        scene.traverse(|node| {
-            if let Some(shape) = node.shape() {
-                let id = node.id();
+        self.stats = RenderStats::default();
 
-                if node.is_dirty() || !self.cache.contains_key(&id) {
-                    let vertices = self.tessellate_shape(shape);
-                    let vertex_buffer = VertexBuffer::from_vertices(&context.device, &vertices);
-                    self.cache.insert(id, CachedMesh { vertex_buffer });
+        // Upload meshes finished by the background worker since last frame
+        let finished = self.worker.as_mut().map(|w| w.drain()).unwrap_or_default();
+        for job in finished {
+            self.store_mesh(context, job.key, job.result);
+        }
+
+        for (key, _) in &pending {
+            if self.cache.contains_shape(key) {
+                self.stats.cache_hits += 1;
+            } else if !self.failed.contains_key(key) {
+                self.stats.cache_misses += 1;
+            }
+        }
+
+        // Tessellate each distinct uncached shape once, in traversal order.
+        // Shapes that already failed or are in flight are not resubmitted.
+        let mut seen = HashSet::new();
+        let dirty: Vec<(ShapeKey, DrawId)> = pending
+            .iter()
+            .filter(|(key, _)| {
+                !self.cache.contains_shape(key)
+                    && !self.failed.contains_key(key)
+                    && !self.worker.as_ref().is_some_and(|w| w.is_in_flight(key))
+                    && seen.insert(key)
+            })
+            .cloned()
+            .collect();
+        self.stats.shapes_tessellated = dirty.len();
+        let options = self.options;
+        if let Some(worker) = &mut self.worker {
+            for (key, id) in &dirty {
+                if let Some((source, options)) =
+                    tessellation_input(layers, *id, &fallbacks, options)
+                {
+                    worker.submit(key.clone(), source.to_boxed(), options);
                 }
+            }
+        } else {
+            for (key, result) in self.tessellate_dirty(layers, &dirty, &fallbacks) {
+                self.store_mesh(context, key, result);
+            }
+        }
 
-                draw_list.push(id);
+        // Point nodes at their shared meshes (skipping failed shapes) and
+        // refresh changed uniforms
+        let mut waiting = HashSet::new();
+        for (key, id) in pending {
+            match self.failed.get(&key) {
+                Some(e) => {
+                    self.cache.release(id);
+                    self.stats.tessellation_errors.push((id.node, e.clone()));
+                }
+                None if self.cache.contains_shape(&key) => self.cache.assign(id, key),
+                // Still being tessellated in the background
+                None => {
+                    waiting.insert(id);
+                }
             }
-        });
+        }
+        // Meshes finished for nodes that changed shape in the meantime are
+        // not used by any node.
+        self.cache.evict_unused();
+        self.rendered = visited
+            .into_iter()
+            .filter(|id| !waiting.contains(id))
+            .collect();
+        #[cfg(feature = "tracing")]
+        let upload =
+            tracing::debug_span!("upload_uniforms", nodes = pending_uniforms.len()).entered();
+        for (id, uniforms, image) in pending_uniforms {
+            let texture = match &image {
+                Some(fill) => self
+                    .textures
+                    .prepare(context, &fill.handle)
+                    .unwrap_or_else(|e| {
+                        self.stats.texture_errors.push((id.node, e));
+                        None
+                    }),
+                None => None,
+            };
+            let uniforms = match (&image, texture) {
+                (Some(fill), Some(key)) => {
+                    let uniforms = uniforms.with_image(fill.bounds, fill.opacity);
+                    match (&fill.slice, self.textures.size(key)) {
+                        (Some(slice), Some(size)) => uniforms.with_slice(slice, size),
+                        _ => uniforms,
+                    }
+                }
+                _ => uniforms,
+            };
+            self.bindings.update(
+                context,
+                &self.bind_group_layout,
+                id,
+                &uniforms,
+                &self.textures,
+                texture,
+            );
+        }
+        #[cfg(feature = "tracing")]
+        drop(upload);
+
+        // Evict GPU resources of removed nodes
+        let live: HashSet<DrawId> = draw_list.iter().map(|&(id, _)| id).collect();
+        self.cache.retain_nodes(&live);
+        self.bindings.retain_nodes(&live);
+        self.textures.retain_images(&images);
 
         // Perform draw calls from prepared list
-        for id in draw_list {
-            if let Some(cached) = self.cache.get(&id) {
-                cached.vertex_buffer.draw(pass);
+        let full = ClipRect {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        };
+        let mut scissor = full;
+        for (id, clip) in draw_list {
+            let clip = clip.unwrap_or(full);
+            if clip.is_empty() {
+                continue;
+            }
+            if let (Some(mesh), Some(bind_group)) =
+                (self.cache.mesh(id), self.bindings.bind_group(id))
+            {
+                if clip != scissor {
+                    pass.set_scissor_rect(clip.x, clip.y, clip.width, clip.height);
+                    scissor = clip;
+                }
+                pass.set_bind_group(0, bind_group, &[]);
+                mesh.draw(pass);
+                self.stats.nodes_drawn += 1;
+            }
+        }
+    }
+
+    /// Recursively collects the work needed to draw a node and its subtree.
+    ///
+    /// Only geometry- or style-dirty nodes are queued for tessellation, and
+    /// only shapes not already cached are tessellated. Nodes whose own
+    /// or ancestor's transform changed, or whose style changed, only get
+    /// their uniforms rewritten and keep their cached mesh.
+    ///
+    /// Nodes that clip their content pass their on-screen bounds down as the
+    /// clip rectangle of their descendants.
+    ///
+    /// Subtrees with level-of-detail settings are skipped or replaced by
+    /// their fallback shape outside their zoom range. Skipped nodes are not
+    /// visited, so they stay dirty until they are drawn again.
+    fn collect_node(
+        &self,
+        scene: &Scene,
+        id: NodeId,
+        parent: &Affine,
+        parent_moved: bool,
+        clip: Option<ClipRect>,
+        frame: &mut FrameNodes,
+    ) {
+        let Some(node) = scene.get_node(id) else {
+            return;
+        };
+        let flags = node.dirty_flags();
+        let world = local_transform(node.transform()).then(parent);
+        let level = node
+            .lod()
+            .map_or(LodLevel::Detail, |lod| lod.level(zoom(&world)));
+        if level == LodLevel::Hidden {
+            return;
+        }
+        let draw = DrawId {
+            layer: frame.layer,
+            node: id,
+        };
+        frame.visited.push(draw);
+        let moved = parent_moved || flags.contains(DirtyFlags::TRANSFORM);
+        let shape = match level {
+            LodLevel::Fallback(shape) => {
+                frame.fallbacks.insert(draw);
+                Some(shape)
+            }
+            _ => node.shape(),
+        };
+        // Open shapes are drawn with their stroke, so without one there is
+        // nothing to draw.
+        let shape = shape.filter(|shape| !shape.is_open() || node.style().stroke.is_some());
+
+        if let Some(shape) = shape {
+            // The fill rule, path effects, and the stroke live in the
+            // style, so style
+            // changes may also select different geometry, and zooming may
+            // switch to or from a fallback shape; unchanged keys are
+            // reassigned cheaply.
+            if flags.intersects(DirtyFlags::GEOMETRY | DirtyFlags::STYLE)
+                || node.lod().is_some()
+                || !self.cache.contains_node(draw)
+            {
+                let style = node.style();
+                let key = ShapeKey::of(
+                    shape,
+                    fill_rule(style),
+                    mesh_gradient(style),
+                    &style.effects,
+                    style.stroke.as_ref(),
+                    node.tolerance(),
+                );
+                frame.pending_meshes.push((key, draw));
+            }
+
+            // Images finish loading without marking the node dirty, so
+            // the bound texture is compared with the image's current state.
+            let image = ImageFill::of(node, shape).filter(|_| !shape.is_open());
+            let texture = image
+                .as_ref()
+                .and_then(|fill| self.textures.key(&fill.handle));
+            if let Some(fill) = &image {
+                frame.images.insert(fill.handle.id());
+            }
+
+            if moved
+                || frame.refresh_all
+                || flags.contains(DirtyFlags::STYLE)
+                || !self.bindings.contains(draw)
+                || self.bindings.texture(draw) != texture
+            {
+                let clip = world.then(&frame.projection);
+                let uniforms = NodeUniforms::new(&clip, node.style())
+                    .with_feather(self.options.feather, self.viewport);
+                frame.pending_uniforms.push((draw, uniforms, image));
+            }
+
+            frame.draw_list.push((draw, clip));
+        }
+        if matches!(level, LodLevel::Fallback(_)) {
+            return;
+        }
+
+        let clip = if node.layout().clips() {
+            let device = world.then_scale(self.scale_factor, self.scale_factor);
+            let bounds = ClipRect::of(node.computed_layout(), &device, self.viewport);
+            Some(clip.map_or(bounds, |clip| clip.intersect(&bounds)))
+        } else {
+            clip
+        };
+        for &child in node.children() {
+            self.collect_node(scene, child, &world, moved, clip, frame);
+        }
+    }
+
+    /// Tessellates the shapes of the given dirty nodes.
+    ///
+    /// Large batches (e.g., the first frame or a theme switch) are tessellated
+    /// in parallel with one `FillTessellator` per worker thread. The returned
+    /// list preserves the order of `dirty`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(shapes = dirty.len()))
+    )]
+    fn tessellate_dirty(
+        &mut self,
+        layers: &[&Scene],
+        dirty: &[(ShapeKey, DrawId)],
+        fallbacks: &HashSet<DrawId>,
+    ) -> Vec<(ShapeKey, Result<Geometry, TessellationError>)> {
+        let options = self.options;
+        let source_of = |id: DrawId| tessellation_input(layers, id, fallbacks, options);
+
+        if dirty.len() < PARALLEL_TESSELLATION_THRESHOLD {
+            return dirty
+                .iter()
+                .filter_map(|(key, id)| {
+                    let (source, options) = source_of(*id)?;
+                    Some((
+                        key.clone(),
+                        source.tessellate(&mut self.tessellator, &options),
+                    ))
+                })
+                .collect();
+        }
+
+        dirty
+            .par_iter()
+            .map_init(FillTessellator::new, |tessellator, (key, id)| {
+                let (source, options) = source_of(*id)?;
+                Some((key.clone(), source.tessellate(tessellator, &options)))
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Uploads a finished tessellation, or records its failure, which is
+    /// reported in the stats of every frame drawing the shape.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn store_mesh(
+        &mut self,
+        context: &GpuContext,
+        key: ShapeKey,
+        result: Result<Geometry, TessellationError>,
+    ) {
+        match result {
+            Ok(geometry) => {
+                self.stats.vertices_tessellated += geometry.vertices.len();
+                self.cache
+                    .insert(key, CachedMesh::upload(context, &geometry));
+            }
+            Err(e) => {
+                self.failed.insert(key, e);
             }
         }
     }
+}
+
+/// Converts a node's transform into an affine matrix in its parent's space.
+///
+/// Scaling is applied first, then rotation, then translation.
+fn local_transform(transform: &Transform) -> Affine {
+    let (sx, sy) = transform.scale;
+    let (tx, ty) = transform.translate;
+    Affine::scale(sx, sy)
+        .then_rotate(Angle::radians(transform.rotate))
+        .then_translate(vector(tx, ty))
+}
+
+/// Returns what a node's mesh is tessellated from together with the
+/// options it is tessellated with. Nodes without a shape have no mesh, and
+/// nodes in `fallbacks` are tessellated from their fallback shape. Open
+/// shapes are drawn with their stroke only, and have no mesh without one.
+fn tessellation_input<'a>(
+    layers: &[&'a Scene],
+    id: DrawId,
+    fallbacks: &HashSet<DrawId>,
+    options: TesselateOptions,
+) -> Option<(MeshSource<'a>, TesselateOptions)> {
+    let node = layers.get(id.layer)?.get_node(id.node)?;
+    let options = TesselateOptions {
+        fill_rule: fill_rule(node.style()),
+        // `max` also replaces NaN, so no tolerance reaching lyon makes it
+        // panic.
+        tolerance: node
+            .tolerance()
+            .unwrap_or(options.tolerance)
+            .max(Node::MIN_TOLERANCE),
+        ..options
+    };
+    let shape = if fallbacks.contains(&id) {
+        node.lod()?.fallback.as_ref()?
+    } else {
+        node.shape()?
+    };
+    let style = node.style();
+    let effects = &style.effects;
+    let stroke = style
+        .stroke
+        .as_ref()
+        .map(|stroke| (shape, stroke, effects.as_slice()));
+    let fill = match mesh_gradient(style) {
+        _ if shape.is_open() => None,
+        Some(mesh) => Some(FillSource::Gradient(mesh)),
+        None if effects.is_empty() => Some(FillSource::Shape(shape)),
+        None => Some(FillSource::Effected(shape, effects)),
+    };
+    if fill.is_none() && stroke.is_none() {
+        return None;
+    }
+    Some((MeshSource { fill, stroke }, options))
+}
+
+/// Returns the scale at which content with the given world transform is
+/// drawn, in logical pixels per local unit.
+fn zoom(world: &Affine) -> f32 {
+    world.determinant().abs().sqrt()
+}
+
+/// Returns the fill rule a node's shape is tessellated with.
+fn fill_rule(style: &Style) -> FillRule {
+    style
+        .fill
+        .as_ref()
+        .map(|fill| fill.rule)
+        .unwrap_or_default()
+}
+
+/// Returns the mesh gradient drawn in place of a node's shape, if any.
+fn mesh_gradient(style: &Style) -> Option<&MeshGradient> {
+    style.fill.as_ref()?.mesh.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ardent_core::shape::Ellipse;
+    use ardent_core::style::Fill;
+
+    #[test]
+    fn invalid_tolerances_do_not_reach_lyon() {
+        let mut scene = Scene::new();
+        let mut node = Node::new();
+        node.set_shape(Shape::Ellipse(Ellipse::new(40.0, 20.0)));
+        node.style_mut().fill = Some(Fill::solid(Color::rgb(0.0, 0.0, 0.0)));
+        node.style_mut().stroke = Some(Stroke::new(Color::rgb(1.0, 1.0, 1.0), 2.0));
+        let id = scene.add_node(scene.root(), node).expect("root exists");
+        let draw = DrawId { layer: 0, node: id };
 
-    /// Tessellates a single shape using the internal lyon tessellator.
-    fn tessellate_shape(&mut self, shape: &Shape) -> Vec<Vertex> {
-        let mut geometry: VertexBuffers<Vertex, u16> = VertexBuffers::new();
-        match shape {
-            Shape::Rect(rect) => {
-                rect.tesselate(&mut geometry, &mut self.tessellator);
-            } // Future: other shape variants
+        for tolerance in [0.0, -1.0, 1e-9, f32::NAN, f32::INFINITY] {
+            let node = scene.get_node_mut(id).expect("node exists");
+            node.set_tolerance(tolerance);
+            assert!(node.tolerance().is_none_or(|t| t >= Node::MIN_TOLERANCE));
+            let options = TesselateOptions {
+                tolerance,
+                ..TesselateOptions::default()
+            };
+            let (source, options) = tessellation_input(&[&scene], draw, &HashSet::new(), options)
+                .expect("node has a mesh");
+            let geometry = source
+                .tessellate(&mut FillTessellator::new(), &options)
+                .expect("shape tessellates");
+            assert!(!geometry.indices.is_empty(), "tolerance {tolerance}");
         }
-        geometry.vertices
     }
 }
@@ -0,0 +1,230 @@
+//! Shape-keyed cache of GPU meshes.
+//!
+//! Tessellated geometry is stored by the shape definition rather
+//! than by node, so nodes with identical shapes (e.g., hundreds of list-row
+//! rectangles) tessellate once and share the same GPU buffers. Each mesh is
+//! reference-counted by the nodes using it and evicted once unused.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use ardent_core::shape::Shape;
+use ardent_core::style::{FillRule, MeshGradient, PathEffect, Stroke};
+
+use super::DrawId;
+use crate::geometry::Geometry;
+use crate::gpu::{GpuContext, IndexBuffer, VertexBuffer};
+
+/// The inputs a mesh is tessellated from: a shape definition, the fill
+/// rule it is tessellated with, the mesh gradient drawn in its place, the
+/// path effects changing its outline, the stroke it is drawn with, and the
+/// node's own tessellation tolerance, used to share geometry between nodes.
+///
+/// Keys hold the inputs themselves rather than only their hash, so shapes
+/// whose hashes collide never share a mesh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ShapeKey {
+    hash: u64,
+    inputs: Arc<[u8]>,
+}
+
+impl ShapeKey {
+    /// Computes the cache key for the given shape, fill rule, mesh
+    /// gradient, path effects, stroke, and tolerance.
+    pub(crate) fn of(
+        shape: &Shape,
+        rule: FillRule,
+        mesh: Option<&MeshGradient>,
+        effects: &[PathEffect],
+        stroke: Option<&Stroke>,
+        tolerance: Option<f32>,
+    ) -> Self {
+        let mut inputs = Inputs::default();
+        shape.hash(&mut inputs);
+        rule.hash(&mut inputs);
+        mesh.hash(&mut inputs);
+        effects.hash(&mut inputs);
+        if let Some(stroke) = stroke {
+            hash_stroke(stroke, &mut inputs);
+        }
+        tolerance.map(f32::to_bits).hash(&mut inputs);
+        Self {
+            hash: inputs.finish(),
+            inputs: inputs.0.into(),
+        }
+    }
+}
+
+impl Hash for ShapeKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+/// A hasher recording the bytes it is fed, so the hashed inputs can be
+/// compared when their hashes match.
+#[derive(Default)]
+struct Inputs(Vec<u8>);
+
+impl Hasher for Inputs {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        hasher.write(&self.0);
+        hasher.finish()
+    }
+}
+
+/// Hashes the parts of a stroke that shape its geometry. The color is left
+/// out, since it is drawn from the node's uniforms.
+fn hash_stroke(stroke: &Stroke, state: &mut impl Hasher) {
+    stroke.width.to_bits().hash(state);
+    stroke.align.hash(state);
+    stroke.cap.hash(state);
+    stroke.join.hash(state);
+    stroke.miter_limit.to_bits().hash(state);
+    stroke.dashes.len().hash(state);
+    for dash in &stroke.dashes {
+        dash.to_bits().hash(state);
+    }
+    stroke.dash_offset.to_bits().hash(state);
+    stroke.trim_start.to_bits().hash(state);
+    stroke.trim_end.to_bits().hash(state);
+    stroke.profile.hash(state);
+    stroke.markers.hash(state);
+}
+
+/// Stores the GPU vertex and index buffers representing a tessellated shape.
+pub(crate) struct CachedMesh {
+    vertex_buffer: VertexBuffer,
+    index_buffer: IndexBuffer,
+}
+
+impl CachedMesh {
+    /// Uploads tessellated geometry to the GPU.
+    pub(crate) fn upload(context: &GpuContext, geometry: &Geometry) -> Self {
+        Self {
+            vertex_buffer: VertexBuffer::from_vertices(&context.device, &geometry.vertices),
+            index_buffer: IndexBuffer::from_indices(&context.device, &geometry.indices),
+        }
+    }
+
+    /// Returns the bytes of GPU memory held by the mesh's buffers.
+    pub(crate) fn memory(&self) -> u64 {
+        self.vertex_buffer.buffer.size() + self.index_buffer.buffer.size()
+    }
+
+    /// Issues an indexed draw call for this mesh.
+    pub(crate) fn draw(&self, pass: &mut wgpu::RenderPass<'_>) {
+        self.index_buffer.draw(&self.vertex_buffer, pass);
+    }
+}
+
+/// A cached mesh together with the number of nodes referencing it.
+struct SharedMesh {
+    mesh: CachedMesh,
+    refs: usize,
+}
+
+/// Maps nodes to shared, reference-counted meshes.
+#[derive(Default)]
+pub(crate) struct MeshCache {
+    meshes: HashMap<ShapeKey, SharedMesh>,
+    nodes: HashMap<DrawId, ShapeKey>,
+}
+
+impl MeshCache {
+    /// Returns `true` if the node currently references a cached mesh.
+    pub(crate) fn contains_node(&self, id: DrawId) -> bool {
+        self.nodes.contains_key(&id)
+    }
+
+    /// Returns `true` if geometry for the given shape key is already cached.
+    pub(crate) fn contains_shape(&self, key: &ShapeKey) -> bool {
+        self.meshes.contains_key(key)
+    }
+
+    /// Stores a freshly uploaded mesh under the given shape key.
+    ///
+    /// The mesh starts without references; use `assign` to attach nodes,
+    /// and `evict_unused` to drop it if no node takes it.
+    pub(crate) fn insert(&mut self, key: ShapeKey, mesh: CachedMesh) {
+        self.meshes
+            .entry(key)
+            .or_insert(SharedMesh { mesh, refs: 0 });
+    }
+
+    /// Points a node at the mesh for `key`, releasing its previous mesh.
+    ///
+    /// The new mesh is referenced before the old one is released, so a
+    /// node never drops the only reference to a mesh it is moving to.
+    pub(crate) fn assign(&mut self, id: DrawId, key: ShapeKey) {
+        if self.nodes.get(&id) == Some(&key) {
+            return;
+        }
+        let Some(shared) = self.meshes.get_mut(&key) else {
+            return;
+        };
+        shared.refs += 1;
+        if let Some(old) = self.nodes.insert(id, key) {
+            self.unreference(&old);
+        }
+    }
+
+    /// Detaches a node from its mesh, evicting the mesh if no longer used.
+    pub(crate) fn release(&mut self, id: DrawId) {
+        if let Some(key) = self.nodes.remove(&id) {
+            self.unreference(&key);
+        }
+    }
+
+    /// Drops one reference to the mesh for `key`, evicting it once unused.
+    fn unreference(&mut self, key: &ShapeKey) {
+        if let Some(shared) = self.meshes.get_mut(key) {
+            shared.refs -= 1;
+            if shared.refs == 0 {
+                self.meshes.remove(key);
+            }
+        }
+    }
+
+    /// Drops every mesh no node references, e.g. one finished in the
+    /// background after the nodes waiting for it changed their shape.
+    pub(crate) fn evict_unused(&mut self) {
+        self.meshes.retain(|_, shared| shared.refs > 0);
+    }
+
+    /// Releases every node not contained in `live`.
+    ///
+    /// Called after each traversal so meshes of removed nodes are evicted.
+    pub(crate) fn retain_nodes(&mut self, live: &HashSet<DrawId>) {
+        let stale: Vec<DrawId> = self
+            .nodes
+            .keys()
+            .filter(|id| !live.contains(id))
+            .copied()
+            .collect();
+        for id in stale {
+            self.release(id);
+        }
+    }
+
+    /// Returns the bytes of GPU memory held by all cached meshes.
+    pub(crate) fn memory(&self) -> u64 {
+        self.meshes
+            .values()
+            .map(|shared| shared.mesh.memory())
+            .sum()
+    }
+
+    /// Returns the mesh referenced by the given node, if any.
+    pub(crate) fn mesh(&self, id: DrawId) -> Option<&CachedMesh> {
+        let key = self.nodes.get(&id)?;
+        self.meshes.get(key).map(|shared| &shared.mesh)
+    }
+}
@@ -0,0 +1,53 @@
+use ardent_core::layout::ComputedLayout;
+use lyon::math::{Box2D, Transform as Affine, point};
+
+/// A scissor rectangle in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ClipRect {
+    pub(crate) x: u32,
+    pub(crate) y: u32,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+impl ClipRect {
+    /// Returns the on-screen bounds of a node's layout box, given the node's
+    /// world transform, limited to the viewport.
+    ///
+    /// Rotated boxes are clipped to their axis-aligned bounding box.
+    pub(crate) fn of(layout: &ComputedLayout, world: &Affine, viewport: (u32, u32)) -> Self {
+        let local = Box2D::new(point(0.0, 0.0), point(layout.width, layout.height));
+        let bounds = world.outer_transformed_box(&local);
+
+        let (max_x, max_y) = (viewport.0 as f32, viewport.1 as f32);
+        let min_x = bounds.min.x.floor().clamp(0.0, max_x);
+        let min_y = bounds.min.y.floor().clamp(0.0, max_y);
+        let end_x = bounds.max.x.ceil().clamp(min_x, max_x);
+        let end_y = bounds.max.y.ceil().clamp(min_y, max_y);
+        Self {
+            x: min_x as u32,
+            y: min_y as u32,
+            width: (end_x - min_x) as u32,
+            height: (end_y - min_y) as u32,
+        }
+    }
+
+    /// Returns the area covered by both rectangles.
+    pub(crate) fn intersect(&self, other: &Self) -> Self {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let end_x = (self.x + self.width).min(other.x + other.width).max(x);
+        let end_y = (self.y + self.height).min(other.y + other.height).max(y);
+        Self {
+            x,
+            y,
+            width: end_x - x,
+            height: end_y - y,
+        }
+    }
+
+    /// Returns `true` if the rectangle covers no pixels.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+}
@@ -0,0 +1,68 @@
+//! The multisampled color target frames are drawn into when MSAA is
+//! enabled, before being resolved into the output texture.
+
+use super::textures::texture_memory;
+use crate::gpu::GpuContext;
+
+/// A multisampled texture matching the size and format of the output.
+pub(crate) struct MsaaTarget {
+    view: wgpu::TextureView,
+    memory: u64,
+    size: (u32, u32),
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+}
+
+impl MsaaTarget {
+    fn new(context: &GpuContext, format: wgpu::TextureFormat, sample_count: u32) -> Self {
+        let (width, height) = context.size;
+        let texture = context.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Ardent MSAA Target"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        Self {
+            view: texture.create_view(&wgpu::TextureViewDescriptor::default()),
+            memory: texture_memory(&texture),
+            size: context.size,
+            format,
+            sample_count,
+        }
+    }
+
+    /// Returns the bytes of GPU memory held by the target.
+    pub(crate) fn memory(&self) -> u64 {
+        self.memory
+    }
+
+    /// Returns a view of the target for the context's size, recreating
+    /// `target` if the size, format, or sample count changed.
+    pub(crate) fn view(
+        target: &mut Option<Self>,
+        context: &GpuContext,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+    ) -> wgpu::TextureView {
+        let current = target.as_ref().is_some_and(|target| {
+            target.size == context.size
+                && target.format == format
+                && target.sample_count == sample_count
+        });
+        if !current {
+            *target = Some(Self::new(context, format, sample_count));
+        }
+        target
+            .as_ref()
+            .map(|target| target.view.clone())
+            .expect("MSAA target was just created")
+    }
+}
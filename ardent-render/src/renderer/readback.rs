@@ -0,0 +1,99 @@
+use std::sync::mpsc;
+
+use crate::error::RenderError;
+use crate::gpu::GpuContext;
+
+/// Number of bytes per pixel of the supported 8-bit color formats.
+const BYTES_PER_PIXEL: u32 = 4;
+
+/// A texture copied into a mappable buffer, waiting to be read.
+///
+/// Buffer rows are padded to `wgpu::COPY_BYTES_PER_ROW_ALIGNMENT`, so the
+/// padding is stripped when reading.
+pub(crate) struct Readback {
+    buffer: wgpu::Buffer,
+    size: (u32, u32),
+    padded_row: u32,
+    bgra: bool,
+}
+
+impl Readback {
+    /// Records a copy of `texture` into a new readback buffer.
+    pub(crate) fn copy(
+        context: &GpuContext,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+    ) -> Self {
+        let (width, height) = (texture.width(), texture.height());
+        let padded_row = (width * BYTES_PER_PIXEL).div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+            * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+        let buffer = context.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Ardent Readback Buffer"),
+            size: (padded_row * height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            texture.size(),
+        );
+
+        Self {
+            buffer,
+            size: (width, height),
+            padded_row,
+            bgra: matches!(
+                texture.format(),
+                wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
+            ),
+        }
+    }
+
+    /// Waits for the copy to finish and returns tightly packed RGBA rows.
+    ///
+    /// The commands recording the copy must have been submitted.
+    pub(crate) fn read(self, context: &GpuContext) -> Result<Vec<u8>, RenderError> {
+        let slice = self.buffer.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        context.device.poll(wgpu::PollType::Wait)?;
+        // The callback has run once waiting succeeded; a dropped sender
+        // means the mapping was abandoned.
+        receiver.recv().unwrap_or(Err(wgpu::BufferAsyncError))?;
+
+        let (width, height) = self.size;
+        let row = (width * BYTES_PER_PIXEL) as usize;
+        let mut pixels = Vec::with_capacity(row * height as usize);
+        {
+            let data = slice.get_mapped_range();
+            for padded in data.chunks(self.padded_row as usize) {
+                pixels.extend_from_slice(&padded[..row]);
+            }
+        }
+        self.buffer.unmap();
+
+        if self.bgra {
+            for pixel in pixels.chunks_exact_mut(BYTES_PER_PIXEL as usize) {
+                pixel.swap(0, 2);
+            }
+        }
+        Ok(pixels)
+    }
+}
@@ -0,0 +1,59 @@
+use ardent_core::node::NodeId;
+use lyon::tessellation::TessellationError;
+
+use crate::error::TextureError;
+
+/// Statistics describing the most recently rendered frame.
+///
+/// Useful for diagnosing performance problems and malformed geometry.
+/// The stats are reset at the start of every frame.
+#[derive(Debug, Clone, Default)]
+pub struct RenderStats {
+    /// Number of nodes drawn.
+    pub nodes_drawn: usize,
+
+    /// Number of distinct shapes tessellated this frame.
+    pub shapes_tessellated: usize,
+
+    /// Number of vertices in the meshes uploaded this frame.
+    pub vertices_tessellated: usize,
+
+    /// Nodes needing a mesh whose shape was already cached.
+    pub cache_hits: usize,
+
+    /// Nodes needing a mesh whose shape had to be tessellated.
+    pub cache_misses: usize,
+
+    /// Nodes that were skipped because their shape failed to tessellate.
+    pub tessellation_errors: Vec<(NodeId, TessellationError)>,
+
+    /// Nodes drawn without their image fill because the image could not be
+    /// used as a texture.
+    pub texture_errors: Vec<(NodeId, TextureError)>,
+}
+
+/// Bytes of GPU memory held by the renderer, by kind of resource.
+///
+/// Sizes are those requested from the GPU; drivers may round allocations
+/// up, so actual usage can be slightly higher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Vertex and index buffers of cached meshes.
+    pub mesh_buffers: u64,
+
+    /// Uniform buffers of drawn nodes.
+    pub uniform_buffers: u64,
+
+    /// Textures of image fills, including external images.
+    pub textures: u64,
+
+    /// Intermediate render targets, such as the MSAA target.
+    pub render_targets: u64,
+}
+
+impl MemoryStats {
+    /// Returns the bytes held by all resources together.
+    pub fn total(&self) -> u64 {
+        self.mesh_buffers + self.uniform_buffers + self.textures + self.render_targets
+    }
+}
@@ -0,0 +1,373 @@
+//! GPU textures of image fills.
+//!
+//! Images are uploaded once per version of their [`ImageHandle`] and shared
+//! by every node filled with them. Nodes without a ready image are bound to
+//! a 1×1 white fallback texture, which the shader ignores.
+//!
+//! External images are textures supplied by the host. Their contents can
+//! change every frame without involving the cache; only replacing the
+//! texture itself starts a new generation, which rebinds the nodes using
+//! it.
+
+use std::collections::{HashMap, HashSet};
+
+use ardent_core::node::Node;
+use ardent_core::shape::Shape;
+use ardent_core::style::{ImageHandle, NineSlice};
+use wgpu::util::DeviceExt;
+
+use crate::error::TextureError;
+use crate::gpu::GpuContext;
+
+/// Identifies the uploaded pixels of an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct TextureKey {
+    image: u64,
+    version: u64,
+}
+
+impl TextureKey {
+    /// Returns the key of the image's current pixels, if it is ready.
+    fn of(handle: &ImageHandle) -> Option<Self> {
+        let version = handle.version();
+        handle.data()?;
+        Some(Self {
+            image: handle.id(),
+            version,
+        })
+    }
+}
+
+/// A node's image fill, waiting for its texture.
+pub(crate) struct ImageFill {
+    pub(crate) handle: ImageHandle,
+
+    /// Local-space box the image is stretched over, as (x, y, width, height).
+    pub(crate) bounds: [f32; 4],
+
+    /// The style opacity of the node.
+    pub(crate) opacity: f32,
+
+    /// Slices the image is scaled in, if not stretched as a whole.
+    pub(crate) slice: Option<NineSlice>,
+}
+
+impl ImageFill {
+    /// Returns the image fill of a node, if it has one.
+    pub(crate) fn of(node: &Node, shape: &Shape) -> Option<Self> {
+        let style = node.style();
+        let fill = style.fill.as_ref()?;
+        let handle = fill.image.clone()?;
+        let (min, max) = shape.bounds()?;
+        let bounds = [min.0, min.1, max.0 - min.0, max.1 - min.1];
+        Some(Self {
+            handle,
+            bounds,
+            opacity: style.opacity,
+            slice: fill.slice,
+        })
+    }
+}
+
+struct GpuTexture {
+    version: u64,
+    view: wgpu::TextureView,
+    size: (u32, u32),
+}
+
+/// A texture supplied for an external image.
+struct ExternalTexture {
+    /// Distinguishes successive textures set for the same image.
+    generation: u64,
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+/// Owns the textures of all images in use.
+pub(crate) struct TextureCache {
+    sampler: wgpu::Sampler,
+    fallback: wgpu::TextureView,
+    textures: HashMap<u64, GpuTexture>,
+
+    /// Images that could not be uploaded, so they are not retried.
+    rejected: HashMap<TextureKey, TextureError>,
+
+    /// Textures of external images, kept until removed by the host.
+    external: HashMap<u64, ExternalTexture>,
+
+    /// Counter used to generate external texture generations.
+    next_generation: u64,
+}
+
+impl TextureCache {
+    pub(crate) fn new(context: &GpuContext) -> Self {
+        let sampler = context.device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Ardent Image Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        let fallback = upload(context, 1, 1, &[255; 4]);
+        Self {
+            sampler,
+            fallback,
+            textures: HashMap::new(),
+            rejected: HashMap::new(),
+            external: HashMap::new(),
+            next_generation: 0,
+        }
+    }
+
+    /// Returns the key the image's texture has once prepared, or `None` if
+    /// the image is not ready or was rejected.
+    pub(crate) fn key(&self, handle: &ImageHandle) -> Option<TextureKey> {
+        if let Some(external) = self.external.get(&handle.id()) {
+            return Some(TextureKey {
+                image: handle.id(),
+                version: external.generation,
+            });
+        }
+        TextureKey::of(handle).filter(|key| !self.rejected.contains_key(key))
+    }
+
+    /// Uploads the image's pixels if they changed since the last upload.
+    ///
+    /// Returns the key of the uploaded pixels, or `None` if the image is not
+    /// ready. Returns an error if the pixels cannot be used as a texture,
+    /// also on later calls until the image changes.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    pub(crate) fn prepare(
+        &mut self,
+        context: &GpuContext,
+        handle: &ImageHandle,
+    ) -> Result<Option<TextureKey>, TextureError> {
+        if let Some(&err) = TextureKey::of(handle).and_then(|key| self.rejected.get(&key)) {
+            return Err(err);
+        }
+        let Some(key) = self.key(handle) else {
+            return Ok(None);
+        };
+        if self.external.contains_key(&key.image)
+            || self
+                .textures
+                .get(&key.image)
+                .is_some_and(|texture| texture.version == key.version)
+        {
+            return Ok(Some(key));
+        }
+
+        let Some(data) = handle.data() else {
+            return Ok(None);
+        };
+        let max = context.device.limits().max_texture_dimension_2d;
+        if let Err(err) = check_pixels(data.width, data.height, &data.pixels, max) {
+            self.rejected.insert(key, err);
+            return Err(err);
+        }
+
+        let view = upload(context, data.width, data.height, &data.pixels);
+        self.textures.insert(
+            key.image,
+            GpuTexture {
+                version: key.version,
+                view,
+                size: (data.width, data.height),
+            },
+        );
+        Ok(Some(key))
+    }
+
+    /// Returns the texture for the given key, or the fallback texture.
+    pub(crate) fn view(&self, key: Option<TextureKey>) -> &wgpu::TextureView {
+        let Some(key) = key else {
+            return &self.fallback;
+        };
+        if let Some(external) = self.external.get(&key.image) {
+            return &external.view;
+        }
+        self.textures
+            .get(&key.image)
+            .map_or(&self.fallback, |texture| &texture.view)
+    }
+
+    /// Returns the size in pixels of the texture for the given key.
+    pub(crate) fn size(&self, key: TextureKey) -> Option<(u32, u32)> {
+        if let Some(external) = self.external.get(&key.image) {
+            return Some((external.texture.width(), external.texture.height()));
+        }
+        self.textures.get(&key.image).map(|texture| texture.size)
+    }
+
+    /// Uses `texture` as the contents of an external image.
+    ///
+    /// Returns an error if the texture cannot be sampled by the image fill
+    /// shader.
+    pub(crate) fn set_external(
+        &mut self,
+        handle: &ImageHandle,
+        texture: &wgpu::Texture,
+    ) -> Result<(), TextureError> {
+        let filterable = texture.format().sample_type(None, None)
+            == Some(wgpu::TextureSampleType::Float { filterable: true });
+        if !filterable
+            || !texture
+                .usage()
+                .contains(wgpu::TextureUsages::TEXTURE_BINDING)
+            || texture.dimension() != wgpu::TextureDimension::D2
+            || texture.sample_count() != 1
+        {
+            return Err(TextureError::Unsampleable(texture.format()));
+        }
+        self.insert_external(handle, texture.clone());
+        Ok(())
+    }
+
+    /// Writes RGBA pixels into the texture of an external image, replacing
+    /// the texture only if it cannot hold them.
+    pub(crate) fn write_external(
+        &mut self,
+        context: &GpuContext,
+        handle: &ImageHandle,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+    ) -> Result<(), TextureError> {
+        let max = context.device.limits().max_texture_dimension_2d;
+        check_pixels(width, height, pixels, max)?;
+
+        let reusable = self.external.get(&handle.id()).filter(|external| {
+            let texture = &external.texture;
+            texture.width() == width
+                && texture.height() == height
+                && texture.format() == wgpu::TextureFormat::Rgba8UnormSrgb
+                && texture.usage().contains(wgpu::TextureUsages::COPY_DST)
+        });
+        match reusable {
+            Some(external) => {
+                context.queue.write_texture(
+                    wgpu::TexelCopyTextureInfo {
+                        texture: &external.texture,
+                        mip_level: 0,
+                        origin: wgpu::Origin3d::ZERO,
+                        aspect: wgpu::TextureAspect::All,
+                    },
+                    pixels,
+                    wgpu::TexelCopyBufferLayout {
+                        offset: 0,
+                        bytes_per_row: Some(width * 4),
+                        rows_per_image: Some(height),
+                    },
+                    wgpu::Extent3d {
+                        width,
+                        height,
+                        depth_or_array_layers: 1,
+                    },
+                );
+            }
+            None => {
+                let texture = create_texture(context, width, height, pixels);
+                self.insert_external(handle, texture);
+            }
+        }
+        Ok(())
+    }
+
+    /// Forgets the texture of an external image.
+    pub(crate) fn remove_external(&mut self, handle: &ImageHandle) {
+        self.external.remove(&handle.id());
+    }
+
+    fn insert_external(&mut self, handle: &ImageHandle, texture: wgpu::Texture) {
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        self.next_generation += 1;
+        self.external.insert(
+            handle.id(),
+            ExternalTexture {
+                generation: self.next_generation,
+                texture,
+                view,
+            },
+        );
+    }
+
+    /// Returns the bytes of GPU memory held by all textures, including the
+    /// fallback and external textures.
+    pub(crate) fn memory(&self) -> u64 {
+        let images: u64 = self
+            .textures
+            .values()
+            .map(|texture| u64::from(texture.size.0) * u64::from(texture.size.1) * 4)
+            .sum();
+        let external: u64 = self
+            .external
+            .values()
+            .map(|external| texture_memory(&external.texture))
+            .sum();
+        // The 1×1 RGBA fallback texture.
+        images + external + 4
+    }
+
+    pub(crate) fn sampler(&self) -> &wgpu::Sampler {
+        &self.sampler
+    }
+
+    /// Drops the textures of every image not contained in `live`. External
+    /// textures are kept until removed.
+    pub(crate) fn retain_images(&mut self, live: &HashSet<u64>) {
+        self.textures.retain(|image, _| live.contains(image));
+        self.rejected.retain(|key, _| live.contains(&key.image));
+    }
+}
+
+/// Checks that RGBA pixels of the given size fit in a texture no larger
+/// than `max` on either side.
+fn check_pixels(width: u32, height: u32, pixels: &[u8], max: u32) -> Result<(), TextureError> {
+    if width == 0 || height == 0 || width > max || height > max {
+        return Err(TextureError::Size { width, height });
+    }
+    if pixels.len() != width as usize * height as usize * 4 {
+        return Err(TextureError::PixelBuffer);
+    }
+    Ok(())
+}
+
+/// Returns the bytes of GPU memory held by a texture, counting every mip
+/// level and sample.
+pub(crate) fn texture_memory(texture: &wgpu::Texture) -> u64 {
+    let texel = u64::from(texture.format().block_copy_size(None).unwrap_or(4));
+    let (width, height) = (u64::from(texture.width()), u64::from(texture.height()));
+    let layers = u64::from(texture.depth_or_array_layers());
+    let texels: u64 = (0..texture.mip_level_count())
+        .map(|level| (width >> level).max(1) * (height >> level).max(1))
+        .sum();
+    texels * layers * texel * u64::from(texture.sample_count())
+}
+
+/// Creates an sRGB texture from RGBA pixels.
+fn upload(context: &GpuContext, width: u32, height: u32, pixels: &[u8]) -> wgpu::TextureView {
+    create_texture(context, width, height, pixels)
+        .create_view(&wgpu::TextureViewDescriptor::default())
+}
+
+/// Creates an sRGB texture from RGBA pixels that can be written to later.
+fn create_texture(context: &GpuContext, width: u32, height: u32, pixels: &[u8]) -> wgpu::Texture {
+    context.device.create_texture_with_data(
+        &context.queue,
+        &wgpu::TextureDescriptor {
+            label: Some("Ardent Image Texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        },
+        wgpu::util::TextureDataOrder::LayerMajor,
+        pixels,
+    )
+}
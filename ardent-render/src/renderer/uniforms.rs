@@ -0,0 +1,391 @@
+//! Per-node uniform buffers holding the world transform, fill color,
+//! gradient, and procedural fill parameters, bound together with the node's image texture.
+//!
+//! Keeping these values out of the vertex data means a node that only moved
+//! or changed color reuses its cached mesh; only its small uniform buffer is
+//! rewritten.
+
+use std::collections::{HashMap, HashSet};
+
+use ardent_core::style::{
+    Color, Gradient, NineSlice, Noise, NoiseKind, Pattern, PatternKind, Style,
+};
+use lyon::math::Transform;
+use wgpu::util::DeviceExt;
+
+use crate::gpu::GpuContext;
+
+use super::DrawId;
+use super::textures::{TextureCache, TextureKey};
+
+/// Number of noise ramp stops the shader reads.
+const NOISE_STOPS: usize = 4;
+
+/// Number of noise octaves the shader computes at most.
+const NOISE_OCTAVES: u32 = 8;
+
+/// Number of gradient stops the shader reads.
+const GRADIENT_STOPS: usize = 8;
+
+/// The uniform block consumed by the shader (`NodeUniforms` in WGSL).
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub(crate) struct NodeUniforms {
+    /// Local-to-clip-space transform, column-major.
+    transform: [[f32; 4]; 4],
+
+    /// Fill color as RGBA, with the style opacity applied to alpha.
+    color: [f32; 4],
+
+    /// Stroke color as RGBA, with the style opacity applied to alpha.
+    stroke_color: [f32; 4],
+
+    /// Local-space box an image is stretched over, or zero without an image.
+    image_bounds: [f32; 4],
+
+    /// Nine-slice insets in image pixels as (left, top, right, bottom), or
+    /// zero to stretch the image as a whole.
+    image_slice: [f32; 4],
+
+    /// Image size in pixels in the first two components.
+    image_size: [f32; 4],
+
+    /// Pattern color as RGBA, with the style opacity applied to alpha, or
+    /// zero without a pattern.
+    pattern_color: [f32; 4],
+
+    /// Pattern kind (1 for stripes, 2 for checkers, 3 for dots), spacing,
+    /// width, and angle.
+    pattern: [f32; 4],
+
+    /// Noise kind (0 for none, 1 for value, 2 for simplex), scale, seed
+    /// bits, and octaves.
+    noise: [f32; 4],
+
+    /// Offsets of the noise ramp stops.
+    noise_offsets: [f32; 4],
+
+    /// Colors of the noise ramp stops as RGBA, with the style opacity
+    /// applied to alpha.
+    noise_colors: [[f32; 4]; NOISE_STOPS],
+
+    /// Gradient kind (0 for none, 1 for linear, 2 for radial).
+    gradient: [f32; 4],
+
+    /// Start and end of a linear gradient, or center and radius of a
+    /// radial one.
+    gradient_points: [f32; 4],
+
+    /// Offsets of the gradient stops, four per element.
+    gradient_offsets: [[f32; 4]; GRADIENT_STOPS / 4],
+
+    /// Colors of the gradient stops as RGBA, with the style opacity applied
+    /// to alpha.
+    gradient_colors: [[f32; 4]; GRADIENT_STOPS],
+
+    /// Feather width in device pixels, and the size of a device pixel in
+    /// clip space along x and y, or zero without feathering.
+    feather: [f32; 4],
+}
+
+impl NodeUniforms {
+    /// Builds the uniforms for a node from its clip-space transform and style.
+    pub(crate) fn new(transform: &Transform, style: &Style) -> Self {
+        let fill = style.fill.as_ref();
+        let Color(r, g, b, a) = fill.map_or_else(Color::transparent, |fill| fill.color);
+        let (pattern_color, pattern) = fill
+            .and_then(|fill| fill.pattern.as_ref())
+            .map_or(([0.0; 4], [0.0; 4]), |pattern| {
+                pattern_uniforms(pattern, style.opacity)
+            });
+        let (noise, noise_offsets, noise_colors) = fill
+            .and_then(|fill| fill.noise.as_ref())
+            .map_or(([0.0; 4], [0.0; 4], [[0.0; 4]; NOISE_STOPS]), |noise| {
+                noise_uniforms(noise, style.opacity)
+            });
+        let Color(sr, sg, sb, sa) = style
+            .stroke
+            .as_ref()
+            .map_or_else(Color::transparent, |stroke| stroke.color);
+        let stroke_color = [sr, sg, sb, sa * style.opacity];
+        // Mesh gradients carry their colors in their vertices.
+        let color = match fill.and_then(|fill| fill.mesh.as_ref()) {
+            Some(_) => [1.0, 1.0, 1.0, style.opacity],
+            None => [r, g, b, a * style.opacity],
+        };
+        let uniforms = Self {
+            transform: transform.to_3d().to_arrays(),
+            color,
+            stroke_color,
+            image_bounds: [0.0; 4],
+            image_slice: [0.0; 4],
+            image_size: [0.0; 4],
+            pattern_color,
+            pattern,
+            noise,
+            noise_offsets,
+            noise_colors,
+            gradient: [0.0; 4],
+            gradient_points: [0.0; 4],
+            gradient_offsets: [[0.0; 4]; GRADIENT_STOPS / 4],
+            gradient_colors: [[0.0; 4]; GRADIENT_STOPS],
+            feather: [0.0; 4],
+        };
+        match fill.and_then(|fill| fill.gradient.as_ref()) {
+            Some(gradient) => uniforms.with_gradient(gradient, style.opacity),
+            None => uniforms,
+        }
+    }
+
+    /// Draws a gradient instead of the fill color.
+    ///
+    /// Stops are cut to [`GRADIENT_STOPS`] stops and padded by repeating the
+    /// last one, which the shader's piecewise mix leaves unchanged. Gradients
+    /// without stops leave the fill color.
+    fn with_gradient(self, gradient: &Gradient, opacity: f32) -> Self {
+        let stops = &gradient.stops()[..gradient.stops().len().min(GRADIENT_STOPS)];
+        let Some(last) = stops.len().checked_sub(1) else {
+            return self;
+        };
+        let (kind, points) = match gradient {
+            Gradient::Linear { start, end, .. } => (1.0, [start.0, start.1, end.0, end.1]),
+            Gradient::Radial { center, radius, .. } => {
+                (2.0, [center.0, center.1, radius.max(0.0), 0.0])
+            }
+        };
+        let mut offsets = [[0.0; 4]; GRADIENT_STOPS / 4];
+        let mut colors = [[0.0; 4]; GRADIENT_STOPS];
+        for i in 0..GRADIENT_STOPS {
+            let stop = stops[i.min(last)];
+            let Color(r, g, b, a) = stop.color;
+            offsets[i / 4][i % 4] = stop.offset;
+            colors[i] = [r, g, b, a * opacity];
+        }
+        Self {
+            gradient: [kind, 0.0, 0.0, 0.0],
+            gradient_points: points,
+            gradient_offsets: offsets,
+            gradient_colors: colors,
+            ..self
+        }
+    }
+
+    /// Pushes the outer edge of feathered fringes `width` device pixels out,
+    /// on a viewport of the given size in device pixels.
+    pub(crate) fn with_feather(self, width: Option<f32>, (columns, rows): (u32, u32)) -> Self {
+        let Some(width) = width else {
+            return self;
+        };
+        Self {
+            feather: [
+                width,
+                2.0 / columns.max(1) as f32,
+                2.0 / rows.max(1) as f32,
+                0.0,
+            ],
+            ..self
+        }
+    }
+
+    /// Draws an image stretched over `bounds` instead of the fill color.
+    pub(crate) fn with_image(self, bounds: [f32; 4], opacity: f32) -> Self {
+        Self {
+            color: [1.0, 1.0, 1.0, opacity],
+            image_bounds: bounds,
+            ..self
+        }
+    }
+
+    /// Scales the image in nine slices with the given insets, for an image
+    /// of `width` by `height` pixels.
+    pub(crate) fn with_slice(self, slice: &NineSlice, (width, height): (u32, u32)) -> Self {
+        let (width, height) = (width as f32, height as f32);
+        // Insets covering the whole image leave no center to stretch.
+        let left = slice.left.max(0.0).min(width);
+        let top = slice.top.max(0.0).min(height);
+        Self {
+            image_slice: [
+                left,
+                top,
+                slice.right.max(0.0).min(width - left),
+                slice.bottom.max(0.0).min(height - top),
+            ],
+            image_size: [width, height, 0.0, 0.0],
+            ..self
+        }
+    }
+}
+
+/// Returns the color and parameters of a pattern as laid out in
+/// [`NodeUniforms`].
+fn pattern_uniforms(pattern: &Pattern, opacity: f32) -> ([f32; 4], [f32; 4]) {
+    let kind = match pattern.kind {
+        PatternKind::Stripes => 1.0,
+        PatternKind::Checkers => 2.0,
+        PatternKind::Dots => 3.0,
+    };
+    let Color(r, g, b, a) = pattern.color;
+    (
+        [r, g, b, a * opacity],
+        [
+            kind,
+            pattern.spacing.max(0.0),
+            pattern.width.max(0.0),
+            pattern.angle,
+        ],
+    )
+}
+
+/// Returns the parameters, ramp offsets, and ramp colors of a noise fill as
+/// laid out in [`NodeUniforms`].
+///
+/// Ramps are cut to [`NOISE_STOPS`] stops and padded by repeating the last
+/// one, which the shader's piecewise mix leaves unchanged.
+fn noise_uniforms(noise: &Noise, opacity: f32) -> ([f32; 4], [f32; 4], [[f32; 4]; NOISE_STOPS]) {
+    let kind = match noise.kind {
+        NoiseKind::Value => 1.0,
+        NoiseKind::Simplex => 2.0,
+    };
+    let params = [
+        kind,
+        noise.scale.max(1e-3),
+        f32::from_bits(noise.seed),
+        noise.octaves.clamp(1, NOISE_OCTAVES) as f32,
+    ];
+
+    let default_ramp = [(0.0, Color::black()), (1.0, Color::white())];
+    let stops: Vec<(f32, Color)> = if noise.ramp.is_empty() {
+        default_ramp.to_vec()
+    } else {
+        noise
+            .ramp
+            .iter()
+            .take(NOISE_STOPS)
+            .map(|stop| (stop.offset, stop.color))
+            .collect()
+    };
+    let mut offsets = [0.0; NOISE_STOPS];
+    let mut colors = [[0.0; 4]; NOISE_STOPS];
+    for i in 0..NOISE_STOPS {
+        let (offset, Color(r, g, b, a)) = stops[i.min(stops.len() - 1)];
+        offsets[i] = offset;
+        colors[i] = [r, g, b, a * opacity];
+    }
+    (params, offsets, colors)
+}
+
+/// A node's uniform buffer and the bind group exposing it to the shader.
+struct NodeBinding {
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+
+    /// The image texture bound, or `None` for the fallback texture.
+    texture: Option<TextureKey>,
+}
+
+/// Owns the uniform buffers of all rendered nodes.
+#[derive(Default)]
+pub(crate) struct NodeBindings {
+    bindings: HashMap<DrawId, NodeBinding>,
+}
+
+impl NodeBindings {
+    /// Returns `true` if the node already has a uniform buffer.
+    pub(crate) fn contains(&self, id: DrawId) -> bool {
+        self.bindings.contains_key(&id)
+    }
+
+    /// Returns the image texture bound to the node, if any.
+    pub(crate) fn texture(&self, id: DrawId) -> Option<TextureKey> {
+        self.bindings.get(&id).and_then(|binding| binding.texture)
+    }
+
+    /// Writes the node's uniforms and binds the given texture, creating its
+    /// buffer on first use.
+    pub(crate) fn update(
+        &mut self,
+        context: &GpuContext,
+        layout: &wgpu::BindGroupLayout,
+        id: DrawId,
+        uniforms: &NodeUniforms,
+        textures: &TextureCache,
+        texture: Option<TextureKey>,
+    ) {
+        if let Some(binding) = self.bindings.get_mut(&id) {
+            context
+                .queue
+                .write_buffer(&binding.buffer, 0, bytemuck::bytes_of(uniforms));
+            if binding.texture != texture {
+                binding.bind_group =
+                    bind_group(context, layout, &binding.buffer, textures, texture);
+                binding.texture = texture;
+            }
+            return;
+        }
+
+        let buffer = context
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Ardent Node Uniform Buffer"),
+                contents: bytemuck::bytes_of(uniforms),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            });
+        let bind_group = bind_group(context, layout, &buffer, textures, texture);
+        self.bindings.insert(
+            id,
+            NodeBinding {
+                buffer,
+                bind_group,
+                texture,
+            },
+        );
+    }
+
+    /// Returns the bind group of the given node, if any.
+    pub(crate) fn bind_group(&self, id: DrawId) -> Option<&wgpu::BindGroup> {
+        self.bindings.get(&id).map(|binding| &binding.bind_group)
+    }
+
+    /// Returns the bytes of GPU memory held by all uniform buffers.
+    pub(crate) fn memory(&self) -> u64 {
+        self.bindings
+            .values()
+            .map(|binding| binding.buffer.size())
+            .sum()
+    }
+
+    /// Drops the buffers of every node not contained in `live`.
+    pub(crate) fn retain_nodes(&mut self, live: &HashSet<DrawId>) {
+        self.bindings.retain(|id, _| live.contains(id));
+    }
+}
+
+/// Creates the bind group of a node's uniform buffer and image texture.
+fn bind_group(
+    context: &GpuContext,
+    layout: &wgpu::BindGroupLayout,
+    buffer: &wgpu::Buffer,
+    textures: &TextureCache,
+    texture: Option<TextureKey>,
+) -> wgpu::BindGroup {
+    context
+        .device
+        .create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Ardent Node Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(textures.view(texture)),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(textures.sampler()),
+                },
+            ],
+        })
+}
@@ -0,0 +1,110 @@
+//! Background tessellation on a dedicated worker thread.
+//!
+//! Dirty shapes are queued to the worker, which tessellates them with its own
+//! `FillTessellator`. Finished meshes are handed back over a channel and
+//! uploaded by the renderer on a later frame, keeping the UI thread
+//! responsive during bursts of geometry changes.
+
+use std::collections::HashSet;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::thread;
+
+use lyon::tessellation::{FillTessellator, TessellationError};
+
+use super::cache::ShapeKey;
+use crate::geometry::Geometry;
+use crate::tesselate::{Tesselate, TesselateOptions};
+
+/// A shape queued for tessellation.
+struct Job {
+    key: ShapeKey,
+    source: Box<dyn Tesselate + Send>,
+    options: TesselateOptions,
+}
+
+/// The outcome of a tessellation job.
+pub(crate) struct Finished {
+    pub key: ShapeKey,
+    pub result: Result<Geometry, TessellationError>,
+}
+
+/// Handle to the background tessellation thread.
+///
+/// Dropping the handle closes the job queue, which stops the thread.
+pub(crate) struct TessellationWorker {
+    jobs: Sender<Job>,
+    finished: Receiver<Finished>,
+    in_flight: HashSet<ShapeKey>,
+}
+
+impl TessellationWorker {
+    /// Spawns the worker thread.
+    pub(crate) fn spawn() -> Self {
+        let (jobs, job_rx) = channel::<Job>();
+        let (finished_tx, finished) = channel();
+
+        thread::Builder::new()
+            .name("ardent-tessellation".into())
+            .spawn(move || {
+                let mut tessellator = FillTessellator::new();
+                for job in job_rx {
+                    #[cfg(feature = "tracing")]
+                    let _span = tracing::trace_span!("tessellate_job").entered();
+                    let mut geometry = Geometry::new();
+                    let result = job
+                        .source
+                        .tesselate(&mut geometry, &mut tessellator, &job.options)
+                        .map(|()| geometry);
+                    let finished = Finished {
+                        key: job.key,
+                        result,
+                    };
+                    if finished_tx.send(finished).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("Failed to spawn tessellation thread");
+
+        Self {
+            jobs,
+            finished,
+            in_flight: HashSet::new(),
+        }
+    }
+
+    /// Returns `true` if the shape is currently being tessellated.
+    pub(crate) fn is_in_flight(&self, key: &ShapeKey) -> bool {
+        self.in_flight.contains(key)
+    }
+
+    /// Returns `true` if any submitted job has not been collected yet.
+    pub(crate) fn is_busy(&self) -> bool {
+        !self.in_flight.is_empty()
+    }
+
+    /// Queues a shape for tessellation.
+    pub(crate) fn submit(
+        &mut self,
+        key: ShapeKey,
+        source: Box<dyn Tesselate + Send>,
+        options: TesselateOptions,
+    ) {
+        if self.in_flight.insert(key.clone()) {
+            let _ = self.jobs.send(Job {
+                key,
+                source,
+                options,
+            });
+        }
+    }
+
+    /// Collects all jobs finished since the last call, without blocking.
+    pub(crate) fn drain(&mut self) -> Vec<Finished> {
+        let finished: Vec<Finished> = self.finished.try_iter().collect();
+        for job in &finished {
+            self.in_flight.remove(&job.key);
+        }
+        finished
+    }
+}
@@ -1,18 +1,143 @@
 use crate::geometry::{Geometry, Vertex};
 
+use ardent_core::style::FillRule;
+
 use lyon::path::Path;
-use lyon::tessellation::{BuffersBuilder, FillOptions, FillTessellator, FillVertex};
+use lyon::tessellation::{
+    BuffersBuilder, FillOptions, FillTessellator, FillVertex, GeometryBuilderError,
+    TessellationError,
+};
 
+mod contour;
+mod dash;
+mod effect;
+mod ellipse;
+mod feather;
+mod marker;
+mod mesh;
+mod path;
 mod rect;
+mod ring;
+mod shape;
+mod simplify;
+mod stroke;
+mod trim;
+mod width;
+
+pub use dash::dash_path;
+pub(crate) use effect::Effected;
+pub use effect::apply_effects;
+pub use marker::tessellate_markers;
+pub(crate) use stroke::{Outlined, Stroked};
+pub use trim::trim_path;
+pub use width::tessellate_variable_stroke;
+
+/// Options controlling how shapes are turned into triangles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TesselateOptions {
+    /// Width of the feathered anti-aliasing fringe, in device pixels.
+    ///
+    /// When set, a thin strip whose coverage ramps from opaque to transparent
+    /// is drawn just outside the edges of fills and strokes. This gives
+    /// smooth edges without MSAA, e.g. `Some(1.0)` for a one-pixel ramp at
+    /// any zoom and on any display. `None`, the default, disables
+    /// feathering.
+    pub feather: Option<f32>,
+
+    /// Rule deciding which regions of the outline are filled.
+    ///
+    /// The renderer sets this per node from the node's fill style.
+    pub fill_rule: FillRule,
+
+    /// Tolerance for simplifying dense outlines before tessellation.
+    ///
+    /// When set, points deviating less than this distance (in local units)
+    /// from the simplified outline are dropped using the Ramer–Douglas–Peucker
+    /// algorithm. Useful for paths with thousands of points, such as GPS
+    /// traces. `None` tessellates outlines as given.
+    pub simplify: Option<f32>,
+
+    /// Largest distance, in local units, between a curve and the straight
+    /// segments it is flattened into.
+    ///
+    /// Smaller values give smoother curves at the cost of more triangles,
+    /// e.g. for shapes drawn greatly magnified. The renderer uses a node's
+    /// own tolerance in place of this one if it has one. Must be positive;
+    /// the renderer raises it to at least [`Node::MIN_TOLERANCE`].
+    ///
+    /// [`Node::MIN_TOLERANCE`]: ardent_core::node::Node::MIN_TOLERANCE
+    pub tolerance: f32,
+}
+
+impl Default for TesselateOptions {
+    fn default() -> Self {
+        Self {
+            feather: None,
+            fill_rule: FillRule::NonZero,
+            simplify: None,
+            tolerance: FillOptions::DEFAULT_TOLERANCE,
+        }
+    }
+}
 
 pub trait Tesselate {
     fn path(&self) -> Path;
 
-    fn tesselate(&self, geometry: &mut Geometry, tessellator: &mut FillTessellator) {
-        let _ = tessellator.tessellate_path(
-            &self.path(),
-            &FillOptions::default(),
-            &mut BuffersBuilder::new(geometry, |v: FillVertex| Vertex::from_fill_vertex(v)),
-        );
+    /// Appends the triangles of this shape to `geometry`.
+    ///
+    /// Returns an error if the outline could not be tessellated, e.g. because
+    /// it contains non-finite coordinates.
+    fn tesselate(
+        &self,
+        geometry: &mut Geometry,
+        tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        fill_path(&self.path(), geometry, tessellator, options)
+    }
+}
+
+/// Appends the triangles filling `path` to `geometry`, simplified and
+/// feathered as the options ask.
+fn fill_path(
+    path: &Path,
+    geometry: &mut Geometry,
+    tessellator: &mut FillTessellator,
+    options: &TesselateOptions,
+) -> Result<(), TessellationError> {
+    let fill_options = FillOptions::default()
+        .with_tolerance(options.tolerance)
+        .with_fill_rule(match options.fill_rule {
+            FillRule::NonZero => lyon::tessellation::FillRule::NonZero,
+            FillRule::EvenOdd => lyon::tessellation::FillRule::EvenOdd,
+        });
+    let simplified;
+    let path = match options.simplify {
+        Some(tolerance) => {
+            simplified = simplify::simplify(path, tolerance, options.tolerance);
+            &simplified
+        }
+        None => path,
+    };
+    tessellator.tessellate_path(
+        path,
+        &fill_options,
+        &mut BuffersBuilder::new(geometry, |v: FillVertex| Vertex::from_fill_vertex(v)),
+    )?;
+    if options.feather.is_some() {
+        feather::add_fringe(path, options.tolerance, geometry)?;
+    }
+    Ok(())
+}
+
+/// Returns the index of the next vertex appended to `geometry`, or an error
+/// if `count` more vertices do not fit in 16-bit indices.
+fn base_index(geometry: &Geometry, count: usize) -> Result<u16, TessellationError> {
+    let base = geometry.vertices.len();
+    if base + count > u16::MAX as usize + 1 {
+        return Err(TessellationError::GeometryBuilder(
+            GeometryBuilderError::TooManyVertices,
+        ));
     }
+    Ok(base as u16)
 }
@@ -0,0 +1,59 @@
+//! Flattened outlines shared by the post-processing steps of tessellation.
+
+use lyon::math::Point;
+use lyon::path::iterator::PathIterator;
+use lyon::path::{Event, Path};
+
+/// A single sub-path of an outline, approximated by straight segments.
+pub(crate) struct Contour {
+    /// The polyline vertices. For closed contours, the closing point
+    /// (equal to the first point) is not repeated.
+    pub points: Vec<Point>,
+
+    /// Whether the sub-path was explicitly closed.
+    pub closed: bool,
+}
+
+/// Flattens a path into polylines within the given tolerance.
+pub(crate) fn flatten(path: &Path, tolerance: f32) -> Vec<Contour> {
+    let mut contours = Vec::new();
+    let mut current = Vec::new();
+
+    for event in path.iter().flattened(tolerance) {
+        match event {
+            Event::Begin { at } => {
+                current.clear();
+                current.push(at);
+            }
+            Event::Line { to, .. } => current.push(to),
+            Event::End { close, .. } => {
+                if current.len() > 1 && current.first() == current.last() {
+                    current.pop();
+                }
+                contours.push(Contour {
+                    points: std::mem::take(&mut current),
+                    closed: close,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    contours
+}
+
+/// Rebuilds a path of straight segments from flattened contours.
+pub(crate) fn to_path(contours: &[Contour]) -> Path {
+    let mut builder = Path::builder();
+    for contour in contours {
+        let Some((first, rest)) = contour.points.split_first() else {
+            continue;
+        };
+        builder.begin(*first);
+        for point in rest {
+            builder.line_to(*point);
+        }
+        builder.end(contour.closed);
+    }
+    builder.build()
+}
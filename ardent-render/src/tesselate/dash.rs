@@ -0,0 +1,229 @@
+//! Dash patterns along outlines.
+//!
+//! The outline is flattened and walked segment by segment, switching
+//! between dashes and gaps at the lengths given by the pattern, so dashes
+//! are spaced by arc length and bend around curves.
+
+use super::contour::{self, Contour};
+
+use lyon::math::Point;
+use lyon::path::Path;
+
+/// Most dashes drawn along an outline, bounding the cost of tiny patterns
+/// on long outlines.
+const MAX_DASHES: usize = 100_000;
+
+/// Returns the dashes of `path` for the pattern of alternating dash and gap
+/// lengths `dashes`, starting `offset` into the pattern.
+///
+/// The pattern restarts on every sub-path, and a pattern of odd length is
+/// repeated to make it even. The result consists of open polylines, one
+/// per dash; zero-length dashes become zero-length polylines, which round
+/// and square caps draw as dots. `path` is returned unchanged for patterns
+/// with negative lengths or no length at all.
+pub fn dash_path(path: &Path, dashes: &[f32], offset: f32, tolerance: f32) -> Path {
+    let period: f32 = dashes.iter().sum();
+    if dashes.iter().any(|&length| length < 0.0) || period <= 0.0 || !period.is_finite() {
+        return path.clone();
+    }
+    let pattern: Vec<f32> = if dashes.len() % 2 == 1 {
+        dashes.iter().chain(dashes).copied().collect()
+    } else {
+        dashes.to_vec()
+    };
+    let period = period * (pattern.len() / dashes.len()) as f32;
+
+    let mut result = Vec::new();
+    for c in contour::flatten(path, tolerance) {
+        let mut points = c.points;
+        if c.closed
+            && let Some(&first) = points.first()
+        {
+            points.push(first);
+        }
+
+        // Find where in the pattern the sub-path starts.
+        let mut index = 0;
+        let mut remaining = pattern[0];
+        let mut skip = offset.rem_euclid(period);
+        while skip > 0.0 {
+            if skip < remaining {
+                remaining -= skip;
+                break;
+            }
+            skip -= remaining;
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+
+        let mut current: Vec<Point> = Vec::new();
+        if index % 2 == 0
+            && let Some(&first) = points.first()
+        {
+            current.push(first);
+        }
+        for segment in points.windows(2) {
+            let (a, b) = (segment[0], segment[1]);
+            let length = (b - a).length();
+            if length <= 0.0 {
+                continue;
+            }
+            let mut along = 0.0;
+            while length - along >= remaining {
+                if result.len() >= MAX_DASHES {
+                    return contour::to_path(&result);
+                }
+                along += remaining;
+                let p = a.lerp(b, along / length);
+                if index % 2 == 0 {
+                    // The dash ends here.
+                    current.push(p);
+                    result.push(Contour {
+                        points: std::mem::take(&mut current),
+                        closed: false,
+                    });
+                } else {
+                    // A dash starts here.
+                    current.push(p);
+                }
+                index = (index + 1) % pattern.len();
+                remaining = pattern[index];
+            }
+            remaining -= length - along;
+            // A dash starting at the end of the segment continues on the
+            // next one from the same point.
+            if index % 2 == 0 && current.last() != Some(&b) {
+                current.push(b);
+            }
+        }
+        if current.len() > 1 {
+            result.push(Contour {
+                points: current,
+                closed: false,
+            });
+        }
+    }
+    contour::to_path(&result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use lyon::math::point;
+    use lyon::path::PathEvent;
+
+    /// Returns a path through `points`, closed if asked.
+    fn polyline(points: &[(f32, f32)], closed: bool) -> Path {
+        let mut builder = Path::builder();
+        builder.begin(point(points[0].0, points[0].1));
+        for &(x, y) in &points[1..] {
+            builder.line_to(point(x, y));
+        }
+        builder.end(closed);
+        builder.build()
+    }
+
+    /// Returns the dashes of `path` as lists of points.
+    fn dashes(path: &Path, pattern: &[f32], offset: f32) -> Vec<Vec<(f32, f32)>> {
+        let mut dashes = Vec::new();
+        for event in dash_path(path, pattern, offset, 0.01).iter() {
+            match event {
+                PathEvent::Begin { at } => dashes.push(vec![(at.x, at.y)]),
+                PathEvent::Line { to, .. } => {
+                    dashes.last_mut().expect("dash begun").push((to.x, to.y));
+                }
+                _ => {}
+            }
+        }
+        dashes
+    }
+
+    fn assert_dashes(actual: Vec<Vec<(f32, f32)>>, expected: &[&[(f32, f32)]]) {
+        let close = |a: &Vec<(f32, f32)>, b: &&[(f32, f32)]| {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b.iter())
+                    .all(|(a, b)| (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4)
+        };
+        assert!(
+            actual.len() == expected.len() && actual.iter().zip(expected).all(|(a, b)| close(a, b)),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn odd_patterns_repeat_to_become_even() {
+        let line = polyline(&[(0.0, 0.0), (10.0, 0.0)], false);
+        assert_dashes(
+            dashes(&line, &[2.0], 0.0),
+            &[
+                &[(0.0, 0.0), (2.0, 0.0)],
+                &[(4.0, 0.0), (6.0, 0.0)],
+                &[(8.0, 0.0), (10.0, 0.0)],
+            ],
+        );
+        // Dashes and gaps swap roles on the repeat.
+        assert_dashes(
+            dashes(&line, &[1.0, 2.0, 3.0], 0.0),
+            &[
+                &[(0.0, 0.0), (1.0, 0.0)],
+                &[(3.0, 0.0), (6.0, 0.0)],
+                &[(7.0, 0.0), (9.0, 0.0)],
+            ],
+        );
+    }
+
+    #[test]
+    fn zero_length_dashes_become_dots() {
+        let line = polyline(&[(0.0, 0.0), (10.0, 0.0)], false);
+        assert_dashes(
+            dashes(&line, &[0.0, 5.0], 0.0),
+            &[
+                &[(0.0, 0.0), (0.0, 0.0)],
+                &[(5.0, 0.0), (5.0, 0.0)],
+                &[(10.0, 0.0), (10.0, 0.0)],
+            ],
+        );
+    }
+
+    #[test]
+    fn negative_offsets_wrap_around_the_pattern() {
+        let line = polyline(&[(0.0, 0.0), (10.0, 0.0)], false);
+        let expected: &[&[(f32, f32)]] = &[&[(1.0, 0.0), (3.0, 0.0)], &[(6.0, 0.0), (8.0, 0.0)]];
+        assert_dashes(dashes(&line, &[2.0, 3.0], -1.0), expected);
+        assert_dashes(dashes(&line, &[2.0, 3.0], 4.0), expected);
+        assert_dashes(dashes(&line, &[2.0, 3.0], -6.0), expected);
+    }
+
+    #[test]
+    fn closed_contours_are_dashed_around_corners_and_the_closing_edge() {
+        let square = polyline(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)], true);
+        assert_dashes(
+            dashes(&square, &[15.0, 5.0], 0.0),
+            &[
+                &[(0.0, 0.0), (10.0, 0.0), (10.0, 5.0)],
+                &[(10.0, 10.0), (0.0, 10.0), (0.0, 5.0)],
+            ],
+        );
+    }
+
+    #[test]
+    fn dashes_stop_at_the_limit() {
+        let line = polyline(&[(0.0, 0.0), (1000.0, 0.0)], false);
+        assert_eq!(dashes(&line, &[0.001, 0.001], 0.0).len(), MAX_DASHES);
+    }
+
+    #[test]
+    fn invalid_patterns_leave_the_path_unchanged() {
+        let line = polyline(&[(0.0, 0.0), (10.0, 0.0)], false);
+        assert_dashes(
+            dashes(&line, &[2.0, -1.0], 0.0),
+            &[&[(0.0, 0.0), (10.0, 0.0)]],
+        );
+        assert_dashes(
+            dashes(&line, &[0.0, 0.0], 0.0),
+            &[&[(0.0, 0.0), (10.0, 0.0)]],
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Path effects applied to outlines before tessellation.
+//!
+//! Outlines are flattened first, and each effect maps the flattened
+//! contours to new ones, so effects compose in any order and curves come
+//! out as finely divided as the tessellation tolerance asks.
+
+use super::contour::{self, Contour};
+use super::{Tesselate, TesselateOptions, fill_path};
+
+use crate::geometry::Geometry;
+
+use ardent_core::shape::Shape;
+use ardent_core::style::PathEffect;
+
+use lyon::geom::QuadraticBezierSegment;
+use lyon::math::{Point, Vector, vector};
+use lyon::path::Path;
+use lyon::tessellation::{FillOptions, FillTessellator, TessellationError};
+
+/// Most points a jittered contour is resampled into, bounding the cost of
+/// tiny spacings on long outlines.
+const MAX_JITTER_POINTS: f32 = 10_000.0;
+
+/// Largest miter of offset corners, as a multiple of the offset distance.
+const MITER_LIMIT: f32 = 4.0;
+
+/// A shape whose outline is changed by path effects before it is filled.
+pub(crate) struct Effected {
+    pub(crate) shape: Shape,
+    pub(crate) effects: Vec<PathEffect>,
+}
+
+impl Effected {
+    /// Returns the changed outline, flattened within `tolerance`.
+    fn outline(&self, tolerance: f32) -> Path {
+        apply_effects(&self.shape.path(), &self.effects, tolerance)
+    }
+}
+
+impl Tesselate for Effected {
+    fn path(&self) -> Path {
+        self.outline(FillOptions::DEFAULT_TOLERANCE)
+    }
+
+    fn tesselate(
+        &self,
+        geometry: &mut Geometry,
+        tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        fill_path(
+            &self.outline(options.tolerance),
+            geometry,
+            tessellator,
+            options,
+        )
+    }
+}
+
+/// Returns `path` changed by `effects` in order, flattened within
+/// `tolerance`.
+///
+/// The result consists of straight segments; without effects, `path` is
+/// returned unchanged.
+pub fn apply_effects(path: &Path, effects: &[PathEffect], tolerance: f32) -> Path {
+    if effects.is_empty() {
+        return path.clone();
+    }
+    let mut contours = contour::flatten(path, tolerance);
+    for effect in effects {
+        contours = match *effect {
+            PathEffect::RoundCorners(radius) => contours
+                .iter()
+                .map(|c| round_corners(c, radius, tolerance))
+                .collect(),
+            PathEffect::Offset(distance) => {
+                // Holes move the other way, so they shrink as the shape grows.
+                let holes = holes(&contours);
+                contours
+                    .iter()
+                    .zip(holes)
+                    .map(|(c, hole)| offset(c, if hole { -distance } else { distance }))
+                    .collect()
+            }
+            PathEffect::Jitter {
+                amount,
+                spacing,
+                seed,
+            } => {
+                let mut index = 0;
+                contours
+                    .iter()
+                    .map(|c| jitter(c, amount, spacing, seed, &mut index))
+                    .collect()
+            }
+        };
+    }
+    contour::to_path(&contours)
+}
+
+/// Replaces the corners of a contour with quadratic curves. The ends of
+/// open contours are kept.
+fn round_corners(contour: &Contour, radius: f32, tolerance: f32) -> Contour {
+    let points = &contour.points;
+    let n = points.len();
+    let mut rounded = Vec::with_capacity(n);
+    for (i, &p) in points.iter().enumerate() {
+        let is_corner = n > 2 && (contour.closed || (i > 0 && i < n - 1));
+        if !is_corner || radius <= 0.0 {
+            rounded.push(p);
+            continue;
+        }
+        let (to_prev, to_next) = (points[(i + n - 1) % n] - p, points[(i + 1) % n] - p);
+        let d = radius
+            .min(to_prev.length() / 2.0)
+            .min(to_next.length() / 2.0);
+        if d <= 0.0 {
+            rounded.push(p);
+            continue;
+        }
+        let curve = QuadraticBezierSegment {
+            from: p + to_prev.normalize() * d,
+            ctrl: p,
+            to: p + to_next.normalize() * d,
+        };
+        rounded.push(curve.from);
+        curve.for_each_flattened(tolerance, &mut |line| rounded.push(line.to));
+    }
+    Contour {
+        points: rounded,
+        closed: contour.closed,
+    }
+}
+
+/// Moves a contour along its normals by `distance`, away from its own
+/// interior for closed contours and to the left of the direction of travel
+/// for open ones.
+fn offset(contour: &Contour, distance: f32) -> Contour {
+    let mut points = contour.points.clone();
+    points.dedup();
+    let n = points.len();
+    let closed = contour.closed;
+    let sign = if closed && signed_area(&points) < 0.0 {
+        -1.0
+    } else {
+        1.0
+    };
+    let normal = |a: Point, b: Point| {
+        let d = b - a;
+        vector(d.y, -d.x).normalize() * sign
+    };
+    let offset = (0..n)
+        .map(|i| {
+            let p = points[i];
+            let incoming = (closed || i > 0).then(|| normal(points[(i + n - 1) % n], p));
+            let outgoing = (closed || i + 1 < n).then(|| normal(p, points[(i + 1) % n]));
+            let (a, b) = match (incoming, outgoing) {
+                (Some(a), Some(b)) => (a, b),
+                (Some(a), None) | (None, Some(a)) => (a, a),
+                (None, None) => return p,
+            };
+            let sum = a + b;
+            let miter = if sum.square_length() > 1e-6 {
+                sum.normalize()
+            } else {
+                a
+            };
+            p + miter * (distance / miter.dot(a).max(1.0 / MITER_LIMIT))
+        })
+        .collect();
+    Contour {
+        points: offset,
+        closed,
+    }
+}
+
+/// Returns for each contour whether it bounds a hole: a closed contour
+/// lying inside an odd number of other closed contours.
+fn holes(contours: &[Contour]) -> Vec<bool> {
+    contours
+        .iter()
+        .enumerate()
+        .map(|(i, contour)| {
+            let Some(&point) = contour.points.first() else {
+                return false;
+            };
+            let enclosing = contours
+                .iter()
+                .enumerate()
+                .filter(|&(j, other)| j != i && other.closed && encloses(&other.points, point))
+                .count();
+            contour.closed && enclosing % 2 == 1
+        })
+        .collect()
+}
+
+/// Returns `true` if `point` lies inside the polygon through `points`.
+fn encloses(points: &[Point], point: Point) -> bool {
+    let n = points.len();
+    let mut inside = false;
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Resamples a contour every `spacing` units and displaces the points
+/// along its normals by random amounts up to `amount`.
+fn jitter(contour: &Contour, amount: f32, spacing: f32, seed: u32, index: &mut u32) -> Contour {
+    let mut polyline = contour.points.clone();
+    if contour.closed
+        && let Some(&first) = polyline.first()
+    {
+        polyline.push(first);
+    }
+    let length: f32 = polyline.windows(2).map(|s| (s[1] - s[0]).length()).sum();
+    if polyline.len() < 2 || length <= 0.0 {
+        return Contour {
+            points: contour.points.clone(),
+            closed: contour.closed,
+        };
+    }
+    let spacing = spacing.max(length / MAX_JITTER_POINTS);
+
+    // Sample evenly spaced points with the direction of the segment each
+    // falls on.
+    let mut samples: Vec<(Point, Vector)> = Vec::new();
+    let mut next = 0.0;
+    let mut offset = 0.0;
+    for segment in polyline.windows(2) {
+        let (a, b) = (segment[0], segment[1]);
+        let d = b - a;
+        let segment_length = d.length();
+        while next <= offset + segment_length && segment_length > 0.0 {
+            samples.push((a + d * ((next - offset) / segment_length), d));
+            next += spacing;
+        }
+        offset += segment_length;
+    }
+    if !contour.closed
+        && let (Some(&last), Some(segment)) = (polyline.last(), polyline.windows(2).last())
+        && samples.last().is_none_or(|&(p, _)| p != last)
+    {
+        samples.push((last, segment[1] - segment[0]));
+    }
+    if contour.closed && samples.len() > 1 {
+        // The closing sample would repeat the first.
+        samples.pop_if(|(p, _)| (*p - polyline[0]).length() < spacing * 0.5);
+    }
+
+    let points = samples
+        .into_iter()
+        .map(|(p, d)| {
+            *index = index.wrapping_add(1);
+            p + vector(d.y, -d.x).normalize() * (amount * random(seed, *index))
+        })
+        .collect();
+    Contour {
+        points,
+        closed: contour.closed,
+    }
+}
+
+/// Returns twice the signed area enclosed by a closed polyline.
+fn signed_area(points: &[Point]) -> f32 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum()
+}
+
+/// Returns a pseudo-random value in [-1.0, 1.0] determined by `seed` and
+/// `index`.
+fn random(seed: u32, index: u32) -> f32 {
+    let mut x = seed.wrapping_mul(0x9E37_79B9) ^ index.wrapping_mul(0x85EB_CA6B);
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7FEB_352D);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846C_A68B);
+    x ^= x >> 16;
+    x as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
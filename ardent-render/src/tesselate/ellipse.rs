@@ -0,0 +1,47 @@
+use super::{Tesselate, TesselateOptions, feather};
+
+use crate::geometry::{Geometry, Vertex};
+
+use ardent_core::shape::Ellipse;
+
+use lyon::math::{Angle, point, vector};
+use lyon::path::{Path, Winding};
+use lyon::tessellation::{BuffersBuilder, FillOptions, FillTessellator, TessellationError};
+
+impl Tesselate for Ellipse {
+    fn path(&self) -> Path {
+        let mut builder = Path::builder();
+        builder.add_ellipse(
+            point(self.rx, self.ry),
+            vector(self.rx, self.ry),
+            Angle::zero(),
+            Winding::Positive,
+        );
+        builder.build()
+    }
+
+    /// Tessellates the ellipse with lyon's dedicated ellipse routine, which
+    /// flattens it without building a path first.
+    ///
+    /// An ellipse never overlaps itself, so the fill rule is ignored.
+    fn tesselate(
+        &self,
+        geometry: &mut Geometry,
+        tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        let fill_options = FillOptions::default().with_tolerance(options.tolerance);
+        tessellator.tessellate_ellipse(
+            point(self.rx, self.ry),
+            vector(self.rx, self.ry),
+            Angle::zero(),
+            Winding::Positive,
+            &fill_options,
+            &mut BuffersBuilder::new(geometry, Vertex::from_fill_vertex),
+        )?;
+        if options.feather.is_some() {
+            feather::add_fringe(&self.path(), fill_options.tolerance, geometry)?;
+        }
+        Ok(())
+    }
+}
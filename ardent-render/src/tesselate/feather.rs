@@ -0,0 +1,189 @@
+//! Feathered edges for anti-aliasing without MSAA.
+//!
+//! A fringe of zero-area quads is laid along the edge of the shape. Its
+//! inner vertices sit on the edge and are fully covered; its outer vertices
+//! also sit on the edge but have zero coverage and carry the direction they
+//! are pushed outwards in. The vertex shader moves them by the feather width
+//! in device pixels, so the fringe lies outside the edge only and stays the
+//! same width at any zoom, and the rasterizer interpolates a smooth alpha
+//! ramp across it.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::{base_index, contour};
+use crate::geometry::{Geometry, Vertex};
+
+use lyon::math::{Point, Vector, point, vector};
+use lyon::path::Path;
+use lyon::tessellation::TessellationError;
+
+/// Upper bound on miter extension at sharp corners, in multiples of the
+/// feather width. Prevents spikes on very acute angles.
+const MITER_LIMIT: f32 = 4.0;
+
+/// Appends a feathered fringe around the outline of `path` to `geometry`.
+///
+/// Returns an error if the fringe does not fit in 16-bit indices.
+pub(crate) fn add_fringe(
+    path: &Path,
+    tolerance: f32,
+    geometry: &mut Geometry,
+) -> Result<(), TessellationError> {
+    let contours: Vec<Vec<Point>> = contour::flatten(path, tolerance)
+        .into_iter()
+        .map(|c| c.points)
+        .collect();
+
+    // With consistently oriented contours (holes wound opposite to their
+    // outer contour), the filled region lies on the same side of the travel
+    // direction for every contour, so a single orientation applies to all.
+    let area: f32 = contours.iter().map(|c| signed_area(c)).sum();
+    let orientation = if area >= 0.0 { 1.0 } else { -1.0 };
+
+    for contour in &contours {
+        let count = contour.len();
+        if count < 3 {
+            continue;
+        }
+
+        let base = base_index(geometry, count * 2)?;
+        for i in 0..count {
+            let prev = contour[(i + count - 1) % count];
+            let point = contour[i];
+            let next = contour[(i + 1) % count];
+
+            let n0 = outward(point - prev, orientation);
+            let n1 = outward(next - point, orientation);
+            geometry.vertices.push(Vertex::new(point.x, point.y, 1.0));
+            geometry
+                .vertices
+                .push(Vertex::fringe(point.x, point.y, miter(n0, n1)));
+        }
+
+        let count = count as u16;
+        for i in 0..count {
+            let j = (i + 1) % count;
+            let (in_i, out_i) = (base + i * 2, base + i * 2 + 1);
+            let (in_j, out_j) = (base + j * 2, base + j * 2 + 1);
+            geometry
+                .indices
+                .extend_from_slice(&[in_i, out_i, out_j, in_i, out_j, in_j]);
+        }
+    }
+    Ok(())
+}
+
+/// Appends a feathered fringe around the triangles `geometry` holds from
+/// index `first` on, e.g. the band of a stroke, whose outline is not a path.
+///
+/// The edge is made of the triangle sides not shared with another triangle.
+/// Vertices at the same position are treated as one, so seams between the
+/// pieces of a stroke get no fringe. Returns an error if the fringe does
+/// not fit in 16-bit indices.
+pub(crate) fn add_mesh_fringe(
+    geometry: &mut Geometry,
+    first: usize,
+) -> Result<(), TessellationError> {
+    let mut welded: HashMap<(u32, u32), u16> = HashMap::new();
+    // Sides by their ends in either order, with the direction they run in
+    // within their triangle, the triangle's orientation, and how many
+    // triangles share them.
+    let mut sides: BTreeMap<(u16, u16), (u16, u16, f32, usize)> = BTreeMap::new();
+    for triangle in geometry.indices[first..].chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| {
+            let [x, y] = geometry.vertices[triangle[i] as usize].position;
+            *welded
+                .entry((x.to_bits(), y.to_bits()))
+                .or_insert(triangle[i])
+        });
+        let area = signed_area(&[a, b, c].map(|i| position(geometry, i)));
+        if area == 0.0 {
+            continue;
+        }
+        let orientation = if area > 0.0 { 1.0 } else { -1.0 };
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            sides
+                .entry((from.min(to), from.max(to)))
+                .and_modify(|side| side.3 += 1)
+                .or_insert((from, to, orientation, 1));
+        }
+    }
+    let edges: Vec<(u16, u16, f32)> = sides
+        .into_values()
+        .filter(|side| side.3 == 1)
+        .map(|(from, to, orientation, _)| (from, to, orientation))
+        .collect();
+
+    // The outside of an edge lies on the same side of its direction as
+    // for the triangle it belongs to.
+    let mut normals: BTreeMap<u16, Vec<Vector>> = BTreeMap::new();
+    for &(from, to, orientation) in &edges {
+        let edge = position(geometry, to) - position(geometry, from);
+        let normal = outward(edge, orientation);
+        normals.entry(from).or_default().push(normal);
+        normals.entry(to).or_default().push(normal);
+    }
+
+    let base = base_index(geometry, normals.len())?;
+    let mut outer = HashMap::with_capacity(normals.len());
+    for (i, (index, edge_normals)) in normals.into_iter().enumerate() {
+        let direction = match edge_normals.as_slice() {
+            &[n0, n1] => miter(n0, n1),
+            all => all
+                .iter()
+                .fold(Vector::zero(), |sum, &n| sum + n)
+                .try_normalize()
+                .unwrap_or_default(),
+        };
+        let p = position(geometry, index);
+        geometry.vertices.push(Vertex::fringe(p.x, p.y, direction));
+        outer.insert(index, base + i as u16);
+    }
+    for (from, to, _) in edges {
+        let (out_from, out_to) = (outer[&from], outer[&to]);
+        geometry
+            .indices
+            .extend_from_slice(&[from, out_from, out_to, from, out_to, to]);
+    }
+    Ok(())
+}
+
+/// Returns the position of the vertex at `index`.
+fn position(geometry: &Geometry, index: u16) -> Point {
+    let [x, y] = geometry.vertices[index as usize].position;
+    point(x, y)
+}
+
+/// Computes twice the signed area of a polygon (shoelace formula).
+fn signed_area(points: &[Point]) -> f32 {
+    let count = points.len();
+    (0..count)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % count]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum()
+}
+
+/// Returns the unit normal of an edge pointing away from the filled region.
+fn outward(edge: Vector, orientation: f32) -> Vector {
+    let normal = vector(edge.y, -edge.x) * orientation;
+    let length = normal.length();
+    if length > 0.0 {
+        normal / length
+    } else {
+        normal
+    }
+}
+
+/// Combines two adjacent edge normals into a miter direction.
+fn miter(n0: Vector, n1: Vector) -> Vector {
+    let sum = n0 + n1;
+    let length = sum.length();
+    if length <= f32::EPSILON {
+        return n0;
+    }
+    let direction = sum / length;
+    let cos = direction.dot(n0).max(1.0 / MITER_LIMIT);
+    direction / cos
+}
@@ -0,0 +1,158 @@
+//! Markers such as arrowheads placed at the vertices of stroked paths.
+//!
+//! Vertices and their directions come from the path's segments rather than
+//! from a flattened outline, so mid markers appear only where segments
+//! meet and not along curves.
+
+use super::Tesselate;
+
+use crate::geometry::{Geometry, Vertex};
+
+use ardent_core::style::{Marker, MarkerKind, Stroke};
+
+use lyon::math::{Angle, Point, Transform, Vector, point};
+use lyon::path::{Event, Path, Winding};
+use lyon::tessellation::{
+    BuffersBuilder, FillOptions, FillTessellator, FillVertex, TessellationError,
+};
+
+/// A vertex of a path with the directions of the segments around it.
+struct PathVertex {
+    at: Point,
+    incoming: Option<Vector>,
+    outgoing: Option<Vector>,
+}
+
+/// Appends the triangles of the markers of `stroke` drawn along `path` to
+/// `geometry`.
+///
+/// Markers are scaled by the stroke width and filled like shapes; the
+/// caller draws them in the stroke color.
+pub fn tessellate_markers(
+    path: &Path,
+    stroke: &Stroke,
+    tolerance: f32,
+    tessellator: &mut FillTessellator,
+    geometry: &mut Geometry,
+) -> Result<(), TessellationError> {
+    let markers = &stroke.markers;
+    if markers.is_empty() {
+        return Ok(());
+    }
+    let vertices = vertices(path);
+    let last = vertices.len().saturating_sub(1);
+    let options = FillOptions::default().with_tolerance(tolerance);
+
+    for (i, vertex) in vertices.iter().enumerate() {
+        let (marker, direction) = if i == 0 {
+            let direction = vertex.outgoing.or(vertex.incoming).map(|d| -d);
+            (markers.start.as_ref(), direction)
+        } else if i == last {
+            (markers.end.as_ref(), vertex.incoming.or(vertex.outgoing))
+        } else {
+            (markers.mid.as_ref(), bisector(vertex))
+        };
+        let Some(marker) = marker else {
+            continue;
+        };
+        let angle = direction.map_or(Angle::zero(), |d| d.angle_from_x_axis());
+        let transform = Transform::scale(marker.size * stroke.width, marker.size * stroke.width)
+            .then_rotate(angle)
+            .then_translate(vertex.at.to_vector());
+        tessellator.tessellate_path(
+            &outline(marker).transformed(&transform),
+            &options,
+            &mut BuffersBuilder::new(geometry, |v: FillVertex| Vertex::from_fill_vertex(v)),
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns the vertices of all sub-paths of `path` in drawing order.
+///
+/// Closed sub-paths end with a vertex back at their start, and their
+/// first vertex takes the direction of the closing segment as incoming.
+fn vertices(path: &Path) -> Vec<PathVertex> {
+    let mut vertices: Vec<PathVertex> = Vec::new();
+    let mut start = 0;
+    for event in path.iter() {
+        match event {
+            Event::Begin { at } => {
+                start = vertices.len();
+                vertices.push(PathVertex {
+                    at,
+                    incoming: None,
+                    outgoing: None,
+                });
+            }
+            Event::Line { from, to } => segment(&mut vertices, [from, to, from, to]),
+            Event::Quadratic { from, ctrl, to } => {
+                segment(&mut vertices, [from, ctrl, ctrl, to]);
+            }
+            Event::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => segment(&mut vertices, [from, ctrl1, ctrl2, to]),
+            Event::End { last, first, close } => {
+                if close && last != first {
+                    segment(&mut vertices, [last, first, last, first]);
+                }
+                if close && let Some(closing) = vertices.last().and_then(|v| v.incoming) {
+                    vertices[start].incoming = Some(closing);
+                }
+            }
+        }
+    }
+    vertices
+}
+
+/// Appends the end of a segment to `vertices`, given its start, the points
+/// its tangents at the start and end aim from, and its end.
+///
+/// Tangents fall back to the chord when control points coincide with the
+/// ends.
+fn segment(vertices: &mut Vec<PathVertex>, [from, out, into, to]: [Point; 4]) {
+    let direction = |a: Point, b: Point| Some(b - a).filter(|d| d.square_length() > 0.0);
+    let chord = direction(from, to);
+    if let Some(last) = vertices.last_mut() {
+        last.outgoing = direction(from, out).or(chord);
+    }
+    vertices.push(PathVertex {
+        at: to,
+        incoming: direction(into, to).or(chord),
+        outgoing: None,
+    });
+}
+
+/// Returns the direction halfway between the segments meeting at `vertex`.
+fn bisector(vertex: &PathVertex) -> Option<Vector> {
+    match (vertex.incoming, vertex.outgoing) {
+        (Some(incoming), Some(outgoing)) => {
+            let sum = incoming.normalize() + outgoing.normalize();
+            Some(if sum.square_length() > 1e-6 {
+                sum
+            } else {
+                incoming
+            })
+        }
+        (incoming, outgoing) => incoming.or(outgoing),
+    }
+}
+
+/// Returns the outline of a marker of unit size pointing along the x-axis.
+fn outline(marker: &Marker) -> Path {
+    let mut builder = Path::builder();
+    match &marker.kind {
+        MarkerKind::Arrow => {
+            builder.begin(point(0.0, 0.0));
+            builder.line_to(point(-1.0, -0.5));
+            builder.line_to(point(-1.0, 0.5));
+            builder.close();
+        }
+        MarkerKind::Dot => builder.add_circle(point(0.0, 0.0), 0.5, Winding::Positive),
+        MarkerKind::Custom(path) => return path.path(),
+    }
+    builder.build()
+}
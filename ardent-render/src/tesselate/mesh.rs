@@ -0,0 +1,200 @@
+use super::{Tesselate, TesselateOptions, base_index};
+
+use crate::geometry::{Geometry, Vertex};
+
+use ardent_core::style::{Color, MeshGradient};
+
+use lyon::math::point;
+use lyon::path::Path;
+use lyon::tessellation::{
+    FillTessellator, GeometryBuilderError, TessellationError, UnsupportedParamater,
+};
+
+/// Approximate length in local units of the steps patches are subdivided
+/// in.
+const STEP: f32 = 8.0;
+
+/// Fewest and most steps a patch is subdivided in along each direction.
+const MIN_STEPS: usize = 4;
+const MAX_STEPS: usize = 24;
+
+/// Evaluates a cubic Bézier curve at `t`.
+fn cubic(p: [(f32, f32); 4], t: f32) -> (f32, f32) {
+    let s = 1.0 - t;
+    let (a, b, c, d) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+    (
+        a * p[0].0 + b * p[1].0 + c * p[2].0 + d * p[3].0,
+        a * p[0].1 + b * p[1].1 + c * p[2].1 + d * p[3].1,
+    )
+}
+
+/// The boundary curves and corner colors of one patch.
+struct Patch {
+    top: [(f32, f32); 4],
+    bottom: [(f32, f32); 4],
+    left: [(f32, f32); 4],
+    right: [(f32, f32); 4],
+
+    /// Colors at the top left, top right, bottom left, and bottom right.
+    colors: [Color; 4],
+}
+
+impl Patch {
+    /// Returns the patch in the given row and column of a valid mesh.
+    fn of(mesh: &MeshGradient, row: usize, column: usize) -> Self {
+        let point = |r: usize, c: usize| mesh.points[r * (3 * mesh.columns + 1) + c];
+        let color = |r: usize, c: usize| mesh.colors[r * (mesh.columns + 1) + c];
+        let (r, c) = (3 * row, 3 * column);
+        Self {
+            top: [0, 1, 2, 3].map(|i| point(r, c + i)),
+            bottom: [0, 1, 2, 3].map(|i| point(r + 3, c + i)),
+            left: [0, 1, 2, 3].map(|i| point(r + i, c)),
+            right: [0, 1, 2, 3].map(|i| point(r + i, c + 3)),
+            colors: [
+                color(row, column),
+                color(row, column + 1),
+                color(row + 1, column),
+                color(row + 1, column + 1),
+            ],
+        }
+    }
+
+    /// Returns the point of the Coons surface at (`u`, `v`): the sum of
+    /// the blends between opposite edges, minus the bilinear blend of the
+    /// corners both count.
+    fn position(&self, u: f32, v: f32) -> (f32, f32) {
+        let (top, bottom) = (cubic(self.top, u), cubic(self.bottom, u));
+        let (left, right) = (cubic(self.left, v), cubic(self.right, v));
+        let [p00, p10, p01, p11] = [self.top[0], self.top[3], self.bottom[0], self.bottom[3]];
+        let corner = |i: fn((f32, f32)) -> f32| {
+            (1.0 - u) * (1.0 - v) * i(p00)
+                + u * (1.0 - v) * i(p10)
+                + (1.0 - u) * v * i(p01)
+                + u * v * i(p11)
+        };
+        let blend = |i: fn((f32, f32)) -> f32| {
+            (1.0 - v) * i(top) + v * i(bottom) + (1.0 - u) * i(left) + u * i(right) - corner(i)
+        };
+        (blend(|p| p.0), blend(|p| p.1))
+    }
+
+    /// Returns the color at (`u`, `v`), blended bilinearly between the
+    /// corners.
+    fn color(&self, u: f32, v: f32) -> [f32; 4] {
+        let [a, b, c, d] = self.colors.map(|Color(r, g, b, a)| [r, g, b, a]);
+        std::array::from_fn(|i| {
+            let upper = a[i] + (b[i] - a[i]) * u;
+            let lower = c[i] + (d[i] - c[i]) * u;
+            upper + (lower - upper) * v
+        })
+    }
+
+    /// Returns how many steps the patch is subdivided in along each
+    /// direction, from the length of its control polygons.
+    fn steps(&self) -> usize {
+        let length = |p: &[(f32, f32); 4]| {
+            p.windows(2)
+                .map(|w| ((w[1].0 - w[0].0).powi(2) + (w[1].1 - w[0].1).powi(2)).sqrt())
+                .sum::<f32>()
+        };
+        let longest = [&self.top, &self.bottom, &self.left, &self.right]
+            .into_iter()
+            .map(length)
+            .fold(0.0, f32::max);
+        ((longest / STEP).ceil() as usize).clamp(MIN_STEPS, MAX_STEPS)
+    }
+}
+
+/// Tessellates each patch of a mesh gradient into a grid of triangles
+/// carrying the blended colors in their vertices.
+///
+/// The fill rule and feathering do not apply; patches are filled as they
+/// are, and their edges are left to MSAA.
+impl Tesselate for MeshGradient {
+    /// Returns the outline of the mesh, along the outer edges of its
+    /// border patches.
+    fn path(&self) -> Path {
+        let mut builder = Path::builder();
+        if !self.is_valid() {
+            return builder.build();
+        }
+        let width = 3 * self.columns + 1;
+        let at = |r: usize, c: usize| {
+            let (x, y) = self.points[r * width + c];
+            point(x, y)
+        };
+        let (last_row, last_column) = (3 * self.rows, 3 * self.columns);
+        builder.begin(at(0, 0));
+        for c in (0..last_column).step_by(3) {
+            builder.cubic_bezier_to(at(0, c + 1), at(0, c + 2), at(0, c + 3));
+        }
+        for r in (0..last_row).step_by(3) {
+            builder.cubic_bezier_to(
+                at(r + 1, last_column),
+                at(r + 2, last_column),
+                at(r + 3, last_column),
+            );
+        }
+        for c in (3..=last_column).rev().step_by(3) {
+            builder.cubic_bezier_to(
+                at(last_row, c - 1),
+                at(last_row, c - 2),
+                at(last_row, c - 3),
+            );
+        }
+        for r in (3..=last_row).rev().step_by(3) {
+            builder.cubic_bezier_to(at(r - 1, 0), at(r - 2, 0), at(r - 3, 0));
+        }
+        builder.close();
+        builder.build()
+    }
+
+    fn tesselate(
+        &self,
+        geometry: &mut Geometry,
+        _tessellator: &mut FillTessellator,
+        _options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        if !self.is_valid() {
+            return Err(TessellationError::GeometryBuilder(
+                GeometryBuilderError::InvalidVertex,
+            ));
+        }
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let patch = Patch::of(self, row, column);
+                let steps = patch.steps();
+                let base = base_index(geometry, (steps + 1) * (steps + 1))?;
+                for j in 0..=steps {
+                    let v = j as f32 / steps as f32;
+                    for i in 0..=steps {
+                        let u = i as f32 / steps as f32;
+                        let (x, y) = patch.position(u, v);
+                        if !x.is_finite() || !y.is_finite() {
+                            return Err(TessellationError::UnsupportedParamater(
+                                UnsupportedParamater::PositionIsNaN,
+                            ));
+                        }
+                        geometry
+                            .vertices
+                            .push(Vertex::colored(x, y, 1.0, patch.color(u, v)));
+                    }
+                }
+                let index = |i: usize, j: usize| base + (j * (steps + 1) + i) as u16;
+                for j in 0..steps {
+                    for i in 0..steps {
+                        geometry.indices.extend_from_slice(&[
+                            index(i, j),
+                            index(i + 1, j),
+                            index(i + 1, j + 1),
+                            index(i, j),
+                            index(i + 1, j + 1),
+                            index(i, j + 1),
+                        ]);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use super::Tesselate;
+
+use ardent_core::shape::{Path as ShapePath, PathCommand};
+
+use lyon::math::point;
+use lyon::path::Path;
+
+impl Tesselate for ShapePath {
+    /// Converts the path commands into a lyon path.
+    ///
+    /// Drawing commands without a preceding `MoveTo` start at the origin,
+    /// and sub-paths left open are ended without closing them.
+    fn path(&self) -> Path {
+        let mut builder = Path::builder();
+        let mut open = false;
+
+        for command in self.commands() {
+            match *command {
+                PathCommand::MoveTo(x, y) => {
+                    if open {
+                        builder.end(false);
+                    }
+                    builder.begin(point(x, y));
+                    open = true;
+                }
+                PathCommand::LineTo(x, y) => {
+                    begin(&mut builder, &mut open);
+                    builder.line_to(point(x, y));
+                }
+                PathCommand::QuadTo { ctrl, to } => {
+                    begin(&mut builder, &mut open);
+                    builder.quadratic_bezier_to(point(ctrl.0, ctrl.1), point(to.0, to.1));
+                }
+                PathCommand::CubicTo { ctrl1, ctrl2, to } => {
+                    begin(&mut builder, &mut open);
+                    builder.cubic_bezier_to(
+                        point(ctrl1.0, ctrl1.1),
+                        point(ctrl2.0, ctrl2.1),
+                        point(to.0, to.1),
+                    );
+                }
+                PathCommand::Close => {
+                    if open {
+                        builder.close();
+                        open = false;
+                    }
+                }
+            }
+        }
+        if open {
+            builder.end(false);
+        }
+        builder.build()
+    }
+}
+
+/// Starts a sub-path at the origin unless one is already open.
+fn begin(builder: &mut lyon::path::path::Builder, open: &mut bool) {
+    if !*open {
+        builder.begin(point(0.0, 0.0));
+        *open = true;
+    }
+}
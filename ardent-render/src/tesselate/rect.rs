@@ -1,8 +1,11 @@
-use super::Tesselate;
+use super::{Tesselate, TesselateOptions, base_index, feather};
+
+use crate::geometry::{Geometry, Vertex};
 
 use ardent_core::shape::Rect;
 
 use lyon::path::Path;
+use lyon::tessellation::{FillTessellator, TessellationError};
 
 impl Tesselate for Rect {
     fn path(&self) -> Path {
@@ -14,4 +17,32 @@ impl Tesselate for Rect {
         path_builder.close();
         path_builder.build()
     }
+
+    /// Emits the two triangles of an axis-aligned rectangle directly.
+    ///
+    /// Rectangles are by far the most common shape in a UI, and their
+    /// triangulation is trivial, so lyon is bypassed entirely. The fill rule
+    /// has no effect on a rectangle and is ignored.
+    fn tesselate(
+        &self,
+        geometry: &mut Geometry,
+        _tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        let base = base_index(geometry, 4)?;
+        geometry.vertices.extend_from_slice(&[
+            Vertex::new(0.0, 0.0, 1.0),
+            Vertex::new(self.width, 0.0, 1.0),
+            Vertex::new(self.width, self.height, 1.0),
+            Vertex::new(0.0, self.height, 1.0),
+        ]);
+        geometry
+            .indices
+            .extend([0, 1, 2, 0, 2, 3].map(|i| base + i));
+
+        if options.feather.is_some() {
+            feather::add_fringe(&self.path(), options.tolerance, geometry)?;
+        }
+        Ok(())
+    }
 }
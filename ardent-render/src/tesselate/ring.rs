@@ -0,0 +1,24 @@
+use super::Tesselate;
+
+use ardent_core::shape::Ring;
+
+use lyon::math::point;
+use lyon::path::{Path, Winding};
+
+/// Rings are filled from lyon's circles, with the inner one wound the other
+/// way, so the hole stays empty under either fill rule.
+impl Tesselate for Ring {
+    fn path(&self) -> Path {
+        let center = point(self.outer_radius, self.outer_radius);
+        let mut builder = Path::builder();
+        builder.add_circle(center, self.outer_radius, Winding::Positive);
+        if self.inner_radius > 0.0 {
+            builder.add_circle(
+                center,
+                self.inner_radius.min(self.outer_radius),
+                Winding::Negative,
+            );
+        }
+        builder.build()
+    }
+}
@@ -0,0 +1,40 @@
+use super::{Tesselate, TesselateOptions};
+
+use crate::geometry::Geometry;
+
+use ardent_core::shape::Shape;
+
+use lyon::path::Path;
+use lyon::tessellation::{FillTessellator, TessellationError};
+
+/// Dispatches to the implementation of the concrete shape variant.
+///
+/// This is the only place that needs to grow when a new shape is added to
+/// `ardent_core`; the renderer only ever tessellates `Shape` values. Shapes
+/// without an implementation here are tessellated from their outline.
+impl Tesselate for Shape {
+    fn path(&self) -> Path {
+        match self {
+            Shape::Rect(rect) => rect.path(),
+            Shape::Ellipse(ellipse) => ellipse.path(),
+            Shape::Ring(ring) => ring.path(),
+            Shape::Path(path) => path.path(),
+            shape => shape.to_path().path(),
+        }
+    }
+
+    fn tesselate(
+        &self,
+        geometry: &mut Geometry,
+        tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        match self {
+            Shape::Rect(rect) => rect.tesselate(geometry, tessellator, options),
+            Shape::Ellipse(ellipse) => ellipse.tesselate(geometry, tessellator, options),
+            Shape::Ring(ring) => ring.tesselate(geometry, tessellator, options),
+            Shape::Path(path) => path.tesselate(geometry, tessellator, options),
+            shape => shape.to_path().tesselate(geometry, tessellator, options),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Polyline simplification for dense paths.
+//!
+//! Paths with thousands of points (GPS traces, plotted data, freehand
+//! strokes) produce vertex counts far beyond what is visible on screen.
+//! The Ramer–Douglas–Peucker algorithm removes points that deviate less than
+//! a tolerance from the simplified line, bounding the work done by lyon.
+
+use super::contour::{self, Contour};
+
+use lyon::math::Point;
+use lyon::path::Path;
+
+/// Returns a simplified copy of `path`.
+///
+/// Curves are first flattened with `flatten_tolerance`; the resulting
+/// polylines are then simplified so that no removed point lies farther than
+/// `tolerance` from the output.
+pub(crate) fn simplify(path: &Path, tolerance: f32, flatten_tolerance: f32) -> Path {
+    let contours: Vec<Contour> = contour::flatten(path, flatten_tolerance)
+        .into_iter()
+        .map(|c| Contour {
+            points: ramer_douglas_peucker(&c.points, tolerance),
+            closed: c.closed,
+        })
+        .collect();
+    contour::to_path(&contours)
+}
+
+/// Simplifies a polyline, always keeping its first and last points.
+fn ramer_douglas_peucker(points: &[Point], tolerance: f32) -> Vec<Point> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // Iterative to avoid deep recursion on very long polylines.
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let (index, distance) = (start + 1..end)
+            .map(|i| (i, segment_distance(points[i], points[start], points[end])))
+            .fold((start, 0.0), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            });
+
+        if distance > tolerance {
+            keep[index] = true;
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, kept)| kept.then_some(*point))
+        .collect()
+}
+
+/// Distance from `p` to the segment `a`–`b`.
+fn segment_distance(p: Point, a: Point, b: Point) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.square_length();
+    if length_sq <= f32::EPSILON {
+        return (p - a).length();
+    }
+    let t = ((p - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+    (p - (a + ab * t)).length()
+}
@@ -0,0 +1,173 @@
+//! Outlines drawn with a stroke, over or instead of a fill.
+
+use super::{
+    Tesselate, TesselateOptions, apply_effects, dash_path, feather, tessellate_markers,
+    tessellate_variable_stroke, trim_path,
+};
+
+use crate::geometry::{Geometry, Vertex};
+
+use ardent_core::shape::Shape;
+use ardent_core::style::{PathEffect, Stroke, StrokeAlign, StrokeCap, StrokeJoin};
+
+use lyon::path::Path;
+use lyon::tessellation::{
+    BuffersBuilder, FillOptions, FillTessellator, LineCap, LineJoin, StrokeOptions,
+    StrokeTessellator, StrokeVertex, TessellationError,
+};
+
+/// A shape drawn as a band of the stroke's width along its outline, e.g.
+/// a line or the border of a button.
+///
+/// The outline is changed by the path effects, trimmed, and cut into the
+/// stroke's dashes before it is stroked, and the stroke's markers are
+/// drawn along the trimmed outline.
+/// Closed outlines are moved inwards or outwards by half the width for
+/// inside and outside alignment; the alignment does not apply to open
+/// outlines. Its vertices are marked as stroke vertices, so they are drawn
+/// in the stroke color. The edges of the band and its markers are feathered
+/// as the options ask.
+pub(crate) struct Stroked {
+    pub(crate) shape: Shape,
+    pub(crate) stroke: Stroke,
+    pub(crate) effects: Vec<PathEffect>,
+}
+
+impl Stroked {
+    /// Returns the outline the stroke is drawn along, with curves changed
+    /// by effects or trimming flattened within `tolerance`.
+    fn outline(&self, tolerance: f32) -> Path {
+        let half = self.stroke.width / 2.0;
+        let align = match self.stroke.align {
+            _ if self.shape.is_open() => None,
+            StrokeAlign::Center => None,
+            StrokeAlign::Inside => Some(PathEffect::Offset(-half)),
+            StrokeAlign::Outside => Some(PathEffect::Offset(half)),
+        };
+        let path = match align {
+            Some(offset) => {
+                let effects: Vec<_> = self.effects.iter().copied().chain([offset]).collect();
+                apply_effects(&self.shape.path(), &effects, tolerance)
+            }
+            None => apply_effects(&self.shape.path(), &self.effects, tolerance),
+        };
+        if self.stroke.is_trimmed() {
+            trim_path(
+                &path,
+                self.stroke.trim_start,
+                self.stroke.trim_end,
+                tolerance,
+            )
+        } else {
+            path
+        }
+    }
+}
+
+impl Tesselate for Stroked {
+    /// Returns the outline the stroke is drawn along.
+    fn path(&self) -> Path {
+        self.outline(FillOptions::DEFAULT_TOLERANCE)
+    }
+
+    fn tesselate(
+        &self,
+        geometry: &mut Geometry,
+        tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        let tolerance = options.tolerance;
+        let path = self.outline(tolerance);
+        let dashed;
+        let body = if self.stroke.is_dashed() {
+            dashed = dash_path(
+                &path,
+                &self.stroke.dashes,
+                self.stroke.dash_offset,
+                tolerance,
+            );
+            &dashed
+        } else {
+            &path
+        };
+        let first = geometry.vertices.len();
+        let first_index = geometry.indices.len();
+        let mut stroker = StrokeTessellator::new();
+        if self.stroke.profile.is_some() {
+            tessellate_variable_stroke(body, &self.stroke, tolerance, &mut stroker, geometry)?;
+        } else {
+            let options = stroke_options(&self.stroke, tolerance);
+            stroker.tessellate_path(
+                body,
+                &options,
+                &mut BuffersBuilder::new(geometry, |v: StrokeVertex| {
+                    let pos = v.position();
+                    Vertex::new(pos.x, pos.y, 1.0)
+                }),
+            )?;
+        }
+        tessellate_markers(&path, &self.stroke, tolerance, tessellator, geometry)?;
+        if options.feather.is_some() {
+            feather::add_mesh_fringe(geometry, first_index)?;
+        }
+        for vertex in &mut geometry.vertices[first..] {
+            vertex.stroke = 1.0;
+        }
+        Ok(())
+    }
+}
+
+/// Returns lyon's options for drawing `stroke`.
+pub(super) fn stroke_options(stroke: &Stroke, tolerance: f32) -> StrokeOptions {
+    let cap = match stroke.cap {
+        StrokeCap::Butt => LineCap::Butt,
+        StrokeCap::Round => LineCap::Round,
+        StrokeCap::Square => LineCap::Square,
+    };
+    let join = match stroke.join {
+        StrokeJoin::Miter => LineJoin::Miter,
+        StrokeJoin::Round => LineJoin::Round,
+        StrokeJoin::Bevel => LineJoin::Bevel,
+    };
+    StrokeOptions::default()
+        .with_tolerance(tolerance)
+        .with_line_width(stroke.width)
+        .with_line_cap(cap)
+        .with_line_join(join)
+        // Lyon rejects limits below 1.0, which no miter can stay within.
+        .with_miter_limit(stroke.miter_limit.max(StrokeOptions::MINIMUM_MITER_LIMIT))
+}
+
+/// A fill with a stroke drawn over it, tessellated into one mesh.
+pub(crate) struct Outlined {
+    /// The fill, or `None` for open shapes.
+    pub(crate) fill: Option<Box<dyn Tesselate + Send>>,
+
+    /// The stroke, or `None` for shapes without one.
+    pub(crate) stroke: Option<Stroked>,
+}
+
+impl Tesselate for Outlined {
+    fn path(&self) -> Path {
+        match (&self.fill, &self.stroke) {
+            (Some(fill), _) => fill.path(),
+            (None, Some(stroke)) => stroke.path(),
+            (None, None) => Path::new(),
+        }
+    }
+
+    fn tesselate(
+        &self,
+        geometry: &mut Geometry,
+        tessellator: &mut FillTessellator,
+        options: &TesselateOptions,
+    ) -> Result<(), TessellationError> {
+        if let Some(fill) = &self.fill {
+            fill.tesselate(geometry, tessellator, options)?;
+        }
+        if let Some(stroke) = &self.stroke {
+            stroke.tesselate(geometry, tessellator, options)?;
+        }
+        Ok(())
+    }
+}
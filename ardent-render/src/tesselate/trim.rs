@@ -0,0 +1,77 @@
+//! Length-parameterized trimming of outlines.
+//!
+//! Stroke trimming draws only the part of an outline between two fractions
+//! of its total length, which powers "draw-on" animations. The outline is
+//! flattened and measured segment by segment, so the fractions correspond to
+//! arc length rather than to path parameters.
+
+use super::contour::{self, Contour};
+
+use lyon::math::Point;
+use lyon::path::Path;
+
+/// Returns the part of `path` between the fractions `start` and `end` of its
+/// total length.
+///
+/// Closed sub-paths include their closing segment in the measured length.
+/// The result consists of open polylines; fractions are clamped to
+/// [0.0, 1.0] and an empty path is returned if `start >= end`.
+pub fn trim_path(path: &Path, start: f32, end: f32, tolerance: f32) -> Path {
+    let start = start.clamp(0.0, 1.0);
+    let end = end.clamp(0.0, 1.0);
+    if start >= end {
+        return Path::new();
+    }
+
+    let polylines: Vec<Vec<Point>> = contour::flatten(path, tolerance)
+        .into_iter()
+        .map(|c| {
+            let mut points = c.points;
+            if c.closed
+                && let Some(&first) = points.first()
+            {
+                points.push(first);
+            }
+            points
+        })
+        .collect();
+
+    let total: f32 = polylines.iter().map(|p| polyline_length(p)).sum();
+    let (from, to) = (start * total, end * total);
+
+    let mut trimmed = Vec::new();
+    let mut offset = 0.0;
+    for polyline in &polylines {
+        let mut current: Vec<Point> = Vec::new();
+        for segment in polyline.windows(2) {
+            let (a, b) = (segment[0], segment[1]);
+            let length = (b - a).length();
+            let (seg_start, seg_end) = (offset, offset + length);
+            offset = seg_end;
+
+            if seg_end < from || seg_start > to || length <= 0.0 {
+                continue;
+            }
+
+            let point_at =
+                |distance: f32| a.lerp(b, ((distance - seg_start) / length).clamp(0.0, 1.0));
+            if current.is_empty() {
+                current.push(point_at(from.max(seg_start)));
+            }
+            current.push(point_at(to.min(seg_end)));
+        }
+        if current.len() > 1 {
+            trimmed.push(Contour {
+                points: current,
+                closed: false,
+            });
+        }
+    }
+
+    contour::to_path(&trimmed)
+}
+
+/// Returns the total length of a polyline.
+fn polyline_length(points: &[Point]) -> f32 {
+    points.windows(2).map(|s| (s[1] - s[0]).length()).sum()
+}
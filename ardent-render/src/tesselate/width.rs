@@ -0,0 +1,85 @@
+//! Strokes whose width varies along the outline.
+//!
+//! The outline is flattened and each point is given the width of the
+//! stroke's [`WidthProfile`] at its fraction of the total length. Lyon's
+//! stroke tessellator then interpolates the width between points, which
+//! gives smoothly tapered, calligraphic strokes.
+
+use super::contour;
+use super::stroke::stroke_options;
+
+use crate::geometry::{Geometry, Vertex};
+
+use ardent_core::style::Stroke;
+
+use lyon::path::Path;
+use lyon::tessellation::{BuffersBuilder, StrokeTessellator, StrokeVertex, TessellationError};
+
+/// Appends the triangles of `stroke` drawn along `path` to `geometry`,
+/// with the width given by the stroke's profile at every point.
+///
+/// Fractions of the length are measured over all sub-paths together,
+/// including the closing segments of closed ones. Strokes without a profile
+/// are drawn with a constant width. The stroke is centered on the outline;
+/// its alignment and trimming are not applied here.
+pub fn tessellate_variable_stroke(
+    path: &Path,
+    stroke: &Stroke,
+    tolerance: f32,
+    tessellator: &mut StrokeTessellator,
+    geometry: &mut Geometry,
+) -> Result<(), TessellationError> {
+    let contours = contour::flatten(path, tolerance);
+    let total: f32 = contours
+        .iter()
+        .map(|c| {
+            let closing = match (c.closed, c.points.first(), c.points.last()) {
+                (true, Some(&first), Some(&last)) => (first - last).length(),
+                _ => 0.0,
+            };
+            c.points
+                .windows(2)
+                .map(|s| (s[1] - s[0]).length())
+                .sum::<f32>()
+                + closing
+        })
+        .sum();
+    let fraction = |distance: f32| if total > 0.0 { distance / total } else { 0.0 };
+    let factor = |distance: f32| {
+        stroke
+            .profile
+            .as_ref()
+            .map_or(1.0, |profile| profile.factor_at(fraction(distance)))
+    };
+
+    let mut builder = Path::builder_with_attributes(1);
+    let mut offset = 0.0;
+    for c in &contours {
+        let Some((&first, rest)) = c.points.split_first() else {
+            continue;
+        };
+        builder.begin(first, &[factor(offset)]);
+        let mut previous = first;
+        for &p in rest {
+            offset += (p - previous).length();
+            builder.line_to(p, &[factor(offset)]);
+            previous = p;
+        }
+        if c.closed {
+            // Lyon strokes the closing segment towards the width at the
+            // start of the contour.
+            offset += (first - previous).length();
+        }
+        builder.end(c.closed);
+    }
+
+    let options = stroke_options(stroke, tolerance).with_variable_line_width(0);
+    tessellator.tessellate_path(
+        &builder.build(),
+        &options,
+        &mut BuffersBuilder::new(geometry, |v: StrokeVertex| {
+            let pos = v.position();
+            Vertex::new(pos.x, pos.y, 1.0)
+        }),
+    )
+}
@@ -0,0 +1,250 @@
+use ardent_core::node::{Node, NodeId};
+use ardent_core::scene::{Scene, SceneError};
+use ardent_core::shape::{Path, PathCommand, Shape};
+use ardent_core::style::{
+    Color, Fill, FillRule, Gradient, GradientStop, Stroke, StrokeCap, StrokeJoin,
+};
+use ardent_core::transform::Transform;
+
+use usvg::tiny_skia_path::{PathSegment, Point};
+
+/// Relative tolerance below which the columns of a transform are treated as
+/// perpendicular, i.e. the transform has no skew.
+const SKEW_EPSILON: f32 = 1e-4;
+
+/// Mirrors a parsed SVG tree into the scene and returns the document node.
+pub(crate) fn import(
+    scene: &mut Scene,
+    parent: NodeId,
+    tree: &usvg::Tree,
+) -> Result<NodeId, SceneError> {
+    let id = scene.add_node(parent, Node::new())?;
+    import_children(scene, id, tree.root(), usvg::Transform::identity(), 1.0)?;
+    Ok(id)
+}
+
+/// Imports the children of a group.
+///
+/// `baked` is a transform that could not be expressed by node transforms and
+/// is applied to path coordinates instead. `opacity` is the combined opacity
+/// of the enclosing groups, since node opacity does not apply to children.
+fn import_children(
+    scene: &mut Scene,
+    parent: NodeId,
+    group: &usvg::Group,
+    baked: usvg::Transform,
+    opacity: f32,
+) -> Result<(), SceneError> {
+    for child in group.children() {
+        match child {
+            usvg::Node::Group(group) => import_group(scene, parent, group, baked, opacity)?,
+            usvg::Node::Path(path) => import_path(scene, parent, path, baked, opacity)?,
+            usvg::Node::Text(text) => {
+                import_group(scene, parent, text.flattened(), baked, opacity)?
+            }
+            // Raster images are not supported yet.
+            usvg::Node::Image(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn import_group(
+    scene: &mut Scene,
+    parent: NodeId,
+    group: &usvg::Group,
+    baked: usvg::Transform,
+    opacity: f32,
+) -> Result<(), SceneError> {
+    let mut node = Node::new();
+
+    // Once a transform had to be baked, every transform below it must be
+    // baked as well to keep the order of transformations.
+    let baked = match decompose(group.transform()) {
+        Some(transform) if baked.is_identity() => {
+            *node.transform_mut() = transform;
+            baked
+        }
+        _ => baked.pre_concat(group.transform()),
+    };
+
+    let id = scene.add_node(parent, node)?;
+    import_children(scene, id, group, baked, opacity * group.opacity().get())
+}
+
+fn import_path(
+    scene: &mut Scene,
+    parent: NodeId,
+    path: &usvg::Path,
+    baked: usvg::Transform,
+    opacity: f32,
+) -> Result<(), SceneError> {
+    if !path.is_visible() {
+        return Ok(());
+    }
+    let data = if baked.is_identity() {
+        Some(path.data().clone())
+    } else {
+        path.data().clone().transform(baked)
+    };
+    let Some(data) = data else {
+        return Ok(());
+    };
+
+    let mut node = Node::new();
+    node.set_shape(Shape::Path(convert_path(&data)));
+
+    let style = node.style_mut();
+    style.opacity = opacity;
+    style.fill = path.fill().map(|fill| Fill {
+        gradient: gradient(fill.paint(), fill.opacity().get(), baked),
+        rule: match fill.rule() {
+            usvg::FillRule::NonZero => FillRule::NonZero,
+            usvg::FillRule::EvenOdd => FillRule::EvenOdd,
+        },
+        ..Fill::solid(paint_color(fill.paint(), fill.opacity().get()))
+    });
+    style.stroke = path.stroke().map(|stroke| {
+        Stroke::new(
+            paint_color(stroke.paint(), stroke.opacity().get()),
+            stroke.width().get(),
+        )
+        .with_cap(match stroke.linecap() {
+            usvg::LineCap::Butt => StrokeCap::Butt,
+            usvg::LineCap::Round => StrokeCap::Round,
+            usvg::LineCap::Square => StrokeCap::Square,
+        })
+        .with_join(match stroke.linejoin() {
+            usvg::LineJoin::Miter | usvg::LineJoin::MiterClip => StrokeJoin::Miter,
+            usvg::LineJoin::Round => StrokeJoin::Round,
+            usvg::LineJoin::Bevel => StrokeJoin::Bevel,
+        })
+        .with_miter_limit(stroke.miterlimit().get())
+        .with_dashes(stroke.dasharray().unwrap_or_default(), stroke.dashoffset())
+    });
+
+    scene.add_node(parent, node)?;
+    Ok(())
+}
+
+/// Splits an affine transform into scale, rotation, and translation.
+///
+/// Returns `None` if the transform skews, which node transforms cannot
+/// express.
+fn decompose(transform: usvg::Transform) -> Option<Transform> {
+    let usvg::Transform {
+        sx,
+        kx,
+        ky,
+        sy,
+        tx,
+        ty,
+    } = transform;
+    let scale_x = sx.hypot(ky);
+    let scale_y = kx.hypot(sy);
+    if scale_x == 0.0 || scale_y == 0.0 {
+        return None;
+    }
+    if (sx * kx + ky * sy).abs() > SKEW_EPSILON * scale_x * scale_y {
+        return None;
+    }
+
+    // A negative determinant means the y axis is mirrored.
+    let determinant = sx * sy - kx * ky;
+    Some(Transform {
+        translate: (tx, ty),
+        scale: (scale_x, determinant / scale_x),
+        rotate: ky.atan2(sx),
+    })
+}
+
+/// Converts usvg path data into a path shape.
+fn convert_path(data: &usvg::tiny_skia_path::Path) -> Path {
+    let point = |p: Point| (p.x, p.y);
+    data.segments()
+        .map(|segment| match segment {
+            PathSegment::MoveTo(p) => PathCommand::MoveTo(p.x, p.y),
+            PathSegment::LineTo(p) => PathCommand::LineTo(p.x, p.y),
+            PathSegment::QuadTo(ctrl, to) => PathCommand::QuadTo {
+                ctrl: point(ctrl),
+                to: point(to),
+            },
+            PathSegment::CubicTo(ctrl1, ctrl2, to) => PathCommand::CubicTo {
+                ctrl1: point(ctrl1),
+                ctrl2: point(ctrl2),
+                to: point(to),
+            },
+            PathSegment::Close => PathCommand::Close,
+        })
+        .collect()
+}
+
+/// Returns the solid color of a paint. Gradients use their first stop and
+/// patterns fall back to black.
+fn paint_color(paint: &usvg::Paint, opacity: f32) -> Color {
+    match paint {
+        usvg::Paint::Color(color) => color_of(*color, opacity),
+        usvg::Paint::LinearGradient(gradient) => first_stop(gradient.stops(), opacity),
+        usvg::Paint::RadialGradient(gradient) => first_stop(gradient.stops(), opacity),
+        usvg::Paint::Pattern(_) => Color::rgba(0.0, 0.0, 0.0, opacity),
+    }
+}
+
+/// Converts a gradient paint.
+///
+/// Gradient transforms are applied to the gradient's defining points, which
+/// is exact for linear gradients and for radial gradients under uniform
+/// scaling.
+fn gradient(paint: &usvg::Paint, opacity: f32, baked: usvg::Transform) -> Option<Gradient> {
+    let map = |transform: usvg::Transform, x: f32, y: f32| {
+        let mut points = [Point::from_xy(x, y)];
+        baked.pre_concat(transform).map_points(&mut points);
+        (points[0].x, points[0].y)
+    };
+
+    match paint {
+        usvg::Paint::LinearGradient(gradient) => Some(Gradient::Linear {
+            start: map(gradient.transform(), gradient.x1(), gradient.y1()),
+            end: map(gradient.transform(), gradient.x2(), gradient.y2()),
+            stops: stops(gradient.stops(), opacity),
+        }),
+        usvg::Paint::RadialGradient(gradient) => {
+            let transform = gradient.transform();
+            let center = map(transform, gradient.cx(), gradient.cy());
+            let edge = map(transform, gradient.cx() + gradient.r().get(), gradient.cy());
+            Some(Gradient::Radial {
+                center,
+                radius: (edge.0 - center.0).hypot(edge.1 - center.1),
+                stops: stops(gradient.stops(), opacity),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn stops(stops: &[usvg::Stop], opacity: f32) -> Vec<GradientStop> {
+    stops
+        .iter()
+        .map(|stop| {
+            GradientStop::new(
+                stop.offset().get(),
+                color_of(stop.color(), stop.opacity().get() * opacity),
+            )
+        })
+        .collect()
+}
+
+fn first_stop(stops: &[usvg::Stop], opacity: f32) -> Color {
+    stops.first().map_or(Color::transparent(), |stop| {
+        color_of(stop.color(), stop.opacity().get() * opacity)
+    })
+}
+
+fn color_of(color: usvg::Color, opacity: f32) -> Color {
+    Color::rgba(
+        color.red as f32 / 255.0,
+        color.green as f32 / 255.0,
+        color.blue as f32 / 255.0,
+        opacity,
+    )
+}
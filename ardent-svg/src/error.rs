@@ -0,0 +1,43 @@
+use std::fmt;
+
+use ardent_core::scene::SceneError;
+
+/// An error raised while importing an SVG document.
+#[derive(Debug)]
+pub enum SvgError {
+    /// The document is not valid SVG.
+    Parse(usvg::Error),
+
+    /// The document could not be added to the scene.
+    Scene(SceneError),
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgError::Parse(err) => write!(f, "failed to parse SVG: {err}"),
+            SvgError::Scene(err) => write!(f, "failed to add SVG to scene: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SvgError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SvgError::Parse(err) => Some(err),
+            SvgError::Scene(err) => Some(err),
+        }
+    }
+}
+
+impl From<usvg::Error> for SvgError {
+    fn from(err: usvg::Error) -> Self {
+        SvgError::Parse(err)
+    }
+}
+
+impl From<SceneError> for SvgError {
+    fn from(err: SceneError) -> Self {
+        SvgError::Scene(err)
+    }
+}
@@ -1,14 +1,36 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! The `ardent_svg` crate imports SVG documents into an ardent [`Scene`].
+//!
+//! Documents are parsed and normalized by [`usvg`], which resolves CSS,
+//! units, `use` references, and converts basic shapes and text into paths.
+//! The resulting tree is then mirrored into scene nodes: groups become
+//! nodes without a shape, and paths become nodes with a
+//! [`Shape::Path`](ardent_core::shape::Shape::Path), carrying their fill,
+//! stroke, and gradient.
+//!
+//! ```rust,ignore
+//! let data = std::fs::read("icon.svg")?;
+//! let icon = ardent_svg::load(&mut scene, scene.root(), &data)?;
+//! ```
+
+mod convert;
+mod error;
+
+use ardent_core::node::NodeId;
+use ardent_core::scene::Scene;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub use error::SvgError;
+
+/// Parses an SVG document and adds it to `scene` as a child of `parent`.
+///
+/// Returns the ID of the node holding the imported document, or an error if
+/// the document is invalid or `parent` is not in the scene.
+pub fn load(scene: &mut Scene, parent: NodeId, data: &[u8]) -> Result<NodeId, SvgError> {
+    let tree = usvg::Tree::from_data(data, &usvg::Options::default())?;
+    Ok(convert::import(scene, parent, &tree)?)
+}
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
+/// Parses an SVG document from a string and adds it to `scene` as a child of
+/// `parent`.
+pub fn load_str(scene: &mut Scene, parent: NodeId, text: &str) -> Result<NodeId, SvgError> {
+    load(scene, parent, text.as_bytes())
 }
@@ -0,0 +1,267 @@
+//! Bidirectional text: ordering mixed left-to-right and right-to-left runs
+//! for display, and the caret and selection geometry that goes with it.
+//!
+//! Text is stored in logical order, the order it is typed and read, but
+//! Arabic and Hebrew are displayed right to left, and may embed numbers or
+//! Latin words displayed left to right. [`BidiLine`] runs the Unicode
+//! Bidirectional Algorithm over a line of text, places its characters from
+//! left to right in visual order, and maps between logical byte offsets
+//! and horizontal positions for carets, selections, and hit testing.
+//!
+//! Widths of characters are supplied by the caller, e.g. from the advances
+//! of shaped glyphs. Mirroring brackets in right-to-left runs is left to
+//! shaping.
+
+use std::ops::Range;
+
+use unicode_bidi::{BidiInfo, Level};
+
+/// The base direction of a paragraph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Taken from the first strong character, e.g. the first Latin or
+    /// Hebrew letter, and left to right without one.
+    #[default]
+    Auto,
+
+    /// Left to right, e.g. for English UIs.
+    Ltr,
+
+    /// Right to left, e.g. for Arabic and Hebrew UIs.
+    Rtl,
+}
+
+/// A run of characters with the same direction, in visual order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisualRun {
+    /// The byte range of the run in the text.
+    pub range: Range<usize>,
+
+    /// Whether the run is displayed right to left.
+    pub rtl: bool,
+
+    /// Position of the run's left edge.
+    pub x: f32,
+
+    /// Width of the run.
+    pub width: f32,
+}
+
+/// A character placed on a line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Placed {
+    /// Byte offset of the character in the text.
+    index: usize,
+
+    /// Byte length of the character.
+    len: usize,
+
+    /// Horizontal extent of the character.
+    left: f32,
+    right: f32,
+
+    /// Whether the character is displayed right to left.
+    rtl: bool,
+}
+
+/// A single line of bidirectional text laid out from left to right.
+///
+/// ```rust,ignore
+/// let line = BidiLine::new("abc שלום", Direction::Auto, |_| 10.0);
+/// let caret = line.caret_x(4);
+/// let highlight = line.selection(2..8);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BidiLine {
+    runs: Vec<VisualRun>,
+
+    /// Characters in logical order.
+    chars: Vec<Placed>,
+
+    rtl: bool,
+    width: f32,
+    len: usize,
+}
+
+impl BidiLine {
+    /// Lays out `text` with the given base direction, measuring each
+    /// character with `advance`.
+    ///
+    /// The text is expected to hold a single line. Paragraph separators
+    /// within it start new paragraphs, which are placed one after another
+    /// in the base direction of the first.
+    pub fn new(text: &str, direction: Direction, advance: impl Fn(char) -> f32) -> Self {
+        let level = match direction {
+            Direction::Auto => None,
+            Direction::Ltr => Some(Level::ltr()),
+            Direction::Rtl => Some(Level::rtl()),
+        };
+        let info = BidiInfo::new(text, level);
+        let rtl = info
+            .paragraphs
+            .first()
+            .map_or(direction == Direction::Rtl, |p| p.level.is_rtl());
+
+        let mut paragraphs: Vec<_> = info.paragraphs.iter().collect();
+        if rtl {
+            paragraphs.reverse();
+        }
+        let mut runs = Vec::new();
+        let mut chars = Vec::new();
+        let mut x = 0.0;
+        for paragraph in paragraphs {
+            let (levels, visual) = info.visual_runs(paragraph, paragraph.range.clone());
+            for range in visual {
+                let run_rtl = levels[range.start].is_rtl();
+                let start = x;
+                let mut placed: Vec<(usize, char)> = text[range.clone()]
+                    .char_indices()
+                    .map(|(i, c)| (range.start + i, c))
+                    .collect();
+                if run_rtl {
+                    placed.reverse();
+                }
+                for (index, c) in placed {
+                    let width = advance(c);
+                    chars.push(Placed {
+                        index,
+                        len: c.len_utf8(),
+                        left: x,
+                        right: x + width,
+                        rtl: run_rtl,
+                    });
+                    x += width;
+                }
+                runs.push(VisualRun {
+                    range,
+                    rtl: run_rtl,
+                    x: start,
+                    width: x - start,
+                });
+            }
+        }
+        chars.sort_by_key(|c| c.index);
+        Self {
+            runs,
+            chars,
+            rtl,
+            width: x,
+            len: text.len(),
+        }
+    }
+
+    /// Returns the runs of the line from left to right.
+    pub fn runs(&self) -> &[VisualRun] {
+        &self.runs
+    }
+
+    /// Returns `true` if the base direction of the line is right to left,
+    /// so it is aligned to the right by default.
+    pub fn is_rtl(&self) -> bool {
+        self.rtl
+    }
+
+    /// Returns the width of the line.
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Returns the position of a caret before the character at byte offset
+    /// `index`, or after the last character if `index` is the length of
+    /// the text.
+    ///
+    /// At the boundary between runs of opposite directions, the caret is
+    /// placed at the leading edge of the character after it.
+    pub fn caret_x(&self, index: usize) -> f32 {
+        match self.chars.iter().find(|c| c.index >= index) {
+            Some(c) if c.rtl => c.right,
+            Some(c) => c.left,
+            None => match self.chars.last() {
+                Some(c) if c.rtl => c.left,
+                Some(c) => c.right,
+                None if self.rtl => self.width,
+                None => 0.0,
+            },
+        }
+    }
+
+    /// Returns the horizontal spans covered by the characters in the byte
+    /// range `range`, from left to right.
+    ///
+    /// A logically contiguous selection spanning runs of both directions
+    /// is visually split into several spans.
+    pub fn selection(&self, range: Range<usize>) -> Vec<Range<f32>> {
+        let mut extents: Vec<(f32, f32)> = self
+            .chars
+            .iter()
+            .filter(|c| c.index >= range.start && c.index + c.len <= range.end)
+            .map(|c| (c.left, c.right))
+            .collect();
+        extents.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut spans: Vec<Range<f32>> = Vec::new();
+        for (left, right) in extents {
+            match spans.last_mut() {
+                Some(span) if left <= span.end => span.end = span.end.max(right),
+                _ => spans.push(left..right),
+            }
+        }
+        spans
+    }
+
+    /// Returns the byte offset of the caret position closest to `x`, e.g.
+    /// where the pointer was pressed.
+    pub fn index_at(&self, x: f32) -> usize {
+        let Some(c) = self
+            .chars
+            .iter()
+            .find(|c| x >= c.left && x < c.right)
+            .or_else(|| {
+                // Outside the line: the character at the nearer end.
+                self.chars.iter().min_by(|a, b| {
+                    let distance = |c: &Placed| (c.left - x).abs().min((c.right - x).abs());
+                    distance(a).total_cmp(&distance(b))
+                })
+            })
+        else {
+            return 0;
+        };
+        let before = x < (c.left + c.right) / 2.0;
+        if before != c.rtl {
+            c.index
+        } else {
+            (c.index + c.len).min(self.len)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_mixed_runs_visually() {
+        // "abc " followed by three Hebrew letters (two bytes each).
+        let line = BidiLine::new("abc אבג", Direction::Ltr, |_| 1.0);
+        let runs = line.runs();
+        assert_eq!(runs.len(), 2);
+        assert!(!runs[0].rtl && runs[1].rtl);
+        assert_eq!(line.width(), 7.0);
+
+        // The first Hebrew letter is displayed rightmost.
+        assert_eq!(line.caret_x(4), 7.0);
+        assert_eq!(line.caret_x(line.len), 4.0);
+        assert_eq!(line.index_at(6.9), 4);
+    }
+
+    #[test]
+    fn splits_selections_across_directions() {
+        let line = BidiLine::new("אב cd", Direction::Auto, |_| 1.0);
+        assert!(line.is_rtl());
+
+        // Displayed as "cd בא": selecting the second Hebrew letter, the
+        // space, and "c" covers two separate spans.
+        let spans = line.selection(2..6);
+        assert_eq!(spans, vec![0.0..1.0, 2.0..4.0]);
+    }
+}
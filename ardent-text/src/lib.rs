@@ -1,3 +1,13 @@
+//! The `ardent_text` crate lays out text for rendering.
+//!
+//! [`bidi`] orders mixed left-to-right and right-to-left text for display
+//! and maps between text offsets and caret and selection positions, so
+//! Arabic and Hebrew UIs lay out correctly.
+
+pub mod bidi;
+
+pub use bidi::{BidiLine, Direction, VisualRun};
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
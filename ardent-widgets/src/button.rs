@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use ardent_core::event::Event;
+use ardent_core::layout::Dimension;
+use ardent_core::node::{NodeId, Role};
+use ardent_core::reactive::Signal;
+use ardent_core::scene::{NodeBuilder, Scene, SceneError};
+
+use crate::label::{self, text_size};
+use crate::theme::{Part, Theme, fill_color};
+
+/// A clickable button with a text.
+///
+/// ```rust,ignore
+/// let count = Signal::new(0);
+/// Button::new("Increment")
+///     .on_click({
+///         let count = count.clone();
+///         move || count.update(|count| *count += 1)
+///     })
+///     .build(&mut scene, root)?;
+/// ```
+#[derive(Clone)]
+pub struct Button {
+    text: String,
+    on_click: Option<Arc<dyn Fn() + Send + Sync>>,
+    theme: Theme,
+}
+
+impl Button {
+    /// Creates a button showing `text`, which is also its accessible name.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            on_click: None,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Sets the callback run when the button is clicked.
+    pub fn on_click<F>(mut self, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_click = Some(Arc::new(handler));
+        self
+    }
+
+    /// Sets the theme.
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Adds the button to the scene under `parent`, and returns its ID.
+    pub fn build(self, scene: &mut Scene, parent: NodeId) -> Result<NodeId, SceneError> {
+        let theme = self.theme;
+        let (padding_x, padding_y) = theme.padding;
+        let (text_width, text_height) = text_size(&self.text, theme.font_size);
+        let (width, height) = (text_width + 2.0 * padding_x, text_height + 2.0 * padding_y);
+
+        let hovered = Signal::new(false);
+        let on_click = self.on_click;
+        let id = NodeBuilder::rect()
+            .size(width, height)
+            .width(Dimension::Px(width))
+            .height(Dimension::Px(height))
+            .role(Role::Button)
+            .label(self.text.clone())
+            .on_event({
+                let hovered = hovered.clone();
+                move |event| match event {
+                    Event::Click => {
+                        if let Some(on_click) = &on_click {
+                            on_click();
+                        }
+                    }
+                    Event::PointerEnter => hovered.set(true),
+                    Event::PointerLeave => hovered.set(false),
+                    _ => {}
+                }
+            })
+            .with(|node| theme.apply(Part::ButtonBackground, node))
+            .child(label::text(&self.text, &theme).translate(padding_x, padding_y))
+            .build(scene, parent)?;
+
+        scene.bind(id, move |node| {
+            let color = if hovered.get() {
+                theme.accent_hover
+            } else {
+                theme.accent
+            };
+            fill_color(node, color);
+        })?;
+        Ok(id)
+    }
+}
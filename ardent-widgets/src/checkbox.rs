@@ -0,0 +1,132 @@
+use ardent_core::event::Event;
+use ardent_core::layout::Dimension;
+use ardent_core::node::{NodeId, Role};
+use ardent_core::reactive::Signal;
+use ardent_core::scene::{NodeBuilder, Scene, SceneError};
+
+use crate::label::{self, text_size};
+use crate::shapes::check_mark;
+use crate::theme::{Part, Theme, fill_color};
+
+/// A box that is checked and unchecked by clicking it or its text.
+///
+/// The state lives in a [`Signal`] shared with the application, which can
+/// also set it.
+///
+/// ```rust,ignore
+/// let enabled = Signal::new(true);
+/// Checkbox::new(enabled.clone())
+///     .text("Enabled")
+///     .build(&mut scene, root)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Checkbox {
+    checked: Signal<bool>,
+    text: Option<String>,
+    theme: Theme,
+}
+
+impl Checkbox {
+    /// Creates a checkbox showing and changing `checked`.
+    pub fn new(checked: Signal<bool>) -> Self {
+        Self {
+            checked,
+            text: None,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Sets the text shown next to the box, which is also its accessible
+    /// name.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets the theme.
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Adds the checkbox to the scene under `parent`, and returns its ID.
+    pub fn build(self, scene: &mut Scene, parent: NodeId) -> Result<NodeId, SceneError> {
+        let theme = self.theme;
+        let size = theme.control_size;
+        let hovered = Signal::new(false);
+        let (row, offset) = switch_row(
+            &self.checked,
+            &hovered,
+            self.text.as_deref(),
+            (size, size),
+            &theme,
+        );
+        let id = row.role(Role::CheckBox).build(scene, parent)?;
+
+        let checked = self.checked;
+        let mark = NodeBuilder::new()
+            .shape(check_mark(size))
+            .fill(theme.on_accent)
+            .with(|node| theme.apply(Part::CheckboxMark, node));
+        let check_box = NodeBuilder::rect()
+            .size(size, size)
+            .translate(0.0, offset)
+            .with(|node| theme.apply(Part::CheckboxBox, node))
+            .build(scene, id)?;
+        let mark = mark.build(scene, check_box)?;
+
+        scene.bind(check_box, {
+            let checked = checked.clone();
+            move |node| {
+                fill_color(node, theme.state_color(checked.get(), hovered.get()));
+            }
+        })?;
+        scene.bind(mark, move |node| {
+            node.style_mut().opacity = if checked.get() { 1.0 } else { 0.0 };
+        })?;
+        Ok(id)
+    }
+}
+
+/// Returns the clickable root shared by two-state widgets: an invisible
+/// area covering a control of size `control` followed by optional text,
+/// which flips `checked` on click and tracks `hovered`.
+///
+/// The caller adds the control as the first child, moved down by the
+/// returned offset to center it vertically.
+pub(crate) fn switch_row(
+    checked: &Signal<bool>,
+    hovered: &Signal<bool>,
+    text: Option<&str>,
+    control: (f32, f32),
+    theme: &Theme,
+) -> (NodeBuilder, f32) {
+    let (text_width, text_height) = text.map_or((0.0, 0.0), |text| {
+        let (width, height) = text_size(text, theme.font_size);
+        (theme.spacing + width, height)
+    });
+    let width = control.0 + text_width;
+    let height = control.1.max(text_height);
+
+    let mut row = NodeBuilder::rect()
+        .size(width, height)
+        .width(Dimension::Px(width))
+        .height(Dimension::Px(height))
+        .on_event({
+            let checked = checked.clone();
+            let hovered = hovered.clone();
+            move |event| match event {
+                Event::Click => checked.update(|checked| *checked = !*checked),
+                Event::PointerEnter => hovered.set(true),
+                Event::PointerLeave => hovered.set(false),
+                _ => {}
+            }
+        });
+    if let Some(text) = text {
+        row = row.label(text).child(
+            label::text(text, theme)
+                .translate(control.0 + theme.spacing, (height - text_height) / 2.0),
+        );
+    }
+    (row, (height - control.1) / 2.0)
+}
@@ -0,0 +1,63 @@
+use ardent_core::layout::Dimension;
+use ardent_core::node::{NodeId, Role};
+use ardent_core::scene::{NodeBuilder, Scene, SceneError};
+
+use crate::theme::{Part, Theme};
+
+/// Static text.
+///
+/// Text rendering is not available yet: a label takes up the space its
+/// text would need and exposes the text to assistive technologies, but
+/// draws nothing.
+///
+/// ```rust,ignore
+/// Label::new("Volume").build(&mut scene, root)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Label {
+    text: String,
+    theme: Theme,
+}
+
+impl Label {
+    /// Creates a label showing `text`.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// Sets the theme.
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Adds the label to the scene under `parent`, and returns its ID.
+    pub fn build(self, scene: &mut Scene, parent: NodeId) -> Result<NodeId, SceneError> {
+        text(&self.text, &self.theme)
+            .role(Role::Label)
+            .label(self.text)
+            .build(scene, parent)
+    }
+}
+
+/// Returns a node taking up the space of `text`, for use within other
+/// widgets, which carry the text as their accessible name.
+pub(crate) fn text(text: &str, theme: &Theme) -> NodeBuilder {
+    let (width, height) = text_size(text, theme.font_size);
+    NodeBuilder::new()
+        .width(Dimension::Px(width))
+        .height(Dimension::Px(height))
+        .with(|node| theme.apply(Part::Label, node))
+}
+
+/// Estimates the size of `text` at the given font size, from an average
+/// glyph width.
+pub(crate) fn text_size(text: &str, font_size: f32) -> (f32, f32) {
+    (
+        text.chars().count() as f32 * font_size * 0.55,
+        font_size * 1.25,
+    )
+}
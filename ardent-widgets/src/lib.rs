@@ -0,0 +1,45 @@
+//! The `ardent_widgets` crate provides basic controls built from scene
+//! nodes: [`Button`], [`Checkbox`], [`Slider`], [`Toggle`], and [`Label`].
+//!
+//! Each widget is configured like a builder and then added under a parent
+//! node as a small subtree with its own event handler. Widget state lives
+//! in [`Signal`](ardent_core::reactive::Signal)s shared with the
+//! application, and scene bindings keep the nodes in sync with it, so
+//! [`Scene::update_bindings`](ardent_core::scene::Scene::update_bindings)
+//! must run before each frame, as the app runner does.
+//!
+//! Colors and sizes come from a [`Theme`], whose [`style`](Theme::style)
+//! hook can further customize every [`Part`] a widget builds.
+//!
+//! ```rust,ignore
+//! use ardent_widgets::{Button, Checkbox, Theme};
+//!
+//! let theme = Theme::dark();
+//! let enabled = Signal::new(true);
+//! Checkbox::new(enabled.clone())
+//!     .text("Enabled")
+//!     .theme(&theme)
+//!     .build(&mut scene, root)?;
+//! Button::new("Save")
+//!     .on_click(|| println!("saved"))
+//!     .theme(&theme)
+//!     .build(&mut scene, root)?;
+//! ```
+//!
+//! Text rendering is not available yet, so text takes up its space and is
+//! exposed to assistive technologies, but is not drawn.
+
+mod button;
+mod checkbox;
+mod label;
+mod shapes;
+mod slider;
+mod theme;
+mod toggle;
+
+pub use button::Button;
+pub use checkbox::Checkbox;
+pub use label::Label;
+pub use slider::Slider;
+pub use theme::{Part, Theme};
+pub use toggle::Toggle;
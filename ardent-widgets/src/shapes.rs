@@ -0,0 +1,46 @@
+use ardent_core::shape::{Path, PathCommand, Shape};
+
+/// Handle length for approximating a quarter circle with a cubic Bézier
+/// curve, relative to the radius.
+const KAPPA: f32 = 0.552_284_8;
+
+/// Returns a circle of the given radius touching the origin's axes, i.e.
+/// centered on `(radius, radius)`.
+pub(crate) fn circle(radius: f32) -> Shape {
+    let (c, k) = (radius, radius * KAPPA);
+    let mut path = Path::new();
+    path.push(PathCommand::MoveTo(2.0 * c, c));
+    for (ctrl1, ctrl2, to) in [
+        ((2.0 * c, c + k), (c + k, 2.0 * c), (c, 2.0 * c)),
+        ((c - k, 2.0 * c), (0.0, c + k), (0.0, c)),
+        ((0.0, c - k), (c - k, 0.0), (c, 0.0)),
+        ((c + k, 0.0), (2.0 * c, c - k), (2.0 * c, c)),
+    ] {
+        path.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+    }
+    path.push(PathCommand::Close);
+    Shape::Path(path)
+}
+
+/// Returns a check mark filling a square of the given side length.
+pub(crate) fn check_mark(size: f32) -> Shape {
+    let points = [
+        (0.2, 0.52),
+        (0.32, 0.4),
+        (0.43, 0.51),
+        (0.7, 0.24),
+        (0.82, 0.36),
+        (0.43, 0.75),
+    ];
+    let mut path = Path::new();
+    for (i, (x, y)) in points.into_iter().enumerate() {
+        let (x, y) = (x * size, y * size);
+        path.push(if i == 0 {
+            PathCommand::MoveTo(x, y)
+        } else {
+            PathCommand::LineTo(x, y)
+        });
+    }
+    path.push(PathCommand::Close);
+    Shape::Path(path)
+}
@@ -0,0 +1,175 @@
+use std::sync::Mutex;
+
+use ardent_core::event::Event;
+use ardent_core::layout::Dimension;
+use ardent_core::node::{NodeId, Role};
+use ardent_core::reactive::Signal;
+use ardent_core::scene::{NodeBuilder, Scene, SceneError};
+use ardent_core::shape::{Rect, Shape};
+
+use crate::shapes::circle;
+use crate::theme::{Part, Theme, fill_color};
+
+/// A horizontal control selecting a number from a range by dragging.
+///
+/// The value lives in a [`Signal`] shared with the application, which can
+/// also set it. Values outside the range are shown clamped.
+///
+/// ```rust,ignore
+/// let volume = Signal::new(0.5);
+/// Slider::new(volume.clone())
+///     .step(0.1)
+///     .label("Volume")
+///     .build(&mut scene, root)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Slider {
+    value: Signal<f32>,
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+    length: f32,
+    label: Option<String>,
+    theme: Theme,
+}
+
+impl Slider {
+    /// Creates a slider showing and changing `value`, in the range
+    /// `[0.0, 1.0]` and 160 logical pixels long.
+    pub fn new(value: Signal<f32>) -> Self {
+        Self {
+            value,
+            min: 0.0,
+            max: 1.0,
+            step: None,
+            length: 160.0,
+            label: None,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Sets the smallest and largest value.
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Rounds dragged values to multiples of `step` above the minimum.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Sets the length of the slider in logical pixels.
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Sets the accessible name.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the theme.
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Adds the slider to the scene under `parent`, and returns its ID.
+    pub fn build(self, scene: &mut Scene, parent: NodeId) -> Result<NodeId, SceneError> {
+        let Self {
+            value,
+            min,
+            max,
+            step,
+            length,
+            label,
+            theme,
+        } = self;
+        let height = theme.control_size;
+        let track_height = (height / 4.0).max(2.0);
+        let track_y = (height - track_height) / 2.0;
+        // The thumb's center moves between half a thumb from either end.
+        let travel = (length - height).max(0.0);
+        let fraction = move |value: f32| {
+            if max > min {
+                ((value - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+
+        let hovered = Signal::new(false);
+        // Where the pointer would put the thumb, kept unclamped so dragging
+        // past an end and back does not shift the thumb against the pointer.
+        let dragged = Mutex::new(0.0);
+        let mut root = NodeBuilder::rect()
+            .size(length, height)
+            .width(Dimension::Px(length))
+            .height(Dimension::Px(height))
+            .role(Role::Slider)
+            .on_event({
+                let value = value.clone();
+                let hovered = hovered.clone();
+                move |event| match event {
+                    Event::DragStart => {
+                        let position = value.with_untracked(|&value| fraction(value)) * travel;
+                        *dragged.lock().unwrap_or_else(|err| err.into_inner()) = position;
+                    }
+                    Event::DragUpdate(dx, _) if travel > 0.0 => {
+                        let mut position = dragged.lock().unwrap_or_else(|err| err.into_inner());
+                        *position += dx;
+                        let mut new = min + (*position / travel).clamp(0.0, 1.0) * (max - min);
+                        if let Some(step) = step {
+                            new = (min + ((new - min) / step).round() * step).min(max);
+                        }
+                        value.set(new);
+                    }
+                    Event::PointerEnter => hovered.set(true),
+                    Event::PointerLeave => hovered.set(false),
+                    _ => {}
+                }
+            });
+        if let Some(label) = label {
+            root = root.label(label);
+        }
+        let id = root.build(scene, parent)?;
+
+        NodeBuilder::rect()
+            .size(length, track_height)
+            .translate(0.0, track_y)
+            .fill(theme.surface)
+            .with(|node| theme.apply(Part::SliderTrack, node))
+            .build(scene, id)?;
+        let fill = NodeBuilder::rect()
+            .translate(0.0, track_y)
+            .fill(theme.accent)
+            .with(|node| theme.apply(Part::SliderFill, node))
+            .build(scene, id)?;
+        let thumb = NodeBuilder::new()
+            .shape(circle(height / 2.0))
+            .with(|node| theme.apply(Part::SliderThumb, node))
+            .build(scene, id)?;
+
+        scene.bind(id, {
+            let value = value.clone();
+            move |node| node.set_value(value.get().to_string())
+        })?;
+        scene.bind(fill, {
+            let value = value.clone();
+            move |node| {
+                let width = fraction(value.get()) * travel + height / 2.0;
+                node.set_shape(Shape::Rect(Rect::new(width, track_height)));
+            }
+        })?;
+        scene.bind(thumb, move |node| {
+            node.transform_mut().translate = (fraction(value.get()) * travel, 0.0);
+            fill_color(node, theme.state_color(true, hovered.get()));
+        })?;
+        Ok(id)
+    }
+}
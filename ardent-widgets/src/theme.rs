@@ -0,0 +1,186 @@
+use std::fmt;
+use std::sync::Arc;
+
+use ardent_core::node::Node;
+use ardent_core::style::{Color, Fill};
+
+/// A node built by a widget, passed to the [`Theme::style`] hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Part {
+    /// The background of a [`Button`](crate::Button).
+    ButtonBackground,
+
+    /// The box of a [`Checkbox`](crate::Checkbox).
+    CheckboxBox,
+
+    /// The mark shown in a checked [`Checkbox`](crate::Checkbox).
+    CheckboxMark,
+
+    /// The groove of a [`Slider`](crate::Slider).
+    SliderTrack,
+
+    /// The part of a [`Slider`](crate::Slider) groove below the value.
+    SliderFill,
+
+    /// The handle dragged along a [`Slider`](crate::Slider).
+    SliderThumb,
+
+    /// The background of a [`Toggle`](crate::Toggle).
+    ToggleTrack,
+
+    /// The knob moving across a [`Toggle`](crate::Toggle).
+    ToggleKnob,
+
+    /// A [`Label`](crate::Label), on its own or as part of another widget.
+    Label,
+}
+
+/// A callback customizing the nodes built by widgets.
+type StyleHook = Arc<dyn Fn(Part, &mut Node) + Send + Sync>;
+
+/// Colors and metrics shared by widgets.
+///
+/// Fields can be changed directly; [`Theme::light`] and [`Theme::dark`]
+/// provide starting points. For changes beyond colors and sizes, e.g. other
+/// shapes or strokes, register a [`style`](Theme::style) hook.
+#[derive(Clone)]
+pub struct Theme {
+    /// Color of active parts, e.g. buttons, checked boxes, and slider fills.
+    pub accent: Color,
+
+    /// Color of active parts under the pointer.
+    pub accent_hover: Color,
+
+    /// Color of content drawn on top of the accent, e.g. check marks and
+    /// knobs.
+    pub on_accent: Color,
+
+    /// Color of inactive parts, e.g. unchecked boxes and slider tracks.
+    pub surface: Color,
+
+    /// Color of inactive parts under the pointer.
+    pub surface_hover: Color,
+
+    /// Color of text.
+    pub text: Color,
+
+    /// Height of text in logical pixels.
+    pub font_size: f32,
+
+    /// Side length of checkboxes and height of toggles and slider thumbs.
+    pub control_size: f32,
+
+    /// Horizontal and vertical space between a button's edge and its text.
+    pub padding: (f32, f32),
+
+    /// Space between a control and its text.
+    pub spacing: f32,
+
+    style: Option<StyleHook>,
+}
+
+impl Theme {
+    /// Returns a theme for light backgrounds.
+    pub fn light() -> Self {
+        Self {
+            accent: Color::rgb(0.2, 0.5, 0.8),
+            accent_hover: Color::rgb(0.26, 0.58, 0.9),
+            on_accent: Color::white(),
+            surface: Color::rgb(0.85, 0.86, 0.88),
+            surface_hover: Color::rgb(0.78, 0.8, 0.83),
+            text: Color::rgb(0.1, 0.1, 0.12),
+            font_size: 14.0,
+            control_size: 18.0,
+            padding: (12.0, 6.0),
+            spacing: 8.0,
+            style: None,
+        }
+    }
+
+    /// Returns a theme for dark backgrounds.
+    pub fn dark() -> Self {
+        Self {
+            accent: Color::rgb(0.3, 0.6, 0.95),
+            accent_hover: Color::rgb(0.4, 0.68, 1.0),
+            on_accent: Color::rgb(0.05, 0.07, 0.1),
+            surface: Color::rgb(0.24, 0.25, 0.28),
+            surface_hover: Color::rgb(0.3, 0.32, 0.36),
+            text: Color::rgb(0.92, 0.93, 0.95),
+            ..Self::light()
+        }
+    }
+
+    /// Sets a hook run on every node a widget builds, after the theme's
+    /// colors and sizes are applied.
+    ///
+    /// The hook runs once, when the widget is built. Widgets keep updating
+    /// the fill of parts that change with their state, e.g. on hover, so
+    /// such changes belong in the theme's colors.
+    ///
+    /// ```rust,ignore
+    /// let theme = Theme::dark().style(|part, node| {
+    ///     if part == Part::ButtonBackground {
+    ///         node.style_mut().stroke = Some(Stroke::new(Color::white(), 1.0));
+    ///     }
+    /// });
+    /// ```
+    pub fn style<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(Part, &mut Node) + Send + Sync + 'static,
+    {
+        self.style = Some(Arc::new(hook));
+        self
+    }
+
+    /// Returns the fill of a part that is active or inactive, e.g. checked
+    /// or unchecked, and possibly under the pointer.
+    pub(crate) fn state_color(&self, active: bool, hovered: bool) -> Color {
+        match (active, hovered) {
+            (true, false) => self.accent,
+            (true, true) => self.accent_hover,
+            (false, false) => self.surface,
+            (false, true) => self.surface_hover,
+        }
+    }
+
+    /// Runs the style hook, if any, on a node built for `part`.
+    pub(crate) fn apply(&self, part: Part, node: &mut Node) {
+        if let Some(hook) = &self.style {
+            hook(part, node);
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl fmt::Debug for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Theme")
+            .field("accent", &self.accent)
+            .field("accent_hover", &self.accent_hover)
+            .field("on_accent", &self.on_accent)
+            .field("surface", &self.surface)
+            .field("surface_hover", &self.surface_hover)
+            .field("text", &self.text)
+            .field("font_size", &self.font_size)
+            .field("control_size", &self.control_size)
+            .field("padding", &self.padding)
+            .field("spacing", &self.spacing)
+            .field("style", &self.style.is_some())
+            .finish()
+    }
+}
+
+/// Sets the color of a node's fill, keeping other fill properties a style
+/// hook may have set.
+pub(crate) fn fill_color(node: &mut Node, color: Color) {
+    node.style_mut()
+        .fill
+        .get_or_insert_with(|| Fill::solid(color))
+        .color = color;
+}
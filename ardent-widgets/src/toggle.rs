@@ -0,0 +1,98 @@
+use ardent_core::node::{NodeId, Role};
+use ardent_core::reactive::Signal;
+use ardent_core::scene::{NodeBuilder, Scene, SceneError};
+
+use crate::checkbox::switch_row;
+use crate::shapes::circle;
+use crate::theme::{Part, Theme, fill_color};
+
+/// Space between a toggle's knob and the edge of its track.
+const KNOB_INSET: f32 = 2.0;
+
+/// A switch turned on and off by clicking it or its text.
+///
+/// Behaves like a [`Checkbox`](crate::Checkbox), drawn as a track with a
+/// knob that sits on the right while on.
+///
+/// ```rust,ignore
+/// let dark_mode = Signal::new(false);
+/// Toggle::new(dark_mode.clone())
+///     .text("Dark mode")
+///     .build(&mut scene, root)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Toggle {
+    on: Signal<bool>,
+    text: Option<String>,
+    theme: Theme,
+}
+
+impl Toggle {
+    /// Creates a toggle showing and changing `on`.
+    pub fn new(on: Signal<bool>) -> Self {
+        Self {
+            on,
+            text: None,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Sets the text shown next to the toggle, which is also its accessible
+    /// name.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets the theme.
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Adds the toggle to the scene under `parent`, and returns its ID.
+    pub fn build(self, scene: &mut Scene, parent: NodeId) -> Result<NodeId, SceneError> {
+        let theme = self.theme;
+        let height = theme.control_size;
+        let width = height * 1.8;
+        let hovered = Signal::new(false);
+        let (row, offset) = switch_row(
+            &self.on,
+            &hovered,
+            self.text.as_deref(),
+            (width, height),
+            &theme,
+        );
+        // Assistive technologies know switches as checkboxes.
+        let id = row.role(Role::CheckBox).build(scene, parent)?;
+
+        let on = self.on;
+        let knob_size = height - 2.0 * KNOB_INSET;
+        let knob = NodeBuilder::new()
+            .shape(circle(knob_size / 2.0))
+            .fill(theme.on_accent)
+            .with(|node| theme.apply(Part::ToggleKnob, node));
+        let track = NodeBuilder::rect()
+            .size(width, height)
+            .translate(0.0, offset)
+            .with(|node| theme.apply(Part::ToggleTrack, node))
+            .build(scene, id)?;
+        let knob = knob.build(scene, track)?;
+
+        scene.bind(track, {
+            let on = on.clone();
+            move |node| {
+                fill_color(node, theme.state_color(on.get(), hovered.get()));
+            }
+        })?;
+        scene.bind(knob, move |node| {
+            let x = if on.get() {
+                width - KNOB_INSET - knob_size
+            } else {
+                KNOB_INSET
+            };
+            node.transform_mut().translate = (x, KNOB_INSET);
+        })?;
+        Ok(id)
+    }
+}
@@ -0,0 +1,52 @@
+//! The `ardent` crate bundles the toolkit behind a single dependency.
+//!
+//! It re-exports the scene graph of `ardent_core` at the top level, merges
+//! the layout types with the layout pass of `ardent_layout` into
+//! [`layout`], and exposes the renderer, input dispatch, windowed app
+//! runner, and basic widgets as [`render`], [`input`], [`app`], and
+//! [`widgets`]. The [`prelude`] covers
+//! everything needed to build and show a scene.
+//!
+//! ```rust,ignore
+//! use ardent::prelude::*;
+//!
+//! fn main() -> Result<(), AppError> {
+//!     App::run(|scene| {
+//!         let root = scene.root();
+//!         NodeBuilder::rect()
+//!             .size(200.0, 100.0)
+//!             .fill("#3380cc")
+//!             .build(scene, root)
+//!             .expect("the root node is always in the scene");
+//!     })
+//! }
+//! ```
+//!
+//! The `app` feature, enabled by default, pulls in the `winit` runner;
+//! disable it to embed the renderer in another application. The `png`,
+//! `taffy`, `parallel`, `serde`, `tracing`, and `debug-validate` features
+//! enable the features of the same name in the underlying crates.
+
+#[cfg(feature = "app")]
+pub use ardent_app as app;
+pub use ardent_core::{
+    animation, clipboard, event, node, reactive, scene, shape, style, transform, view,
+};
+pub use ardent_input as input;
+pub use ardent_render as render;
+pub use ardent_widgets as widgets;
+
+/// Layout properties of nodes and the layout pass that resolves them.
+pub mod layout {
+    pub use ardent_core::layout::*;
+    pub use ardent_layout::*;
+}
+
+pub mod prelude {
+    pub use ardent_core::prelude::*;
+    pub use ardent_layout::{LayoutExt, compute_layout};
+    pub use ardent_render::prelude::*;
+
+    #[cfg(feature = "app")]
+    pub use ardent_app::{App, AppBuilder, AppContext, AppError, AppWindow, RedrawPolicy};
+}